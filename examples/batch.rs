@@ -0,0 +1,47 @@
+//! Sequential vs parallel batch generation.
+//!
+//! Run with: `cargo run --release --example batch --features rayon`
+//!
+//! Generates the same batch of scenarios with `generate_batch` and
+//! `generate_batch_par`, checks that both give identical output, and prints
+//! the wall-clock time of each.  Requests are independent, so the parallel
+//! version should scale with the number of cores; on a single core the two
+//! times are about equal.
+
+use std::time::Instant;
+
+use poker_drill_gen::{generate_batch, generate_batch_par, DifficultyLevel, TrainingRequest, TrainingTopic};
+
+/// Scenarios per topic.
+const PER_TOPIC: u64 = 2_000;
+
+fn requests() -> Vec<TrainingRequest> {
+    TrainingTopic::ALL
+        .into_iter()
+        .flat_map(|topic| {
+            (0..PER_TOPIC).map(move |seed| TrainingRequest {
+                difficulty: DifficultyLevel::Advanced,
+                rng_seed: Some(seed),
+                ..TrainingRequest::new(topic)
+            })
+        })
+        .collect()
+}
+
+fn main() {
+    let n = requests().len();
+    println!("{n} scenarios on {} threads", rayon::current_num_threads());
+
+    let start = Instant::now();
+    let sequential = generate_batch(requests()).expect("generation failed");
+    let seq_time = start.elapsed();
+    println!("generate_batch     {seq_time:>10.2?}");
+
+    let start = Instant::now();
+    let parallel = generate_batch_par(requests()).expect("generation failed");
+    let par_time = start.elapsed();
+    println!("generate_batch_par {par_time:>10.2?}");
+
+    assert_eq!(sequential, parallel, "parallel output differs");
+    println!("speedup            {:>9.2}x", seq_time.as_secs_f64() / par_time.as_secs_f64());
+}
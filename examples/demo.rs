@@ -1,4 +1,4 @@
-//! Full demo of all 16 training topics.
+//! Full demo of all 38 training topics.
 //!
 //! Run with: `cargo run --example demo`
 //!
@@ -8,7 +8,7 @@
 //!    (same seed = same cards) in Simple and Technical mode, showing how the
 //!    wording changes while the game logic stays identical.
 //!
-//! 2. **All 16 topics** — one scenario per topic in Simple mode with fixed
+//! 2. **All 38 topics** — one scenario per topic in Simple mode with fixed
 //!    seeds, so the output is deterministic and reproducible.
 //!
 //! ## Key concepts demonstrated
@@ -35,7 +35,9 @@ fn print_scenario(topic: TrainingTopic, seed: u64, style: TextStyle) {
         difficulty: DifficultyLevel::Intermediate,
         rng_seed: Some(seed),
         text_style: style,
-    });
+        is_bounty: false,
+        excluded_topics: Vec::new(),
+    }).unwrap();
 
     let ts = &scenario.table_setup;
     let style_label = match style {
@@ -74,9 +76,9 @@ fn main() {
     println!();
     println!("══ Minimal API: TrainingRequest::new() ══");
     println!();
-    let s1 = generate_training(TrainingRequest::new(TrainingTopic::PreflopDecision));
+    let s1 = generate_training(TrainingRequest::new(TrainingTopic::PreflopDecision)).unwrap();
     println!("  Specific topic:  {}  ID: {}", s1.topic, s1.scenario_id);
-    let s2 = generate_training(TrainingRequest::new(Street::Flop));
+    let s2 = generate_training(TrainingRequest::new(Street::Flop)).unwrap();
     println!("  Random from Flop: {}  ID: {}", s2.topic, s2.scenario_id);
     println!();
 
@@ -89,11 +91,11 @@ fn main() {
     print_scenario(TrainingTopic::BluffSpot, 4004, TextStyle::Simple);
     print_scenario(TrainingTopic::BluffSpot, 4004, TextStyle::Technical);
 
-    // ── All 16 topics ────────────────────────────────────────────────────────
+    // ── All 38 topics ────────────────────────────────────────────────────────
     // One scenario per topic, fixed seed for reproducible output.
-    // Topics are ordered by their internal number (T1–T16).
+    // Topics are ordered by their internal number (T1–T38).
     println!();
-    println!("══ All 16 topics (Simple mode) ══");
+    println!("══ All 38 topics (Simple mode) ══");
     println!();
 
     let topics = [
@@ -113,6 +115,28 @@ fn main() {
         (TrainingTopic::RiverCallOrFold,          1414),
         (TrainingTopic::TurnProbeBet,             1515),
         (TrainingTopic::DelayedCbet,              1616),
+        (TrainingTopic::StackOffDecision,         1717),
+        (TrainingTopic::SetMining,                1818),
+        (TrainingTopic::ContinuationBetDefense,   1919),
+        (TrainingTopic::ThreeBetDefense,          2020),
+        (TrainingTopic::TripleBarrel,             2121),
+        (TrainingTopic::ExploitativePlay,         2222),
+        (TrainingTopic::ChipLeaderPlay,           2323),
+        (TrainingTopic::BlindsVsBlinds,           2424),
+        (TrainingTopic::ShortStackStrategy,       2525),
+        (TrainingTopic::BlindStealing,            2626),
+        (TrainingTopic::HeadsUpTournament,        2727),
+        (TrainingTopic::ProtectionBet,            2828),
+        (TrainingTopic::SuitedConnectors,         2929),
+        (TrainingTopic::PotControl,               3030),
+        (TrainingTopic::TurnDraw,                 3131),
+        (TrainingTopic::PokerMath,                3232),
+        (TrainingTopic::HandReading,              3333),
+        (TrainingTopic::BeginnerHandRankings,     3434),
+        (TrainingTopic::DrawCounting,             3535),
+        (TrainingTopic::FoldEquityBluff,          3636),
+        (TrainingTopic::LayDown,                  3737),
+        (TrainingTopic::WhenToGiveUp,             3838),
     ];
 
     for (topic, seed) in topics {
@@ -137,7 +161,9 @@ fn main() {
             difficulty: DifficultyLevel::Intermediate,
             rng_seed: Some(seed),
             text_style: TextStyle::Simple,
-        });
+            is_bounty: false,
+            excluded_topics: Vec::new(),
+        }).unwrap();
         println!("  Street: {street}  →  Topic picked: {}  ID: {}",
             scenario.topic, scenario.scenario_id);
         println!("  Q: {}", scenario.question);
@@ -3,7 +3,7 @@
 //! Run with: `cargo run --example topics`
 //!
 //! This example is designed as a reference card — it shows what each of the
-//! 16 training topics teaches, with a concrete scenario generated from a fixed
+//! 38 training topics teaches, with a concrete scenario generated from a fixed
 //! seed (deterministic, always the same output).
 //!
 //! Each block shows:
@@ -132,6 +132,154 @@ fn topics() -> Vec<TopicMeta> {
                       bet medium with strong hands for value, small with medium hands on \
                       blank turns, and check weak hands or medium hands on scare cards.",
         },
+        TopicMeta {
+            topic: TrainingTopic::StackOffDecision,
+            seed: 1717,
+            teaches: "Stacking off at low SPR: compare the equity needed to call a shove after \
+                      betting with the hand's equity against a stacking range, commit strong \
+                      hands and bet-fold the rest.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::SetMining,
+            seed: 1818,
+            teaches: "Set mining with small pairs: call an open only when the effective stack is \
+                      at least ten times the call, so the sets you flop pay for the misses.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::ContinuationBetDefense,
+            seed: 1919,
+            teaches: "Defending against a flop c-bet from the Big Blind: check-raise strong hands on \
+                      low boards and combo draws, fold air on high boards, and call the rest \
+                      to meet the minimum defense frequency.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::ThreeBetDefense,
+            seed: 2020,
+            teaches: "Facing a 3-bet from the blinds after opening late: 4-bet premiums, call \
+                      strong and playable hands in position against wide ranges, and fold \
+                      the rest.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::TripleBarrel,
+            seed: 2121,
+            teaches: "Triple barrelling: after betting flop and turn, bet the river with value \
+                      hands and missed draws on good cards, and check hands with showdown value \
+                      or no bluffing story.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::ExploitativePlay,
+            seed: 2222,
+            teaches: "Exploiting villain tendencies: bluff over-folders, value bet calling stations \
+                      thinner and stop bluffing them, and fold bluff-catchers to players who \
+                      never bluff or only overbet the nuts.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::ChipLeaderPlay,
+            seed: 2323,
+            teaches: "As the tournament chip leader: call short stacks wide, since they risk \
+                      elimination and you risk little, but fold marginal hands to the other \
+                      big stack.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::BlindsVsBlinds,
+            seed: 2424,
+            teaches: "Small blind against the big blind alone: raise hands that are ahead, \
+                      complete marginal hands for the discount and check the flop, fold \
+                      trash — remembering the SB acts first after the flop.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::ShortStackStrategy,
+            seed: 2525,
+            teaches: "Cash-game push/fold with 10–19 BB: no ICM, so shove whenever chip EV \
+                      says so — wider from late position and with shorter stacks.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::BlindStealing,
+            seed: 2626,
+            teaches: "Stealing from CO, BTN and SB: open every hand inside the seat's range \
+                      (about 27% / 43% / 40%), not just premiums, because the blinds fold \
+                      so often.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::HeadsUpTournament,
+            seed: 2727,
+            teaches: "Heads-up SNG push/fold: ranges are far wider than at a full table — any \
+                      pair, any ace, most kings and broadways shove — and winner-take-all \
+                      means no ICM.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::ProtectionBet,
+            seed: 2828,
+            teaches: "Protection betting with top pair: charge draws on wet boards — bigger \
+                      with more draws — and check dry boards where a free card rarely \
+                      hurts.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::SuitedConnectors,
+            seed: 2929,
+            teaches: "Suited connectors facing an open: call in position or from the BB when \
+                      stacks are 20× the call, fold from the SB or when shallow — never \
+                      3-bet for value.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::PotControl,
+            seed: 3030,
+            teaches: "Pot control with weak top pair: check back at SPR 3–6, where a bet can \
+                      only be bet-fold, and bet below SPR 2, where the stack is committed.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::TurnDraw,
+            seed: 3131,
+            teaches: "Drawing on the turn: with one card to come a draw has about half its \
+                      flop equity (rule of 2) — call only small bets, fold to big ones, \
+                      raise combo draws.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::PokerMath,
+            seed: 3232,
+            teaches: "Poker math: count combos with C(n,k), price bets as required equity, and \
+                      know the flop and draw odds — with the usual misconceptions as the \
+                      wrong answers.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::HandReading,
+            seed: 3333,
+            teaches: "Hand reading: three streets of value mean two pair or better, two streets \
+                      then a check mean top pair, calling down means a medium pair, and a \
+                      river lead when the draw misses is a bluff.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::BeginnerHandRankings,
+            seed: 3434,
+            teaches: "Hand rankings: category first (a flush beats a straight), then ranks in \
+                      showdown order when the category matches; suits never break ties.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::DrawCounting,
+            seed: 3535,
+            teaches: "Draw counting: list the cards that complete the draw — 4 for a gutshot, 8 \
+                      for an open-ender, 9 for a flush draw, 15 for both — then apply the \
+                      rule of 2 and 4.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::FoldEquityBluff,
+            seed: 3636,
+            teaches: "Fold equity: a semi-bluff raise wins the pot when villain folds and keeps \
+                      draw equity when called — raise when the fold frequency beats \
+                      break-even.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::LayDown,
+            seed: 3737,
+            teaches: "Lay down: a big river check-raise is weighted to the nuts — fold strong \
+                      but non-nut hands unless the price is small.",
+        },
+        TopicMeta {
+            topic: TrainingTopic::WhenToGiveUp,
+            seed: 3838,
+            teaches: "When to give up: a player who called flop and turn holds a made hand — \
+                      check air on the river unless your cards block the nuts.",
+        },
     ]
 }
 
@@ -145,7 +293,9 @@ fn print_example(meta: &TopicMeta) {
         difficulty: DifficultyLevel::Beginner,
         rng_seed: Some(meta.seed),
         text_style: TextStyle::Simple,
-    });
+        is_bounty: false,
+        excluded_topics: Vec::new(),
+    }).unwrap();
 
     let ts = &scenario.table_setup;
 
@@ -239,7 +389,7 @@ fn main() {
     }
 
     divider('═', 66);
-    println!("  16 topics shown.  Run 'cargo run --example demo' for the");
+    println!("  38 topics shown.  Run 'cargo run --example demo' for the");
     println!("  full randomised demo.");
     divider('═', 66);
     println!();
@@ -2,7 +2,7 @@
 //!
 //! A fully offline, deterministic poker training scenario generator.
 //!
-//! This library generates randomised poker training scenarios across 16 topics
+//! This library generates randomised poker training scenarios across 38 topics
 //! covering all four streets (preflop, flop, turn, river). Each scenario
 //! includes a concrete hand situation, a multiple-choice question, and
 //! per-option explanations so players understand the *why* behind each decision.
@@ -15,7 +15,7 @@
 //!    classifies the situation (hand strength, board texture, draw type, etc.),
 //!    picks the correct answer based on poker strategy, and builds dynamic
 //!    explanations for every option.
-//! 3. The returned [`TrainingScenario`] contains the full table state, question,
+//! 3. The returned `Ok(`[`TrainingScenario`]`)` contains the full table state, question,
 //!    and answer options — ready to display in any UI.
 //!
 //! ## Key features
@@ -36,7 +36,7 @@
 //! };
 //!
 //! // Minimal — only topic is required (defaults: Beginner, entropy, Simple):
-//! let scenario = generate_training(TrainingRequest::new(TrainingTopic::PreflopDecision));
+//! let scenario = generate_training(TrainingRequest::new(TrainingTopic::PreflopDecision))?;
 //! println!("Q: {}", scenario.question);
 //!
 //! // Full control — set every field:
@@ -45,7 +45,9 @@
 //!     difficulty: DifficultyLevel::Intermediate,
 //!     rng_seed: Some(42),
 //!     text_style: TextStyle::Technical,
-//! });
+//!     is_bounty: false,
+//!     excluded_topics: Vec::new(),
+//! })?;
 //!
 //! println!("Scenario: {}", scenario.scenario_id);
 //! for ans in &scenario.answers {
@@ -54,19 +56,31 @@
 //! }
 //!
 //! // Random topic from a street:
-//! let flop_drill = generate_training(TrainingRequest::new(Street::Flop));
+//! let flop_drill = generate_training(TrainingRequest::new(Street::Flop))?;
 //! println!("Random flop drill: {}", flop_drill.topic);
+//! # Ok::<(), poker_drill_gen::GenerationError>(())
 //! ```
 
 pub mod training_engine;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 // Convenience re-exports so callers can use `poker_drill_gen::generate_training`
 // directly without reaching into `training_engine::`.
 pub use training_engine::{
-    generate_training, AnswerOption, DifficultyLevel, GameType,
-    PlayerState, Position, Street, TableSetup, TextStyle, TopicSelector,
-    TrainingRequest, TrainingScenario, TrainingTopic,
+    anki_deck_from_scenarios, generate_batch, generate_curriculum, generate_hand_sequence,
+    generate_training, generate_until, topics_for_concept, ActionSequence, AdaptiveSession,
+    AnswerOption, AnswerRating, BalancedSelector, BetSize, CurriculumLevel, DifficultyLevel,
+    GameType, GenerationError, HandHistory, HandHistoryEntry, ParseRequestError, PlayerState,
+    Position, ScenarioFilter, Street, TableSetup, TextStyle, TopicSelector, TrainingRequest,
+    TrainingRequestBuilder, TrainingScenario, TrainingTopic, ValidationError, VillainAction,
+    VillainTendency,
 };
+#[cfg(feature = "rayon")]
+pub use training_engine::generate_batch_par;
+#[cfg(feature = "bincode")]
+pub use training_engine::generate_training_bytes;
+pub use training_engine::evaluator::{count_outs, normalized_hand_notation};
 
 #[cfg(test)]
 mod tests;
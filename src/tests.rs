@@ -2,24 +2,62 @@
 //!
 //! Included from `lib.rs` under `#[cfg(test)]`.
 //!
-//! # Coverage (49 tests)
+//! # Coverage (191 tests with default features)
 //!
 //! | Group | What is tested |
 //! |-------|----------------|
 //! | Determinism | Same seed → identical output; different seeds → varied output |
 //! | Structural | One correct answer; ≥2 answers; non-empty explanations; ID prefixes; non-empty branch keys |
-//! | Deck integrity | Hero cards absent from board; board cards unique |
-//! | Per-topic | Street (board card count), game type, hero position, bet presence |
+//! | Deck integrity | Hero cards absent from board; board cards unique and sized for the street; `Deck::with_excluded` never deals an excluded card; `MockDeck` fixes the cards a topic deals; `deal_community_cards` / `deal_additional_cards` sizes for every street, panic on a longer board; `deal_matching` and `Deck::put_on_top` keep the other cards in order |
+//! | Per-topic | Street (board card count), game type, hero position, bet presence; T18 always deals 22–66 and calls only when the stack is ≥ 10× the call; T19 decides like T7 on the same deal; T20 hero opens CO/BTN against a blind 3-bet; T23 is a tournament spot where hero covers everyone twice, calls short stacks wide and folds marginal hands to the big stack; T24 is SB vs BB alone on an empty board, with a fourth flop-plan option above Beginner; T25 hero always has 10–19 BB effective in a cash game; T26 steals every hand inside the CO / BTN / SB opening width, including marginal and trash categories; T27 is a two-player tournament with hero on the BTN, where any pair or ace shoves; T28 hero pairs the top flop card, acts first, and sizes up with the board draw count; T29 always holds 54s–98s and folds from the SB; T30 hero holds top pair, weak kicker, and bets only below SPR 2; T31 hero holds a draw on a 4-card board facing a bet, priced on one-street equity; T32 answers match exact combinatorics and every question is drawn; T33 names one listed holding on an unpaired runout, facing a bet only when villain bets the river; T34 picks the hand `compare_hands` ranks higher, different categories at Beginner and one category at Advanced; T35 answers the `count_outs` total for a flop draw, never a gutshot or combo draw at Beginner; T36 raises a turn draw exactly when `ev_of_raise` at the stated fold frequency is positive; T37 calls river min-raises and folds all-ins with a second-nut flush or top set; T38 checks river air after two calls, bluffing only with the nut-flush ace at Advanced; T21 barrels value and checks showdown hands |
 //! | Difficulty | All three levels produce valid scenarios |
 //! | Entropy | `rng_seed: None` produces a valid scenario (smoke test) |
 //! | TextStyle | Simple produces non-empty text; Simple ≠ Technical; correct answer unaffected by style |
-//! | Street selector | Street produces correct-street topic; deterministic; varies across seeds; round-trip |
-//! | ICM hand strength | Push/fold produces both pushes and folds across seeds |
-//! | Hand classification | classify_hand correctly categorises premium, strong, playable, marginal, trash |
+//! | Street selector | Street produces correct-street topic; deterministic; varies across seeds; round-trip; `next`/`prev` chain; excluded topics never picked, fully excluded street falls back to other streets |
+//! | ICM hand strength | Push/fold produces both pushes and folds across seeds; `nash_push_threshold_bb` ranks AA highest and 72o lowest at every seat, BTN widest; named in T5 Technical text |
+//! | Hand classification | classify_hand correctly categorises premium, strong, playable, marginal, trash; `HandCategory::from_hand` / `name` / `all`; `chen_score` and `starting_hand_percentile` order AA above AKs above T9s down to 72o; `DrawType` outs and equity |
+//! | Board texture score | Monotone connected ≥ 0.9, dry rainbow ≤ 0.1, mixed boards in between; c-bet frequency Dry > SemiWet > Wet, quoted in T2; only Wet is draw-heavy; `cbet_size_recommendation` grows with wetness and shrinks with SPR and OOP, drives T2 sizing; `range_advantage` favours openers on A/K-high boards and blinds / earlier seats on low ones, drives T2 `Dry:RangeAdv`; suit counts, rainbow / monotone predicates; paired / trips boards from 0 to 5 cards, paired flops noted in T2; `board_draw_count` flush suits + open-ended straight groups on 3–5 cards, raises complexity; `straight_possible` / `flush_possible` / `flush_on_board` on known boards incl. wheel and river, counted in T14; nut flush suit and draw incl. monotone boards, named in T4; backdoor flush / straight draws, named in T2 / T7; nut straight ranks and blocker score, blocked combos counted in T4 |
+//! | Hand vs board | Every `HandVsBoardStrength` variant on hand-crafted boards; board-only straight ignored; `hero_has_top_pair` / `overpair` / `set` / `two_pair`, pocket pair + board card is a set |
+//! | Outs | `count_outs` for flush, OESD, gutshot, combo and backdoor draws; `classify_draw_combined` lists hero's straight then flush draw, none for made hands or the river; `equity_of_combined_draws` counts shared outs once; seen cards and river excluded; `equity_from_outs` vs rule of 2 and 4 |
+//! | Bet-to-pot math | Break-even equity, MDF, max bluff frequency, pot-odds ratio incl. zero bet; `calculate_pot_odds` ratio, break-even and `Display`; `adjusted_equity` IP ≥ OOP, gap widening with depth, T3 decides on OOP realized equity; balanced bluff share / combo count and the ±2 balance band, quoted in T4; used by T3/T4 text; `implied_odds` falls as implied gain grows; `set_mining_ev` follows the 5% rule; both quoted in T3 |
+//! | Expected value | `expected_value` / `ev_of_bet` / `ev_of_raise` break-even points; EV sign in T3/T4/T8 explanations agrees with the correct answer |
+//! | Range widths | Opening width by position; `PositionRange` 6- and 9-max tables widen HJ → CO → BTN, 9-max backs `villain_range_width`, 6-max quoted in T1 opens; BB defence narrows vs early openers and big raises; quoted in T3 / T12; preflop equity vs the opening range puts AA above and 72o below every hand at every seat, rising as the opener widens |
+//! | Commitment | `pot_committed` around SPR 1.5; T1 3-bet pots and T13 explanations flag low SPR |
+//! | Ranges | `RangeString` combo / hand-type counts, `+` segments, `contains`, parse errors |
+//! | ICM | Equal stacks split equally; leader's equity < chip share; payouts sum to 1; bad tables rejected; `Custom` stage interpolates between fixed stages; PKO bounties only with `is_bounty`; antes add to the T5 pot; SNG bubble is 4 players of a 9 × 1500 field |
+//! | Five-card evaluation | Category ladder (royal flush > straight flush > quads …); kicker tie-breaks; best 5 of 7; `hand_description` for every category |
+//! | Request builder | Builder matches struct literal and `new()` defaults; missing topic panics |
+//! | Answer ratings | Only the correct option is rated `Correct`; `score_answer` follows ratings; T1 fold severity |
+//! | Hints | Correct answer carries a ≤ 40-word hint in both text styles |
+//! | Answer lookup | `correct_answer` / `is_answered_correctly` on all topics; panic names the scenario |
+//! | Card parsing | All 52 cards round-trip through `Display`/`FromStr`; case-insensitive; bad input errors; broadway / wheel cards, 6–9 neither; suit colours, `Suit::all` order, Unicode symbols |
+//! | Hand | Notation for suited, offsuit, pair and mis-ordered input, also via `normalized_hand_notation`; high/low card; array round-trip |
+//! | Table setup | `effective_stack` / `spr` on known stacks, including an all-in player; `helpers::pot_after_call` / `pot_after_raise` with zero pot or bet, `helpers::effective_stack` 0 when all are all-in; `street()` matches topic |
+//! | Validation | Every topic × seed passes `validate()`; a corrupted scenario reports each invariant, including a repeated hole card and board card; `generate_training` returns `Ok`, `GenerationError` messages |
+//! | Follow-up & related | Raise / call / fold on each street lead to the mapped next topic; option IDs resolve to their text; follow-ups generate; 2–4 related topics per topic, never itself, every topic reachable |
+//! | Key concepts | ≥ 2 non-empty concepts per topic × seed, topic concepts always present; T3 / T4 / T5 fixed lists; T1 3-bet pots add `spr`; `topics_for_concept` lookups |
+//! | Scenario filter | `generate_until` finds SPR ≥ 5 and branch-key matches; first seed is the request's; impossible filters give up |
+//! | Scenario hash | Same seed → same hash; Simple and Technical hash alike; 320 deals hash apart |
+//! | Batch generation | `generate_batch` equals one-at-a-time generation, in order; `rayon` feature: `generate_batch_par` output is byte-identical |
+//! | Binary encoding | `bincode` feature: `to_bytes` is smaller than JSON and round-trips; `generate_training_bytes` matches |
+//! | Markdown | `to_markdown` is deterministic and holds the ID, every option and the correct explanation in `<details>` |
+//! | Anki export | One tab per row, back starts with the correct option; `#deck:PokerDrill` header once, one row per scenario |
+//! | CSV export | `to_csv_row` splits into as many fields as `csv_headers`, fourth column is the inferred difficulty, question round-trips through quoting, last column is the correct ID, no explanations |
+//! | Hand sequence | Same hole cards on all four streets; boards 0 / 3 / 4 / 5 extend each other; shared ID prefix; every topic combination either matches across streets or, for a topic that picks its own cards, is rejected; wrong-street topic is an `InvalidTopicConstraint` error |
+//! | Hand history | JSON round-trip equals the original; correct / incorrect counts skip unanswered entries |
+//! | Difficulty steps | `harder` / `easier` chain stops at each end; `as_u8` / `from_u8` round-trip |
+//! | Request parsing | `"Topic,Difficulty,seed,Style"` equals the struct literal; case and whitespace ignored; trailing fields optional; each bad field reported; topics parse from their prefix and display name |
+//! | Curriculum | Every level is non-empty and generates valid scenarios; Novice is 5 × T1 + 3 × T3 and shorter than Advanced; Advanced covers all topics; seeds fixed and distinct |
+//! | Complexity | Trash fold scores < 0.3, marginal river bluff-catcher > 0.7; every score within 0–1; drawier board scores higher |
+//! | Adaptive session | 5 correct in a row steps a topic up, 3 misses step it down, clamped at both ends; skips and trivial wins ignored; unanswered topics requested first |
+//! | Balanced selector | 38 consecutive requests cover every topic once; seeds count up from the offset; same offset → same schedule |
+//! | Stack-off | T17 SPR stays 1.5–4 on a flop with the BTN 3-bettor; sets always commit, weak top pair bet-folds above SPR 2; hero holds two broadway cards and at least a pair, and no plan is correct in more than two spots of three; shove price `(stack − bet) / (pot + 2 × stack)` |
+//! | Exploitative play | Every `VillainTendency` is drilled, the answer is never the balanced line (rated `Plausible`), leads carry a bet; `villain_tendency` round-trips and defaults to `None` in older JSON |
+//! | Positions | `all` / 6- and 9-max seat lists; early / middle / late / blind groups; `is_in_position_vs` for every pair |
 
 use crate::training_engine::{
-    generate_training, DifficultyLevel, GameType, Position, Street, TextStyle, TrainingRequest,
-    TrainingTopic,
+    generate_training, models::{Card, Hand, ParseCardError, Rank, Suit}, AnswerRating, DifficultyLevel, GameType,
+    PlayerState, Position, Street, TableSetup, TextStyle, TrainingRequest, TrainingTopic,
 };
 
 // ── helpers ──────────────────────────────────────────────────────────────────
@@ -33,11 +71,13 @@ fn req(topic: impl Into<crate::training_engine::TopicSelector>, seed: u64) -> Tr
         difficulty: DifficultyLevel::Intermediate,
         rng_seed: Some(seed),
         text_style: TextStyle::Simple,
+        is_bounty: false,
+        excluded_topics: Vec::new(),
     }
 }
 
 /// All sixteen training topics in canonical order.
-fn all_topics() -> [TrainingTopic; 16] {
+fn all_topics() -> [TrainingTopic; 38] {
     [
         TrainingTopic::PreflopDecision,
         TrainingTopic::PostflopContinuationBet,
@@ -55,6 +95,28 @@ fn all_topics() -> [TrainingTopic; 16] {
         TrainingTopic::RiverCallOrFold,
         TrainingTopic::TurnProbeBet,
         TrainingTopic::DelayedCbet,
+        TrainingTopic::StackOffDecision,
+        TrainingTopic::SetMining,
+        TrainingTopic::ContinuationBetDefense,
+        TrainingTopic::ThreeBetDefense,
+        TrainingTopic::TripleBarrel,
+        TrainingTopic::ExploitativePlay,
+        TrainingTopic::ChipLeaderPlay,
+        TrainingTopic::BlindsVsBlinds,
+        TrainingTopic::ShortStackStrategy,
+        TrainingTopic::BlindStealing,
+        TrainingTopic::HeadsUpTournament,
+        TrainingTopic::ProtectionBet,
+        TrainingTopic::SuitedConnectors,
+        TrainingTopic::PotControl,
+        TrainingTopic::TurnDraw,
+        TrainingTopic::PokerMath,
+        TrainingTopic::HandReading,
+        TrainingTopic::BeginnerHandRankings,
+        TrainingTopic::DrawCounting,
+        TrainingTopic::FoldEquityBluff,
+        TrainingTopic::LayDown,
+        TrainingTopic::WhenToGiveUp,
     ]
 }
 
@@ -66,8 +128,8 @@ const SEEDS: [u64; 5] = [1, 42, 999, 0xDEAD_BEEF, 7];
 #[test]
 fn same_seed_produces_identical_scenario() {
     for topic in all_topics() {
-        let a = generate_training(req(topic, 12345));
-        let b = generate_training(req(topic, 12345));
+        let a = generate_training(req(topic, 12345)).unwrap();
+        let b = generate_training(req(topic, 12345)).unwrap();
         assert_eq!(a.scenario_id, b.scenario_id, "scenario_id mismatch for {topic:?}");
         assert_eq!(a.question,    b.question,    "question mismatch for {topic:?}");
         assert_eq!(a.branch_key, b.branch_key,  "branch_key mismatch for {topic:?}");
@@ -88,8 +150,8 @@ fn different_seeds_produce_varied_questions() {
     let mut same_count = 0usize;
     let pairs = 40u64;
     for seed in 0..pairs {
-        let a = generate_training(req(TrainingTopic::PreflopDecision, seed));
-        let b = generate_training(req(TrainingTopic::PreflopDecision, seed + 500));
+        let a = generate_training(req(TrainingTopic::PreflopDecision, seed)).unwrap();
+        let b = generate_training(req(TrainingTopic::PreflopDecision, seed + 500)).unwrap();
         if a.question == b.question {
             same_count += 1;
         }
@@ -108,7 +170,9 @@ fn entropy_seed_produces_a_valid_scenario() {
         difficulty: DifficultyLevel::Intermediate,
         rng_seed: None,
         text_style: TextStyle::Simple,
-    });
+        is_bounty: false,
+        excluded_topics: Vec::new(),
+    }).unwrap();
     assert!(!s.scenario_id.is_empty());
     assert!(!s.question.is_empty());
     assert!(!s.branch_key.is_empty());
@@ -122,7 +186,7 @@ fn entropy_seed_produces_a_valid_scenario() {
 fn every_scenario_has_exactly_one_correct_answer() {
     for topic in all_topics() {
         for seed in SEEDS {
-            let scenario = generate_training(req(topic, seed));
+            let scenario = generate_training(req(topic, seed)).unwrap();
             let correct = scenario.answers.iter().filter(|a| a.is_correct).count();
             assert_eq!(
                 correct, 1,
@@ -136,7 +200,7 @@ fn every_scenario_has_exactly_one_correct_answer() {
 #[test]
 fn every_scenario_has_at_least_two_answers() {
     for topic in all_topics() {
-        let scenario = generate_training(req(topic, 42));
+        let scenario = generate_training(req(topic, 42)).unwrap();
         assert!(
             scenario.answers.len() >= 2,
             "{topic:?} must have at least 2 answer options (got {})",
@@ -148,7 +212,7 @@ fn every_scenario_has_at_least_two_answers() {
 #[test]
 fn every_answer_has_non_empty_text_and_explanation() {
     for topic in all_topics() {
-        let scenario = generate_training(req(topic, 77));
+        let scenario = generate_training(req(topic, 77)).unwrap();
         for ans in &scenario.answers {
             assert!(
                 !ans.text.is_empty(),
@@ -183,9 +247,31 @@ fn every_scenario_id_starts_with_topic_prefix() {
         (TrainingTopic::RiverCallOrFold,          "RF-"),
         (TrainingTopic::TurnProbeBet,             "PB-"),
         (TrainingTopic::DelayedCbet,              "DC-"),
+        (TrainingTopic::StackOffDecision,         "SO-"),
+        (TrainingTopic::SetMining,                "SM-"),
+        (TrainingTopic::ContinuationBetDefense,   "CD-"),
+        (TrainingTopic::ThreeBetDefense,          "TD-"),
+        (TrainingTopic::TripleBarrel,             "TR-"),
+        (TrainingTopic::ExploitativePlay,         "EX-"),
+        (TrainingTopic::ChipLeaderPlay,           "CL-"),
+        (TrainingTopic::BlindsVsBlinds,           "BV-"),
+        (TrainingTopic::ShortStackStrategy,       "SS-"),
+        (TrainingTopic::BlindStealing,            "ST-"),
+        (TrainingTopic::HeadsUpTournament,        "HU-"),
+        (TrainingTopic::ProtectionBet,            "PR-"),
+        (TrainingTopic::SuitedConnectors,         "SC-"),
+        (TrainingTopic::PotControl,               "PC-"),
+        (TrainingTopic::TurnDraw,                 "DR-"),
+        (TrainingTopic::PokerMath,                "PM-"),
+        (TrainingTopic::HandReading,              "HR-"),
+        (TrainingTopic::BeginnerHandRankings,     "RK-"),
+        (TrainingTopic::DrawCounting,             "OU-"),
+        (TrainingTopic::FoldEquityBluff,          "FE-"),
+        (TrainingTopic::LayDown,                  "LD-"),
+        (TrainingTopic::WhenToGiveUp,             "GU-"),
     ];
     for (topic, prefix) in expected_prefixes {
-        let s = generate_training(req(topic, 1));
+        let s = generate_training(req(topic, 1)).unwrap();
         assert!(
             s.scenario_id.starts_with(prefix),
             "ID '{}' for {topic:?} does not start with expected prefix '{prefix}'",
@@ -198,7 +284,7 @@ fn every_scenario_id_starts_with_topic_prefix() {
 fn every_scenario_has_non_empty_branch_key() {
     for topic in all_topics() {
         for seed in SEEDS {
-            let s = generate_training(req(topic, seed));
+            let s = generate_training(req(topic, seed)).unwrap();
             assert!(
                 !s.branch_key.is_empty(),
                 "Empty branch_key for {topic:?} seed={seed}"
@@ -210,8 +296,8 @@ fn every_scenario_has_non_empty_branch_key() {
 #[test]
 fn branch_key_is_deterministic() {
     for topic in all_topics() {
-        let a = generate_training(req(topic, 12345));
-        let b = generate_training(req(topic, 12345));
+        let a = generate_training(req(topic, 12345)).unwrap();
+        let b = generate_training(req(topic, 12345)).unwrap();
         assert_eq!(
             a.branch_key, b.branch_key,
             "branch_key is not deterministic for {topic:?}"
@@ -225,7 +311,7 @@ fn branch_key_is_deterministic() {
 fn hero_hand_cards_not_on_board() {
     for topic in all_topics() {
         for seed in [10u64, 20, 30, 40, 50] {
-            let s = generate_training(req(topic, seed));
+            let s = generate_training(req(topic, seed)).unwrap();
             let ts = &s.table_setup;
             for card in &ts.hero_hand {
                 assert!(
@@ -241,8 +327,13 @@ fn hero_hand_cards_not_on_board() {
 fn board_cards_are_unique() {
     for topic in all_topics() {
         for seed in SEEDS {
-            let s = generate_training(req(topic, seed));
+            let s = generate_training(req(topic, seed)).unwrap();
             let board = &s.table_setup.board;
+            assert_eq!(
+                board.len(),
+                topic.street().board_card_count(),
+                "{topic:?} seed={seed}: board length does not match street"
+            );
             let mut seen = std::collections::HashSet::new();
             for card in board {
                 // Use Display ("Ah", "Kc", …) as the deduplication key.
@@ -260,7 +351,7 @@ fn board_cards_are_unique() {
 #[test]
 fn hero_hand_is_always_two_cards() {
     for topic in all_topics() {
-        let s = generate_training(req(topic, 1));
+        let s = generate_training(req(topic, 1)).unwrap();
         assert_eq!(
             s.table_setup.hero_hand.len(), 2,
             "Hero hand for {topic:?} must always be exactly 2 cards"
@@ -268,6 +359,29 @@ fn hero_hand_is_always_two_cards() {
     }
 }
 
+#[test]
+fn mock_deck_fixes_the_cards_a_topic_deals() {
+    use crate::training_engine::{deck::MockDeck, topics};
+    use rand::SeedableRng;
+    let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+
+    let mut deck = MockDeck::new(cards("Ah Kh 7h 8h 2h"));
+    let s = topics::flop::generate_cbet(&mut rng, &mut deck, DifficultyLevel::Beginner, "CB-1".into(), TextStyle::Simple).unwrap();
+    assert_eq!(s.table_setup.hero_hand.to_vec(), cards("Ah Kh"));
+    assert_eq!(s.table_setup.board, cards("7h 8h 2h"));
+    assert!(s.validate().is_empty());
+
+    // Flop then turn, dealt in the order given.
+    let mut deck = MockDeck::new(cards("Qs Qd 9c 5d 2s Ah"));
+    let s = topics::turn::generate_barrel(&mut rng, &mut deck, DifficultyLevel::Beginner, "TB-1".into(), TextStyle::Simple).unwrap();
+    assert_eq!(s.table_setup.board, cards("9c 5d 2s Ah"));
+
+    // Too few cards is an error, not a panic.
+    let mut short = MockDeck::new(cards("Ah Kh 7h"));
+    let err = topics::river::generate_bluff(&mut rng, &mut short, DifficultyLevel::Beginner, "BL-1".into(), TextStyle::Simple);
+    assert_eq!(err.unwrap_err(), crate::training_engine::GenerationError::DeckExhausted);
+}
+
 // ── difficulty levels ─────────────────────────────────────────────────────────
 
 #[test]
@@ -283,7 +397,9 @@ fn all_difficulty_levels_produce_valid_scenarios() {
                 difficulty: diff,
                 rng_seed: Some(1),
                 text_style: TextStyle::Simple,
-            });
+                is_bounty: false,
+                excluded_topics: Vec::new(),
+            }).unwrap();
             assert!(!s.question.is_empty(), "{topic:?} at {diff:?} produced empty question");
             let correct = s.answers.iter().filter(|a| a.is_correct).count();
             assert_eq!(correct, 1, "{topic:?} at {diff:?} must have exactly 1 correct answer");
@@ -301,7 +417,9 @@ fn text_style_simple_produces_non_empty_text() {
             difficulty: DifficultyLevel::Intermediate,
             rng_seed: Some(42),
             text_style: TextStyle::Simple,
-        });
+            is_bounty: false,
+            excluded_topics: Vec::new(),
+        }).unwrap();
         assert!(
             !s.question.is_empty(),
             "Simple style produced empty question for {topic:?}"
@@ -329,13 +447,17 @@ fn text_style_technical_produces_different_text_than_simple() {
             difficulty: DifficultyLevel::Intermediate,
             rng_seed: Some(42),
             text_style: TextStyle::Simple,
-        });
+            is_bounty: false,
+            excluded_topics: Vec::new(),
+        }).unwrap();
         let technical = generate_training(TrainingRequest {
             topic: topic.into(),
             difficulty: DifficultyLevel::Intermediate,
             rng_seed: Some(42),
             text_style: TextStyle::Technical,
-        });
+            is_bounty: false,
+            excluded_topics: Vec::new(),
+        }).unwrap();
         assert_ne!(
             simple.question, technical.question,
             "Simple and Technical produced identical question for {topic:?} — \
@@ -353,13 +475,17 @@ fn text_style_does_not_affect_correct_answer() {
                 difficulty: DifficultyLevel::Intermediate,
                 rng_seed: Some(seed),
                 text_style: TextStyle::Simple,
-            });
+                is_bounty: false,
+                excluded_topics: Vec::new(),
+            }).unwrap();
             let technical = generate_training(TrainingRequest {
                 topic: topic.into(),
                 difficulty: DifficultyLevel::Intermediate,
                 rng_seed: Some(seed),
                 text_style: TextStyle::Technical,
-            });
+                is_bounty: false,
+                excluded_topics: Vec::new(),
+            }).unwrap();
             let simple_correct = simple
                 .answers
                 .iter()
@@ -386,7 +512,7 @@ fn text_style_does_not_affect_correct_answer() {
 #[test]
 fn preflop_decision_has_no_board_cards() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::PreflopDecision, seed));
+        let s = generate_training(req(TrainingTopic::PreflopDecision, seed)).unwrap();
         assert!(
             s.table_setup.board.is_empty(),
             "PreflopDecision must have no board cards (seed={seed})"
@@ -397,7 +523,7 @@ fn preflop_decision_has_no_board_cards() {
 #[test]
 fn postflop_cbet_has_exactly_3_board_cards() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::PostflopContinuationBet, seed));
+        let s = generate_training(req(TrainingTopic::PostflopContinuationBet, seed)).unwrap();
         assert_eq!(
             s.table_setup.board.len(), 3,
             "PostflopContinuationBet must have a 3-card flop (seed={seed})"
@@ -408,7 +534,7 @@ fn postflop_cbet_has_exactly_3_board_cards() {
 #[test]
 fn pot_odds_has_3_board_cards_and_positive_bet() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::PotOddsAndEquity, seed));
+        let s = generate_training(req(TrainingTopic::PotOddsAndEquity, seed)).unwrap();
         assert_eq!(
             s.table_setup.board.len(), 3,
             "PotOddsAndEquity must be on the flop (3 board cards) (seed={seed})"
@@ -423,7 +549,7 @@ fn pot_odds_has_3_board_cards_and_positive_bet() {
 #[test]
 fn bluff_spot_has_5_board_cards() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::BluffSpot, seed));
+        let s = generate_training(req(TrainingTopic::BluffSpot, seed)).unwrap();
         assert_eq!(
             s.table_setup.board.len(), 5,
             "BluffSpot must be on the river (5 board cards) (seed={seed})"
@@ -434,7 +560,7 @@ fn bluff_spot_has_5_board_cards() {
 #[test]
 fn icm_scenario_is_tournament_type() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::ICMAndTournamentDecision, seed));
+        let s = generate_training(req(TrainingTopic::ICMAndTournamentDecision, seed)).unwrap();
         assert_eq!(
             s.table_setup.game_type,
             GameType::Tournament,
@@ -446,7 +572,7 @@ fn icm_scenario_is_tournament_type() {
 #[test]
 fn turn_barrel_has_4_board_cards() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::TurnBarrelDecision, seed));
+        let s = generate_training(req(TrainingTopic::TurnBarrelDecision, seed)).unwrap();
         assert_eq!(
             s.table_setup.board.len(), 4,
             "TurnBarrelDecision must have 3 flop + 1 turn = 4 board cards (seed={seed})"
@@ -457,7 +583,7 @@ fn turn_barrel_has_4_board_cards() {
 #[test]
 fn check_raise_has_3_board_cards_positive_bet_and_bb_hero() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::CheckRaiseSpot, seed));
+        let s = generate_training(req(TrainingTopic::CheckRaiseSpot, seed)).unwrap();
         assert_eq!(
             s.table_setup.board.len(), 3,
             "CheckRaiseSpot must have a 3-card flop (seed={seed})"
@@ -482,7 +608,7 @@ fn check_raise_has_3_board_cards_positive_bet_and_bb_hero() {
 #[test]
 fn semi_bluff_has_3_board_cards_and_positive_bet() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::SemiBluffDecision, seed));
+        let s = generate_training(req(TrainingTopic::SemiBluffDecision, seed)).unwrap();
         assert_eq!(
             s.table_setup.board.len(), 3,
             "SemiBluffDecision must have a 3-card flop (seed={seed})"
@@ -497,7 +623,7 @@ fn semi_bluff_has_3_board_cards_and_positive_bet() {
 #[test]
 fn anti_limper_has_no_board_cards_and_is_cash() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::AntiLimperIsolation, seed));
+        let s = generate_training(req(TrainingTopic::AntiLimperIsolation, seed)).unwrap();
         assert!(
             s.table_setup.board.is_empty(),
             "AntiLimperIsolation is preflop and must have no board cards (seed={seed})"
@@ -513,7 +639,7 @@ fn anti_limper_has_no_board_cards_and_is_cash() {
 #[test]
 fn river_value_bet_has_5_board_cards_and_btn_hero() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::RiverValueBet, seed));
+        let s = generate_training(req(TrainingTopic::RiverValueBet, seed)).unwrap();
         assert_eq!(
             s.table_setup.board.len(), 5,
             "RiverValueBet must be on the river (5 board cards) (seed={seed})"
@@ -533,7 +659,7 @@ fn river_value_bet_has_5_board_cards_and_btn_hero() {
 #[test]
 fn squeeze_play_has_no_board_and_btn_hero() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::SqueezePlay, seed));
+        let s = generate_training(req(TrainingTopic::SqueezePlay, seed)).unwrap();
         assert!(
             s.table_setup.board.is_empty(),
             "SqueezePlay is preflop and must have no board cards (seed={seed})"
@@ -553,7 +679,7 @@ fn squeeze_play_has_no_board_and_btn_hero() {
 #[test]
 fn big_blind_defense_has_no_board_and_bb_hero() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::BigBlindDefense, seed));
+        let s = generate_training(req(TrainingTopic::BigBlindDefense, seed)).unwrap();
         assert!(
             s.table_setup.board.is_empty(),
             "BigBlindDefense is preflop and must have no board cards (seed={seed})"
@@ -573,7 +699,7 @@ fn big_blind_defense_has_no_board_and_bb_hero() {
 #[test]
 fn three_bet_pot_cbet_has_3_board_cards_and_btn_hero() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::ThreeBetPotCbet, seed));
+        let s = generate_training(req(TrainingTopic::ThreeBetPotCbet, seed)).unwrap();
         assert_eq!(
             s.table_setup.board.len(), 3,
             "ThreeBetPotCbet must be on the flop (3 board cards) (seed={seed})"
@@ -590,10 +716,536 @@ fn three_bet_pot_cbet_has_3_board_cards_and_btn_hero() {
     }
 }
 
+#[test]
+fn stack_off_decision_is_a_low_spr_flop_spot() {
+    for diff in [DifficultyLevel::Beginner, DifficultyLevel::Intermediate, DifficultyLevel::Advanced] {
+        for seed in SEEDS {
+            let s = generate_training(TrainingRequest { difficulty: diff, ..req(TrainingTopic::StackOffDecision, seed) }).unwrap();
+            let spr = s.table_setup.spr();
+            assert_eq!(s.table_setup.board.len(), 3, "StackOffDecision is a flop spot (seed={seed})");
+            assert_eq!(s.table_setup.hero_position, Position::BTN, "seed={seed}");
+            assert_eq!(s.table_setup.current_bet, 0, "villain checks to hero (seed={seed})");
+            assert!((1.0..=5.0).contains(&spr), "SPR {spr} out of range ({diff:?}, seed={seed})");
+            assert!(s.branch_key.starts_with("StackOff:"), "{}", s.branch_key);
+        }
+    }
+}
+
+#[test]
+fn set_mining_deals_a_small_pair_and_calls_at_ten_times() {
+    for diff in [DifficultyLevel::Beginner, DifficultyLevel::Intermediate, DifficultyLevel::Advanced] {
+        for seed in 0..30u64 {
+            let s = generate_training(TrainingRequest { difficulty: diff, ..req(TrainingTopic::SetMining, seed) }).unwrap();
+            let [a, b] = s.table_setup.hero_hand;
+            assert_eq!(a.rank, b.rank, "hero must hold a pair (seed={seed})");
+            assert!((2..=6).contains(&a.rank.0), "pair {a}{b} above 66 (seed={seed})");
+            assert!(s.table_setup.board.is_empty());
+            let call = s.table_setup.current_bet;
+            assert!(call > 0);
+            let deep = s.table_setup.effective_stack() >= 10 * call;
+            assert_eq!(s.correct_answer().text.starts_with("Call"), deep, "seed={seed}");
+            assert!(s.branch_key.starts_with(&format!("SetMining:{}:", a.rank.symbol())), "{}", s.branch_key);
+        }
+    }
+}
+
+#[test]
+fn cbet_defense_is_a_bb_flop_spot_that_mirrors_check_raise() {
+    for seed in 0..40u64 {
+        let s = generate_training(req(TrainingTopic::ContinuationBetDefense, seed)).unwrap();
+        assert_eq!(s.table_setup.board.len(), 3, "seed={seed}");
+        assert!(s.table_setup.current_bet > 0, "the Button c-bets (seed={seed})");
+        assert_eq!(s.table_setup.hero_position, Position::BB, "seed={seed}");
+
+        // Same seed deals the same cards, so the T7 spot classifies the same way.
+        let cr = generate_training(req(TrainingTopic::CheckRaiseSpot, seed)).unwrap();
+        assert_eq!(s.table_setup.hero_hand, cr.table_setup.hero_hand);
+        assert_eq!(s.branch_key, format!("CbetDefense:{}", cr.branch_key));
+        assert_eq!(s.correct_answer().id, cr.correct_answer().id, "seed={seed}");
+    }
+}
+
+#[test]
+fn three_bet_defense_hero_opens_late_and_faces_a_blind() {
+    use crate::training_engine::evaluator::{classify_hand, HandCategory};
+    for seed in 0..40u64 {
+        let s = generate_training(req(TrainingTopic::ThreeBetDefense, seed)).unwrap();
+        let hero = s.table_setup.hero_position;
+        assert!(matches!(hero, Position::CO | Position::BTN), "hero {hero:?} (seed={seed})");
+        assert!(s.table_setup.board.is_empty(), "seed={seed}");
+        let villain = s.table_setup.players.iter().find(|p| !p.is_hero).unwrap().position;
+        assert!(matches!(villain, Position::SB | Position::BB), "villain {villain:?}");
+        assert!(s.branch_key.starts_with(&format!("3BetDefense:{hero:?}:{villain:?}:")), "{}", s.branch_key);
+        match classify_hand(s.table_setup.hero_hand) {
+            HandCategory::Premium => assert_eq!(s.correct_answer().id, "C", "premiums 4-bet"),
+            HandCategory::Marginal | HandCategory::Trash => assert_eq!(s.correct_answer().id, "A"),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn chip_leader_is_a_tournament_spot_with_hero_covering_everyone_twice() {
+    use crate::training_engine::evaluator::{classify_hand, HandCategory};
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Advanced] {
+        for seed in 0..40u64 {
+            let mut r = req(TrainingTopic::ChipLeaderPlay, seed);
+            r.difficulty = difficulty;
+            let s = generate_training(r).unwrap();
+            let t = &s.table_setup;
+            assert_eq!(t.game_type, GameType::Tournament, "seed={seed}");
+            assert!(t.board.is_empty(), "seed={seed}");
+            let hero = t.players.iter().find(|p| p.is_hero).unwrap().stack;
+            let next = t.players.iter().filter(|p| !p.is_hero).map(|p| p.stack).max().unwrap();
+            assert!(hero >= 2 * next, "hero {hero} vs next {next} (seed={seed})");
+            let correct = s.correct_answer().id.as_str();
+            match (s.branch_key.split(':').nth(1), classify_hand(t.hero_hand)) {
+                (_, HandCategory::Premium) => assert_eq!(correct, "B", "{}", s.branch_key),
+                (_, HandCategory::Trash) => assert_eq!(correct, "A", "{}", s.branch_key),
+                (Some("Big"), HandCategory::Playable | HandCategory::Marginal) => assert_eq!(correct, "A"),
+                (Some("Short"), HandCategory::Marginal) => assert_eq!(correct, "B"),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn blinds_vs_blinds_is_sb_against_bb_alone() {
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Intermediate] {
+        for seed in 0..40u64 {
+            let mut r = req(TrainingTopic::BlindsVsBlinds, seed);
+            r.difficulty = difficulty;
+            let s = generate_training(r).unwrap();
+            let t = &s.table_setup;
+            assert_eq!(t.players.len(), 2, "seed={seed}");
+            assert!(t.board.is_empty(), "the flop plan never deals a board (seed={seed})");
+            assert_eq!(t.hero_position, Position::SB);
+            assert!(t.players.iter().any(|p| !p.is_hero && p.position == Position::BB));
+            let compound = difficulty != DifficultyLevel::Beginner;
+            assert_eq!(s.answers.len(), if compound { 4 } else { 3 }, "seed={seed}");
+            let expected = match s.branch_key.split(':').nth(1) {
+                Some("Fold")  => "A",
+                Some("Limp")  => "B",
+                Some("Raise") => "C",
+                _ => panic!("unexpected branch key {}", s.branch_key),
+            };
+            assert_eq!(s.correct_answer().id, expected, "{}", s.branch_key);
+        }
+    }
+}
+
+#[test]
+fn short_stack_is_a_10_to_19_bb_cash_game_shove_or_fold() {
+    use crate::training_engine::evaluator::{classify_hand, HandCategory};
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Intermediate, DifficultyLevel::Advanced] {
+        for seed in 0..40u64 {
+            let mut r = req(TrainingTopic::ShortStackStrategy, seed);
+            r.difficulty = difficulty;
+            let s = generate_training(r).unwrap();
+            let t = &s.table_setup;
+            let hero_bb = t.players.iter().find(|p| p.is_hero).unwrap().stack / 2;
+            assert!((10..=19).contains(&hero_bb), "{hero_bb} BB (seed={seed})");
+            assert_eq!(t.effective_stack(), hero_bb * 2, "seed={seed}");
+            assert_eq!(t.game_type, GameType::CashGame);
+            assert!(s.branch_key.starts_with(&format!("ShortStack:{hero_bb}BB:")), "{}", s.branch_key);
+            match classify_hand(t.hero_hand) {
+                HandCategory::Premium | HandCategory::Strong => assert_eq!(s.correct_answer().id, "B"),
+                HandCategory::Trash => assert_eq!(s.correct_answer().id, "A"),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn blind_stealing_opens_position_wide_ranges_from_steal_seats() {
+    use crate::training_engine::evaluator::{classify_hand, starting_hand_percentile, HandCategory};
+    let mut wide_steals = 0;
+    for seed in 0..60u64 {
+        let s = generate_training(req(TrainingTopic::BlindStealing, seed)).unwrap();
+        let t = &s.table_setup;
+        assert!(matches!(t.hero_position, Position::CO | Position::BTN | Position::SB), "seed={seed}");
+        assert!(t.board.is_empty(), "seed={seed}");
+        let width = if t.hero_position == Position::CO { 0.27 } else { 0.40 };
+        let pct = starting_hand_percentile(t.hero_hand);
+        if pct < width {
+            assert_eq!(s.correct_answer().id, "B", "{} top {pct:.2}", s.branch_key);
+        }
+        if pct > 0.45 {
+            assert_eq!(s.correct_answer().id, "A", "{} top {pct:.2}", s.branch_key);
+        }
+        let cat = classify_hand(t.hero_hand);
+        if s.correct_answer().id == "B" && matches!(cat, HandCategory::Marginal | HandCategory::Trash) {
+            wide_steals += 1;
+        }
+    }
+    assert!(wide_steals > 0, "steals are not limited to premium hands");
+}
+
+#[test]
+fn hu_tournament_is_a_two_player_button_shove_or_fold() {
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Advanced] {
+        for seed in 0..40u64 {
+            let mut r = req(TrainingTopic::HeadsUpTournament, seed);
+            r.difficulty = difficulty;
+            let s = generate_training(r).unwrap();
+            let t = &s.table_setup;
+            assert_eq!(t.game_type, GameType::Tournament, "seed={seed}");
+            assert_eq!(t.players.len(), 2, "seed={seed}");
+            assert_eq!(t.hero_position, Position::BTN, "seed={seed}");
+            let [a, b] = [t.hero_hand[0].rank, t.hero_hand[1].rank];
+            if a == b || a == Rank(14) || b == Rank(14) {
+                assert_eq!(s.correct_answer().id, "B", "pairs and aces always shove ({})", s.branch_key);
+            }
+        }
+    }
+}
+
+#[test]
+fn protection_bet_hero_pairs_the_top_card_and_acts_first() {
+    use crate::training_engine::evaluator::board_draw_count;
+    for seed in SEEDS {
+        let s = generate_training(req(TrainingTopic::ProtectionBet, seed)).unwrap();
+        let t = &s.table_setup;
+        assert_eq!(t.board.len(), 3, "seed={seed}");
+        assert_eq!(t.current_bet, 0, "hero acts first (seed={seed})");
+        let top = t.board.iter().map(|c| c.rank).max().unwrap();
+        assert!(t.hero_hand.iter().any(|c| c.rank == top), "hero pairs the top card (seed={seed})");
+        let expected = match board_draw_count(&t.board) {
+            0 => "A",
+            1 => "B",
+            _ => "C",
+        };
+        assert_eq!(s.correct_answer().id, expected, "{}", s.branch_key);
+    }
+}
+
+#[test]
+fn suited_connectors_call_deep_in_position_and_fold_the_small_blind() {
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Advanced] {
+        for seed in 0..40u64 {
+            let mut r = req(TrainingTopic::SuitedConnectors, seed);
+            r.difficulty = difficulty;
+            let s = generate_training(r).unwrap();
+            let t = &s.table_setup;
+            let [hi, lo] = t.hero_hand;
+            assert_eq!(hi.suit, lo.suit, "seed={seed}");
+            assert_eq!(hi.rank.0, lo.rank.0 + 1, "seed={seed}");
+            assert!((4..=8).contains(&lo.rank.0), "54s–98s only (seed={seed})");
+            if t.hero_position == Position::SB {
+                assert_eq!(s.correct_answer().id, "A", "{}", s.branch_key);
+            }
+            let expected = if difficulty == DifficultyLevel::Advanced { 4 } else { 3 };
+            assert_eq!(s.answers.len(), expected, "seed={seed}");
+        }
+    }
+}
+
+#[test]
+fn pot_control_checks_weak_top_pair_at_medium_spr_and_bets_low_spr() {
+    use crate::training_engine::evaluator::{classify_hero_hand_vs_board, HandVsBoardStrength};
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Advanced] {
+        for seed in 0..40u64 {
+            let mut r = req(TrainingTopic::PotControl, seed);
+            r.difficulty = difficulty;
+            let s = generate_training(r).unwrap();
+            let t = &s.table_setup;
+            assert_eq!(t.board.len(), 3, "seed={seed}");
+            assert_eq!(t.hero_position, Position::BTN, "seed={seed}");
+            assert_eq!(
+                classify_hero_hand_vs_board(t.hero_hand, &t.board),
+                HandVsBoardStrength::TopPairWeakKicker,
+                "seed={seed}"
+            );
+            let spr = t.spr();
+            assert!(spr < 2.0 || (2.9..=6.0).contains(&spr), "spr {spr} (seed={seed})");
+            let expected = if spr < 2.0 { "B" } else { "A" };
+            assert_eq!(s.correct_answer().id, expected, "{}", s.branch_key);
+        }
+    }
+}
+
+#[test]
+fn turn_draw_uses_one_street_equity_against_a_turn_bet() {
+    use crate::training_engine::evaluator::{break_even_equity, classify_draw_combined, equity_of_combined_draws};
+    for seed in 0..60u64 {
+        let s = generate_training(req(TrainingTopic::TurnDraw, seed)).unwrap();
+        let t = &s.table_setup;
+        assert_eq!(t.board.len(), 4, "seed={seed}");
+        assert!(t.current_bet > 0, "seed={seed}");
+        let draws = classify_draw_combined(t.hero_hand, &t.board);
+        assert!(!draws.is_empty(), "hero holds a draw (seed={seed})");
+        let required = break_even_equity(t.current_bet, t.pot_size);
+        let expected = if draws.len() == 2 {
+            "C"
+        } else if equity_of_combined_draws(&draws, 1) >= required {
+            "B"
+        } else {
+            "A"
+        };
+        assert_eq!(s.correct_answer().id, expected, "{}", s.branch_key);
+    }
+}
+
+#[test]
+fn poker_math_answers_are_exact_combinatorics() {
+    use crate::training_engine::topics::preflop::MATH_QUESTIONS;
+    fn choose(n: u64, k: u64) -> f64 {
+        (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+    }
+    let pct = |p: f64| format!("{:.1}%", p * 100.0);
+    let expected = [
+        ("PairCombos", format!("{}", choose(4, 2))),
+        ("StartingHands", "1,326".to_string()),
+        ("FlopSet", pct(2.0 * (choose(48, 2) - 72.0) / choose(50, 3))),
+        ("FlopPair", pct(1.0 - choose(44, 3) / choose(50, 3))),
+        ("OesdOnRiver", pct(8.0 / 46.0)),
+        ("AceOnBoard", pct(1.0 - choose(46, 5) / choose(50, 5))),
+        ("PairCombosTotal", format!("{}", 13.0 * choose(4, 2))),
+    ];
+    assert!(MATH_QUESTIONS.len() >= 10);
+    for (key, value) in expected {
+        let q = MATH_QUESTIONS.iter().find(|q| q.key == key).unwrap();
+        assert_eq!(q.options[q.correct], value, "{key}");
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    for seed in 0..200u64 {
+        let mut r = req(TrainingTopic::PokerMath, seed);
+        r.difficulty = DifficultyLevel::Advanced;
+        let s = generate_training(r).unwrap();
+        assert_eq!(s.answers.len(), 4, "seed={seed}");
+        assert!(s.table_setup.board.is_empty(), "seed={seed}");
+        seen.insert(s.branch_key);
+    }
+    assert_eq!(seen.len(), MATH_QUESTIONS.len(), "every question is drawn at Advanced");
+}
+
+#[test]
+fn hand_reading_answer_is_one_of_the_options_and_matches_the_line() {
+    use crate::training_engine::models::{ActionSequence, BetSize, VillainAction};
+    let line = ActionSequence {
+        villain_raised: true,
+        flop: VillainAction::Bet(BetSize::Small),
+        turn: VillainAction::Bet(BetSize::Large),
+        river: VillainAction::Check,
+    };
+    assert_eq!(line.code(), "R-BS-BL-X");
+    assert!(line.to_string().starts_with("raised preflop, bet small on the flop"));
+
+    let mut keys = std::collections::HashSet::new();
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Intermediate, DifficultyLevel::Advanced] {
+        for seed in 0..40u64 {
+            let mut r = req(TrainingTopic::HandReading, seed);
+            r.difficulty = difficulty;
+            let s = generate_training(r).unwrap();
+            let ids: Vec<&str> = s.answers.iter().map(|a| a.id.as_str()).collect();
+            assert_eq!(s.answers.iter().filter(|a| a.is_correct).count(), 1, "seed={seed}");
+            assert!(ids.contains(&s.correct_answer().id.as_str()), "seed={seed}");
+
+            let board = &s.table_setup.board;
+            assert_eq!(board.len(), 5, "seed={seed}");
+            let ranks: std::collections::HashSet<_> = board.iter().map(|c| c.rank).collect();
+            assert_eq!(ranks.len(), 5, "runout is unpaired (seed={seed})");
+
+            let code: Vec<&str> = s.branch_key.split(':').nth(1).unwrap().split('-').collect();
+            assert_eq!(code.len(), 4, "preflop + three streets (seed={seed})");
+            assert!(code[0] == "R" || code[0] == "C", "seed={seed}");
+            let river_bet = code[3].starts_with('B');
+            assert_eq!(s.table_setup.current_bet > 0, river_bet, "seed={seed}");
+            keys.insert(s.branch_key);
+        }
+    }
+    assert!(keys.len() >= 5, "several lines are drawn");
+}
+
+#[test]
+fn hand_ranking_correct_answer_matches_compare_hands() {
+    use crate::training_engine::evaluator::{compare_hands, evaluate_five_cards};
+    use std::cmp::Ordering;
+    let parse = |q: &str, label: &str| -> [Card; 5] {
+        let start = q.find(label).unwrap() + label.len();
+        q[start..].split_whitespace().take(5).map(|c| c.trim_end_matches('.').parse().unwrap())
+            .collect::<Vec<Card>>().try_into().unwrap()
+    };
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Intermediate, DifficultyLevel::Advanced] {
+        for seed in 0..60u64 {
+            let mut r = req(TrainingTopic::BeginnerHandRankings, seed);
+            r.difficulty = difficulty;
+            let s = generate_training(r).unwrap();
+            let (h1, h2) = (parse(&s.question, "Hand 1: "), parse(&s.question, "Hand 2: "));
+            let all: std::collections::HashSet<Card> =
+                h1.into_iter().chain(h2).chain(s.table_setup.hero_hand).collect();
+            assert_eq!(all.len(), 12, "quiz and hole cards are distinct (seed={seed})");
+
+            let expected = match compare_hands(h1, h2) {
+                Ordering::Greater => "A",
+                Ordering::Less    => "B",
+                Ordering::Equal   => panic!("tie dealt (seed={seed})"),
+            };
+            assert_eq!(s.correct_answer().id, expected, "{difficulty:?} seed={seed}");
+
+            let (c1, c2) = (evaluate_five_cards(h1).category(), evaluate_five_cards(h2).category());
+            match difficulty {
+                DifficultyLevel::Beginner => assert_ne!(c1, c2, "seed={seed}"),
+                DifficultyLevel::Advanced => assert_eq!(c1, c2, "seed={seed}"),
+                DifficultyLevel::Intermediate => {}
+            }
+            let (w, l) = if expected == "A" { (c1, c2) } else { (c2, c1) };
+            assert_eq!(s.branch_key, format!("HandRanking:{w:?}:{l:?}"));
+        }
+    }
+}
+
+#[test]
+fn draw_counting_answer_matches_count_outs() {
+    use crate::training_engine::evaluator::count_outs;
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Intermediate, DifficultyLevel::Advanced] {
+        for seed in 0..40u64 {
+            let mut r = req(TrainingTopic::DrawCounting, seed);
+            r.difficulty = difficulty;
+            let s = generate_training(r).unwrap();
+            let board = &s.table_setup.board;
+            assert_eq!(board.len(), 3, "seed={seed}");
+
+            let outs = count_outs(s.table_setup.hero_hand, board);
+            let shown: u8 = s.correct_answer().text.split_whitespace().next().unwrap().parse().unwrap();
+            assert_eq!(shown, outs, "{difficulty:?} seed={seed}");
+            assert!(s.branch_key.starts_with("DrawCounting:"));
+            assert!(s.branch_key.ends_with(&format!(":{outs}")), "{}", s.branch_key);
+            if difficulty == DifficultyLevel::Beginner {
+                assert!(outs == 8 || outs == 9, "Beginner draws only flush draws and open-enders");
+            }
+        }
+    }
+}
+
+#[test]
+fn fold_equity_bluff_answer_matches_ev_sign() {
+    use crate::training_engine::evaluator::{classify_draw_combined, equity_of_combined_draws, ev_of_raise};
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Intermediate, DifficultyLevel::Advanced] {
+        for seed in 0..40u64 {
+            let mut r = req(TrainingTopic::FoldEquityBluff, seed);
+            r.difficulty = difficulty;
+            let s = generate_training(r).unwrap();
+            let t = &s.table_setup;
+            assert_eq!(t.board.len(), 4, "seed={seed}");
+
+            let parts: Vec<&str> = s.branch_key.split(':').collect();
+            assert_eq!(parts[0], "FoldEquityBluff");
+            let fold = parts[1].parse::<f32>().unwrap() / 100.0;
+            let raise_to: u32 = s.answers[0].text.rsplit(' ').next().unwrap().parse().unwrap();
+            let equity = equity_of_combined_draws(&classify_draw_combined(t.hero_hand, &t.board), 1);
+            let ev = ev_of_raise(fold, t.pot_size, t.current_bet, raise_to, equity);
+
+            assert_eq!(parts[2], format!("{}", (equity * 100.0).round() as u32));
+            assert_eq!(parts[3], if ev > 0.0 { "Profitable" } else { "Unprofitable" });
+            assert_eq!(s.correct_answer().id, if ev > 0.0 { "A" } else { "B" }, "{} seed={seed}", s.branch_key);
+            assert!(s.question.contains(&format!("{}%", parts[1])), "fold frequency is stated");
+        }
+    }
+}
+
+#[test]
+fn lay_down_folds_big_raises_with_strong_non_nut_hands() {
+    use crate::training_engine::evaluator::{best_hand_from_seven, board_suit_counts, MadeHand};
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Intermediate, DifficultyLevel::Advanced] {
+        for seed in 0..40u64 {
+            let mut r = req(TrainingTopic::LayDown, seed);
+            r.difficulty = difficulty;
+            let s = generate_training(r).unwrap();
+            let (hand, board) = (s.table_setup.hero_hand, s.table_setup.board.clone());
+            let river: [Card; 5] = board.clone().try_into().unwrap();
+            let category = best_hand_from_seven(hand, river).1.category();
+            let suits = board_suit_counts(&board);
+            let paired = (1..5).any(|i| board[..i].iter().any(|c| c.rank == board[i].rank));
+
+            let parts: Vec<&str> = s.branch_key.split(':').collect();
+            assert_eq!(parts[0], "LayDown");
+            match parts[1] {
+                "SecondNutFlush" => {
+                    assert_eq!(category, MadeHand::Flush, "seed={seed}");
+                    assert!(paired && suits.contains(&3), "paired three-flush board (seed={seed})");
+                    assert!(hand.iter().all(|c| c.suit == hand[0].suit) && hand.iter().any(|c| c.rank == Rank(13)));
+                    assert!(!board.iter().any(|c| c.rank == Rank(14) && c.suit == hand[0].suit));
+                }
+                "TopSet" => {
+                    assert_eq!(category, MadeHand::ThreeOfAKind, "seed={seed}");
+                    assert!(!paired && suits.contains(&4), "unpaired four-flush board (seed={seed})");
+                    assert_eq!(hand[0].rank, board.iter().map(|c| c.rank).max().unwrap());
+                }
+                other => panic!("unexpected hand {other}"),
+            }
+            let expected = match (parts[1], parts[2]) {
+                (_, "Min") | ("SecondNutFlush", "Triple") => "B",
+                _ => "A",
+            };
+            assert_eq!(s.correct_answer().id, expected, "{} seed={seed}", s.branch_key);
+            if difficulty == DifficultyLevel::Beginner {
+                assert_ne!(parts[2], "Triple");
+            }
+        }
+    }
+}
+
+#[test]
+fn give_up_checks_air_unless_hero_blocks_the_nut_flush() {
+    use crate::training_engine::evaluator::{best_hand_from_seven, flush_possible, MadeHand};
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Intermediate, DifficultyLevel::Advanced] {
+        for seed in 0..60u64 {
+            let mut r = req(TrainingTopic::WhenToGiveUp, seed);
+            r.difficulty = difficulty;
+            r.text_style = TextStyle::Technical;
+            let s = generate_training(r).unwrap();
+            let (hand, board) = (s.table_setup.hero_hand, s.table_setup.board.clone());
+            let river: [Card; 5] = board.clone().try_into().unwrap();
+            assert_eq!(best_hand_from_seven(hand, river).1.category(), MadeHand::HighCard, "seed={seed}");
+            assert_eq!(s.table_setup.current_bet, 0);
+
+            let flush = flush_possible(&board).first().copied();
+            let range = s.branch_key.strip_prefix("GiveUp:").unwrap();
+            match range {
+                "Condensed" => assert_eq!(flush, None, "seed={seed}"),
+                "Strengthened" => {
+                    let suit = flush.expect("the flush completes");
+                    assert!(hand.iter().all(|c| c.suit != suit), "seed={seed}");
+                }
+                "Capped" => {
+                    assert_eq!(difficulty, DifficultyLevel::Advanced);
+                    let suit = flush.expect("the flush completes");
+                    assert!(hand.contains(&Card { rank: Rank(14), suit }), "seed={seed}");
+                }
+                other => panic!("unexpected range {other}"),
+            }
+            let bluff = range == "Capped";
+            assert_eq!(s.correct_answer().id, if bluff { "B" } else { "A" }, "{} seed={seed}", s.branch_key);
+            assert_eq!(s.correct_answer().explanation.contains("negligible fold equity"), !bluff);
+        }
+    }
+}
+
+#[test]
+fn triple_barrel_bets_value_and_checks_showdown_hands() {
+    for seed in 0..40u64 {
+        let s = generate_training(req(TrainingTopic::TripleBarrel, seed)).unwrap();
+        assert_eq!(s.table_setup.board.len(), 5, "seed={seed}");
+        assert_eq!(s.table_setup.current_bet, 0, "villain checks the river (seed={seed})");
+        let key = s.branch_key.as_str();
+        let correct = s.correct_answer().id.as_str();
+        match key.split(':').nth(1) {
+            Some("Value")    => assert_eq!(correct, "B", "{key}"),
+            Some("Showdown") => assert_eq!(correct, "A", "{key}"),
+            Some("Air")      => assert!(key.ends_with(":MissedDraw") || key.ends_with(":PureAir"), "{key}"),
+            _ => panic!("unexpected branch key {key}"),
+        }
+    }
+}
+
 #[test]
 fn river_call_or_fold_has_5_board_cards_and_positive_bet() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::RiverCallOrFold, seed));
+        let s = generate_training(req(TrainingTopic::RiverCallOrFold, seed)).unwrap();
         assert_eq!(
             s.table_setup.board.len(), 5,
             "RiverCallOrFold must be on the river (5 board cards) (seed={seed})"
@@ -608,7 +1260,7 @@ fn river_call_or_fold_has_5_board_cards_and_positive_bet() {
 #[test]
 fn turn_probe_bet_has_4_board_cards_and_bb_hero() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::TurnProbeBet, seed));
+        let s = generate_training(req(TrainingTopic::TurnProbeBet, seed)).unwrap();
         assert_eq!(
             s.table_setup.board.len(), 4,
             "TurnProbeBet must have 3 flop + 1 turn = 4 board cards (seed={seed})"
@@ -636,7 +1288,7 @@ fn icm_produces_mix_of_pushes_and_folds() {
     let trials = 200u64;
 
     for seed in 0..trials {
-        let s = generate_training(req(TrainingTopic::ICMAndTournamentDecision, seed));
+        let s = generate_training(req(TrainingTopic::ICMAndTournamentDecision, seed)).unwrap();
         let correct = s.answers.iter().find(|a| a.is_correct).unwrap();
         if correct.id == "A" { push_count += 1; } else { fold_count += 1; }
     }
@@ -646,6 +1298,31 @@ fn icm_produces_mix_of_pushes_and_folds() {
     );
 }
 
+#[test]
+fn nash_push_threshold_orders_hands_and_seats() {
+    use crate::training_engine::evaluator::nash_push_threshold_bb;
+    let hand = |s: &str| -> [Card; 2] { cards(s).try_into().unwrap() };
+    let probes = ["Ah Ad", "Kc Kd", "Jh Js", "Ac Qd", "8s 8d", "Kh Qh", "9c 8c", "Ks 7d", "7c 2d"];
+    for &pos in Position::all() {
+        let thresholds: Vec<u32> = probes.iter().map(|h| nash_push_threshold_bb(hand(h), pos)).collect();
+        let aa = nash_push_threshold_bb(hand("Ah Ad"), pos);
+        let trash = nash_push_threshold_bb(hand("7c 2d"), pos);
+        assert!(thresholds.iter().all(|&t| t <= aa && t >= trash), "{pos:?}: {thresholds:?}");
+        assert!(aa > trash, "{pos:?}");
+        assert!(nash_push_threshold_bb(hand("Ah Ad"), Position::BTN) >= aa);
+    }
+    assert!(
+        nash_push_threshold_bb(hand("8s 8d"), Position::BTN) > nash_push_threshold_bb(hand("8s 8d"), Position::SB)
+    );
+
+    let technical = TrainingRequest {
+        text_style: TextStyle::Technical,
+        ..req(TrainingTopic::ICMAndTournamentDecision, 3)
+    };
+    let s = generate_training(technical).unwrap();
+    assert!(s.answers.iter().all(|a| a.explanation.contains("nash_push_threshold_bb")));
+}
+
 // ── evaluator classify_hand tests ───────────────────────────────────────────
 
 #[test]
@@ -722,6 +1399,42 @@ fn classify_hand_strong_and_playable() {
     assert_eq!(classify_hand(threes), HandCategory::Marginal);
 }
 
+#[test]
+fn hand_category_and_draw_type_methods() {
+    use crate::training_engine::evaluator::{classify_hand, equity_from_outs, DrawType, HandCategory};
+    let aks: [Card; 2] = cards("Ah Kh").try_into().unwrap();
+    assert_eq!(HandCategory::from_hand(aks), classify_hand(aks));
+    let names: Vec<&str> = HandCategory::all().iter().map(|c| c.name()).collect();
+    assert_eq!(names, ["premium", "strong", "playable", "marginal", "trash"]);
+    #[allow(deprecated)]
+    let old = crate::training_engine::evaluator::hand_category_name(HandCategory::Playable);
+    assert_eq!(old, HandCategory::Playable.name());
+    assert_eq!(HandCategory::Trash.to_string(), "trash");
+
+    let outs: Vec<u8> = DrawType::all().iter().map(|d| d.outs()).collect();
+    assert_eq!(outs, [15, 9, 8, 4]);
+    for d in DrawType::all() {
+        assert_eq!(d.equity(2), equity_from_outs(d.outs(), 2));
+        assert!(d.equity(1) < d.equity(2), "{d:?}");
+    }
+    assert!((DrawType::FlushDraw.equity(2) - 0.35).abs() < 0.01);
+}
+
+#[test]
+fn chen_score_and_percentile_rank_starting_hands() {
+    use crate::training_engine::evaluator::{chen_score, starting_hand_percentile};
+    let hand = |s: &str| -> [Card; 2] { cards(s).try_into().unwrap() };
+    assert_eq!(chen_score(hand("Ah As")), 20);
+    assert_eq!(chen_score(hand("Ah Kh")), 12);
+    assert_eq!(chen_score(hand("Jh Ts")), 7);
+    assert_eq!(chen_score(hand("2h 2s")), 5);
+    assert_eq!(chen_score(hand("7h 2s")), -1);
+    let ladder = ["Ah As", "Ah Kh", "Th 9h", "Kh Ts", "8h 7d", "7h 2s"];
+    let pcts: Vec<f32> = ladder.iter().map(|h| starting_hand_percentile(hand(h))).collect();
+    assert!(pcts.windows(2).all(|w| w[0] < w[1]), "{pcts:?}");
+    assert!(pcts[0] < 0.01 && pcts[5] > 0.95, "{pcts:?}");
+}
+
 // ── delayed c-bet hand / turn classification ─────────────────────────────────
 
 #[test]
@@ -805,7 +1518,7 @@ fn delayed_cbet_exercises_all_branch_keys() {
     let mut seen = std::collections::HashSet::new();
     let trials = 500u64;
     for seed in 0..trials {
-        let s = generate_training(req(TrainingTopic::DelayedCbet, seed));
+        let s = generate_training(req(TrainingTopic::DelayedCbet, seed)).unwrap();
         seen.insert(s.branch_key.clone());
     }
     // 3 strengths × 2 turn types = 6 possible branch keys
@@ -825,7 +1538,7 @@ fn delayed_cbet_exercises_all_branch_keys() {
 #[test]
 fn delayed_cbet_has_4_board_cards_btn_hero_and_zero_bet() {
     for seed in SEEDS {
-        let s = generate_training(req(TrainingTopic::DelayedCbet, seed));
+        let s = generate_training(req(TrainingTopic::DelayedCbet, seed)).unwrap();
         assert_eq!(
             s.table_setup.board.len(), 4,
             "DelayedCbet must have 3 flop + 1 turn = 4 board cards (seed={seed})"
@@ -853,7 +1566,7 @@ fn delayed_cbet_has_4_board_cards_btn_hero_and_zero_bet() {
 fn street_selector_produces_topic_from_correct_street() {
     for street in [Street::Preflop, Street::Flop, Street::Turn, Street::River] {
         for seed in SEEDS {
-            let s = generate_training(req(street, seed));
+            let s = generate_training(req(street, seed)).unwrap();
             assert!(
                 street.topics().contains(&s.topic),
                 "Street::{street:?} with seed={seed} produced {topic:?} which is not a {street:?} topic",
@@ -866,8 +1579,8 @@ fn street_selector_produces_topic_from_correct_street() {
 #[test]
 fn street_selector_is_deterministic() {
     for street in [Street::Preflop, Street::Flop, Street::Turn, Street::River] {
-        let a = generate_training(req(street, 42));
-        let b = generate_training(req(street, 42));
+        let a = generate_training(req(street, 42)).unwrap();
+        let b = generate_training(req(street, 42)).unwrap();
         assert_eq!(a.scenario_id, b.scenario_id, "Street::{street:?} not deterministic");
         assert_eq!(a.topic, b.topic, "Street::{street:?} picked different topics with same seed");
     }
@@ -879,7 +1592,7 @@ fn street_selector_varies_topics_across_seeds() {
     for street in [Street::Preflop, Street::Flop] {
         let mut seen = std::collections::HashSet::new();
         for seed in 0..50u64 {
-            let s = generate_training(req(street, seed));
+            let s = generate_training(req(street, seed)).unwrap();
             seen.insert(s.topic);
         }
         assert!(
@@ -911,3 +1624,2421 @@ fn every_street_has_at_least_one_topic() {
     }
 }
 
+#[test]
+fn street_next_and_prev_chain() {
+    let forward = [Street::Preflop, Street::Flop, Street::Turn, Street::River];
+    for pair in forward.windows(2) {
+        assert_eq!(pair[0].next(), Some(pair[1]));
+        assert_eq!(pair[1].prev(), Some(pair[0]));
+        assert!(pair[0].board_card_count() < pair[1].board_card_count());
+    }
+    assert_eq!(Street::River.next(), None);
+    assert_eq!(Street::Preflop.prev(), None);
+
+    let mut walked = vec![Street::Preflop];
+    while let Some(next) = walked.last().unwrap().next() {
+        walked.push(next);
+    }
+    assert_eq!(walked, forward);
+}
+
+
+#[test]
+fn street_selector_skips_excluded_topics() {
+    use TrainingTopic::*;
+    let mut seen = std::collections::HashSet::new();
+    for seed in 0..100 {
+        let request = req(Street::Turn, seed).exclude_topics(vec![TurnProbeBet]);
+        let s = generate_training(request).unwrap();
+        assert_ne!(s.topic, TurnProbeBet, "seed {seed}");
+        seen.insert(s.topic);
+    }
+    assert_eq!(seen.len(), Street::Turn.topics().len() - 1, "{seen:?}");
+
+    // A fully excluded street falls back to another street's topics.
+    for seed in 0..20 {
+        let request = req(Street::River, seed).exclude_topics(Street::River.topics().to_vec());
+        let topic = generate_training(request).unwrap().topic;
+        assert_ne!(topic.street(), Street::River, "seed {seed}");
+    }
+
+    // An explicit topic is honoured, and an empty list changes nothing.
+    let explicit = req(TurnProbeBet, 5).exclude_topics(vec![TurnProbeBet]);
+    assert_eq!(generate_training(explicit).unwrap().topic, TurnProbeBet);
+    assert_eq!(
+        generate_training(req(Street::Flop, 9).exclude_topics(vec![])).unwrap(),
+        generate_training(req(Street::Flop, 9)).unwrap()
+    );
+    let built = TrainingRequest::builder().topic(Street::Turn).exclude(vec![DelayedCbet]).build();
+    assert_eq!(built.excluded_topics, [DelayedCbet]);
+}
+
+// ── request builder ──────────────────────────────────────────────────────
+
+#[test]
+fn builder_matches_struct_literal() {
+    let built = TrainingRequest::builder()
+        .topic(TrainingTopic::RiverValueBet)
+        .difficulty(DifficultyLevel::Advanced)
+        .seed(42)
+        .style(TextStyle::Technical)
+        .build();
+    let literal = TrainingRequest {
+        topic: TrainingTopic::RiverValueBet.into(),
+        difficulty: DifficultyLevel::Advanced,
+        rng_seed: Some(42),
+        text_style: TextStyle::Technical,
+        is_bounty: false,
+        excluded_topics: Vec::new(),
+    };
+    assert_eq!(built.topic, literal.topic);
+    assert_eq!(built.difficulty, literal.difficulty);
+    assert_eq!(built.rng_seed, literal.rng_seed);
+    assert_eq!(built.text_style, literal.text_style);
+    assert_eq!(built.is_bounty, literal.is_bounty);
+}
+
+#[test]
+fn builder_defaults_match_new() {
+    let built = TrainingRequest::builder().topic(Street::Turn).build();
+    let new = TrainingRequest::new(Street::Turn);
+    assert_eq!(built.topic, new.topic);
+    assert_eq!(built.difficulty, new.difficulty);
+    assert_eq!(built.rng_seed, new.rng_seed);
+    assert_eq!(built.text_style, new.text_style);
+    assert_eq!(built.is_bounty, new.is_bounty);
+}
+
+#[test]
+#[should_panic(expected = "topic is required")]
+fn builder_without_topic_panics() {
+    TrainingRequest::builder().seed(1).build();
+}
+
+// ── answer ratings ───────────────────────────────────────────────────────
+
+#[test]
+fn only_the_correct_answer_is_rated_correct() {
+    for topic in all_topics() {
+        for seed in SEEDS {
+            let s = generate_training(req(topic, seed)).unwrap();
+            for a in &s.answers {
+                assert_eq!(
+                    a.is_correct,
+                    a.rating == AnswerRating::Correct,
+                    "{topic:?} seed {seed}: option {} is_correct={} but rating={}",
+                    a.id, a.is_correct, a.rating
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn score_answer_follows_rating() {
+    for topic in all_topics() {
+        let s = generate_training(req(topic, 42)).unwrap();
+        for a in &s.answers {
+            assert_eq!(s.score_answer(&a.id), a.rating.score());
+        }
+        let correct = s.answers.iter().find(|a| a.is_correct).unwrap();
+        assert_eq!(s.score_answer(&correct.id), 100);
+        assert_eq!(s.score_answer("Z"), 0, "{topic:?}: unknown ID should score 0");
+    }
+}
+
+#[test]
+fn preflop_fold_rating_scales_with_hand_strength() {
+    let (mut premium_fold, mut playable_fold, mut premium_limp) = (false, false, false);
+    for seed in 0..500 {
+        let s = generate_training(req(TrainingTopic::PreflopDecision, seed)).unwrap();
+        let rating = |id: &str| s.answers.iter().find(|a| a.id == id).unwrap().rating;
+        if rating("A") == AnswerRating::Correct {
+            continue;
+        }
+        if s.branch_key.contains(":premium") {
+            assert_eq!(rating("A"), AnswerRating::Blunder, "{}", s.branch_key);
+            premium_fold = true;
+            if s.branch_key.starts_with("OpenRaise") {
+                assert_eq!(rating("C"), AnswerRating::Plausible, "{}", s.branch_key);
+                premium_limp = true;
+            }
+        } else if s.branch_key.contains(":playable") {
+            assert_eq!(rating("A"), AnswerRating::Mistake, "{}", s.branch_key);
+            playable_fold = true;
+        }
+    }
+    assert!(premium_fold && playable_fold && premium_limp, "not every branch was generated");
+}
+
+// ── hints ────────────────────────────────────────────────────────────────
+
+#[test]
+fn correct_answer_has_hint_in_both_styles() {
+    for topic in all_topics() {
+        for style in [TextStyle::Simple, TextStyle::Technical] {
+            let s = generate_training(TrainingRequest { text_style: style, ..req(topic, 42) }).unwrap();
+            let hint = s.correct_answer_hint().unwrap_or_else(|| {
+                panic!("{topic:?} ({style:?}): correct answer has no hint")
+            });
+            let words = hint.split_whitespace().count();
+            assert!(
+                (1..=40).contains(&words),
+                "{topic:?} ({style:?}): hint has {words} words: {hint}"
+            );
+        }
+    }
+}
+
+// ── answer lookup ────────────────────────────────────────────────────────
+
+#[test]
+fn correct_answer_and_is_answered_correctly() {
+    for topic in all_topics() {
+        for seed in SEEDS {
+            let s = generate_training(req(topic, seed)).unwrap();
+            let correct = s.correct_answer();
+            assert!(correct.is_correct);
+            assert!(s.is_answered_correctly(&correct.id));
+            assert!(s.is_answered_correctly(&format!("  {}\n", correct.id)));
+            for a in s.answers.iter().filter(|a| !a.is_correct) {
+                assert!(!s.is_answered_correctly(&a.id), "{topic:?} seed {seed}: {}", a.id);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "has no correct answer")]
+fn correct_answer_panics_with_scenario_id() {
+    let mut s = generate_training(req(TrainingTopic::BluffSpot, 1)).unwrap();
+    s.answers.iter_mut().for_each(|a| a.is_correct = false);
+    s.correct_answer();
+}
+
+// ── table setup ──────────────────────────────────────────────────────────
+
+/// Heads-up BTN vs BB setup with the given stacks and pot.
+fn table(hero_stack: u32, villain_stack: u32, pot_size: u32) -> TableSetup {
+    let card = |r, suit| Card { rank: Rank(r), suit };
+    let player = |seat, position, stack, is_hero| PlayerState {
+        seat, position, stack, is_hero, is_active: true, bounty: None,
+    };
+    TableSetup {
+        game_type: GameType::CashGame,
+        hero_position: Position::BTN,
+        hero_hand: [card(14, Suit::Spades), card(13, Suit::Spades)],
+        board: vec![],
+        players: vec![
+            player(1, Position::BTN, hero_stack, true),
+            player(2, Position::BB, villain_stack, false),
+        ],
+        pot_size,
+        current_bet: 0,
+        antes: None,
+    }
+}
+
+#[test]
+fn effective_stack_and_spr_known_values() {
+    let t = table(200, 80, 20);
+    assert_eq!(t.effective_stack(), 80);
+    assert!((t.spr() - 4.0).abs() < 1e-6);
+
+    let t = table(50, 300, 40);
+    assert_eq!(t.effective_stack(), 50);
+    assert!((t.spr() - 1.25).abs() < 1e-6);
+}
+
+#[test]
+fn effective_stack_with_all_in_player() {
+    let mut t = table(0, 120, 30);
+    assert_eq!(t.effective_stack(), 0);
+    assert_eq!(t.spr(), 0.0);
+
+    // Folded players are ignored.
+    t.players[0].is_active = false;
+    assert_eq!(t.effective_stack(), 120);
+    t.players[1].is_active = false;
+    assert_eq!(t.effective_stack(), 0);
+}
+
+#[test]
+fn pot_and_stack_helpers_edge_cases() {
+    use crate::training_engine::helpers::{effective_stack, pot_after_call, pot_after_raise};
+    assert_eq!(pot_after_call(100, 50), 150);
+    assert_eq!(pot_after_call(0, 50), 50);
+    assert_eq!(pot_after_call(100, 0), 100);
+    assert_eq!(pot_after_call(0, 0), 0);
+    // Villain bets 50 into 100; hero raises to 150.
+    assert_eq!(pot_after_raise(150, 50, 150), 300);
+    assert_eq!(pot_after_raise(0, 0, 30), 30);
+    assert_eq!(pot_after_raise(40, 0, 0), 40);
+
+    let mut t = table(200, 80, 20);
+    assert_eq!(effective_stack(&t.players), 80);
+    for p in &mut t.players {
+        p.stack = 0;
+    }
+    assert_eq!(effective_stack(&t.players), 0, "everyone all-in");
+    assert_eq!(effective_stack(&[]), 0);
+}
+
+#[test]
+fn table_street_agrees_with_topic_street() {
+    for topic in all_topics() {
+        for seed in SEEDS {
+            let s = generate_training(req(topic, seed)).unwrap();
+            assert_eq!(s.street(), topic.street(), "{topic:?} seed {seed}");
+            assert_eq!(s.table_setup.street(), s.street());
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "invalid board length 2")]
+fn table_street_panics_on_invalid_board() {
+    let mut t = table(100, 100, 10);
+    t.board = t.hero_hand.to_vec();
+    t.street();
+}
+
+// ── card parsing ─────────────────────────────────────────────────────────
+
+#[test]
+fn every_card_round_trips_through_from_str() {
+    for suit in Suit::all() {
+        for r in 2..=14 {
+            let card = Card { rank: Rank(r), suit };
+            assert_eq!(card.to_string().parse::<Card>().unwrap(), card);
+        }
+    }
+}
+
+#[test]
+fn card_parsing_is_case_insensitive() {
+    let ace_hearts = Card { rank: Rank(14), suit: Suit::Hearts };
+    assert_eq!("Ah".parse::<Card>().unwrap(), ace_hearts);
+    assert_eq!("AH".parse::<Card>().unwrap(), ace_hearts);
+    assert_eq!("ah".parse::<Card>().unwrap(), ace_hearts);
+    assert_eq!("tc".parse::<Card>().unwrap(), Card { rank: Rank(10), suit: Suit::Clubs });
+}
+
+#[test]
+fn invalid_card_strings_are_rejected() {
+    assert_eq!("1h".parse::<Card>(), Err(ParseCardError::InvalidRank('1')));
+    assert_eq!("Ax".parse::<Card>(), Err(ParseCardError::InvalidSuit('x')));
+    assert_eq!("".parse::<Card>(), Err(ParseCardError::InvalidLength(0)));
+    assert_eq!("10h".parse::<Card>(), Err(ParseCardError::InvalidLength(3)));
+    assert!("Kd ".parse::<Card>().is_err());
+}
+
+#[test]
+fn broadway_and_wheel_cards() {
+    for r in 2..=14 {
+        let card = Card { rank: Rank(r), suit: Suit::Spades };
+        assert_eq!(card.is_broadway(), r >= 10, "{card}");
+        assert_eq!(card.rank.is_honor(), card.is_broadway());
+        assert_eq!(card.is_wheel_card(), r <= 5 || r == 14, "{card}");
+        if (6..=9).contains(&r) {
+            assert!(!card.is_broadway() && !card.is_wheel_card(), "{card}");
+        }
+    }
+    // The Ace is both.
+    let ace = "Ad".parse::<Card>().unwrap();
+    assert!(ace.is_broadway() && ace.is_wheel_card());
+}
+
+#[test]
+fn suit_colors_and_symbols() {
+    use crate::training_engine::models::SuitColor;
+    let all = Suit::all();
+    assert_eq!(all.len(), 4);
+    assert_eq!(all, [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]);
+    let colors: Vec<SuitColor> = all.iter().map(|s| s.color()).collect();
+    assert_eq!(colors, [SuitColor::Black, SuitColor::Red, SuitColor::Red, SuitColor::Black]);
+    let symbols: Vec<&str> = all.iter().map(|s| s.unicode_symbol()).collect();
+    assert_eq!(symbols, ["♣", "♦", "♥", "♠"]);
+    assert!(symbols.iter().all(|s| !s.is_ascii()));
+}
+
+// ── hand ─────────────────────────────────────────────────────────────────
+
+/// Parse a two-card hand like `"AhKh"`.
+fn hand(s: &str) -> Hand {
+    Hand::from([s[..2].parse::<Card>().unwrap(), s[2..].parse::<Card>().unwrap()])
+}
+
+#[test]
+fn hand_notation() {
+    assert_eq!(hand("AhKh").notation(), "AKs");
+    assert_eq!(hand("QsJd").notation(), "QJo");
+    assert_eq!(hand("7c7d").notation(), "77");
+    // Low card first is normalised to high card first.
+    assert_eq!(hand("5dAd").notation(), "A5s");
+    assert_eq!(hand("9cTh").notation(), "T9o");
+
+    // The free-function form takes raw hole cards and is re-exported at the crate root.
+    let cards = |s: &str| -> [Card; 2] { hand(s).into() };
+    assert_eq!(crate::normalized_hand_notation(cards("2cAh")), "A2o");
+    assert_eq!(crate::normalized_hand_notation(cards("AhKh")), "AKs");
+    assert_eq!(crate::normalized_hand_notation(cards("7c7d")), "77");
+}
+
+#[test]
+fn hand_predicates_and_ordering() {
+    let h = hand("5dAd");
+    assert!(h.is_suited());
+    assert!(!h.is_pair());
+    assert_eq!(h.high_card().to_string(), "Ad");
+    assert_eq!(h.low_card().to_string(), "5d");
+    assert!(hand("7c7d").is_pair());
+    assert!(!hand("7c7d").is_suited());
+
+    let cards: [Card; 2] = h.into();
+    assert_eq!(Hand::from(cards), h);
+}
+
+// ── hand vs board ────────────────────────────────────────────────────────
+
+/// Parse space-separated cards like `"Kh 7c 2d"`.
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+}
+
+#[test]
+fn classify_hero_hand_vs_board_variants() {
+    use crate::training_engine::evaluator::{
+        classify_hero_hand_vs_board as classify, HandVsBoardStrength as S,
+    };
+    let cases = [
+        ("KsKd", "Kh 7c 2d",       S::TopSet),
+        ("7s7d", "Kh 7c 2d",       S::MiddleSet),
+        ("2s2h", "Kh 7c 2d",       S::BottomSet),
+        ("Ah5h", "Kh 7h 2h",       S::Flush),
+        ("9s8d", "Th 7c 6d",       S::Straight),
+        ("Kc7d", "Kh 7c 2d",       S::TwoPair),
+        ("AcKd", "Kh 7c 2d",       S::TopPairTopKicker),
+        ("KcTd", "Kh 7c 2d",       S::TopPairWeakKicker),
+        ("8c7d", "Kh 7c 2d",       S::MiddlePair),
+        ("9c9d", "Kh 7c 2d",       S::MiddlePair),
+        ("Ac2c", "Kh 7d 2d",       S::BottomPair),
+        ("QcQd", "Jh 7c 2d",       S::OverPair),
+        ("AcQd", "Jh 7c 2d",       S::NoPair),
+        ("Kc7d", "Kh 7c 2d 2s",    S::TwoPair),
+        ("Ac2s", "9h 2c 2d",       S::BottomSet),
+    ];
+    for (hand, board, expected) in cases {
+        assert_eq!(classify(self::hand(hand).into(), &cards(board)), expected, "{hand} on {board}");
+    }
+}
+
+#[test]
+fn pair_type_predicates() {
+    use crate::training_engine::evaluator::{
+        hero_has_overpair, hero_has_set, hero_has_top_pair, hero_has_two_pair,
+    };
+    // (hand, board, top pair, overpair, set, two pair)
+    let cases = [
+        ("AcKd", "Kh 7c 2d", true,  false, false, false),
+        ("KcTd", "Kh 7c 2d", true,  false, false, false),
+        ("KsKd", "Kh 7c 2d", false, false, true,  false), // pocket pair + board card = set
+        ("7s7d", "Kh 7c 2d", false, false, true,  false),
+        ("QcQd", "Jh 7c 2d", false, true,  false, false),
+        ("9c9d", "Kh 7c 2d", false, false, false, false),
+        ("Kc7d", "Kh 7c 2d", false, false, false, true),
+        ("Kc5d", "Kh Kc 2d", false, false, false, false), // trips, not a set
+        ("8c7d", "Kh 7c 2d", false, false, false, false),
+        ("AcQd", "Jh 7c 2d", false, false, false, false),
+        ("AhAd", "",         false, false, false, false),
+    ];
+    for (h, b, top, over, set, two) in cases {
+        let (h2, board) = (hand(h).into(), cards(b));
+        assert_eq!(hero_has_top_pair(h2, &board), top, "top pair: {h} on {b}");
+        assert_eq!(hero_has_overpair(h2, &board), over, "overpair: {h} on {b}");
+        assert_eq!(hero_has_set(h2, &board), set, "set: {h} on {b}");
+        assert_eq!(hero_has_two_pair(h2, &board), two, "two pair: {h} on {b}");
+    }
+}
+
+#[test]
+fn overpair_does_not_count_board_straight() {
+    use crate::training_engine::evaluator::{classify_hero_hand_vs_board, HandVsBoardStrength};
+    assert_eq!(
+        classify_hero_hand_vs_board(hand("AhAd").into(), &cards("2c 3d 4h 5s 6c")),
+        HandVsBoardStrength::OverPair
+    );
+}
+
+// ── five-card evaluation ─────────────────────────────────────────────────
+
+/// Parse exactly five space-separated cards.
+fn five(s: &str) -> [Card; 5] {
+    cards(s).try_into().unwrap()
+}
+
+#[test]
+fn five_card_categories_are_ordered() {
+    use crate::training_engine::evaluator::{evaluate_five_cards as eval, MadeHand};
+    let ladder = [
+        ("As Ks Qs Js Ts", MadeHand::StraightFlush), // royal flush
+        ("9h 8h 7h 6h 5h", MadeHand::StraightFlush),
+        ("7c 7d 7h 7s 2c", MadeHand::FourOfAKind),
+        ("Kc Kd Kh 4s 4c", MadeHand::FullHouse),
+        ("Ad Jd 8d 6d 2d", MadeHand::Flush),
+        ("Tc 9d 8h 7s 6c", MadeHand::Straight),
+        ("5c 4d 3h 2s Ac", MadeHand::Straight), // wheel
+        ("Qc Qd Qh 9s 2c", MadeHand::ThreeOfAKind),
+        ("Jc Jd 3h 3s Ac", MadeHand::TwoPair),
+        ("Tc Td 8h 5s 2c", MadeHand::OnePair),
+        ("Ac Qd 9h 6s 3c", MadeHand::HighCard),
+    ];
+    for (hand, category) in ladder {
+        assert_eq!(eval(five(hand)).category(), category, "{hand}");
+    }
+    for pair in ladder.windows(2) {
+        assert!(eval(five(pair[0].0)) > eval(five(pair[1].0)), "{} vs {}", pair[0].0, pair[1].0);
+    }
+}
+
+#[test]
+fn five_card_kickers_break_ties() {
+    use crate::training_engine::evaluator::evaluate_five_cards as eval;
+    // Same pair, better kicker.
+    assert!(eval(five("Ac Ad Kh 5s 2c")) > eval(five("As Ah Qh 5d 2d")));
+    // Same two pair, better kicker.
+    assert!(eval(five("Jc Jd 3h 3s Ac")) > eval(five("Js Jh 3c 3d Kc")));
+    // Full house compares trips first.
+    assert!(eval(five("3c 3d 3h 2s 2c")) > eval(five("2d 2h 2s Ac Ad")));
+    // Flush compares every card.
+    assert!(eval(five("Ad Jd 8d 6d 3d")) > eval(five("Ac Jc 8c 6c 2c")));
+    // Identical strength, different suits.
+    assert_eq!(eval(five("Kc Qd 9h 6s 3c")), eval(five("Kd Qh 9s 6c 3d")));
+}
+
+#[test]
+fn best_hand_from_seven_finds_the_strongest_five() {
+    use crate::training_engine::evaluator::{best_hand_from_seven, evaluate_five_cards, MadeHand};
+    let (best, rank) = best_hand_from_seven(hand("AhKh").into(), five("Qh Jh Th 2c 2d"));
+    assert_eq!(rank.category(), MadeHand::StraightFlush);
+    assert_eq!(evaluate_five_cards(best), rank);
+
+    // Board plays: hero's cards don't improve a board straight.
+    let (_, rank) = best_hand_from_seven(hand("2c3d").into(), five("Ts 9h 8d 7c 6s"));
+    assert_eq!(rank, evaluate_five_cards(five("Ts 9h 8d 7c 6s")));
+}
+
+#[test]
+fn hand_description_names_every_category() {
+    use crate::training_engine::evaluator::hand_description as describe;
+    let cases = [
+        ("As Ks Qs Js Ts", "Royal flush"),
+        ("9h 8h 7h 6h 5h", "Straight flush, Nine high"),
+        ("7c 7d 7h 7s 2c", "Four of a kind, Sevens with Two kicker"),
+        ("Kc Kd Kh 4s 4c", "Full house, Kings full of Fours"),
+        ("4d 4h Kc Ks 4s", "Full house, Fours full of Kings"),
+        ("Ad Jd 8d 6d 2d", "Flush, Ace high"),
+        ("Tc 9d 8h 7s 6c", "Straight, Ten high"),
+        ("Ac 2d 3h 4s 5c", "Straight, Five high"),
+        ("Qc Qd Qh 9s 2c", "Three of a kind, Queens with Nine kicker"),
+        ("Kc 5d Kh 5s Ac", "Two pair, Kings and Fives with Ace kicker"),
+        ("6c 6d 8h 5s 2c", "Pair of Sixes with Eight kicker"),
+        ("Ac Qd 9h 6s 3c", "Ace high with Queen kicker"),
+    ];
+    for (hand, expected) in cases {
+        assert_eq!(describe(five(hand)), expected, "{hand}");
+    }
+}
+
+#[test]
+fn technical_river_text_names_made_hand() {
+    for topic in [TrainingTopic::BluffSpot, TrainingTopic::RiverValueBet, TrainingTopic::RiverCallOrFold] {
+        let s = generate_training(TrainingRequest { text_style: TextStyle::Technical, ..req(topic, 42) }).unwrap();
+        assert!(s.question.contains("best five: "), "{topic:?}: {}", s.question);
+    }
+}
+
+// ── outs ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn count_outs_for_standard_draws() {
+    use crate::count_outs;
+    // Nut flush draw: 9 hearts left.
+    assert_eq!(count_outs(hand("AhKh").into(), &cards("2h 7h 9c")), 9);
+    // Flush draw plus the wheel gutshot: 9 hearts + 3 other fives.
+    assert_eq!(count_outs(hand("AhKh").into(), &cards("2h 3h 4c")), 12);
+    // Open-ended straight draw: four 4s and four 9s.
+    assert_eq!(count_outs(hand("6c7d").into(), &cards("5h 8h Jc")), 8);
+    // Gutshot: four 6s.
+    assert_eq!(count_outs(hand("9c8d").into(), &cards("5h 7h Kc")), 4);
+    // Combo draw (flush + open-ender): 9 hearts + 6 non-heart straight cards.
+    assert_eq!(count_outs(hand("9h8h").into(), &cards("7h 6c 2h")), 15);
+    // Backdoor flush only — no outs for the next card.
+    assert_eq!(count_outs(hand("9s8s").into(), &cards("Ah 2h 7s")), 0);
+}
+
+#[test]
+fn count_outs_excludes_seen_cards_and_completed_hands() {
+    use crate::count_outs;
+    // Flush already made on the turn: nothing left to draw to.
+    assert_eq!(count_outs(hand("AhKh").into(), &cards("2h 7h 9c 4h")), 0);
+    // A blank turn leaves all 9 hearts.
+    assert_eq!(count_outs(hand("AhKh").into(), &cards("2h 7h 9c 4c")), 9);
+    // No cards to come on the river.
+    assert_eq!(count_outs(hand("AhKh").into(), &cards("2h 7h 9c 4c 5d")), 0);
+}
+
+#[test]
+fn classify_draw_combined_lists_hero_draws() {
+    use crate::training_engine::evaluator::{classify_draw_combined, DrawType};
+    let draws = |h: &str, b: &str| classify_draw_combined(hand(h).into(), &cards(b));
+    assert_eq!(draws("AhKh", "2h 7h 9s"), vec![DrawType::FlushDraw]);
+    assert_eq!(draws("6c7d", "5h 8h Jc"), vec![DrawType::OESD]);
+    assert_eq!(draws("9c8d", "5h 7h Kc"), vec![DrawType::GutShot]);
+    assert_eq!(draws("6h7h", "5h 8h Jc"), vec![DrawType::OESD, DrawType::FlushDraw]);
+    assert_eq!(draws("AcKd", "2h 7h 9s"), vec![]);
+    // Made hands and full boards draw to nothing.
+    assert_eq!(draws("AhKh", "2h 7h 9h"), vec![]);
+    assert_eq!(draws("6c7d", "5h 8h 9c"), vec![]);
+    assert_eq!(draws("AhKh", "2h 7h 9s 4c 5d"), vec![]);
+
+    // T3 and T8 build the spot around hero's own draw when there is one.
+    for topic in [TrainingTopic::PotOddsAndEquity, TrainingTopic::SemiBluffDecision] {
+        for seed in 0..40 {
+            let s = generate_training(req(topic, seed)).unwrap();
+            let d = classify_draw_combined(s.table_setup.hero_hand, &s.table_setup.board);
+            let expected = match d.as_slice() {
+                [DrawType::FlushDraw] => "FlushDraw",
+                [DrawType::OESD] => "OESD",
+                [DrawType::GutShot] => "GutShot",
+                [_, DrawType::FlushDraw] => "ComboDraw",
+                _ => continue,
+            };
+            assert!(s.branch_key.starts_with(expected), "{} vs {:?}", s.branch_key, d);
+        }
+    }
+}
+
+#[test]
+fn combined_draw_equity_counts_shared_outs_once() {
+    use crate::training_engine::evaluator::{equity_from_outs, equity_of_combined_draws, DrawType};
+    for streets in [1u8, 2] {
+        let fd = DrawType::FlushDraw.equity(streets);
+        assert_eq!(equity_of_combined_draws(&[DrawType::FlushDraw], streets), fd);
+        assert_eq!(equity_of_combined_draws(&[], streets), 0.0);
+        // Flush + gutshot: 9 + 4 − 1 shared out.
+        let fg = equity_of_combined_draws(&[DrawType::GutShot, DrawType::FlushDraw], streets);
+        assert!(fg < fd + DrawType::GutShot.equity(streets));
+        assert!(fg > fd);
+        assert!((fg - equity_from_outs(12, streets)).abs() < 1e-5, "{fg}");
+        // Flush + open-ender is the 15-out combo draw.
+        let fo = equity_of_combined_draws(&[DrawType::OESD, DrawType::FlushDraw], streets);
+        assert!((fo - DrawType::ComboDraw.equity(streets)).abs() < 1e-5, "{fo}");
+    }
+}
+
+#[test]
+fn equity_from_outs_matches_rule_of_2_and_4() {
+    use crate::training_engine::evaluator::{equity_from_outs, equity_from_outs_rule_of_2_4};
+    for outs in [4u8, 8, 9] {
+        for streets in [1u8, 2] {
+            let exact = equity_from_outs(outs, streets);
+            let quick = equity_from_outs_rule_of_2_4(outs, streets);
+            assert!((exact - quick).abs() <= 0.03, "{outs} outs, {streets} streets: {exact} vs {quick}");
+        }
+    }
+    // Nine flush outs on the flop ≈ 35%.
+    assert!((equity_from_outs(9, 2) - 0.35).abs() < 0.005);
+    assert_eq!(equity_from_outs(9, 0), 0.0);
+}
+
+#[test]
+fn equity_from_outs_never_exceeds_one() {
+    use crate::count_outs;
+    use crate::training_engine::evaluator::{equity_from_outs, equity_from_outs_rule_of_2_4};
+    for outs in 0..=u8::MAX {
+        for streets in 0..=3 {
+            assert!(equity_from_outs(outs, streets) <= 1.0);
+            assert!(equity_from_outs_rule_of_2_4(outs, streets) <= 1.0);
+        }
+    }
+    // Composes with count_outs.
+    let outs = count_outs(hand("6c7d").into(), &cards("5h 8h Jc"));
+    assert!((equity_from_outs(outs, 2) - 0.315).abs() < 0.005);
+}
+
+// ── bet-to-pot math ──────────────────────────────────────────────────────
+
+#[test]
+fn bet_to_pot_frequencies() {
+    use crate::training_engine::evaluator::{
+        break_even_equity, maximum_bluff_frequency, minimum_defense_frequency, pot_odds_ratio,
+    };
+    // Pot-sized bet: call 100 into 200 (pot + bet) → 33%.
+    assert!((break_even_equity(100, 200) - 1.0 / 3.0).abs() < 1e-6);
+    assert!((minimum_defense_frequency(100, 100) - 0.5).abs() < 1e-6);
+    assert!((maximum_bluff_frequency(100, 100) - 1.0 / 3.0).abs() < 1e-6);
+    assert_eq!(pot_odds_ratio(100, 100), (1, 1));
+    assert_eq!(pot_odds_ratio(300, 100), (3, 1));
+    assert_eq!(pot_odds_ratio(250, 100), (5, 2));
+
+    // Zero bet.
+    assert_eq!(break_even_equity(0, 100), 0.0);
+    assert_eq!(minimum_defense_frequency(0, 100), 1.0);
+    assert_eq!(maximum_bluff_frequency(0, 100), 0.0);
+    assert_eq!(pot_odds_ratio(100, 0), (1, 0));
+    assert_eq!(break_even_equity(0, 0), 0.0);
+    assert_eq!(pot_odds_ratio(0, 0), (0, 0));
+}
+
+#[test]
+fn calculate_pot_odds_bundles_ratio_and_equity() {
+    use crate::training_engine::evaluator::calculate_pot_odds;
+    // Call 75 into 100: 75 / 175 to break even.
+    let odds = calculate_pot_odds(75, 100);
+    assert_eq!(odds.ratio, (4, 3));
+    assert!((odds.break_even_equity - 0.4286).abs() < 1e-4);
+    assert!((odds.percentage - 42.86).abs() < 1e-2);
+    assert_eq!(odds.to_string(), "4:3 (43% equity needed)");
+    assert_eq!(calculate_pot_odds(100, 300).to_string(), "3:1 (25% equity needed)");
+    assert_eq!(calculate_pot_odds(0, 100).break_even_equity, 0.0);
+}
+
+#[test]
+fn adjusted_equity_favours_position() {
+    use crate::training_engine::evaluator::adjusted_equity;
+    for raw in [0.0f32, 0.1, 0.35, 0.6, 0.95, 1.0] {
+        for stack_bb in [0u32, 20, 100, 250] {
+            let (ip, oop) = (adjusted_equity(raw, true, stack_bb), adjusted_equity(raw, false, stack_bb));
+            assert!(ip >= oop, "{raw} at {stack_bb} BB");
+            assert!((0.0..=1.0).contains(&ip) && (0.0..=1.0).contains(&oop));
+        }
+    }
+    assert!((adjusted_equity(0.4, true, 100) - 0.42).abs() < 1e-5);
+    assert!((adjusted_equity(0.4, false, 100) - 0.34).abs() < 1e-5);
+    // Deeper stacks widen the gap; all-in there is nothing left to realize.
+    let gap = |bb| adjusted_equity(0.4, true, bb) - adjusted_equity(0.4, false, bb);
+    assert!(gap(200) > gap(100) && gap(100) > gap(20));
+    assert_eq!(adjusted_equity(0.4, false, 0), 0.4);
+    assert_eq!(adjusted_equity(0.98, true, 200), 1.0);
+
+    // T3 hero defends the BB out of position and quotes realized equity.
+    for seed in SEEDS {
+        let s = generate_training(TrainingRequest {
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::PotOddsAndEquity, seed)
+        })
+        .unwrap();
+        assert!(s.answers[0].explanation.contains("out of position 100 BB deep"), "{}", s.answers[0].explanation);
+    }
+}
+
+#[test]
+fn balanced_bluff_counts() {
+    use crate::training_engine::evaluator::{
+        gto_bluff_combo_count, is_range_balanced, maximum_bluff_frequency, optimal_bluff_to_value_ratio,
+    };
+    // Pot-sized bet: one-third bluffs, one bluff per two value combos.
+    assert!((optimal_bluff_to_value_ratio(100, 100) - 1.0 / 3.0).abs() < 1e-6);
+    assert_eq!(optimal_bluff_to_value_ratio(75, 100), maximum_bluff_frequency(75, 100));
+    assert_eq!(gto_bluff_combo_count(100, 100, 20), 10);
+    assert_eq!(gto_bluff_combo_count(50, 100, 30), 10);
+    assert_eq!(gto_bluff_combo_count(0, 100, 20), 0);
+    // Bigger bets support more bluffs.
+    assert!(gto_bluff_combo_count(150, 100, 20) > gto_bluff_combo_count(75, 100, 20));
+
+    assert!(is_range_balanced(20, 10, 100, 100));
+    assert!(is_range_balanced(20, 12, 100, 100));
+    assert!(is_range_balanced(20, 8, 100, 100));
+    assert!(!is_range_balanced(20, 13, 100, 100));
+    assert!(!is_range_balanced(20, 7, 100, 100));
+
+    // T4 quotes the balanced bluff count for its large bet.
+    for seed in SEEDS {
+        let s = generate_training(TrainingRequest {
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::BluffSpot, seed)
+        })
+        .unwrap();
+        let pot = s.table_setup.pot_size;
+        let large_bet = (pot as f32 * 0.75).round() as u32;
+        let n = gto_bluff_combo_count(large_bet, pot, 20);
+        let large = &s.answers[2].explanation;
+        assert!(large.contains(&format!("{n} bluff combos per 20 value combos")), "{large}");
+    }
+}
+
+#[test]
+fn expected_value_and_ev_of_bet() {
+    use crate::training_engine::evaluator::{ev_of_bet, ev_of_raise, expected_value};
+    // 25% to win 300, risking 100: 75 − 75 = break-even.
+    assert!(expected_value(0.25, 300, 100).abs() < 1e-4);
+    assert!(expected_value(0.40, 300, 100) > 0.0);
+    assert!(expected_value(0.10, 300, 100) < 0.0);
+
+    // Pure bluff: 75-chip bet into 100 breaks even at 75/175 folds.
+    assert!(ev_of_bet(0.75 / 1.75, 100, 75, 0.0).abs() < 1e-3);
+    assert!(ev_of_bet(0.55, 100, 75, 0.0) > 0.0);
+    assert!(ev_of_bet(0.25, 100, 75, 0.0) < 0.0);
+    // Always folds → win the pot; never folds → plain EV of the call: win
+    // pot + villain's bet, lose hero's bet.
+    assert_eq!(ev_of_bet(1.0, 100, 50, 0.3), 100.0);
+    assert!((ev_of_bet(0.0, 100, 50, 0.3) - expected_value(0.3, 150, 50)).abs() < 1e-4);
+    assert!((ev_of_bet(0.0, 100, 50, 0.3) - (0.3 * 200.0 - 50.0)).abs() < 1e-4);
+    // A called bet that always wins nets the pot plus the bet.
+    assert!((ev_of_bet(0.0, 100, 50, 1.0) - 150.0).abs() < 1e-4);
+
+    // Raise to 30 over a 10-chip bet in a 30-chip pot: a call makes it 80.
+    assert_eq!(ev_of_raise(1.0, 30, 10, 30, 0.2), 30.0);
+    assert!((ev_of_raise(0.0, 30, 10, 30, 0.2) - (0.2 * 80.0 - 30.0)).abs() < 1e-4);
+    assert!(ev_of_raise(0.5, 30, 10, 30, 0.2) > 0.0);
+    assert!(ev_of_raise(0.2, 30, 10, 30, 0.2) < 0.0);
+}
+
+#[test]
+fn ev_sign_in_explanations_matches_correct_answer() {
+    let tech = |topic, seed| {
+        generate_training(TrainingRequest { text_style: TextStyle::Technical, ..req(topic, seed) }).unwrap()
+    };
+    for seed in SEEDS {
+        let s = tech(TrainingTopic::PotOddsAndEquity, seed);
+        let call = &s.answers[0];
+        assert_eq!(call.explanation.contains("= +"), call.is_correct, "{}", call.explanation);
+
+        let s = tech(TrainingTopic::BluffSpot, seed);
+        let large = &s.answers[2];
+        assert_eq!(large.explanation.contains("EV is +"), large.is_correct, "{}", large.explanation);
+
+        let s = tech(TrainingTopic::SemiBluffDecision, seed);
+        let correct = s.correct_answer();
+        if correct.id == "A" {
+            assert!(correct.explanation.contains("EV of calling: -"), "{}", correct.explanation);
+        } else {
+            assert!(correct.explanation.contains(" +"), "{}", correct.explanation);
+            assert!(!correct.explanation.contains(" -"), "{}", correct.explanation);
+        }
+    }
+}
+
+#[test]
+fn pot_committed_threshold() {
+    use crate::training_engine::evaluator::{commit_threshold_spr, pot_committed};
+    assert_eq!(commit_threshold_spr(), 1.5);
+    assert!(pot_committed(100, 100)); // SPR 1.0
+    assert!(!pot_committed(200, 100)); // SPR 2.0
+    assert!(!pot_committed(150, 100)); // SPR 1.5 is not below the threshold
+    assert!(pot_committed(149, 100));
+}
+
+#[test]
+fn low_spr_explanations_mention_commitment() {
+    use crate::training_engine::evaluator::pot_committed;
+    // Committed T13 spots need a short Advanced stack and a big pot — sample widely.
+    let (mut seen_committed, mut seen_deep) = (false, false);
+    for seed in 0..2000 {
+        let r = TrainingRequest {
+            difficulty: DifficultyLevel::Advanced,
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::ThreeBetPotCbet, seed)
+        };
+        let s = generate_training(r).unwrap();
+        let stack = s.table_setup.players[1].stack;
+        let committed = pot_committed(stack, s.table_setup.pot_size);
+        seen_committed |= committed;
+        seen_deep |= !committed;
+        for a in &s.answers {
+            assert_eq!(a.explanation.contains("pot-committed"), committed, "{}", a.explanation);
+        }
+
+        let s = generate_training(TrainingRequest {
+            difficulty: DifficultyLevel::Advanced,
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::PreflopDecision, seed)
+        }).unwrap();
+        if !s.branch_key.starts_with("ThreeBetPot") {
+            continue;
+        }
+        let hero = s.table_setup.players.iter().find(|p| p.is_hero).unwrap().stack;
+        let (pot, three_bet) = (s.table_setup.pot_size, s.table_setup.current_bet);
+        let behind = hero.saturating_sub(three_bet);
+        let committed = pot_committed(behind, pot + three_bet - three_bet / 3);
+        assert_eq!(s.answers[1].explanation.contains("pot-committed"), committed);
+    }
+    assert!(seen_committed && seen_deep);
+}
+
+// ── ranges ───────────────────────────────────────────────────────────────
+
+#[test]
+fn range_string_combo_counts() {
+    use crate::training_engine::evaluator::range::RangeString;
+    let range = |s: &str| s.parse::<RangeString>().unwrap();
+    assert_eq!(range("AA").combo_count(), 6);
+    assert_eq!(range("AKs").combo_count(), 4);
+    assert_eq!(range("AKo").combo_count(), 12);
+    assert_eq!(range("AK").combo_count(), 16);
+    assert_eq!(range("AK"), range("AKs,AKo"));
+    assert_eq!(range("AK").hand_type_count(), 2);
+
+    let pairs = range("88+");
+    assert_eq!(pairs.hand_type_count(), 7);
+    assert_eq!(pairs.combo_count(), 42);
+    assert_eq!(range("ATs+"), range("ATs,AJs,AQs,AKs"));
+    assert_eq!(range("AA,KK,AKs,AQs").combo_count(), 20);
+    assert_eq!(range(" kk , aa ").to_vec().len(), 12);
+}
+
+#[test]
+fn range_string_contains_and_errors() {
+    use crate::training_engine::evaluator::range::{ParseRangeError, RangeString};
+    let r: RangeString = "88+,AKs".parse().unwrap();
+    assert!(r.contains(hand("8c8d").into()));
+    assert!(r.contains(hand("KhAh").into()));
+    assert!(!r.contains(hand("AhKd").into()));
+    assert!(!r.contains(hand("7c7d").into()));
+    assert!(r.to_vec().iter().all(|&h| r.contains(h)));
+
+    assert_eq!("".parse::<RangeString>(), Err(ParseRangeError::Empty));
+    assert_eq!("AKx".parse::<RangeString>(), Err(ParseRangeError::InvalidToken("AKx".into())));
+    assert!("AAs".parse::<RangeString>().is_err());
+    assert!("A1".parse::<RangeString>().is_err());
+}
+
+// ── ICM ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn icm_equity_properties() {
+    use crate::training_engine::evaluator::icm::{icm_equity, ICMPayoutTable};
+    let table = ICMPayoutTable { places: vec![0.50, 0.30, 0.20] };
+
+    let eq = icm_equity(&[1000, 1000, 1000, 1000], &table).unwrap();
+    assert!(eq.iter().all(|e| (e - 0.25).abs() < 1e-9), "{eq:?}");
+
+    for chips in [[5000, 3000, 1500, 500], [200, 9000, 400, 400], [2500, 2500, 4000, 1000]] {
+        let eq = icm_equity(&chips, &table).unwrap();
+        assert!((eq.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        let total: u32 = chips.iter().sum();
+        let leader = (0..chips.len()).max_by_key(|&i| chips[i]).unwrap();
+        assert!(eq[leader] < chips[leader] as f64 / total as f64, "{chips:?} → {eq:?}");
+    }
+
+    // Winner-take-all is just the chip fraction.
+    let wta = icm_equity(&[300, 100], &ICMPayoutTable { places: vec![1.0] }).unwrap();
+    assert!((wta[0] - 0.75).abs() < 1e-9);
+}
+
+#[test]
+fn icm_equity_rejects_bad_tables() {
+    use crate::training_engine::evaluator::icm::{icm_equity, ICMError, ICMPayoutTable};
+    let three = ICMPayoutTable { places: vec![0.50, 0.30, 0.20] };
+    assert_eq!(icm_equity(&[100, 100], &three), Err(ICMError::PayoutMismatch));
+    assert_eq!(
+        icm_equity(&[100, 100], &ICMPayoutTable { places: vec![] }),
+        Err(ICMError::PayoutMismatch)
+    );
+    assert_eq!(
+        icm_equity(&[100, 100, 100], &ICMPayoutTable { places: vec![0.5, 0.3] }),
+        Err(ICMError::PayoutsDoNotSumToOne)
+    );
+}
+
+#[test]
+fn custom_tournament_stage_interpolates() {
+    use crate::training_engine::topics::preflop::{
+        generate_icm_at_stage, icm_risk_premium_pct, TournamentStage,
+    };
+    use rand::SeedableRng;
+    let custom = TournamentStage::Custom { players_remaining: 10, paid_spots: 3 };
+    let premium = icm_risk_premium_pct(custom);
+    let (bubble, final_table) = (
+        icm_risk_premium_pct(TournamentStage::Bubble),
+        icm_risk_premium_pct(TournamentStage::FinalTable),
+    );
+    assert!(premium > final_table.min(bubble) && premium < final_table.max(bubble), "{premium}");
+    assert_eq!(custom.to_string(), "Custom (10 left, 3 paid)");
+
+    // Outside the anchored ratios the nearest stage applies.
+    let deep = TournamentStage::Custom { players_remaining: 1000, paid_spots: 10 };
+    assert_eq!(icm_risk_premium_pct(deep), icm_risk_premium_pct(TournamentStage::EarlyLevels));
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+    let mut deck = crate::training_engine::deck::Deck::new_shuffled(&mut rng);
+    let s = generate_icm_at_stage(&mut rng, &mut deck, DifficultyLevel::Beginner, "IC-1".into(), TextStyle::Simple, custom).unwrap();
+    assert!(s.branch_key.starts_with("Custom:"), "{}", s.branch_key);
+    assert!(s.question.contains("10 players left, top 3 get paid"), "{}", s.question);
+}
+
+#[test]
+fn bounty_tournaments_populate_player_bounties() {
+    for seed in SEEDS {
+        let plain = generate_training(req(TrainingTopic::ICMAndTournamentDecision, seed)).unwrap();
+        assert!(plain.table_setup.players.iter().all(|p| p.bounty.is_none()));
+
+        let pko = generate_training(TrainingRequest {
+            is_bounty: true,
+            excluded_topics: Vec::new(),
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::ICMAndTournamentDecision, seed)
+        }).unwrap();
+        assert!(pko.table_setup.players.iter().all(|p| matches!(p.bounty, Some(b) if b >= 10)));
+        assert!(pko.answers[0].explanation.contains("bounty"), "{}", pko.answers[0].explanation);
+        // Bounties never change the decision.
+        assert_eq!(pko.correct_answer().id, plain.correct_answer().id);
+
+        // Other topics ignore the flag.
+        let cbet = generate_training(TrainingRequest {
+            is_bounty: true,
+            excluded_topics: Vec::new(),
+            ..req(TrainingTopic::PostflopContinuationBet, seed)
+        }).unwrap();
+        assert!(cbet.table_setup.players.iter().all(|p| p.bounty.is_none()));
+    }
+}
+
+#[test]
+fn ante_games_add_the_ante_to_the_pot() {
+    let (mut with, mut without) = (0, 0);
+    for seed in 0..20 {
+        let s = generate_training(TrainingRequest {
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::ICMAndTournamentDecision, seed)
+        }).unwrap();
+        let blinds = 150; // 1.5 BB at 100 chips per BB
+        match s.table_setup.antes {
+            Some(ante) => {
+                with += 1;
+                assert!(s.table_setup.pot_size >= blinds + ante);
+                assert!(s.question.contains("1BB ante, pot is 2.5 BB preflop vs 1.5 BB without"), "{}", s.question);
+            }
+            None => {
+                without += 1;
+                assert_eq!(s.table_setup.pot_size, blinds);
+                assert!(!s.question.contains("ante"), "{}", s.question);
+            }
+        }
+    }
+    assert!(with > 0 && without > 0);
+
+    for topic in all_topics().into_iter().filter(|&t| t != TrainingTopic::ICMAndTournamentDecision) {
+        assert_eq!(generate_training(req(topic, 1)).unwrap().table_setup.antes, None, "{topic:?}");
+    }
+}
+
+#[test]
+fn sng_bubble_uses_sit_and_go_structure() {
+    use crate::training_engine::topics::preflop::generate_icm_sng;
+    use rand::SeedableRng;
+    let (mut pushes, mut folds) = (0, 0);
+    for seed in 0..30 {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let mut deck = crate::training_engine::deck::Deck::new_shuffled(&mut rng);
+        let s = generate_icm_sng(&mut rng, &mut deck, DifficultyLevel::Intermediate, format!("IC-{seed}"), TextStyle::Technical).unwrap();
+        assert_eq!(s.table_setup.game_type, GameType::SitAndGo);
+        assert_eq!(s.table_setup.players.len(), 4);
+        let chips: u32 = s.table_setup.players.iter().map(|p| p.stack).sum();
+        assert_eq!(chips, 9 * 1500);
+        assert_eq!(s.answers.iter().filter(|a| a.is_correct).count(), 1);
+        assert!(s.answers[0].explanation.contains("bubble risk premium"), "{}", s.answers[0].explanation);
+        if s.answers[0].is_correct { pushes += 1 } else { folds += 1 }
+    }
+    assert!(pushes > 0 && folds > 0);
+    assert_eq!(GameType::SpinAndGo.to_string(), "Spin & Go");
+}
+
+#[test]
+fn board_texture_score_orders_boards_by_wetness() {
+    use crate::training_engine::evaluator::board_texture_score;
+    let score = |b: &str| board_texture_score(&cards(b));
+    assert!(score("8h 9h Th") >= 0.9);
+    assert!(score("2d 7h Kc") <= 0.1);
+
+    let (dry, wet) = (score("2d 7h Kc"), score("8h 9h Th"));
+    for mid in ["Kh 7h 2d", "9c 8d 2h", "Jh Td 4h", "Ah 2c 3d"] {
+        let s = score(mid);
+        assert!(dry < s && s < wet, "{mid}: {s}");
+    }
+    // Two-tone connected beats two-tone disconnected.
+    assert!(score("Jh Td 4h") > score("Kh 7h 2d"));
+    assert_eq!(score(""), 0.0);
+}
+
+#[test]
+fn board_texture_cbet_frequency_and_draw_heaviness() {
+    use crate::training_engine::evaluator::BoardTexture;
+    let freqs: Vec<f32> = BoardTexture::all().iter().map(|t| t.cbet_frequency()).collect();
+    assert!(freqs.windows(2).all(|w| w[0] > w[1]), "{freqs:?}");
+    assert_eq!(freqs, [0.85, 0.70, 0.55]);
+    let heavy: Vec<BoardTexture> = BoardTexture::all().into_iter().filter(|t| t.is_draw_heavy()).collect();
+    assert_eq!(heavy, [BoardTexture::Wet]);
+
+    // T2 quotes the texture's frequency in Technical explanations.
+    for seed in SEEDS {
+        let s = generate_training(TrainingRequest {
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::PostflopContinuationBet, seed)
+        })
+        .unwrap();
+        let expected = if s.branch_key.starts_with("Dry") {
+            "85%"
+        } else if s.branch_key == "SemiWet" {
+            "70%"
+        } else {
+            "55%"
+        };
+        assert!(s.correct_answer().explanation.contains(expected), "{}", s.correct_answer().explanation);
+    }
+}
+
+#[test]
+fn cbet_size_recommendation_follows_texture_spr_and_position() {
+    use crate::training_engine::evaluator::{cbet_size_recommendation, BoardTexture};
+    assert!(cbet_size_recommendation(BoardTexture::Wet, 1.0, Position::BTN) >= 0.75);
+    assert!(cbet_size_recommendation(BoardTexture::Dry, 12.0, Position::BB) <= 0.40);
+    for texture in BoardTexture::all() {
+        for spr in [0.5, 1.0, 2.5, 4.0, 10.0, 50.0] {
+            let ip = cbet_size_recommendation(texture, spr, Position::BTN);
+            let oop = cbet_size_recommendation(texture, spr, Position::SB);
+            assert!((0.25..=1.25).contains(&ip) && (0.25..=1.25).contains(&oop));
+            assert!(oop <= ip, "{texture:?} SPR {spr}");
+            assert!(cbet_size_recommendation(texture, spr + 1.0, Position::BTN) <= ip);
+        }
+    }
+    let at = |t| cbet_size_recommendation(t, 10.0, Position::BTN);
+    assert!(at(BoardTexture::Dry) < at(BoardTexture::SemiWet) && at(BoardTexture::SemiWet) < at(BoardTexture::Wet));
+
+    // T2 bets the size nearest the recommendation: small on dry boards unless
+    // the stack is shallow, 75% on wet ones.
+    for seed in 0..300 {
+        let s = generate_training(TrainingRequest {
+            difficulty: DifficultyLevel::Advanced,
+            ..req(TrainingTopic::PostflopContinuationBet, seed)
+        })
+        .unwrap();
+        let id = &s.correct_answer().id;
+        match s.branch_key.as_str() {
+            "Dry:RangeAdv" if s.table_setup.spr() >= 2.0 => assert_eq!(id, "B"),
+            "Dry:RangeAdv" => assert!(id == "B" || id == "C"),
+            "Dry:NoRangeAdv" => assert_eq!(id, "A"),
+            _ => assert_eq!(id, "C"),
+        }
+    }
+}
+
+#[test]
+fn range_advantage_follows_board_height_and_seats() {
+    use crate::training_engine::evaluator::{range_advantage, RangeAdvantage};
+    let high = cards("Ad Kc 7h");
+    let low = cards("2c 6d 9h");
+    assert_eq!(range_advantage(Position::BTN, Position::BB, &high), RangeAdvantage::Hero);
+    assert_eq!(range_advantage(Position::BTN, Position::BB, &low), RangeAdvantage::Villain);
+    assert_eq!(range_advantage(Position::BB, Position::BTN, &high), RangeAdvantage::Villain);
+    assert_eq!(range_advantage(Position::BB, Position::CO, &low), RangeAdvantage::Hero);
+    assert_eq!(range_advantage(Position::BTN, Position::BB, &cards("Qd 8c 3h")), RangeAdvantage::Neutral);
+    // Between two openers only low boards split them, toward the earlier seat.
+    assert_eq!(range_advantage(Position::UTG, Position::BTN, &low), RangeAdvantage::Hero);
+    assert_eq!(range_advantage(Position::UTG, Position::BTN, &high), RangeAdvantage::Neutral);
+    assert_eq!(range_advantage(Position::SB, Position::BB, &low), RangeAdvantage::Neutral);
+
+    // T2 c-bets small on dry A/K-high boards and checks dry low ones.
+    for seed in 0..200 {
+        let s = generate_training(req(TrainingTopic::PostflopContinuationBet, seed)).unwrap();
+        let t = &s.table_setup;
+        let adv = range_advantage(t.hero_position, Position::BB, &t.board);
+        match s.branch_key.as_str() {
+            "Dry:RangeAdv" => assert_eq!(adv, RangeAdvantage::Hero),
+            "Dry:NoRangeAdv" => assert_ne!(adv, RangeAdvantage::Hero),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn paired_and_trips_boards() {
+    use crate::training_engine::evaluator::{classify_board_paired, classify_board_trips};
+    let cases = [
+        // (board, paired, trips)
+        ("",               None,     None),
+        ("Kh",             None,     None),
+        ("Kh 7c 2d",       None,     None),
+        ("Kh Kc 2d",       Some(13), None),
+        ("7h 7c 7d",       Some(7),  Some(7)),
+        ("Kh 7c 2d 2s",    Some(2),  None),
+        ("Kh 7c 2d 9s Ad", None,     None),
+        ("Kh Kc 7d 7s 2c", Some(13), None),
+        ("5h 5c 5d Qs Qc", Some(12), Some(5)),
+        ("9h 9c 9d 9s 2c", Some(9),  Some(9)),
+    ];
+    for (board, paired, trips) in cases {
+        assert_eq!(classify_board_paired(&cards(board)), paired, "{board}");
+        assert_eq!(classify_board_trips(&cards(board)), trips, "{board}");
+    }
+
+    // T2 Technical text calls out paired flops, and only those.
+    let mut seen = false;
+    for seed in 0..60 {
+        let s = generate_training(TrainingRequest {
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::PostflopContinuationBet, seed)
+        })
+        .unwrap();
+        let paired = classify_board_paired(&s.table_setup.board).is_some();
+        seen |= paired;
+        assert!(s.answers.iter().all(|a| a.explanation.contains("board is paired") == paired), "seed {seed}");
+    }
+    assert!(seen, "no paired flop in 60 seeds");
+}
+
+#[test]
+fn board_draw_counts() {
+    use crate::training_engine::evaluator::board_draw_count;
+    let cases = [
+        ("Kh 7c 2d",       0), // dry rainbow
+        ("Kh 7h 2d",       1), // two-tone, disconnected
+        ("9h 8h 2c",       2), // two-tone, open-ended straight draw
+        ("9c 8d 7h",       1), // one connected group
+        ("Ac Kd 3h",       0), // A-K and A-3 only allow one-ended draws
+        ("Kh 7c 2d 4s",    1), // 2-4 connect
+        ("Kh Qh 5c 4c",    4), // two flush draws, two straight groups
+        ("Kh 7c 2d 9s Jd", 2), // 7-9-J group plus diamonds
+        ("Ah Kh Qh Jh Th", 2), // flush suit plus broadway group
+    ];
+    for (board, expected) in cases {
+        assert_eq!(board_draw_count(&cards(board)), expected, "{board}");
+    }
+
+    // The same spot on a drawier board is more complex.
+    let mut s = generate_training(req(TrainingTopic::PostflopContinuationBet, 3)).unwrap();
+    s.table_setup.board = cards("Kh 7c 2d");
+    let dry = s.complexity_score();
+    s.table_setup.board = cards("9h 8h 7d");
+    assert!(s.complexity_score() > dry);
+}
+
+#[test]
+fn possible_straights_and_flushes() {
+    use crate::training_engine::evaluator::{flush_on_board, flush_possible, straight_possible};
+    assert_eq!(straight_possible(&cards("Kh 7c 2d")), Vec::<[u8; 5]>::new());
+    assert_eq!(straight_possible(&cards("9h 8c 7d")), vec![
+        [7, 8, 9, 10, 11], [6, 7, 8, 9, 10], [5, 6, 7, 8, 9],
+    ]);
+    assert_eq!(straight_possible(&cards("Ah 4c 3d Ks")), vec![[14, 2, 3, 4, 5]]);
+    assert_eq!(straight_possible(&cards("Qh Jc Td 2s 3c")), vec![
+        [10, 11, 12, 13, 14], [9, 10, 11, 12, 13], [8, 9, 10, 11, 12],
+    ]);
+    // A straight made on the board alone is still possible.
+    assert_eq!(straight_possible(&cards("6h 5c 4d 3s 2c"))[0], [4, 5, 6, 7, 8]);
+    assert_eq!(straight_possible(&cards("6h 5c 4d 3s 2c")).len(), 4);
+
+    assert_eq!(flush_possible(&cards("Kh 7c 2d")), vec![]);
+    assert_eq!(flush_possible(&cards("Kh 7h 2d")), vec![Suit::Hearts]);
+    assert_eq!(flush_possible(&cards("Kh 7h 2c 3c")), vec![Suit::Clubs, Suit::Hearts]);
+    // On the river two suited cards are no longer a threat.
+    assert_eq!(flush_possible(&cards("Kh 7h 2c 3c 9d")), vec![]);
+    assert_eq!(flush_possible(&cards("Kh 7h 2h 3c 9d")), vec![Suit::Hearts]);
+
+    assert_eq!(flush_on_board(&cards("Kh 7h 2h 3h 9d")), None);
+    assert_eq!(flush_on_board(&cards("Kh 7h 2h 3h 9h")), Some(Suit::Hearts));
+    assert_eq!(flush_on_board(&cards("Kh 7h 2h")), None);
+
+    // T14 names the board's straight and flush threats in Technical text.
+    for seed in SEEDS {
+        let s = generate_training(TrainingRequest {
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::RiverCallOrFold, seed)
+        })
+        .unwrap();
+        let n = straight_possible(&s.table_setup.board).len();
+        assert!(s.question.contains("This board has"), "{}", s.question);
+        assert_eq!(s.question.contains("no straight"), n == 0, "{}", s.question);
+    }
+}
+
+#[test]
+fn board_suit_distribution() {
+    use crate::training_engine::evaluator::{board_suit_counts, is_monotone_board, is_rainbow_board};
+    let rainbow = cards("2d 7h Kc");
+    assert_eq!(board_suit_counts(&rainbow), [1, 1, 1, 0]);
+    assert!(is_rainbow_board(&rainbow) && !is_monotone_board(&rainbow));
+
+    let monotone = cards("8h 9h Th");
+    assert_eq!(board_suit_counts(&monotone), [0, 0, 3, 0]);
+    assert!(is_monotone_board(&monotone) && !is_rainbow_board(&monotone));
+
+    let mixed = cards("As 9s 4c 4d");
+    assert_eq!(board_suit_counts(&mixed), [1, 1, 0, 2]);
+    assert!(!is_rainbow_board(&mixed) && !is_monotone_board(&mixed));
+
+    assert!(is_rainbow_board(&[]) && !is_monotone_board(&[]));
+}
+
+#[test]
+fn nut_flush_suit_and_draw() {
+    use crate::training_engine::evaluator::{hero_has_nut_flush_draw, nut_flush_suit};
+    assert_eq!(nut_flush_suit(&cards("2d 7h Kc")), None);
+    assert_eq!(nut_flush_suit(&cards("2h 7h Kc")), Some(Suit::Hearts));
+    // Tie on a paired-suit turn goes to suit order.
+    assert_eq!(nut_flush_suit(&cards("2s 7s Kd 9d")), Some(Suit::Diamonds));
+
+    // Monotone board: the lone ace of the suit is already the nut flush draw.
+    let mono = cards("8h 9h 2h");
+    assert!(hero_has_nut_flush_draw(hand("AhKc").into(), &mono));
+    assert!(!hero_has_nut_flush_draw(hand("KhQh").into(), &mono));
+    // Two-tone: the ace needs a suited partner.
+    let two_tone = cards("8h 9h 2c");
+    assert!(hero_has_nut_flush_draw(hand("Ah5h").into(), &two_tone));
+    assert!(!hero_has_nut_flush_draw(hand("AhKc").into(), &two_tone));
+    assert!(!hero_has_nut_flush_draw(hand("AhKh").into(), &cards("2d 7c Kc")));
+}
+
+#[test]
+fn bluff_spot_mentions_nut_flush_when_possible() {
+    use crate::training_engine::evaluator::nut_flush_suit;
+    for seed in 0..20 {
+        let s = generate_training(TrainingRequest {
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::BluffSpot, seed)
+        }).unwrap();
+        let large = &s.answers[2].explanation;
+        assert_eq!(
+            large.contains("nut flush"),
+            nut_flush_suit(&s.table_setup.board).is_some(),
+            "{large}"
+        );
+    }
+}
+
+#[test]
+fn backdoor_draws_from_hole_cards() {
+    use crate::training_engine::evaluator::{has_backdoor_flush_draw, has_backdoor_straight_draw};
+    // Suited hole cards with exactly one board card of the suit.
+    assert!(has_backdoor_flush_draw(hand("Ah5h").into(), &cards("Kh 8c 2d")));
+    assert!(!has_backdoor_flush_draw(hand("Ah5h").into(), &cards("Kh 8h 2d")));
+    assert!(!has_backdoor_flush_draw(hand("Ah5c").into(), &cards("Kh 8c 2d")));
+    assert!(!has_backdoor_flush_draw(hand("Ah5h").into(), &cards("Kc 8c 2d")));
+
+    // Three distinct ranks inside one straight window, ace low included.
+    assert!(has_backdoor_straight_draw(hand("9c8d").into(), &cards("Qh 3s 2d")));
+    assert!(has_backdoor_straight_draw(hand("Ac2d").into(), &cards("5h Ks Td")));
+    assert!(has_backdoor_straight_draw(hand("AcKd").into(), &cards("Th 4s 2d")));
+    assert!(!has_backdoor_straight_draw(hand("9c5d").into(), &cards("Kh 3s 2d")));
+    assert!(!has_backdoor_straight_draw(hand("8c8d").into(), &cards("9h 3s 2d")));
+}
+
+#[test]
+fn cbet_and_check_raise_mention_backdoor_draws() {
+    use crate::training_engine::evaluator::{has_backdoor_flush_draw, has_backdoor_straight_draw};
+    for topic in [TrainingTopic::PostflopContinuationBet, TrainingTopic::CheckRaiseSpot] {
+        for seed in 0..40 {
+            let s = generate_training(TrainingRequest {
+                text_style: TextStyle::Technical,
+                ..req(topic, seed)
+            }).unwrap();
+            let t = &s.table_setup;
+            let backdoor = has_backdoor_flush_draw(t.hero_hand, &t.board)
+                || has_backdoor_straight_draw(t.hero_hand, &t.board);
+            for a in &s.answers {
+                assert_eq!(a.explanation.contains("extra equity"), backdoor, "{topic:?} seed {seed}: {}", a.explanation);
+            }
+        }
+    }
+}
+
+#[test]
+fn blocker_score_and_nut_straight() {
+    use crate::training_engine::evaluator::{
+        blocker_value_score, blocks_nut_straight, nut_straight_ranks,
+    };
+    // Best straight on 6-7-8-K-K needs 9-T; 5-9 and 4-5 make lesser ones.
+    let board = cards("6c 7d 8h Ks Kd");
+    assert_eq!(nut_straight_ranks(&board), vec![Rank(9), Rank(10)]);
+    assert!(blocks_nut_straight(hand("Tc2d").into(), &board));
+    assert!(!blocks_nut_straight(hand("5c2d").into(), &board));
+    assert_eq!(blocker_value_score(hand("Tc2d").into(), &board), 0.75);
+    assert_eq!(blocker_value_score(hand("5c2d").into(), &board), 0.25);
+    assert_eq!(blocker_value_score(hand("3c2d").into(), &board), 0.0);
+
+    // A-K-Q leaves exactly one straight: J-T.
+    let broadway = cards("Ac Kd Qh 3s 3d");
+    assert_eq!(nut_straight_ranks(&broadway), vec![Rank(10), Rank(11)]);
+    assert_eq!(blocker_value_score(hand("Jc2d").into(), &broadway), 1.0);
+    assert!(nut_straight_ranks(&cards("2c 7d Qh")).is_empty());
+
+    // Nut flush ace peaks the score; a flush halves straight blockers.
+    let flushy = cards("2h 7h Jh 8c 9d");
+    assert_eq!(blocker_value_score(hand("Ah3c").into(), &flushy), 1.0);
+    assert_eq!(blocker_value_score(hand("Kh3c").into(), &flushy), 0.5);
+    assert_eq!(blocker_value_score(hand("Tc3d").into(), &flushy), 0.375);
+}
+
+#[test]
+fn bluff_spot_counts_blocked_combos() {
+    use crate::training_engine::evaluator::blocker_value_score;
+    for seed in 0..200 {
+        let s = generate_training(TrainingRequest {
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::BluffSpot, seed)
+        }).unwrap();
+        let large = &s.answers[2].explanation;
+        if large.contains("Blockers:") {
+            assert!(large.contains("combos") || large.contains("leaving villain"), "{large}");
+            assert!(blocker_value_score(s.table_setup.hero_hand, &s.table_setup.board) > 0.0);
+        }
+    }
+}
+
+#[test]
+fn range_width_estimates() {
+    use crate::training_engine::evaluator::{bb_defense_range_width, villain_range_width};
+    assert_eq!(villain_range_width(Position::UTG), 0.15);
+    assert_eq!(villain_range_width(Position::HJ), 0.20);
+    assert_eq!(villain_range_width(Position::CO), 0.25);
+    assert_eq!(villain_range_width(Position::BTN), 0.40);
+    // Early to late, every open is at least as wide as the one before.
+    let opens: Vec<f32> = [Position::UTG, Position::UTG1, Position::UTG2, Position::LJ,
+        Position::HJ, Position::CO, Position::BTN]
+        .iter()
+        .map(|&p| villain_range_width(p))
+        .collect();
+    assert!(opens.windows(2).all(|w| w[0] <= w[1]));
+
+    // Defend wider against late openers and smaller raises.
+    assert!(bb_defense_range_width(Position::BTN, 3) > bb_defense_range_width(Position::UTG, 3));
+    assert!(bb_defense_range_width(Position::BTN, 2) > bb_defense_range_width(Position::BTN, 4));
+    assert!((bb_defense_range_width(Position::BTN, 5) - 0.30).abs() < 1e-6);
+    assert!(bb_defense_range_width(Position::BTN, 0) <= 1.0);
+}
+
+#[test]
+fn preflop_equity_vs_position_range_ranks_aa_first_and_trash_last() {
+    use crate::training_engine::evaluator::preflop_hand_equity_vs_position_range;
+    let hand = |s: &str| -> [Card; 2] { cards(s).try_into().unwrap() };
+    let mut every_hand = Vec::new();
+    for hi in 2..=14u8 {
+        for lo in 2..=hi {
+            let suits: &[Suit] = if hi == lo { &[Suit::Hearts] } else { &[Suit::Spades, Suit::Hearts] };
+            for &s in suits {
+                every_hand.push([Card { rank: Rank(hi), suit: Suit::Spades }, Card { rank: Rank(lo), suit: s }]);
+            }
+        }
+    }
+    for &pos in Position::all() {
+        let eq = |h: [Card; 2]| preflop_hand_equity_vs_position_range(h, pos);
+        let aa = eq(hand("Ah As"));
+        let trash = eq(hand("7h 2s"));
+        assert!((0.80..=0.86).contains(&aa), "{pos:?}: {aa}");
+        for &h in &every_hand {
+            let e = eq(h);
+            let is_aa = h[0].rank.0 == 14 && h[1].rank.0 == 14;
+            assert!(is_aa || e < aa, "{pos:?} {h:?}: {e}");
+            assert!(e >= trash, "{pos:?} {h:?}: {e}");
+        }
+    }
+    // Tighter openers leave less equity; the BB never opens, so it faces any two.
+    let mid_pair = hand("7h 7s");
+    let vs = |pos| preflop_hand_equity_vs_position_range(mid_pair, pos);
+    assert!(vs(Position::UTG) < vs(Position::CO) && vs(Position::CO) < vs(Position::BTN));
+    assert!(vs(Position::BB) > vs(Position::BTN));
+    let connector = preflop_hand_equity_vs_position_range(hand("7h 6h"), Position::UTG);
+    assert!((0.35..0.50).contains(&connector), "{connector}");
+}
+
+#[test]
+fn position_range_tables_widen_toward_the_button() {
+    use crate::training_engine::evaluator::{villain_range_width, PositionRange};
+    for table in [PositionRange::standard_6max(), PositionRange::standard_9max()] {
+        assert!(table.get(Position::BTN) > table.get(Position::CO), "{table:?}");
+        assert!(table.get(Position::CO) > table.get(Position::HJ), "{table:?}");
+        assert_eq!(table.get(Position::BB), 0.0);
+        assert_eq!(table.get(Position::UTG), table.utg);
+    }
+    for &pos in Position::all() {
+        assert_eq!(villain_range_width(pos), PositionRange::standard_9max().get(pos));
+    }
+
+    // T1 open-raise spots quote the 6-max width in Technical text.
+    let quoted = (0..40).any(|seed| {
+        let s = generate_training(TrainingRequest {
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::PreflopDecision, seed)
+        })
+        .unwrap();
+        s.branch_key.starts_with("OpenRaise") && s.answers.iter().all(|a| a.explanation.contains("opens about"))
+    });
+    assert!(quoted);
+}
+
+#[test]
+fn bb_defense_and_pot_odds_quote_range_widths() {
+    for topic in [TrainingTopic::BigBlindDefense, TrainingTopic::PotOddsAndEquity] {
+        for &seed in &SEEDS {
+            let s = generate_training(TrainingRequest {
+                text_style: TextStyle::Technical,
+                ..req(topic, seed)
+            }).unwrap();
+            for a in &s.answers {
+                assert!(a.explanation.contains("% of hands"), "{topic:?}: {}", a.explanation);
+            }
+        }
+    }
+}
+
+#[test]
+fn implied_odds_and_set_mining() {
+    use crate::training_engine::evaluator::{break_even_equity, implied_odds, set_mining_ev};
+    assert_eq!(implied_odds(50, 100, 0), break_even_equity(50, 100));
+    let mut last = 1.0;
+    for gain in [0, 25, 50, 100, 400] {
+        let eq = implied_odds(50, 100, gain);
+        assert!(eq < last, "gain {gain}: {eq}");
+        last = eq;
+    }
+    assert!((implied_odds(50, 100, 50) - 0.25).abs() < 1e-6);
+    assert_eq!(implied_odds(0, 0, 0), 0.0);
+
+    // 5% rule: multiway calls up to 5% of the stack are profitable.
+    assert!(set_mining_ev(40, 10, 1000, 2) > 0.0);
+    assert!(set_mining_ev(60, 10, 1000, 3) < 0.0);
+    // Heads-up the same small call loses; no opponents never pays.
+    assert!(set_mining_ev(40, 10, 1000, 1) < 0.0);
+    assert!(set_mining_ev(10, 0, 1000, 0) < 0.0);
+}
+
+#[test]
+fn pot_odds_technical_mentions_implied_odds() {
+    let mut pairs = 0;
+    for seed in 0..60 {
+        let s = generate_training(TrainingRequest {
+            text_style: TextStyle::Technical,
+            ..req(TrainingTopic::PotOddsAndEquity, seed)
+        }).unwrap();
+        let pair = s.table_setup.hero_hand[0].rank == s.table_setup.hero_hand[1].rank;
+        for a in &s.answers {
+            assert!(a.explanation.contains("Implied odds"), "{}", a.explanation);
+            assert_eq!(a.explanation.contains("set mining"), pair, "{}", a.explanation);
+        }
+        pairs += pair as u32;
+
+        let simple = generate_training(req(TrainingTopic::PotOddsAndEquity, seed)).unwrap();
+        assert!(simple.answers.iter().all(|a| !a.explanation.contains("Implied odds")));
+    }
+    assert!(pairs > 0);
+}
+
+// ── batch generation ─────────────────────────────────────────────────────
+
+/// One request per topic × seed, including a street selector.
+fn batch_requests() -> Vec<TrainingRequest> {
+    let mut requests: Vec<TrainingRequest> =
+        all_topics().into_iter().flat_map(|t| SEEDS.map(|seed| req(t, seed))).collect();
+    requests.push(req(Street::Turn, 3));
+    requests
+}
+
+#[test]
+fn generate_batch_matches_one_at_a_time() {
+    use crate::training_engine::generate_batch;
+    let requests = batch_requests();
+    let batch = generate_batch(requests.clone()).unwrap();
+    assert_eq!(batch.len(), requests.len());
+    for (request, scenario) in requests.into_iter().zip(&batch) {
+        assert_eq!(&generate_training(request).unwrap(), scenario);
+    }
+    assert!(generate_batch(vec![]).unwrap().is_empty());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn generate_batch_par_is_byte_identical_to_sequential() {
+    use crate::training_engine::{generate_batch, generate_batch_par};
+    let sequential = generate_batch(batch_requests()).unwrap();
+    let parallel = generate_batch_par(batch_requests()).unwrap();
+    assert_eq!(serde_json::to_string(&parallel).unwrap(), serde_json::to_string(&sequential).unwrap());
+}
+
+// ── binary encoding ──────────────────────────────────────────────────────
+
+#[cfg(feature = "bincode")]
+#[test]
+fn bincode_round_trip_is_smaller_than_json() {
+    use crate::training_engine::{generate_training_bytes, TrainingScenario};
+    for topic in all_topics() {
+        let s = generate_training(req(topic, 11)).unwrap();
+        let bytes = s.to_bytes().unwrap();
+        assert!(bytes.len() < serde_json::to_string(&s).unwrap().len(), "{topic:?}");
+        assert_eq!(TrainingScenario::from_bytes(&bytes).unwrap(), s);
+        assert_eq!(generate_training_bytes(req(topic, 11)).unwrap(), bytes);
+    }
+    assert!(TrainingScenario::from_bytes(&[1, 2, 3]).is_err());
+}
+
+// ── follow-up ────────────────────────────────────────────────────────────
+
+#[test]
+fn follow_up_maps_each_action_to_the_next_topic() {
+    use crate::training_engine::TopicSelector;
+    let next = |topic, action: &str| {
+        let s = generate_training(req(topic, 4)).unwrap();
+        s.follow_up(action).topic
+    };
+    let is = |t| TopicSelector::Topic(t);
+    use TrainingTopic::*;
+    assert_eq!(next(PreflopDecision, "Raise to 3 BB"), is(PostflopContinuationBet));
+    assert_eq!(next(PreflopDecision, "call"), is(CheckRaiseSpot));
+    assert_eq!(next(PreflopDecision, "Fold"), is(PreflopDecision));
+    assert_eq!(next(BigBlindDefense, "3-bet to 9 BB"), is(ThreeBetPotCbet));
+    assert_eq!(next(SqueezePlay, "Call"), is(PotOddsAndEquity));
+    assert_eq!(next(ICMAndTournamentDecision, "All-in"), is(ICMAndTournamentDecision));
+    assert_eq!(next(PostflopContinuationBet, "Bet small"), is(TurnBarrelDecision));
+    assert_eq!(next(PostflopContinuationBet, "Check"), is(DelayedCbet));
+    assert_eq!(next(CheckRaiseSpot, "Call"), is(TurnProbeBet));
+    assert_eq!(next(TurnBarrelDecision, "Bet large"), is(RiverValueBet));
+    assert_eq!(next(TurnProbeBet, "Check"), is(RiverCallOrFold));
+    assert_eq!(next(BluffSpot, "All-in"), is(PreflopDecision));
+
+    // Option IDs resolve to their text; the follow-up is deterministic and generates.
+    let s = generate_training(req(PotOddsAndEquity, 4)).unwrap();
+    let call = s.answers.iter().find(|a| a.text == "Call").unwrap();
+    let by_id = s.follow_up(&call.id);
+    assert_eq!(by_id, s.follow_up("Call"));
+    assert_eq!(by_id.topic, is(TurnProbeBet));
+    assert_ne!(by_id.rng_seed, s.follow_up("Fold").rng_seed);
+    for a in &s.answers {
+        assert!(generate_training(s.follow_up(&a.id)).is_ok());
+    }
+}
+
+#[test]
+fn related_topics_cover_every_topic() {
+    for topic in all_topics() {
+        let related = generate_training(req(topic, 2)).unwrap().related_topics();
+        assert!((2..=4).contains(&related.len()), "{topic:?}: {related:?}");
+        assert!(!related.contains(&topic), "{topic:?} lists itself");
+        // Every topic is reachable from some other topic.
+        assert!(all_topics().iter().any(|&t| t.related_topics().contains(&topic)), "{topic:?}");
+    }
+    assert_eq!(
+        TrainingTopic::BluffSpot.related_topics()[..2],
+        [TrainingTopic::RiverCallOrFold, TrainingTopic::SemiBluffDecision]
+    );
+}
+
+// ── key concepts ─────────────────────────────────────────────────────────────
+
+#[test]
+fn every_topic_tags_at_least_two_concepts() {
+    for topic in all_topics() {
+        for seed in SEEDS {
+            let s = generate_training(req(topic, seed)).unwrap();
+            assert!(s.key_concepts.len() >= 2, "{topic:?} seed {seed}: {:?}", s.key_concepts);
+            assert!(s.key_concepts.iter().all(|c| !c.is_empty()), "{topic:?}: {:?}", s.key_concepts);
+            for base in topic.key_concepts() {
+                assert!(s.key_concepts.iter().any(|c| c == base), "{topic:?} seed {seed} lacks {base}");
+            }
+        }
+    }
+    assert_eq!(TrainingTopic::PotOddsAndEquity.key_concepts()[..2], ["pot_odds", "draw_equity"]);
+    assert_eq!(TrainingTopic::ICMAndTournamentDecision.key_concepts()[..2], ["icm", "push_fold"]);
+    assert_eq!(TrainingTopic::BluffSpot.key_concepts()[..2], ["fold_equity", "bluffing"]);
+
+    let three_bet = (0..100)
+        .map(|seed| generate_training(req(TrainingTopic::PreflopDecision, seed)).unwrap())
+        .find(|s| s.branch_key.starts_with("ThreeBetPot"))
+        .unwrap();
+    assert!(three_bet.key_concepts.contains(&"spr".to_string()), "{:?}", three_bet.key_concepts);
+}
+
+#[test]
+fn topics_for_concept_lists_every_teaching_topic() {
+    use crate::training_engine::topics_for_concept;
+    assert_eq!(
+        topics_for_concept("pot_odds"),
+        [TrainingTopic::PotOddsAndEquity, TrainingTopic::BigBlindDefense, TrainingTopic::RiverCallOrFold]
+    );
+    assert_eq!(
+        topics_for_concept("icm"),
+        [TrainingTopic::ICMAndTournamentDecision, TrainingTopic::ChipLeaderPlay]
+    );
+    assert!(topics_for_concept("no_such_concept").is_empty());
+    for topic in all_topics() {
+        for concept in topic.key_concepts() {
+            assert!(topics_for_concept(concept).contains(&topic), "{topic:?} / {concept}");
+        }
+    }
+}
+
+// ── scenario filter ──────────────────────────────────────────────────────
+
+#[test]
+fn generate_until_finds_a_matching_scenario() {
+    use crate::training_engine::{generate_until, ScenarioFilter};
+    let deep = ScenarioFilter { min_spr: Some(5.0), ..ScenarioFilter::default() };
+    let s = generate_until(req(TrainingTopic::PotOddsAndEquity, 1), &deep, 50).unwrap();
+    assert!(s.table_setup.spr() >= 5.0, "{}", s.table_setup.spr());
+    assert!(s.matches_filter(&deep));
+
+    let calls = ScenarioFilter {
+        required_street: Some(Street::Flop),
+        branch_key_contains: Some(":Call".into()),
+        ..ScenarioFilter::default()
+    };
+    let s = generate_until(req(TrainingTopic::PotOddsAndEquity, 1), &calls, 200).unwrap();
+    assert!(s.branch_key.ends_with(":Call"), "{}", s.branch_key);
+
+    // Seeds count up from the request's seed.
+    let any = ScenarioFilter::default();
+    assert_eq!(
+        generate_until(req(TrainingTopic::BluffSpot, 9), &any, 1),
+        Some(generate_training(req(TrainingTopic::BluffSpot, 9)).unwrap())
+    );
+
+    // An impossible filter gives up after `max_attempts`.
+    let wrong_topic = ScenarioFilter { topic: Some(TrainingTopic::BluffSpot), ..ScenarioFilter::default() };
+    assert_eq!(generate_until(req(TrainingTopic::DelayedCbet, 1), &wrong_topic, 10), None);
+    let river_on_flop = ScenarioFilter { required_street: Some(Street::River), ..ScenarioFilter::default() };
+    assert!(!generate_training(req(TrainingTopic::PostflopContinuationBet, 1)).unwrap().matches_filter(&river_on_flop));
+}
+
+// ── scenario hash ────────────────────────────────────────────────────────
+
+#[test]
+fn scenario_hash_identifies_the_spot_not_the_wording() {
+    for topic in all_topics() {
+        let a = generate_training(req(topic, 3)).unwrap();
+        let b = generate_training(req(topic, 3)).unwrap();
+        assert_eq!(a.scenario_hash(), b.scenario_hash(), "{topic:?}");
+
+        let technical = generate_training(TrainingRequest {
+            text_style: TextStyle::Technical,
+            ..req(topic, 3)
+        }).unwrap();
+        assert_ne!(a.question, technical.question);
+        assert_eq!(a.scenario_hash(), technical.scenario_hash(), "{topic:?}");
+    }
+
+    // Different deals hash apart.
+    let mut hashes: Vec<u64> = all_topics()
+        .into_iter()
+        .flat_map(|t| (0..20).map(move |seed| generate_training(req(t, seed)).unwrap().scenario_hash()))
+        .collect();
+    let n = hashes.len();
+    hashes.sort();
+    hashes.dedup();
+    assert_eq!(hashes.len(), n);
+}
+
+// ── validation ───────────────────────────────────────────────────────────
+
+#[test]
+fn generated_scenarios_validate_cleanly() {
+    for topic in all_topics() {
+        for &seed in &SEEDS {
+            let s = generate_training(req(topic, seed)).unwrap();
+            assert_eq!(s.validate(), vec![], "{topic:?} seed {seed}");
+        }
+    }
+}
+
+#[test]
+fn generate_training_returns_ok_and_errors_display() {
+    use crate::training_engine::GenerationError;
+    // Callers migrating from the infallible API only need `.unwrap()` or `?`.
+    let s: crate::training_engine::TrainingScenario =
+        generate_training(req(TrainingTopic::BluffSpot, 1)).unwrap();
+    assert!(s.validate().is_empty());
+    assert!(all_topics().iter().all(|&t| generate_training(req(t, 9)).is_ok()));
+
+    assert_eq!(GenerationError::DeckExhausted.to_string(), "deck exhausted");
+    assert_eq!(
+        GenerationError::InvariantViolation("PF-1: question is empty".into()).to_string(),
+        "scenario invariant violated: PF-1: question is empty"
+    );
+}
+
+#[test]
+fn validate_reports_each_broken_invariant() {
+    use crate::training_engine::ValidationError;
+    let mut s = generate_training(req(TrainingTopic::PostflopContinuationBet, 1)).unwrap();
+    let hero = s.table_setup.hero_hand[0];
+    let first = s.table_setup.board[0];
+    s.table_setup.hero_hand[1] = hero;
+    s.table_setup.board.push(hero);
+    s.table_setup.board.push(first);
+    s.answers[1].id = s.answers[0].id.clone();
+    for a in &mut s.answers {
+        a.is_correct = false;
+    }
+    s.question.clear();
+    s.branch_key.clear();
+    s.table_setup.pot_size = 0;
+
+    let dup = s.answers[0].id.clone();
+    assert_eq!(
+        s.validate(),
+        vec![
+            ValidationError::CorrectAnswerCount(0),
+            ValidationError::DuplicateAnswerId(dup),
+            ValidationError::DuplicateHoleCard(hero),
+            ValidationError::BoardOverlapsHand(hero),
+            ValidationError::DuplicateBoardCard(first),
+            ValidationError::BoardLength { expected: 3, found: 5 },
+            ValidationError::EmptyQuestion,
+            ValidationError::EmptyBranchKey,
+            ValidationError::EmptyPot,
+        ]
+    );
+    assert_eq!(ValidationError::CorrectAnswerCount(0).to_string(), "expected exactly 1 correct answer, found 0");
+}
+
+// ── markdown ─────────────────────────────────────────────────────────────
+
+#[cfg(feature = "markdown")]
+#[test]
+fn markdown_lists_question_answers_and_explanation() {
+    for topic in all_topics() {
+        let s = generate_training(req(topic, 42)).unwrap();
+        let md = s.to_markdown();
+        assert_eq!(md, s.to_markdown(), "deterministic for the same scenario");
+        assert!(md.contains(&s.scenario_id));
+        assert!(md.contains("## Question"));
+        for a in &s.answers {
+            assert!(md.contains(&format!("- **{}.** {}", a.id, a.text)), "{md}");
+        }
+        let details = &md[md.find("<details>").expect("spoiler block")..];
+        assert!(details.contains(&s.correct_answer().explanation));
+        assert_eq!(md.matches("```").count(), 4, "hand and board code blocks");
+    }
+}
+
+// ── anki export ──────────────────────────────────────────────────────────
+
+#[test]
+fn anki_rows_have_one_tab_and_the_correct_answer() {
+    use crate::training_engine::anki_deck_from_scenarios;
+    let scenarios: Vec<_> = all_topics()
+        .into_iter()
+        .map(|topic| generate_training(TrainingRequest { text_style: TextStyle::Technical, ..req(topic, 42) }).unwrap())
+        .collect();
+    for s in &scenarios {
+        let row = s.to_anki_export();
+        assert_eq!(row.matches('\t').count(), 1, "{row}");
+        assert!(!row.contains('\n'));
+        let (front, back) = row.split_once('\t').unwrap();
+        assert!(front.contains("Hand: "), "{front}");
+        let correct = s.correct_answer();
+        assert!(back.starts_with(&correct.text), "{back}");
+        assert!(back.contains(correct.explanation.replace('\n', " ").as_str()));
+    }
+
+    let deck = anki_deck_from_scenarios(&scenarios);
+    assert_eq!(deck.lines().filter(|l| *l == "#deck:PokerDrill").count(), 1);
+    let rows: Vec<&str> = deck.lines().filter(|l| !l.starts_with('#')).collect();
+    assert_eq!(rows.len(), scenarios.len());
+    assert!(rows.iter().all(|r| r.matches('\t').count() == 1));
+}
+
+// ── csv export ───────────────────────────────────────────────────────────
+
+#[test]
+fn csv_rows_match_headers_and_the_correct_answer() {
+    use crate::training_engine::models::TrainingScenario;
+    // Minimal RFC 4180 field splitter: quoted fields may hold commas and "".
+    fn split(row: &str) -> Vec<String> {
+        let (mut fields, mut cur, mut quoted, mut chars) = (Vec::new(), String::new(), false, row.chars().peekable());
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => { cur.push('"'); chars.next(); }
+                '"' => quoted = !quoted,
+                ',' if !quoted => fields.push(std::mem::take(&mut cur)),
+                _ => cur.push(c),
+            }
+        }
+        fields.push(cur);
+        fields
+    }
+    let headers: Vec<&str> = TrainingScenario::csv_headers().split(',').collect();
+    assert_eq!(headers[3], "difficulty");
+    assert_eq!(headers.last(), Some(&"correct_answer_id"));
+    for topic in all_topics() {
+        for seed in SEEDS {
+            let s = generate_training(req(topic, seed)).unwrap();
+            let row = s.to_csv_row();
+            assert!(!row.contains('\n'), "{row}");
+            let fields = split(&row);
+            assert_eq!(fields.len(), headers.len(), "{row}");
+            assert_eq!(fields[0], s.scenario_id);
+            assert_eq!(fields[3], s.inferred_difficulty().to_string());
+            assert_eq!(fields[5], s.question);
+            assert_eq!(fields[6], s.correct_answer().id);
+            assert!(!row.contains(&s.correct_answer().explanation));
+        }
+    }
+}
+
+// ── hand sequence ────────────────────────────────────────────────────────
+
+#[test]
+fn hand_sequence_shares_hole_cards_across_streets() {
+    use crate::training_engine::generate_hand_sequence;
+    let topic_sets = [
+        [TrainingTopic::PreflopDecision, TrainingTopic::PostflopContinuationBet,
+         TrainingTopic::TurnBarrelDecision, TrainingTopic::BluffSpot],
+        [TrainingTopic::ICMAndTournamentDecision, TrainingTopic::PotOddsAndEquity,
+         TrainingTopic::DelayedCbet, TrainingTopic::RiverCallOrFold],
+        [TrainingTopic::BigBlindDefense, TrainingTopic::ThreeBetPotCbet,
+         TrainingTopic::TurnProbeBet, TrainingTopic::RiverValueBet],
+    ];
+    for topics in topic_sets {
+        for &seed in &SEEDS {
+            let seq = generate_hand_sequence(seed, topics, DifficultyLevel::Intermediate, TextStyle::Simple).unwrap();
+            let hero = seq[0].table_setup.hero_hand;
+            let river = &seq[3].table_setup.board;
+            for (i, s) in seq.iter().enumerate() {
+                assert_eq!(s.topic, topics[i]);
+                assert_eq!(s.table_setup.hero_hand, hero, "seed {seed} step {i}");
+                assert_eq!(s.table_setup.board.len(), [0, 3, 4, 5][i]);
+                assert_eq!(&river[..s.table_setup.board.len()], &s.table_setup.board[..]);
+            }
+
+            let prefix = seq[0].scenario_id.strip_suffix("-S1").expect("first ID ends in -S1");
+            assert!(prefix.starts_with("HS-"));
+            for (i, s) in seq.iter().enumerate() {
+                assert_eq!(s.scenario_id, format!("{prefix}-S{}", i + 1));
+            }
+        }
+    }
+}
+
+#[test]
+fn hand_sequence_covers_every_topic_combination() {
+    use crate::training_engine::{generate_hand_sequence, GenerationError};
+    let on = |street: Street| all_topics().into_iter().filter(move |t| t.street() == street);
+    let difficulties = [DifficultyLevel::Beginner, DifficultyLevel::Intermediate, DifficultyLevel::Advanced];
+    let mut seed = 0u64;
+    for pre in on(Street::Preflop) {
+        for flop in on(Street::Flop) {
+            for turn in on(Street::Turn) {
+                for river in on(Street::River) {
+                    seed += 1;
+                    let topics = [pre, flop, turn, river];
+                    let difficulty = difficulties[seed as usize % 3];
+                    let result = generate_hand_sequence(seed, topics, difficulty, TextStyle::Simple);
+                    if !topics.iter().all(|t| t.supports_hand_sequence()) {
+                        assert!(matches!(result, Err(GenerationError::InvalidTopicConstraint(_))), "{topics:?}");
+                        continue;
+                    }
+                    let seq = result.unwrap_or_else(|e| panic!("{topics:?} seed {seed}: {e}"));
+                    let hero = seq[0].table_setup.hero_hand;
+                    let river_board = &seq[3].table_setup.board;
+                    for s in &seq {
+                        assert_eq!(s.table_setup.hero_hand, hero, "{topics:?} seed {seed}");
+                        assert_eq!(&river_board[..s.table_setup.board.len()], &s.table_setup.board[..], "{topics:?} seed {seed}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn hand_sequence_rejects_topic_on_wrong_street() {
+    use crate::training_engine::GenerationError;
+    let err = crate::training_engine::generate_hand_sequence(
+        1,
+        [TrainingTopic::PostflopContinuationBet, TrainingTopic::PotOddsAndEquity,
+         TrainingTopic::DelayedCbet, TrainingTopic::BluffSpot],
+        DifficultyLevel::Beginner,
+        TextStyle::Simple,
+    )
+    .unwrap_err();
+    assert!(matches!(&err, GenerationError::InvalidTopicConstraint(m) if m.contains("Preflop topic")), "{err}");
+}
+
+// ── hand history ─────────────────────────────────────────────────────────
+
+#[test]
+fn hand_history_json_round_trip() {
+    use crate::training_engine::HandHistory;
+    let mut history = HandHistory::new();
+    for (i, topic) in all_topics().into_iter().enumerate() {
+        history.record(generate_training(req(topic, i as u64)).unwrap(), Some("A"));
+    }
+    history.record(generate_training(req(TrainingTopic::BluffSpot, 3)).unwrap(), None);
+
+    let json = history.to_json();
+    let restored = HandHistory::from_json(&json).expect("round-trip JSON parses");
+    assert_eq!(restored, history);
+    assert!(history.entries.iter().all(|e| e.timestamp_secs.is_some()));
+    assert!(HandHistory::from_json("{not json").is_err());
+}
+
+#[test]
+fn hand_history_counts_correct_and_incorrect() {
+    use crate::training_engine::HandHistory;
+    let mut history = HandHistory::new();
+    let mut expected_correct = 0;
+    for (i, topic) in all_topics().into_iter().enumerate() {
+        let s = generate_training(req(topic, i as u64)).unwrap();
+        // Alternate right and wrong answers.
+        let id = if i % 2 == 0 {
+            expected_correct += 1;
+            s.correct_answer().id.clone()
+        } else {
+            s.answers.iter().find(|a| !a.is_correct).unwrap().id.clone()
+        };
+        history.record(s, Some(&id));
+    }
+    history.record(generate_training(req(TrainingTopic::BluffSpot, 3)).unwrap(), None);
+
+    assert_eq!(history.correct_count(), expected_correct);
+    assert_eq!(history.incorrect_entries().len(), all_topics().len() - expected_correct);
+    assert!(history.incorrect_entries().iter().all(|e| e.answer_id.is_some()));
+}
+
+// ── difficulty steps ─────────────────────────────────────────────────────────
+
+#[test]
+fn difficulty_steps_up_and_down() {
+    use DifficultyLevel::*;
+    assert_eq!(Beginner.harder(), Some(Intermediate));
+    assert_eq!(Intermediate.harder(), Some(Advanced));
+    assert_eq!(Advanced.harder(), None);
+    assert_eq!(Advanced.easier(), Some(Intermediate));
+    assert_eq!(Intermediate.easier(), Some(Beginner));
+    assert_eq!(Beginner.easier(), None);
+    assert_eq!(Beginner.harder().and_then(DifficultyLevel::harder).and_then(DifficultyLevel::harder), None);
+
+    for d in [Beginner, Intermediate, Advanced] {
+        assert_eq!(DifficultyLevel::from_u8(d.as_u8()), Some(d));
+    }
+    assert_eq!([Beginner.as_u8(), Intermediate.as_u8(), Advanced.as_u8()], [0, 1, 2]);
+    assert_eq!(DifficultyLevel::from_u8(3), None);
+}
+
+// ── request parsing ──────────────────────────────────────────────────────────
+
+#[test]
+fn request_parses_from_comma_separated_fields() {
+    let parsed: TrainingRequest = "BluffSpot,Beginner,42,Simple".parse().unwrap();
+    let manual = TrainingRequest {
+        topic: TrainingTopic::BluffSpot.into(),
+        difficulty: DifficultyLevel::Beginner,
+        rng_seed: Some(42),
+        text_style: TextStyle::Simple,
+        is_bounty: false,
+        excluded_topics: Vec::new(),
+    };
+    assert_eq!(parsed, manual);
+
+    let parsed: TrainingRequest = " preflopdecision , ADVANCED , 7 , technical ".parse().unwrap();
+    let built = TrainingRequest::builder()
+        .topic(TrainingTopic::PreflopDecision)
+        .difficulty(DifficultyLevel::Advanced)
+        .seed(7)
+        .style(TextStyle::Technical)
+        .build();
+    assert_eq!(parsed, built);
+
+    // Trailing fields are optional.
+    assert_eq!(
+        "DelayedCbet".parse::<TrainingRequest>().unwrap(),
+        TrainingRequest::new(TrainingTopic::DelayedCbet)
+    );
+    for t in all_topics() {
+        assert_eq!(format!("{t:?}").parse::<TrainingTopic>(), Ok(t));
+    }
+}
+
+#[test]
+fn topic_prefix_and_display_name_round_trip() {
+    assert_eq!(TrainingTopic::all(), all_topics());
+    for t in TrainingTopic::all() {
+        assert_eq!(t.prefix().len(), 3, "{t}");
+        assert_eq!(t.prefix().parse::<TrainingTopic>().unwrap(), t);
+        assert_eq!(t.to_string().parse::<TrainingTopic>().unwrap(), t);
+        assert!(generate_training(req(t, 5)).unwrap().scenario_id.starts_with(t.prefix()));
+    }
+    assert_eq!("pot odds & equity".parse::<TrainingTopic>(), Ok(TrainingTopic::PotOddsAndEquity));
+    assert_eq!("3b-".parse::<TrainingTopic>(), Ok(TrainingTopic::ThreeBetPotCbet));
+    assert!("PF".parse::<TrainingTopic>().is_err());
+}
+
+#[test]
+fn request_parse_errors_name_the_bad_field() {
+    use crate::training_engine::ParseRequestError;
+    let err = |s: &str| s.parse::<TrainingRequest>().unwrap_err();
+    assert_eq!(err("Bluff,Beginner,1,Simple"), ParseRequestError::InvalidTopic("Bluff".into()));
+    assert_eq!(err("BluffSpot,Expert,1,Simple"), ParseRequestError::InvalidDifficulty("Expert".into()));
+    assert_eq!(err("BluffSpot,Beginner,x,Simple"), ParseRequestError::InvalidField(2));
+    assert_eq!(err("BluffSpot,Beginner,1,Fancy"), ParseRequestError::InvalidField(3));
+    assert_eq!(err("BluffSpot,Beginner,1,Simple,extra"), ParseRequestError::InvalidField(4));
+    assert_eq!(err(""), ParseRequestError::InvalidTopic("".into()));
+    assert_eq!(err("Bluff,Beginner").to_string(), "invalid topic 'Bluff'");
+}
+
+// ── curriculum ───────────────────────────────────────────────────────────────
+
+#[test]
+fn curriculum_levels_grow_and_generate() {
+    use crate::training_engine::{generate_curriculum, CurriculumLevel, TopicSelector};
+    let levels = [
+        CurriculumLevel::Novice,
+        CurriculumLevel::Beginner,
+        CurriculumLevel::Intermediate,
+        CurriculumLevel::Advanced,
+    ];
+    for level in levels {
+        let plan = generate_curriculum(level);
+        assert!(!plan.is_empty(), "{level:?}");
+        assert_eq!(plan.len(), generate_curriculum(level).len());
+        for r in plan {
+            assert!(matches!(r.topic, TopicSelector::Topic(_)), "{level:?}");
+            let s = generate_training(r).unwrap();
+            assert!(s.validate().is_empty(), "{}", s.scenario_id);
+        }
+    }
+
+    let novice = generate_curriculum(CurriculumLevel::Novice);
+    let advanced = generate_curriculum(CurriculumLevel::Advanced);
+    assert!(novice.len() < advanced.len());
+    assert_eq!(novice.len(), 8);
+    assert!(novice.iter().all(|r| r.difficulty == DifficultyLevel::Beginner));
+    assert!(novice[..5].iter().all(|r| r.topic == TopicSelector::Topic(TrainingTopic::PreflopDecision)));
+    assert!(novice[5..].iter().all(|r| r.topic == TopicSelector::Topic(TrainingTopic::PotOddsAndEquity)));
+    assert!(advanced.iter().all(|r| r.difficulty == DifficultyLevel::Advanced));
+    for t in all_topics() {
+        assert!(advanced.iter().any(|r| r.topic == TopicSelector::Topic(t)), "{t}");
+    }
+}
+
+#[test]
+fn curriculum_seeds_are_fixed_and_distinct() {
+    use crate::training_engine::{generate_curriculum, CurriculumLevel};
+    let seeds = |level| -> Vec<Option<u64>> {
+        generate_curriculum(level).iter().map(|r| r.rng_seed).collect()
+    };
+    assert_eq!(seeds(CurriculumLevel::Advanced), seeds(CurriculumLevel::Advanced));
+    assert_eq!(seeds(CurriculumLevel::Novice)[0], Some(1000));
+    let mut all: Vec<_> = [
+        CurriculumLevel::Novice,
+        CurriculumLevel::Beginner,
+        CurriculumLevel::Intermediate,
+        CurriculumLevel::Advanced,
+    ]
+    .into_iter()
+    .flat_map(seeds)
+    .collect();
+    let n = all.len();
+    all.sort();
+    all.dedup();
+    assert_eq!(all.len(), n, "seeds must not repeat across levels");
+}
+
+// ── complexity ───────────────────────────────────────────────────────────────
+
+/// First seed from 0 whose scenario for `topic` has a branch key starting with `branch`.
+fn find_branch(topic: TrainingTopic, branch: &str) -> crate::training_engine::TrainingScenario {
+    (0..500)
+        .map(|seed| generate_training(req(topic, seed)).unwrap())
+        .find(|s| s.branch_key.starts_with(branch))
+        .unwrap_or_else(|| panic!("no {branch} spot for {topic:?}"))
+}
+
+#[test]
+fn complexity_score_separates_clear_folds_from_close_calls() {
+    let trash = find_branch(TrainingTopic::PreflopDecision, "OpenRaise:trash");
+    assert!(trash.complexity_score() < 0.3, "{}", trash.complexity_score());
+    let bluff_catcher = find_branch(TrainingTopic::RiverCallOrFold, "Marginal");
+    assert!(bluff_catcher.complexity_score() > 0.7, "{}", bluff_catcher.complexity_score());
+
+    for topic in all_topics() {
+        for seed in SEEDS {
+            let score = generate_training(req(topic, seed)).unwrap().complexity_score();
+            assert!((0.0..=1.0).contains(&score), "{topic:?} seed {seed}: {score}");
+        }
+    }
+}
+
+#[test]
+fn adaptive_session_ignores_trivial_wins() {
+    use crate::training_engine::AdaptiveSession;
+    let mut session = AdaptiveSession::new(10, 1, TextStyle::Simple);
+    let t = TrainingTopic::PreflopDecision;
+    let trivial = find_branch(t, "OpenRaise:trash");
+    for _ in 0..10 {
+        let id = trivial.correct_answer().id.clone();
+        session.record(trivial.clone(), Some(&id));
+    }
+    assert_eq!(session.difficulty(t), DifficultyLevel::Beginner);
+    assert_eq!(session.recent_accuracy(t), Some(1.0));
+}
+
+// ── adaptive session ─────────────────────────────────────────────────────────
+
+/// Generate the session's next request and answer it right or wrong.
+fn answer_next(session: &mut crate::training_engine::AdaptiveSession, correct: bool) -> TrainingTopic {
+    let scenario = generate_training(session.next_request()).unwrap();
+    let topic = scenario.topic;
+    let id = scenario
+        .answers
+        .iter()
+        .find(|a| a.is_correct == correct)
+        .map(|a| a.id.clone())
+        .unwrap();
+    session.record(scenario, Some(&id));
+    topic
+}
+
+#[test]
+fn adaptive_session_steps_up_after_five_correct() {
+    use crate::training_engine::AdaptiveSession;
+    let mut session = AdaptiveSession::new(10, 1, TextStyle::Simple);
+    let t = TrainingTopic::BluffSpot;
+    let scenario = |seed| generate_training(req(t, seed)).unwrap();
+
+    for seed in 0..4 {
+        let s = scenario(seed);
+        let id = s.correct_answer().id.clone();
+        session.record(s, Some(&id));
+    }
+    assert_eq!(session.difficulty(t), DifficultyLevel::Beginner);
+    let s = scenario(4);
+    let id = s.correct_answer().id.clone();
+    session.record(s, Some(&id));
+    assert_eq!(session.difficulty(t), DifficultyLevel::Intermediate);
+
+    // Skips neither extend nor break a streak; the next level needs 5 more.
+    session.record(scenario(5), None);
+    for seed in 6..10 {
+        let s = scenario(seed);
+        let id = s.correct_answer().id.clone();
+        session.record(s, Some(&id));
+    }
+    assert_eq!(session.difficulty(t), DifficultyLevel::Intermediate);
+    assert_eq!(session.recent_accuracy(t), Some(1.0));
+}
+
+#[test]
+fn adaptive_session_steps_down_after_three_misses() {
+    use crate::training_engine::AdaptiveSession;
+    let mut session = AdaptiveSession::new(10, 1, TextStyle::Simple);
+
+    // Fresh session: every topic is unanswered, so requests walk the topics in order.
+    let first = answer_next(&mut session, true);
+    assert_eq!(first, TrainingTopic::PreflopDecision);
+    for _ in 0..4 {
+        // The only answered topic is at 100%, so it stays last in line.
+        assert_ne!(answer_next(&mut session, true), first);
+    }
+
+    let t = TrainingTopic::RiverCallOrFold;
+    let miss = |session: &mut AdaptiveSession, seed| {
+        let s = generate_training(req(t, seed)).unwrap();
+        let id = s.answers.iter().find(|a| !a.is_correct).unwrap().id.clone();
+        session.record(s, Some(&id));
+    };
+    for seed in 0..5 {
+        let s = generate_training(req(t, 100 + seed)).unwrap();
+        let id = s.correct_answer().id.clone();
+        session.record(s, Some(&id));
+    }
+    assert_eq!(session.difficulty(t), DifficultyLevel::Intermediate);
+    miss(&mut session, 1);
+    miss(&mut session, 2);
+    assert_eq!(session.difficulty(t), DifficultyLevel::Intermediate);
+    miss(&mut session, 3);
+    assert_eq!(session.difficulty(t), DifficultyLevel::Beginner);
+    // Already at the bottom: more misses keep it at Beginner.
+    for seed in 4..7 {
+        miss(&mut session, seed);
+    }
+    assert_eq!(session.difficulty(t), DifficultyLevel::Beginner);
+    assert_eq!(session.history().entries.len(), 5 + 5 + 6);
+}
+
+// ── balanced selector ────────────────────────────────────────────────────────
+
+#[test]
+fn balanced_selector_covers_every_topic_once_per_cycle() {
+    use crate::training_engine::{BalancedSelector, TopicSelector};
+    let selector = BalancedSelector::new(100);
+    let topics: Vec<TopicSelector> = (0..TrainingTopic::ALL.len())
+        .map(|_| selector.next_request(DifficultyLevel::Advanced, TextStyle::Technical).topic)
+        .collect();
+    for t in all_topics() {
+        assert_eq!(topics.iter().filter(|&&s| s == TopicSelector::Topic(t)).count(), 1, "{t}");
+    }
+
+    // The next request starts the next cycle with the next seed.
+    let next = selector.next_request(DifficultyLevel::Beginner, TextStyle::Simple);
+    assert_eq!(next.topic, TopicSelector::Topic(TrainingTopic::PreflopDecision));
+    assert_eq!(next.rng_seed, Some(100 + TrainingTopic::ALL.len() as u64));
+    assert_eq!(next.difficulty, DifficultyLevel::Beginner);
+}
+
+#[test]
+fn balanced_selector_is_deterministic() {
+    use crate::training_engine::BalancedSelector;
+    let (a, b) = (BalancedSelector::new(7), BalancedSelector::new(7));
+    for _ in 0..20 {
+        let (ra, rb) = (
+            a.next_request(DifficultyLevel::Intermediate, TextStyle::Simple),
+            b.next_request(DifficultyLevel::Intermediate, TextStyle::Simple),
+        );
+        assert_eq!((ra.topic, ra.rng_seed), (rb.topic, rb.rng_seed));
+        assert_eq!(
+            generate_training(ra).unwrap().scenario_id,
+            generate_training(rb).unwrap().scenario_id
+        );
+    }
+}
+
+// ── positions ────────────────────────────────────────────────────────────
+
+#[test]
+fn position_groups_and_table_sizes() {
+    use Position::*;
+    assert_eq!(Position::all(), &[UTG, UTG1, UTG2, LJ, HJ, CO, BTN, SB, BB]);
+    assert_eq!(Position::positions_for_table_size(9), Position::all());
+    assert_eq!(Position::positions_for_table_size(6), &[UTG, HJ, CO, BTN, SB, BB]);
+
+    for &p in Position::all() {
+        let groups = [p.is_early(), p.is_middle(), p.is_late(), p.is_blind()];
+        assert_eq!(groups.iter().filter(|&&g| g).count(), 1, "{p:?}");
+    }
+    assert!(UTG1.is_early() && LJ.is_middle() && HJ.is_middle() && SB.is_blind());
+    assert_eq!(UTG2.to_string(), "UTG+2");
+}
+
+#[test]
+#[should_panic(expected = "unsupported table size 8")]
+fn positions_for_unsupported_table_size_panics() {
+    Position::positions_for_table_size(8);
+}
+
+#[test]
+fn in_position_follows_postflop_action_order() {
+    use Position::*;
+    let postflop = [SB, BB, UTG, UTG1, UTG2, LJ, HJ, CO, BTN];
+    for (i, &a) in postflop.iter().enumerate() {
+        for (j, &b) in postflop.iter().enumerate() {
+            assert_eq!(a.is_in_position_vs(b), i > j, "{a:?} vs {b:?}");
+        }
+    }
+}
+
+#[test]
+fn pot_odds_and_bluff_explanations_use_bet_to_pot_math() {
+    use crate::training_engine::evaluator::{break_even_equity, calculate_pot_odds};
+    let tech = |topic, seed| {
+        generate_training(TrainingRequest { text_style: TextStyle::Technical, ..req(topic, seed) }).unwrap()
+    };
+    for seed in SEEDS {
+        let s = tech(TrainingTopic::PotOddsAndEquity, seed);
+        let (pot, bet) = (s.table_setup.pot_size, s.table_setup.current_bet);
+        let call = &s.answers[0].explanation;
+        assert!(call.contains(&format!("{:.1}%", break_even_equity(bet, pot) * 100.0)), "{call}");
+        assert!(call.contains(&format!("pot odds {}", calculate_pot_odds(bet, pot))), "{call}");
+
+        let s = tech(TrainingTopic::BluffSpot, seed);
+        let pot = s.table_setup.pot_size;
+        let large_bet = (pot as f32 * 0.75).round() as u32;
+        let large = &s.answers[2].explanation;
+        assert!(large.contains(&format!("{:.1}%", break_even_equity(large_bet, pot) * 100.0)), "{large}");
+    }
+}
+
+// ── stack-off ────────────────────────────────────────────────────────────────
+
+#[test]
+fn stack_off_commits_sets_and_bet_folds_weak_top_pair_deep() {
+    use crate::training_engine::{deck::MockDeck, topics::flop::generate_stack_off};
+    use rand::SeedableRng;
+    for seed in 0..20u64 {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let mut deck = MockDeck::new(cards("Ks Kd Kh 7c 2d"));
+        let s = generate_stack_off(&mut rng, &mut deck, DifficultyLevel::Advanced, "SO-1".into(), TextStyle::Technical).unwrap();
+        assert!(s.table_setup.spr() < 4.0 + f32::EPSILON);
+        assert!(s.branch_key.starts_with("StackOff:Strong:"), "{}", s.branch_key);
+        assert_ne!(s.correct_answer().id, "A", "a set must commit at SPR {}", s.table_setup.spr());
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let mut deck = MockDeck::new(cards("Kd Jc Kh 9s 2c"));
+        let s = generate_stack_off(&mut rng, &mut deck, DifficultyLevel::Advanced, "SO-1".into(), TextStyle::Technical).unwrap();
+        assert!(s.branch_key.starts_with("StackOff:WeakTopPair:"), "{}", s.branch_key);
+        if s.table_setup.spr() > 2.0 {
+            assert_eq!(s.correct_answer().id, "A", "weak top pair bet-folds at SPR {}", s.table_setup.spr());
+        }
+    }
+}
+
+#[test]
+fn stack_off_holds_a_pair_and_spreads_its_answers() {
+    use crate::training_engine::evaluator::{classify_hero_hand_vs_board, HandVsBoardStrength};
+    for difficulty in [DifficultyLevel::Beginner, DifficultyLevel::Intermediate, DifficultyLevel::Advanced] {
+        let mut counts = std::collections::HashMap::new();
+        for seed in 0..300u64 {
+            let s = generate_training(TrainingRequest { difficulty, ..req(TrainingTopic::StackOffDecision, seed) }).unwrap();
+            let (hand, board) = (s.table_setup.hero_hand, &s.table_setup.board);
+            assert!(hand.iter().all(|c| c.rank.0 >= 10), "seed={seed}");
+            assert_ne!(classify_hero_hand_vs_board(hand, board), HandVsBoardStrength::NoPair, "seed={seed}");
+            *counts.entry(s.correct_answer().id.clone()).or_insert(0) += 1;
+        }
+        for id in ["A", "B", "C"] {
+            let n = counts.get(id).copied().unwrap_or(0);
+            assert!(n > 0 && n <= 200, "{difficulty:?}: {id} correct in {n} of 300 spots ({counts:?})");
+        }
+    }
+}
+
+#[test]
+fn stack_off_required_equity_matches_the_shove_price() {
+    use crate::training_engine::topics::flop::stack_off_required_equity;
+    // 100 pot, 200 behind, bet 50: call 150 into a final pot of 500.
+    assert!((stack_off_required_equity(100, 200, 50) - 0.30).abs() < 1e-6);
+    // Shallower stacks need less equity.
+    assert!(stack_off_required_equity(100, 150, 50) < stack_off_required_equity(100, 400, 50));
+}
+
+// ── exploitative play ───────────────────────────────────────────────────────
+
+#[test]
+fn exploitative_answer_differs_from_the_balanced_line_for_every_tendency() {
+    use crate::training_engine::topics::flop::{exploit_decision, villain_leads, ExploitHolding};
+    use crate::training_engine::VillainTendency;
+    let mut seen = std::collections::HashSet::new();
+    for seed in 0..200u64 {
+        let s = generate_training(req(TrainingTopic::ExploitativePlay, seed)).unwrap();
+        let tendency = s.villain_tendency.expect("exploit spots carry a read");
+        seen.insert(tendency);
+        assert!(s.branch_key.starts_with(&format!("Exploit:{tendency:?}:")), "{}", s.branch_key);
+        assert_eq!(s.table_setup.current_bet > 0, villain_leads(tendency), "{}", s.branch_key);
+        // The balanced option is the plausible runner-up, never the answer.
+        let plausible: Vec<_> = s.answers.iter().filter(|a| a.rating == AnswerRating::Plausible).collect();
+        assert_eq!(plausible.len(), 1, "{}", s.branch_key);
+        assert_ne!(plausible[0].id, s.correct_answer().id);
+    }
+    assert_eq!(seen.len(), VillainTendency::ALL.len(), "every tendency is drilled");
+
+    // Pure table check: a calling station flips every non-strong holding.
+    use ExploitHolding::*;
+    for holding in [TopPair, Medium, Air] {
+        let (gto, exploit) = exploit_decision(VillainTendency::CallsFrequently, holding);
+        assert_ne!(gto, exploit, "{holding:?}");
+    }
+
+    // Other topics play a balanced opponent.
+    assert_eq!(generate_training(req(TrainingTopic::PostflopContinuationBet, 1)).unwrap().villain_tendency, None);
+}
+
+#[test]
+fn villain_tendency_round_trips_and_defaults_to_none() {
+    let s = generate_training(req(TrainingTopic::ExploitativePlay, 3)).unwrap();
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(serde_json::from_str::<crate::training_engine::TrainingScenario>(&json).unwrap(), s);
+
+    // JSON written before the field existed still loads.
+    let mut value: serde_json::Value = serde_json::to_value(generate_training(req(TrainingTopic::BluffSpot, 3)).unwrap()).unwrap();
+    value.as_object_mut().unwrap().remove("villain_tendency");
+    let old: crate::training_engine::TrainingScenario = serde_json::from_value(value).unwrap();
+    assert_eq!(old.villain_tendency, None);
+}
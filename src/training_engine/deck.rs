@@ -5,17 +5,93 @@
 //! exactly once and that the deal order is fully determined by the RNG state —
 //! critical for the engine's determinism guarantee.
 //!
-//! Most topic generators call [`helpers::deal()`](super::helpers::deal) which
-//! wraps `Deck::new_shuffled` + two hero cards + N board cards.  Topics that
-//! need flop and turn dealt separately (e.g. turn barrel) use the deck directly.
+//! Topic generators deal through the [`CardDealer`] trait.  The generator
+//! hands them a shuffled `Deck`; tests can pass a [`MockDeck`] instead to fix
+//! the exact cards.  Most topics call [`helpers::deal()`](super::helpers::deal)
+//! for two hero cards + N board cards; topics that need flop and turn dealt
+//! separately (e.g. turn barrel) use the dealer directly.
 
 use rand::Rng;
-use crate::training_engine::models::{Card, Rank, Suit};
+use crate::training_engine::models::{Card, GenerationError, Rank, Street, Suit};
+
+/// Anything cards can be dealt from: a shuffled [`Deck`], or a [`MockDeck`]
+/// with a fixed order for tests.  Topic generators are generic over it.
+pub trait CardDealer {
+    /// Deal one card, or [`GenerationError::DeckExhausted`] once every card is out.
+    fn deal(&mut self) -> Result<Card, GenerationError>;
+
+    /// Deal `n` cards at once.
+    fn deal_n(&mut self, n: usize) -> Result<Vec<Card>, GenerationError> {
+        (0..n).map(|_| self.deal()).collect()
+    }
+
+    /// Deal the next card that satisfies `wanted` — e.g. a second card of
+    /// the rank already dealt, to make a pocket pair.
+    ///
+    /// The default deals and discards the cards it passes over; [`Deck`] and
+    /// [`MockDeck`] keep them in their original order for the next deal.
+    fn deal_matching(&mut self, wanted: impl Fn(&Card) -> bool) -> Result<Card, GenerationError>
+    where
+        Self: Sized,
+    {
+        loop {
+            let card = self.deal()?;
+            if wanted(&card) {
+                return Ok(card);
+            }
+        }
+    }
+
+    /// Deal a whole board for `street`: 0, 3, 4 or 5 cards.
+    fn deal_community_cards(&mut self, street: Street) -> Result<Vec<Card>, GenerationError> {
+        self.deal_n(street.board_card_count())
+    }
+
+    /// Deal only the cards that take `existing_board` to `target_street` —
+    /// e.g. one card to turn a flop into a turn board.
+    ///
+    /// # Panics
+    ///
+    /// If `existing_board` already has more cards than `target_street` holds.
+    fn deal_additional_cards(
+        &mut self,
+        existing_board: &[Card],
+        target_street: Street,
+    ) -> Result<Vec<Card>, GenerationError> {
+        let target = target_street.board_card_count();
+        assert!(
+            existing_board.len() <= target,
+            "board of {} cards is already past the {target_street}",
+            existing_board.len()
+        );
+        self.deal_n(target - existing_board.len())
+    }
+}
+
+/// All 52 cards, 2c 3c … Ac 2d … As.
+fn ordered_cards() -> Vec<Card> {
+    Suit::all()
+        .into_iter()
+        .flat_map(|suit| (2u8..=14).map(move |r| Card { rank: Rank(r), suit }))
+        .collect()
+}
+
+/// Take the first card from `cards[from..]` that satisfies `wanted`, shifting
+/// the cards before it one place back so they keep their order.
+fn take_matching(
+    cards: &mut [Card],
+    from: usize,
+    wanted: impl Fn(&Card) -> bool,
+) -> Result<Card, GenerationError> {
+    let offset = cards[from..].iter().position(wanted).ok_or(GenerationError::DeckExhausted)?;
+    cards[from..=from + offset].rotate_right(1);
+    Ok(cards[from])
+}
 
 /// A standard 52-card deck that can be shuffled and dealt from.
 ///
 /// Cards are stored in a `Vec` and dealt via an advancing cursor.
-/// The deck panics if you try to deal more than 52 cards.
+/// Dealing past the last card returns [`GenerationError::DeckExhausted`].
 pub struct Deck {
     cards: Vec<Card>,
     cursor: usize,
@@ -24,10 +100,16 @@ pub struct Deck {
 impl Deck {
     /// Build a fresh ordered deck and shuffle it with `rng`.
     pub fn new_shuffled<R: Rng>(rng: &mut R) -> Self {
-        let suits = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
-        let mut cards: Vec<Card> = suits
-            .iter()
-            .flat_map(|&suit| (2u8..=14).map(move |r| Card { rank: Rank(r), suit }))
+        Self::with_excluded(rng, &[])
+    }
+
+    /// Shuffle the 52-card deck minus `excluded` (e.g. cards already known
+    /// to be in play).  With nothing excluded this deals exactly like
+    /// [`Deck::new_shuffled`].
+    pub fn with_excluded<R: Rng>(rng: &mut R, excluded: &[Card]) -> Self {
+        let mut cards: Vec<Card> = ordered_cards()
+            .into_iter()
+            .filter(|c| !excluded.contains(c))
             .collect();
 
         // Fisher-Yates shuffle
@@ -39,21 +121,19 @@ impl Deck {
         Deck { cards, cursor: 0 }
     }
 
-    /// Deal one card; panics if the deck is exhausted.
-    pub fn deal(&mut self) -> Card {
-        assert!(self.cursor < self.cards.len(), "Deck exhausted");
-        let card = self.cards[self.cursor];
-        self.cursor += 1;
-        card
-    }
-
-    /// Deal `n` cards at once.
-    pub fn deal_n(&mut self, n: usize) -> Vec<Card> {
-        (0..n).map(|_| self.deal()).collect()
+    /// Move `cards` to the top of the undealt cards, in the given order; the
+    /// rest keep their shuffled order.  Cards already dealt or missing from
+    /// the deck are ignored.
+    pub fn put_on_top(&mut self, cards: &[Card]) {
+        for &card in cards.iter().rev() {
+            if let Some(offset) = self.cards[self.cursor..].iter().position(|&c| c == card) {
+                self.cards[self.cursor..=self.cursor + offset].rotate_right(1);
+            }
+        }
     }
 
-    /// Remaining cards available.
-    pub fn remaining(&self) -> usize {
+    /// Cards not yet dealt.
+    pub fn remaining_count(&self) -> usize {
         self.cards.len() - self.cursor
     }
 
@@ -63,6 +143,58 @@ impl Deck {
     }
 }
 
+impl CardDealer for Deck {
+    fn deal(&mut self) -> Result<Card, GenerationError> {
+        let card = *self.cards.get(self.cursor).ok_or(GenerationError::DeckExhausted)?;
+        self.cursor += 1;
+        Ok(card)
+    }
+
+    fn deal_matching(&mut self, wanted: impl Fn(&Card) -> bool) -> Result<Card, GenerationError> {
+        let card = take_matching(&mut self.cards, self.cursor, wanted)?;
+        self.cursor += 1;
+        Ok(card)
+    }
+}
+
+/// A dealer that hands out cards in exactly the order given — for testing a
+/// topic on a chosen board.
+///
+/// ```ignore
+/// let mut deck = MockDeck::new(cards("AhKh 7h 8h 2c"));
+/// topics::flop::generate_cbet(&mut rng, &mut deck, difficulty, id, style)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockDeck {
+    cards: Vec<Card>,
+    index: usize,
+}
+
+impl MockDeck {
+    pub fn new(order: Vec<Card>) -> MockDeck {
+        MockDeck { cards: order, index: 0 }
+    }
+
+    /// All 52 cards unshuffled, 2c through As (clubs, diamonds, hearts, spades).
+    pub fn full_deck_sorted() -> MockDeck {
+        MockDeck::new(ordered_cards())
+    }
+}
+
+impl CardDealer for MockDeck {
+    fn deal(&mut self) -> Result<Card, GenerationError> {
+        let card = *self.cards.get(self.index).ok_or(GenerationError::DeckExhausted)?;
+        self.index += 1;
+        Ok(card)
+    }
+
+    fn deal_matching(&mut self, wanted: impl Fn(&Card) -> bool) -> Result<Card, GenerationError> {
+        let card = take_matching(&mut self.cards, self.index, wanted)?;
+        self.index += 1;
+        Ok(card)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,7 +205,8 @@ mod tests {
     fn deck_has_52_unique_cards() {
         let mut rng = StdRng::seed_from_u64(42);
         let mut deck = Deck::new_shuffled(&mut rng);
-        let all: Vec<Card> = (0..52).map(|_| deck.deal()).collect();
+        let all: Vec<Card> = (0..52).map(|_| deck.deal().unwrap()).collect();
+        assert_eq!(deck.deal(), Err(GenerationError::DeckExhausted));
 
         // All unique
         let mut seen = std::collections::HashSet::new();
@@ -89,9 +222,94 @@ mod tests {
         let make = |seed: u64| -> Vec<Card> {
             let mut rng = StdRng::seed_from_u64(seed);
             let mut deck = Deck::new_shuffled(&mut rng);
-            deck.deal_n(5)
+            deck.deal_n(5).unwrap()
         };
         assert_eq!(make(99), make(99));
         assert_ne!(make(99), make(100));
     }
+
+    #[test]
+    fn excluded_cards_are_never_dealt() {
+        let ace_of_spades: Card = "As".parse().unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut deck = Deck::with_excluded(&mut rng, &[ace_of_spades]);
+        assert_eq!(deck.remaining_count(), 51);
+
+        let dealt = deck.deal_n(51).unwrap();
+        assert!(!dealt.contains(&ace_of_spades));
+        assert_eq!(deck.remaining_count(), 0);
+        assert_eq!(deck.deal(), Err(GenerationError::DeckExhausted));
+        assert_eq!(deck.deal_n(2), Err(GenerationError::DeckExhausted));
+
+        // Excluding nothing deals exactly like `new_shuffled`.
+        let mut a = Deck::with_excluded(&mut StdRng::seed_from_u64(3), &[]);
+        let mut b = Deck::new_shuffled(&mut StdRng::seed_from_u64(3));
+        assert_eq!(a.deal_n(52).unwrap(), b.deal_n(52).unwrap());
+    }
+
+    #[test]
+    fn mock_deck_deals_in_the_given_order() {
+        let ace_of_spades: Card = "As".parse().unwrap();
+        let king_of_hearts: Card = "Kh".parse().unwrap();
+        let mut deck = MockDeck::new(vec![ace_of_spades, king_of_hearts]);
+        assert_eq!(deck.deal(), Ok(ace_of_spades));
+        assert_eq!(deck.deal(), Ok(king_of_hearts));
+        assert_eq!(deck.deal(), Err(GenerationError::DeckExhausted));
+
+        let sorted = MockDeck::full_deck_sorted().deal_n(52).unwrap();
+        assert_eq!(sorted[0].to_string(), "2c");
+        assert_eq!(sorted[51], ace_of_spades);
+    }
+
+    #[test]
+    fn street_aware_dealing_counts() {
+        let streets = [Street::Preflop, Street::Flop, Street::Turn, Street::River];
+        for street in streets {
+            let mut deck = Deck::new_shuffled(&mut StdRng::seed_from_u64(11));
+            let board = deck.deal_community_cards(street).unwrap();
+            assert_eq!(board.len(), street.board_card_count(), "{street}");
+            for target in streets.into_iter().filter(|t| t.board_card_count() >= board.len()) {
+                let extra = MockDeck::full_deck_sorted().deal_additional_cards(&board, target).unwrap();
+                assert_eq!(board.len() + extra.len(), target.board_card_count(), "{street} → {target}");
+            }
+        }
+
+        // Flop then turn deals the same cards as a turn board in one go.
+        let mut a = MockDeck::full_deck_sorted();
+        let flop = a.deal_community_cards(Street::Flop).unwrap();
+        let turn = a.deal_additional_cards(&flop, Street::Turn).unwrap();
+        let mut b = MockDeck::full_deck_sorted();
+        assert_eq!([flop, turn].concat(), b.deal_community_cards(Street::Turn).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "board of 4 cards is already past the Flop")]
+    fn deal_additional_cards_rejects_a_longer_board() {
+        let mut deck = MockDeck::full_deck_sorted();
+        let board = deck.deal_community_cards(Street::Turn).unwrap();
+        let _ = deck.deal_additional_cards(&board, Street::Flop);
+    }
+
+    #[test]
+    fn deal_matching_keeps_skipped_cards_in_order() {
+        let mut deck = MockDeck::new("2c 3d Ah 4s".split(' ').map(|c| c.parse().unwrap()).collect());
+        assert_eq!(deck.deal_matching(|c| c.rank.0 == 14).unwrap().to_string(), "Ah");
+        assert_eq!(deck.deal_n(3).unwrap().iter().map(Card::to_string).collect::<Vec<_>>(), ["2c", "3d", "4s"]);
+
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut deck = Deck::new_shuffled(&mut rng);
+        let pair = [deck.deal().unwrap(), deck.deal_matching(|_| true).unwrap()];
+        let rest = deck.deal_n(50).unwrap();
+        assert!(rest.iter().all(|c| !pair.contains(c)));
+        assert_eq!(deck.deal_matching(|_| true), Err(GenerationError::DeckExhausted));
+
+        // put_on_top moves known cards in front of the rest, keeping both orders.
+        let shuffled = Deck::new_shuffled(&mut StdRng::seed_from_u64(9)).deal_n(52).unwrap();
+        let top = &shuffled[5..7];
+        let mut expected = top.to_vec();
+        expected.extend(shuffled.iter().filter(|c| !top.contains(c)));
+        let mut a = Deck::new_shuffled(&mut StdRng::seed_from_u64(9));
+        a.put_on_top(top);
+        assert_eq!(a.deal_n(52).unwrap(), expected);
+    }
 }
@@ -6,24 +6,71 @@
 //! ## Board texture
 //! `board_texture()` classifies a board as Dry, SemiWet, or Wet based on flush
 //! and straight draw potential.  C-bet sizing in flop topics is driven by this.
+//! `board_texture_score()` gives the same idea as a continuous 0.0–1.0 wetness;
+//! `board_suit_counts()`, `is_rainbow_board()` and `is_monotone_board()` describe
+//! the suit distribution.  `nut_flush_suit()` names the flush suit and
+//! `hero_has_nut_flush_draw()` checks hero holds its ace.
+//! `nut_straight_ranks()` lists the ranks the best possible straight needs;
+//! `blocks_nut_straight()` and `blocker_value_score()` rate how well hero's
+//! hole cards block villain's strongest hands (T4 bluff selection).
 //!
 //! ## Draw classification
 //! `DrawType` + `classify_draw()` identify the strongest draw present on a
 //! board.  Used by pot-odds (T3), semi-bluff (T8), and check-raise (T7).
 //! `draw_equity_flop()` returns approximate equity for each draw type.
+//! `has_backdoor_flush_draw()` and `has_backdoor_straight_draw()` flag hero's
+//! runner-runner draws (worth roughly 2–4% extra equity), used by T2 and T7.
 //!
 //! ## Hand classification (5-category)
 //! `HandCategory` + `classify_hand()` sort a 2-card hand into Premium / Strong /
 //! Playable / Marginal / Trash.  Used by preflop topics (T1, T9, T11, T12).
+//! `normalized_hand_notation()` writes a hand as `"AKs"` / `"A2o"` / `"77"`.
+//!
+//! ## Hand vs board
+//! `HandVsBoardStrength` + `classify_hero_hand_vs_board()` describe hero's made
+//! hand postflop (set, two pair, top pair + kicker, overpair, …).
+//!
+//! ## Outs
+//! `count_outs()` counts the unseen cards that complete hero's flush or
+//! straight draw and `out_cards()` lists them; `equity_from_outs()` turns the
+//! count into exact hit odds, and `equity_from_outs_rule_of_2_4()` gives the
+//! table-side approximation.
+//!
+//! ## Five-card evaluation
+//! `evaluate_five_cards()` returns an ordered `HandRank` for any 5 cards and
+//! `compare_hands()` settles a showdown between two;
+//! `best_hand_from_seven()` picks hero's best 5 out of hole cards + river board;
+//! `hand_description()` names the result ("Full house, Kings full of Fours").
 //!
 //! ## Pot odds
-//! `required_equity()` computes the minimum equity needed to break even on a
-//! call: `call / (pot + call)`.
+//! `break_even_equity()` computes the minimum equity needed to break even on a
+//! call: `call / (pot + call)`.  `minimum_defense_frequency()`,
+//! `maximum_bluff_frequency()` and `pot_odds_ratio()` cover the other sides of
+//! the same bet-to-pot relationship.  `expected_value()`, `ev_of_bet()` and
+//! `ev_of_raise()` turn equity and fold equity into chips.  `pot_committed()` flags an SPR
+//! below `commit_threshold_spr()` (1.5).
+//!
+//! ## Range widths
+//! `villain_range_width()` estimates the opening range from each position and
+//! `bb_defense_range_width()` how wide the big blind defends against it.
+//! `preflop_hand_equity_vs_position_range()` looks up a hand's equity against
+//! that opening range.
+//!
+//! ## Ranges
+//! `range::RangeString` parses range notation (`"AA,KK,AKs,88+"`) and expands
+//! it to two-card combos.
+//!
+//! ## ICM
+//! `icm::icm_equity()` turns tournament chip counts and an `ICMPayoutTable`
+//! into each player's share of the prize pool.
 
-use crate::training_engine::models::{Card, Suit};
+use crate::training_engine::models::{Card, Hand, Position, Rank, Suit};
+
+pub mod icm;
+pub mod range;
 
 /// Describes the texture of a flop/board for human-readable explanations.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BoardTexture {
     /// All three suits different, no pair, no connected cards.
     Dry,
@@ -43,6 +90,85 @@ impl std::fmt::Display for BoardTexture {
     }
 }
 
+impl BoardTexture {
+    /// Every texture, driest first.
+    pub fn all() -> [BoardTexture; 3] {
+        [BoardTexture::Dry, BoardTexture::SemiWet, BoardTexture::Wet]
+    }
+
+    /// Approximate solver c-bet frequency for the preflop aggressor:
+    /// 85% on dry boards, 70% semi-wet, 55% wet.
+    pub fn cbet_frequency(self) -> f32 {
+        match self {
+            BoardTexture::Dry     => 0.85,
+            BoardTexture::SemiWet => 0.70,
+            BoardTexture::Wet     => 0.55,
+        }
+    }
+
+    /// True for wet boards, where both ranges hold many draws.
+    pub fn is_draw_heavy(self) -> bool {
+        self == BoardTexture::Wet
+    }
+}
+
+/// Recommended c-bet size as a fraction of the pot, 0.25–1.25.
+///
+/// Starts from the texture — 33% dry, 60% semi-wet, 75% wet, larger where
+/// there is more equity to deny — then:
+/// - adds up to 25% as SPR falls from 4 to 1, so shallow stacks bet big
+///   enough to commit;
+/// - takes 10% off out of position, for pot control.  Late positions (CO,
+///   BTN) count as in position.
+pub fn cbet_size_recommendation(texture: BoardTexture, spr: f32, position: Position) -> f32 {
+    let base = match texture {
+        BoardTexture::Dry     => 0.33,
+        BoardTexture::SemiWet => 0.60,
+        BoardTexture::Wet     => 0.75,
+    };
+    let commit = 0.25 * ((4.0 - spr) / 3.0).clamp(0.0, 1.0);
+    let pot_control = if position.is_late() { 0.0 } else { 0.10 };
+    (base + commit - pot_control).clamp(0.25, 1.25)
+}
+
+/// Which player's preflop range connects better with the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeAdvantage {
+    Hero,
+    Villain,
+    Neutral,
+}
+
+/// Whose preflop range hits `board` harder, from the two players' seats.
+///
+/// - A- or K-high boards favour an opener over a blind: the opener's range
+///   is full of broadway cards that the blind mostly 3-bets or folds.
+/// - Nine-high or lower boards favour a blind over an opener, because the
+///   wide defence holds the small pairs and connectors.  Between two
+///   openers, the earlier seat's tighter range holds more overpairs.
+/// - T-, J- and Q-high boards, and seats in the same group, are `Neutral`.
+pub fn range_advantage(hero_pos: Position, villain_pos: Position, board: &[Card]) -> RangeAdvantage {
+    let Some(high) = board.iter().map(|c| c.rank.0).max() else {
+        return RangeAdvantage::Neutral;
+    };
+    let favoured = |hero: bool| if hero { RangeAdvantage::Hero } else { RangeAdvantage::Villain };
+    if hero_pos.is_blind() != villain_pos.is_blind() {
+        let hero_opened = villain_pos.is_blind();
+        return match high {
+            13.. => favoured(hero_opened),
+            ..=9 => favoured(!hero_opened),
+            _ => RangeAdvantage::Neutral,
+        };
+    }
+    // Opening seats from tightest to widest.
+    let seat = |p: Position| if p.is_early() { 0 } else if p.is_middle() { 1 } else { 2 };
+    if high <= 9 && !hero_pos.is_blind() && seat(hero_pos) != seat(villain_pos) {
+        favoured(seat(hero_pos) < seat(villain_pos))
+    } else {
+        RangeAdvantage::Neutral
+    }
+}
+
 /// Classify the texture of up to 5 board cards.
 pub fn board_texture(board: &[Card]) -> BoardTexture {
     if board.is_empty() {
@@ -64,22 +190,214 @@ pub fn board_texture(board: &[Card]) -> BoardTexture {
     }
 }
 
-/// True if 2+ cards share a suit (flush draw possible).
-pub fn has_flush_draw(board: &[Card]) -> bool {
-    let mut counts = [0u8; 4]; // clubs, diamonds, hearts, spades
+/// Continuous board wetness: 0.0 (e.g. `2d 7h Kc`) to 1.0 (e.g. `8h 9h Th`).
+///
+/// Half the score is flush potential — same-suit pairs on the board, capped at
+/// the three a monotone flop has.  The other half is straight potential — the
+/// most distinct ranks that fit in one five-rank straight window (ace plays
+/// high and low): one card scores 0, three or more score 1.
+pub fn board_texture_score(board: &[Card]) -> f32 {
+    let suited_pairs: u32 = board_suit_counts(board)
+        .iter()
+        .map(|&n| n as u32 * (n as u32).saturating_sub(1) / 2)
+        .sum();
+    let flush = (suited_pairs as f32 / 3.0).min(1.0);
+
+    let mut present = [false; 15]; // index = rank, 1 = ace low
     for c in board {
-        let idx = match c.suit {
-            Suit::Clubs    => 0,
-            Suit::Diamonds => 1,
-            Suit::Hearts   => 2,
-            Suit::Spades   => 3,
-        };
-        counts[idx] += 1;
-        if counts[idx] >= 2 {
-            return true;
+        present[c.rank.0 as usize] = true;
+        if c.rank.0 == 14 {
+            present[1] = true;
         }
     }
-    false
+    let in_window = (1..=10)
+        .map(|low| present[low..low + 5].iter().filter(|&&p| p).count())
+        .max()
+        .unwrap_or(0);
+    let straight = (in_window.saturating_sub(1) as f32 / 2.0).min(1.0);
+
+    0.5 * flush + 0.5 * straight
+}
+
+/// Cards of each suit on the board, ordered Clubs / Diamonds / Hearts / Spades.
+pub fn board_suit_counts(board: &[Card]) -> [u8; 4] {
+    let mut counts = [0u8; 4];
+    for c in board {
+        counts[suit_index(c.suit)] += 1;
+    }
+    counts
+}
+
+/// Every board card has a different suit (an empty board counts as rainbow).
+pub fn is_rainbow_board(board: &[Card]) -> bool {
+    board_suit_counts(board).iter().all(|&n| n <= 1)
+}
+
+/// Every board card shares one suit; `false` for an empty board.
+pub fn is_monotone_board(board: &[Card]) -> bool {
+    !board.is_empty() && board_suit_counts(board).contains(&(board.len() as u8))
+}
+
+/// Highest rank that appears on the board at least `n` times.
+fn board_rank_repeated(board: &[Card], n: usize) -> Option<u8> {
+    board
+        .iter()
+        .map(|c| c.rank.0)
+        .filter(|&r| board.iter().filter(|c| c.rank.0 == r).count() >= n)
+        .max()
+}
+
+/// The paired rank on a paired board: the highest rank that appears at
+/// least twice (so a trips board is paired too).  `None` when every board
+/// rank is different.
+pub fn classify_board_paired(board: &[Card]) -> Option<u8> {
+    board_rank_repeated(board, 2)
+}
+
+/// The rank of three (or four) of a kind on the board, if any.
+pub fn classify_board_trips(board: &[Card]) -> Option<u8> {
+    board_rank_repeated(board, 3)
+}
+
+/// How many distinct draws the board offers, as a rough complexity measure.
+///
+/// Each suit with 2+ board cards is one flush draw.  Board ranks no more than
+/// three apart can share an open-ended straight draw (with the ace playing
+/// high); each group of such ranks is one straight draw.  A two-tone
+/// connected flop therefore counts 2, a dry rainbow flop 0.
+pub fn board_draw_count(board: &[Card]) -> u8 {
+    let flush = board_suit_counts(board).iter().filter(|&&n| n >= 2).count() as u8;
+
+    let mut ranks: Vec<u8> = board.iter().map(|c| c.rank.0).collect();
+    ranks.sort_unstable();
+    ranks.dedup();
+    let mut straight = 0u8;
+    let mut group_counted = false;
+    for w in ranks.windows(2) {
+        let (lo, hi) = (w[0], w[1]);
+        if hi - lo > 3 {
+            group_counted = false;
+            continue;
+        }
+        // Some four-rank run from 2–5 up to T–K (open at both ends) holds both.
+        let open_ended = hi.saturating_sub(3).max(2) <= lo.min(10);
+        if open_ended && !group_counted {
+            straight += 1;
+            group_counted = true;
+        }
+    }
+    flush + straight
+}
+
+/// The suit a flush would be made in: the most common board suit when 2+
+/// board cards share it (ties go to Clubs, Diamonds, Hearts, Spades order);
+/// `None` on a rainbow board.
+pub fn nut_flush_suit(board: &[Card]) -> Option<Suit> {
+    let counts = board_suit_counts(board);
+    let best = (0..4).rev().max_by_key(|&i| counts[i])?;
+    (counts[best] >= 2).then_some(Suit::all()[best])
+}
+
+/// Hero holds the ace of [`nut_flush_suit`] and at least four cards of that
+/// suit between hand and board (a completed nut flush also counts).
+pub fn hero_has_nut_flush_draw(hand: [Card; 2], board: &[Card]) -> bool {
+    let Some(suit) = nut_flush_suit(board) else {
+        return false;
+    };
+    let holds_ace = hand.iter().any(|c| c.suit == suit && c.rank.0 == 14);
+    let in_hand = hand.iter().filter(|c| c.suit == suit).count();
+    holds_ace && in_hand + board_suit_counts(board)[suit_index(suit)] as usize >= 4
+}
+
+/// Missing ranks of every five-rank window the board fills to three or four
+/// cards — the straights villain can hold with two hole cards — best first.
+/// The ace-low wheel is the window `A-5`; aces are reported as 14.
+fn straight_windows(board: &[Card]) -> Vec<Vec<u8>> {
+    let on_board = |r: u8| board.iter().any(|c| c.rank.0 == r || (r == 1 && c.rank.0 == 14));
+    (1..=10u8)
+        .rev()
+        .map(|lo| {
+            (lo..lo + 5)
+                .filter(|&r| !on_board(r))
+                .map(|r| if r == 1 { 14 } else { r })
+                .collect::<Vec<u8>>()
+        })
+        .filter(|missing| (1..=2).contains(&missing.len()))
+        .collect()
+}
+
+/// Every straight the board allows, best first, as five ranks low to high
+/// (the wheel is `[14, 2, 3, 4, 5]`).  A straight counts when the board holds
+/// at least three of its ranks, so two hole cards can complete it.
+pub fn straight_possible(board: &[Card]) -> Vec<[u8; 5]> {
+    let on_board = |r: u8| board.iter().any(|c| c.rank.0 == r || (r == 1 && c.rank.0 == 14));
+    (1..=10u8)
+        .rev()
+        .filter(|&lo| (lo..lo + 5).filter(|&r| on_board(r)).count() >= 3)
+        .map(|lo| std::array::from_fn(|i| if lo == 1 && i == 0 { 14 } else { lo + i as u8 }))
+        .collect()
+}
+
+/// Suits a flush could still be made in, in Clubs / Diamonds / Hearts /
+/// Spades order: 2+ board cards before the river, 3+ once all five are out.
+pub fn flush_possible(board: &[Card]) -> Vec<Suit> {
+    let needed = if board.len() >= 5 { 3 } else { 2 };
+    let counts = board_suit_counts(board);
+    Suit::all().into_iter().filter(|&s| counts[suit_index(s)] >= needed).collect()
+}
+
+/// The suit of a flush made by five board cards alone, if any.
+pub fn flush_on_board(board: &[Card]) -> Option<Suit> {
+    let counts = board_suit_counts(board);
+    Suit::all().into_iter().find(|&s| counts[suit_index(s)] >= 5)
+}
+
+/// Ranks villain needs for the best straight the board allows; empty when no
+/// straight is possible.
+pub fn nut_straight_ranks(board: &[Card]) -> Vec<Rank> {
+    straight_windows(board)
+        .first()
+        .map(|w| w.iter().map(|&r| Rank(r)).collect())
+        .unwrap_or_default()
+}
+
+/// Hero holds a card of a rank villain needs for the nut straight.
+pub fn blocks_nut_straight(hand: [Card; 2], board: &[Card]) -> bool {
+    let needed = nut_straight_ranks(board);
+    hand.iter().any(|c| needed.contains(&c.rank))
+}
+
+/// How strongly hero's hole cards block villain's best made hands, 0.0–1.0.
+///
+/// Holding the ace of a three-plus-card flush suit scores 1.0 (the king 0.5).
+/// Blocking the nut straight scores 1.0 when it is the only straight the
+/// board allows and 0.75 otherwise; blocking a lesser straight scores 0.25.
+/// Straight blockers count half when a flush is also possible.  The result is
+/// the strongest single blocker.
+pub fn blocker_value_score(hand: [Card; 2], board: &[Card]) -> f32 {
+    let flush_suit = nut_flush_suit(board)
+        .filter(|&s| board_suit_counts(board)[suit_index(s)] >= 3);
+    let flush = match flush_suit {
+        Some(s) if hand.iter().any(|c| c.suit == s && c.rank.0 == 14) => 1.0,
+        Some(s) if hand.iter().any(|c| c.suit == s && c.rank.0 == 13) => 0.5,
+        _ => 0.0,
+    };
+
+    let windows = straight_windows(board);
+    let blocks = |w: &Vec<u8>| hand.iter().any(|c| w.contains(&c.rank.0));
+    let straight = match windows.first() {
+        Some(nut) if blocks(nut) => if windows.len() == 1 { 1.0 } else { 0.75 },
+        Some(_) if windows.iter().any(blocks) => 0.25,
+        _ => 0.0,
+    };
+    let straight = if flush_suit.is_some() { straight * 0.5 } else { straight };
+
+    f32::max(flush, straight)
+}
+
+/// True if 2+ cards share a suit (flush draw possible).
+pub fn has_flush_draw(board: &[Card]) -> bool {
+    !is_rainbow_board(board)
 }
 
 /// True if there are 2+ cards within a tight rank span (consecutive or 1-gap),
@@ -132,14 +450,298 @@ pub fn combo_draw_equity(streets_remaining: u8) -> f32 {
     }
 }
 
+/// Exact chance of hitting one of `outs` by the river.
+///
+/// Two streets (flop): `1 - (47-outs)/47 × (46-outs)/46`.  One street (turn):
+/// `outs / 46`.  Pair with [`count_outs`] for hero's actual draw.
+pub fn equity_from_outs(outs: u8, streets_remaining: u8) -> f32 {
+    let outs = outs.min(46) as f32;
+    match streets_remaining {
+        0 => 0.0,
+        1 => outs / 46.0,
+        _ => 1.0 - ((47.0 - outs) / 47.0) * ((46.0 - outs) / 46.0),
+    }
+}
+
+/// Rule of 2 and 4: outs × 4% with two streets to come, outs × 2% with one.
+/// Capped at 100%.
+pub fn equity_from_outs_rule_of_2_4(outs: u8, streets_remaining: u8) -> f32 {
+    let per_out = match streets_remaining {
+        0 => 0.0,
+        1 => 0.02,
+        _ => 0.04,
+    };
+    (outs as f32 * per_out).min(1.0)
+}
+
+/// Share of `raw_equity` hero actually realizes: in position about 105%, out
+/// of position about 85% at 100 BB.  The gap grows with `stack_bb` (more
+/// play left for position to matter) and closes at 0 BB, where the hand is
+/// simply run out.  Clamped to `[0.0, 1.0]`.
+pub fn adjusted_equity(raw_equity: f32, is_ip: bool, stack_bb: u32) -> f32 {
+    let depth = (stack_bb as f32 / 100.0).min(2.0);
+    let realization = if is_ip { 1.0 + 0.05 * depth } else { 1.0 - 0.15 * depth };
+    (raw_equity * realization).clamp(0.0, 1.0)
+}
+
+/// EV of putting in `investment` chips to win `pot_if_win` with `equity`:
+/// `equity × pot_if_win − (1 − equity) × investment`.
+pub fn expected_value(equity: f32, pot_if_win: u32, investment: u32) -> f32 {
+    equity * pot_if_win as f32 - (1.0 - equity) * investment as f32
+}
+
+/// EV of betting `bet_size` into `current_pot`: villain folds with
+/// `fold_probability` (hero wins the pot), otherwise calls and hero realises
+/// `equity_if_called` of the bigger pot, less the bet put in:
+/// `fold × pot + (1 − fold) × (equity × (pot + 2 × bet) − bet)`.
+pub fn ev_of_bet(fold_probability: f32, current_pot: u32, bet_size: u32, equity_if_called: f32) -> f32 {
+    // Winning a call nets the pot plus villain's bet; losing costs hero's bet.
+    fold_probability * current_pot as f32
+        + (1.0 - fold_probability)
+            * expected_value(equity_if_called, current_pot + bet_size, bet_size)
+}
+
+/// EV of raising to `raise_to` over a `facing_bet` that is already in
+/// `current_pot`: villain folds with `fold_probability` (hero wins the pot),
+/// otherwise calls the raise and hero realises `equity_if_called` of the
+/// bigger pot, less the raise put in:
+/// `fold × pot + (1 − fold) × (equity × (pot + 2 × raise_to − facing_bet) − raise_to)`.
+pub fn ev_of_raise(
+    fold_probability: f32,
+    current_pot: u32,
+    facing_bet: u32,
+    raise_to: u32,
+    equity_if_called: f32,
+) -> f32 {
+    let pot_if_called = current_pot + 2 * raise_to - facing_bet;
+    fold_probability * current_pot as f32
+        + (1.0 - fold_probability) * (equity_if_called * pot_if_called as f32 - raise_to as f32)
+}
+
+/// Equity needed to break even on a call: `call / (pot + call)`.
+///
+/// The same ratio is the fold frequency a bluff of `call_size` into `pot`
+/// needs to break even.
+pub fn break_even_equity(call_size: u32, pot: u32) -> f32 {
+    let total = pot + call_size;
+    if total == 0 {
+        return 0.0;
+    }
+    call_size as f32 / total as f32
+}
+
 /// Compute pot odds as a fraction: `call / (pot + call)`.
 /// Returns required equity to break even.
+#[deprecated(note = "renamed to `break_even_equity`")]
 pub fn required_equity(call_amount: u32, pot_before_call: u32) -> f32 {
-    let total = pot_before_call + call_amount;
+    break_even_equity(call_amount, pot_before_call)
+}
+
+/// How often the defender must continue against `bet` into `pot` so that a
+/// pure bluff does not profit: `pot / (pot + bet)`.  1.0 when there is no bet.
+pub fn minimum_defense_frequency(bet: u32, pot: u32) -> f32 {
+    if bet == 0 {
+        return 1.0;
+    }
+    pot as f32 / (pot + bet) as f32
+}
+
+/// Largest share of a balanced betting range that can be bluffs:
+/// `bet / (pot + 2 × bet)` — the caller's break-even equity.  0.0 when there
+/// is no bet.
+pub fn maximum_bluff_frequency(bet: u32, pot: u32) -> f32 {
+    if bet == 0 {
+        return 0.0;
+    }
+    bet as f32 / (pot + 2 * bet) as f32
+}
+
+/// Share of a balanced river betting range that is bluffs for a `bet_size`
+/// bet into `pot`: the caller's break-even equity,
+/// `break_even_equity(bet, pot + bet)` — the same as
+/// [`maximum_bluff_frequency`].  A pot-sized bet is one-third bluffs.
+pub fn optimal_bluff_to_value_ratio(bet_size: u32, pot: u32) -> f32 {
+    maximum_bluff_frequency(bet_size, pot)
+}
+
+/// Bluff combos that balance `value_combos` for a `bet_size` bet into `pot`:
+/// `value × r / (1 − r)` with `r` from [`optimal_bluff_to_value_ratio`],
+/// rounded to the nearest combo.
+pub fn gto_bluff_combo_count(bet_size: u32, pot: u32, value_combos: u32) -> u32 {
+    let r = optimal_bluff_to_value_ratio(bet_size, pot);
+    (value_combos as f32 * r / (1.0 - r)).round() as u32
+}
+
+/// True if `bluffs` is within ±2 combos of [`gto_bluff_combo_count`] for
+/// `value` value combos.
+pub fn is_range_balanced(value: u32, bluffs: u32, bet: u32, pot: u32) -> bool {
+    gto_bluff_combo_count(bet, pot, value).abs_diff(bluffs) <= 2
+}
+
+/// `pot : bet` reduced to lowest terms, e.g. `(300, 100)` → `(3, 1)`.
+///
+/// A zero on either side is returned as `(1, 0)` / `(0, 1)`; `(0, 0)` stays.
+pub fn pot_odds_ratio(pot: u32, bet: u32) -> (u32, u32) {
+    fn gcd(a: u32, b: u32) -> u32 {
+        if b == 0 { a } else { gcd(b, a % b) }
+    }
+    match gcd(pot, bet) {
+        0 => (0, 0),
+        g => (pot / g, bet / g),
+    }
+}
+
+/// Everything a call of `call_size` into `pot` costs, from
+/// [`calculate_pot_odds`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PotOdds {
+    /// `pot : call` in lowest terms, e.g. `(3, 1)` for 30 chips to call into 90.
+    pub ratio: (u32, u32),
+    /// Equity needed to call, as a percentage (0–100).
+    pub percentage: f32,
+    /// Equity needed to call, as a fraction: [`break_even_equity`].
+    pub break_even_equity: f32,
+}
+
+impl std::fmt::Display for PotOdds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (pot, call) = self.ratio;
+        write!(f, "{pot}:{call} ({:.0}% equity needed)", self.percentage)
+    }
+}
+
+/// Pot odds of calling `call_size` into `pot` (villain's bet already in it):
+/// the reduced [`pot_odds_ratio`] and the matching [`break_even_equity`].
+pub fn calculate_pot_odds(call_size: u32, pot: u32) -> PotOdds {
+    let break_even = break_even_equity(call_size, pot);
+    PotOdds {
+        ratio: pot_odds_ratio(pot, call_size),
+        percentage: break_even * 100.0,
+        break_even_equity: break_even,
+    }
+}
+
+/// Break-even equity once future winnings are counted:
+/// `call / (pot + call + implied_gain)`.
+///
+/// `implied_gain` is what hero expects to win on later streets when the draw
+/// comes in; with 0 this equals [`break_even_equity`].
+pub fn implied_odds(call_size: u32, pot: u32, implied_gain: u32) -> f32 {
+    let total = pot + call_size + implied_gain;
     if total == 0 {
         return 0.0;
     }
-    call_amount as f32 / total as f32
+    call_size as f32 / total as f32
+}
+
+/// Chance a pocket pair flops a set or better (~1 in 8.5).
+pub const SET_ON_FLOP: f32 = 0.118;
+
+/// Share of the effective stack a flopped set wins against two or more
+/// opponents.  Chosen so that, with no dead money, set mining breaks even at
+/// a call of 5% of the stack — the "5% rule".  Heads-up the set is paid off
+/// half as often.
+const SET_MINING_STACK_SHARE: f32 = 0.375;
+
+/// Rough EV in chips of calling `call_size` preflop with a pocket pair
+/// purely to flop a set: `SET_ON_FLOP × (pot + implied) − miss × call`.
+///
+/// The implied winnings are [`SET_MINING_STACK_SHARE`] of `stack` with two or
+/// more `opponents`, half that heads-up, and nothing with none.  The result is
+/// positive roughly when `call_size ≤ 5% of stack` in a multiway pot.
+pub fn set_mining_ev(call_size: u32, pot: u32, stack: u32, opponents: u8) -> f32 {
+    let share = match opponents {
+        0 => 0.0,
+        1 => SET_MINING_STACK_SHARE / 2.0,
+        _ => SET_MINING_STACK_SHARE,
+    };
+    let implied = stack as f32 * share;
+    SET_ON_FLOP * (pot as f32 + implied) - (1.0 - SET_ON_FLOP) * call_size as f32
+}
+
+/// SPR below which a player is pot-committed.  Exposed as a function so
+/// callers can build their own thresholds around it.
+pub fn commit_threshold_spr() -> f32 {
+    1.5
+}
+
+/// `true` when `stack / pot` is below [`commit_threshold_spr`]: folding would
+/// surrender too much of the pot compared to getting the rest in.
+pub fn pot_committed(stack: u32, pot: u32) -> bool {
+    (stack as f32 / pot as f32) < commit_threshold_spr()
+}
+
+// ---------------------------------------------------------------------------
+// Range widths
+//
+// Rough fractions of all starting hands, used to put a number on "villain's
+// range" in explanations.  These are population estimates, not solver output.
+// ---------------------------------------------------------------------------
+
+/// Opening-range width per seat, as a fraction of all hands.
+///
+/// The big blind never opens, so it has no field and [`PositionRange::get`]
+/// returns 0.0 for it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionRange {
+    pub utg: f32,
+    pub utg1: f32,
+    pub utg2: f32,
+    pub lj: f32,
+    pub hj: f32,
+    pub co: f32,
+    pub btn: f32,
+    pub sb: f32,
+}
+
+impl PositionRange {
+    /// Typical 6-max opening widths.  The seats a 6-max table lacks (UTG+1,
+    /// UTG+2, LJ) copy UTG, which is the first seat to act there.
+    pub fn standard_6max() -> PositionRange {
+        PositionRange {
+            utg: 0.17, utg1: 0.17, utg2: 0.17, lj: 0.17,
+            hj: 0.21, co: 0.27, btn: 0.43, sb: 0.40,
+        }
+    }
+
+    /// Typical full-ring (9-max) opening widths.
+    pub fn standard_9max() -> PositionRange {
+        PositionRange {
+            utg: 0.15, utg1: 0.16, utg2: 0.17, lj: 0.18,
+            hj: 0.20, co: 0.25, btn: 0.40, sb: 0.35,
+        }
+    }
+
+    /// Opening width for `pos`; 0.0 for the big blind.
+    pub fn get(self, pos: Position) -> f32 {
+        match pos {
+            Position::UTG  => self.utg,
+            Position::UTG1 => self.utg1,
+            Position::UTG2 => self.utg2,
+            Position::LJ   => self.lj,
+            Position::HJ   => self.hj,
+            Position::CO   => self.co,
+            Position::BTN  => self.btn,
+            Position::SB   => self.sb,
+            Position::BB   => 0.0,
+        }
+    }
+}
+
+/// Estimated opening-range width from `pos` as a fraction of all hands:
+/// the [`PositionRange::standard_9max`] widths.
+/// The big blind never opens, so it returns 0.0.
+pub fn villain_range_width(pos: Position) -> f32 {
+    PositionRange::standard_9max().get(pos)
+}
+
+/// Estimated fraction of hands the big blind defends (calls or 3-bets)
+/// against a `raise_size_bb` open from `vs_pos`.  Wider openers are defended
+/// wider; the width scales with the 2.5 BB standard open, so a bigger raise
+/// narrows it.  Raises below a min-raise are treated as 2 BB.
+pub fn bb_defense_range_width(vs_pos: Position, raise_size_bb: u32) -> f32 {
+    let size_factor = 2.5 / raise_size_bb.max(2) as f32;
+    ((0.20 + villain_range_width(vs_pos)) * size_factor).clamp(0.0, 1.0)
 }
 
 // ---------------------------------------------------------------------------
@@ -165,9 +767,38 @@ pub enum HandCategory {
     Trash,
 }
 
+impl HandCategory {
+    /// Categorise a starting hand (see [`classify_hand`]).
+    pub fn from_hand(hand: [Card; 2]) -> HandCategory {
+        classify_hand(hand)
+    }
+
+    /// Lower-case name used in branch keys and text: `"premium"` … `"trash"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            HandCategory::Premium  => "premium",
+            HandCategory::Strong   => "strong",
+            HandCategory::Playable => "playable",
+            HandCategory::Marginal => "marginal",
+            HandCategory::Trash    => "trash",
+        }
+    }
+
+    /// Every category, strongest first.
+    pub fn all() -> [HandCategory; 5] {
+        [
+            HandCategory::Premium,
+            HandCategory::Strong,
+            HandCategory::Playable,
+            HandCategory::Marginal,
+            HandCategory::Trash,
+        ]
+    }
+}
+
 impl std::fmt::Display for HandCategory {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", hand_category_name(*self))
+        write!(f, "{}", self.name())
     }
 }
 
@@ -182,7 +813,7 @@ pub fn classify_hand(hand: [Card; 2]) -> HandCategory {
 
     if pair {
         return match r1 {
-            14 | 13 | 12 => HandCategory::Premium,
+            12..=14      => HandCategory::Premium,
             11 | 10      => HandCategory::Strong,
             7..=9        => HandCategory::Playable,
             _            => HandCategory::Marginal,
@@ -204,14 +835,214 @@ pub fn classify_hand(hand: [Card; 2]) -> HandCategory {
     }
 }
 
+#[deprecated(note = "use `HandCategory::name`")]
 pub fn hand_category_name(cat: HandCategory) -> &'static str {
-    match cat {
-        HandCategory::Premium  => "premium",
-        HandCategory::Strong   => "strong",
-        HandCategory::Playable => "playable",
-        HandCategory::Marginal => "marginal",
-        HandCategory::Trash    => "trash",
+    cat.name()
+}
+
+// ---------------------------------------------------------------------------
+// Push/fold tiers
+//
+// A coarser 4-tier split used for short-stack shove decisions (T5).
+// `nash_push_threshold_bb` turns a tier into the deepest stack that still
+// shoves first-in from a given seat, before any ICM adjustment.
+// ---------------------------------------------------------------------------
+
+/// Hand strength tiers for push/fold (simplified).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushTier {
+    Premium,  // AA, KK, QQ, AKs — always push short stacks
+    Strong,   // JJ, TT, AQ, AK — push at moderate depths
+    Playable, // Mid pairs, suited broadways — push only when short
+    Weak,     // Everything else — only push when desperate
+}
+
+pub fn classify_push_tier(hand: [Card; 2]) -> PushTier {
+    let (r1, r2) = {
+        let mut ranks = [hand[0].rank.0, hand[1].rank.0];
+        ranks.sort_unstable_by(|a, b| b.cmp(a));
+        (ranks[0], ranks[1])
+    };
+    let suited = hand[0].suit == hand[1].suit;
+    let pair = r1 == r2;
+
+    if pair && r1 >= 12 { return PushTier::Premium; }       // QQ+
+    if r1 == 14 && r2 == 13 && suited { return PushTier::Premium; } // AKs
+    if pair && r1 >= 10 { return PushTier::Strong; }         // JJ, TT
+    if r1 == 14 && r2 >= 12 { return PushTier::Strong; }    // AK, AQ
+    if pair && r1 >= 7 { return PushTier::Playable; }        // 77-99
+    if r1 == 14 && r2 >= 10 && suited { return PushTier::Playable; } // ATs+
+    if r1 >= 12 && r2 >= 11 && suited { return PushTier::Playable; } // KQs, KJs, QJs
+    PushTier::Weak
+}
+
+/// Approximate deepest stack (in BB) at which shoving `hand` first-in from
+/// `pos` is Nash-optimal in chip EV.
+///
+/// Each [`PushTier`] has a button threshold — 20 / 16 / 12 / 6 BB — scaled
+/// by seat: the button shoves widest, the cutoff and middle positions a
+/// little tighter, and early positions and the blinds tightest (70%).
+/// Thresholds round to the nearest BB, so pairs within a tier share a value.
+pub fn nash_push_threshold_bb(hand: [Card; 2], pos: Position) -> u32 {
+    let button: f32 = match classify_push_tier(hand) {
+        PushTier::Premium  => 20.0,
+        PushTier::Strong   => 16.0,
+        PushTier::Playable => 12.0,
+        PushTier::Weak     => 6.0,
+    };
+    let seat = match pos {
+        Position::BTN                  => 1.0,
+        Position::CO                   => 0.9,
+        Position::HJ | Position::LJ    => 0.8,
+        Position::UTG | Position::UTG1 | Position::UTG2
+        | Position::SB | Position::BB  => 0.7,
+    };
+    (button * seat).round() as u32
+}
+
+/// Canonical starting-hand notation for two hole cards: `"AKs"`, `"A2o"`,
+/// `"77"` — high card first, `s` / `o` for unpaired hands.  Same as
+/// [`Hand::notation`]; preflop topics use it for hero's hand.
+pub fn normalized_hand_notation(hand: [Card; 2]) -> String {
+    Hand::from(hand).notation()
+}
+
+// ---------------------------------------------------------------------------
+// Starting-hand ranking
+//
+// A finer ordering than `HandCategory` for range-width decisions (T26): the
+// Chen formula scores every starting hand, and the percentile turns a score
+// into "top x% of hands" by combo count.
+// ---------------------------------------------------------------------------
+
+/// Chen score from the two ranks (high first) and suitedness.
+fn chen_points(hi: u8, lo: u8, suited: bool) -> i32 {
+    let value = |r: u8| match r {
+        14 => 10.0,
+        13 => 8.0,
+        12 => 7.0,
+        11 => 6.0,
+        r  => r as f32 / 2.0,
+    };
+    if hi == lo {
+        return (value(hi) * 2.0).max(5.0).ceil() as i32;
+    }
+    let gap = hi - lo - 1;
+    let mut score = value(hi) - match gap {
+        0 => 0.0,
+        1 => 1.0,
+        2 => 2.0,
+        3 => 4.0,
+        _ => 5.0,
+    };
+    if suited {
+        score += 2.0;
+    }
+    if gap <= 1 && hi < 12 {
+        score += 1.0;
     }
+    score.ceil() as i32
+}
+
+/// Bill Chen's starting-hand score: 20 for AA down to −1 for 72o.
+pub fn chen_score(hand: [Card; 2]) -> i32 {
+    let (hi, lo) = (hand[0].rank.0.max(hand[1].rank.0), hand[0].rank.0.min(hand[1].rank.0));
+    chen_points(hi, lo, hand[0].suit == hand[1].suit)
+}
+
+/// Where `hand` ranks among all 1326 starting combos by [`chen_score`], from
+/// 0.0 (best) to 1.0: the share of combos scoring higher plus half of those
+/// tied with it.  A hand is inside a `w`-wide range when this is below `w`.
+pub fn starting_hand_percentile(hand: [Card; 2]) -> f32 {
+    let score = chen_score(hand);
+    let (mut above, mut tied) = (0u32, 0u32);
+    for hi in 2..=14u8 {
+        for lo in 2..=hi {
+            let classes: &[(bool, u32)] = if hi == lo { &[(false, 6)] } else { &[(true, 4), (false, 12)] };
+            for &(suited, combos) in classes {
+                match chen_points(hi, lo, suited).cmp(&score) {
+                    std::cmp::Ordering::Greater => above += combos,
+                    std::cmp::Ordering::Equal   => tied += combos,
+                    std::cmp::Ordering::Less    => {}
+                }
+            }
+        }
+    }
+    (above as f32 + tied as f32 / 2.0) / 1326.0
+}
+
+// ---------------------------------------------------------------------------
+// Preflop equity vs an opening range
+//
+// A lookup table of all-in equity against the top-N% of hands (ranked by
+// `starting_hand_percentile`), measured by simulation at four range widths
+// and smoothed so equity never drops as the hand or the range improves for
+// hero.  Pairs have their own rows; other hands are grouped by Chen score.
+// ---------------------------------------------------------------------------
+
+/// Range widths (fractions of all hands) the equity tables are measured at.
+const EQUITY_RANGE_WIDTHS: [f32; 4] = [0.15, 0.25, 0.40, 1.00];
+
+/// Equity of each pocket pair, 22 through AA, against the [`EQUITY_RANGE_WIDTHS`].
+const PAIR_EQUITY: [[f32; 4]; 13] = [
+    [0.42, 0.44, 0.47, 0.50], // 22
+    [0.44, 0.45, 0.48, 0.54], // 33
+    [0.44, 0.46, 0.50, 0.58], // 44
+    [0.45, 0.47, 0.52, 0.60], // 55
+    [0.47, 0.49, 0.56, 0.63], // 66
+    [0.47, 0.51, 0.58, 0.66], // 77
+    [0.51, 0.54, 0.61, 0.67], // 88
+    [0.54, 0.57, 0.64, 0.72], // 99
+    [0.60, 0.64, 0.68, 0.74], // TT
+    [0.64, 0.68, 0.72, 0.79], // JJ
+    [0.70, 0.73, 0.74, 0.80], // QQ
+    [0.75, 0.77, 0.78, 0.82], // KK
+    [0.84, 0.84, 0.85, 0.85], // AA
+];
+
+/// Average equity of unpaired hands by Chen score, −1 (72o) through 12 (AKs),
+/// against the [`EQUITY_RANGE_WIDTHS`].
+const UNPAIRED_EQUITY: [[f32; 4]; 14] = [
+    [0.28, 0.29, 0.31, 0.36], // -1
+    [0.29, 0.31, 0.34, 0.41], //  0
+    [0.30, 0.33, 0.36, 0.43], //  1
+    [0.32, 0.35, 0.38, 0.46], //  2
+    [0.34, 0.37, 0.39, 0.47], //  3
+    [0.34, 0.37, 0.40, 0.47], //  4
+    [0.39, 0.42, 0.45, 0.53], //  5
+    [0.39, 0.42, 0.46, 0.54], //  6
+    [0.42, 0.46, 0.49, 0.57], //  7
+    [0.46, 0.50, 0.53, 0.60], //  8
+    [0.50, 0.53, 0.57, 0.62], //  9
+    [0.57, 0.59, 0.61, 0.65], // 10
+    [0.59, 0.61, 0.64, 0.67], // 11
+    [0.62, 0.64, 0.66, 0.68], // 12
+];
+
+/// Approximate all-in preflop equity of `hand` against the opening range of
+/// `villain_pos`: the top [`villain_range_width`] of hands.
+///
+/// Read from a lookup table and interpolated linearly between the widths it
+/// was measured at; ranges tighter than 15% use the 15% column.  The big blind
+/// never opens, so against it hero faces any two cards.
+pub fn preflop_hand_equity_vs_position_range(hand: [Card; 2], villain_pos: Position) -> f32 {
+    let (hi, lo) = (hand[0].rank.0.max(hand[1].rank.0), hand[0].rank.0.min(hand[1].rank.0));
+    let row = if hi == lo {
+        PAIR_EQUITY[(hi - 2) as usize]
+    } else {
+        UNPAIRED_EQUITY[(chen_score(hand) + 1).clamp(0, 13) as usize]
+    };
+    let width = match villain_range_width(villain_pos) {
+        w if w > 0.0 => w,
+        _            => 1.0,
+    };
+    let widths = EQUITY_RANGE_WIDTHS;
+    if width <= widths[0] {
+        return row[0];
+    }
+    let i = widths.iter().rposition(|&w| w <= width).unwrap_or(0).min(widths.len() - 2);
+    let t = ((width - widths[i]) / (widths[i + 1] - widths[i])).min(1.0);
+    row[i] + (row[i + 1] - row[i]) * t
 }
 
 // ---------------------------------------------------------------------------
@@ -258,6 +1089,29 @@ impl std::fmt::Display for DrawType {
     }
 }
 
+impl DrawType {
+    /// Every draw type, strongest first.
+    pub fn all() -> [DrawType; 4] {
+        [DrawType::ComboDraw, DrawType::FlushDraw, DrawType::OESD, DrawType::GutShot]
+    }
+
+    /// Typical number of clean outs: 15 / 9 / 8 / 4.
+    pub fn outs(self) -> u8 {
+        match self {
+            DrawType::ComboDraw => 15,
+            DrawType::FlushDraw => 9,
+            DrawType::OESD      => 8,
+            DrawType::GutShot   => 4,
+        }
+    }
+
+    /// Chance of completing with `streets` cards to come, from
+    /// [`outs`](Self::outs) via [`equity_from_outs`].
+    pub fn equity(self, streets: u8) -> f32 {
+        equity_from_outs(self.outs(), streets)
+    }
+}
+
 /// Classify the draw type present on the board.
 pub fn classify_draw(board: &[Card]) -> DrawType {
     match (has_flush_draw(board), has_straight_draw(board)) {
@@ -268,6 +1122,78 @@ pub fn classify_draw(board: &[Card]) -> DrawType {
     }
 }
 
+/// Every draw hero's own hole cards hold, straight draw first: `OESD` (two or
+/// more straight-completing ranks) or `GutShot` (one), then `FlushDraw` (four
+/// to a flush including a hole card).  Made straights and flushes, draws the
+/// board holds alone, and full boards give no draw; `ComboDraw` is never
+/// listed — a combo draw shows up as both of its parts.
+pub fn classify_draw_combined(hand: [Card; 2], board: &[Card]) -> Vec<DrawType> {
+    let mut draws = Vec::new();
+    if board.len() >= 5 {
+        return draws;
+    }
+
+    let board_ranks: Vec<u8> = board.iter().map(|c| c.rank.0).collect();
+    let hero_straight = |extra: Option<u8>| {
+        let mut all: Vec<u8> = hand.iter().map(|c| c.rank.0).chain(board_ranks.iter().copied()).collect();
+        let mut board_only = board_ranks.clone();
+        all.extend(extra);
+        board_only.extend(extra);
+        straight_high(&all).is_some_and(|hi| straight_high(&board_only).is_none_or(|b| hi > b))
+    };
+    if !hero_straight(None) {
+        match (2..=14u8).filter(|&r| hero_straight(Some(r))).count() {
+            0 => {}
+            1 => draws.push(DrawType::GutShot),
+            _ => draws.push(DrawType::OESD),
+        }
+    }
+
+    let suit_total = |s: Suit| hand.iter().chain(board).filter(|c| c.suit == s).count();
+    if hand.iter().any(|c| suit_total(c.suit) == 4) {
+        draws.push(DrawType::FlushDraw);
+    }
+    draws
+}
+
+/// Combined chance that at least one of `draws` completes with `streets` cards
+/// to come, by inclusion-exclusion: `P(A ∪ B) = P(A) + P(B) − P(A ∩ B)`.
+///
+/// A flush draw and a straight draw share the straight cards of hero's suit
+/// (one of a gutshot's four outs, two of an open-ender's eight), and
+/// `P(A ∩ B)` is the chance both complete by the last card.  `ComboDraw`
+/// counts as a flush draw plus an open-ender; of several straight draws only
+/// the best counts.  Matches [`equity_from_outs`] on the union of the outs.
+pub fn equity_of_combined_draws(draws: &[DrawType], streets: u8) -> f32 {
+    let flush = draws.iter().any(|d| matches!(d, DrawType::FlushDraw | DrawType::ComboDraw));
+    let straight_outs = draws
+        .iter()
+        .map(|d| match d {
+            DrawType::ComboDraw | DrawType::OESD => DrawType::OESD.outs(),
+            DrawType::GutShot => DrawType::GutShot.outs(),
+            DrawType::FlushDraw => 0,
+        })
+        .max()
+        .unwrap_or(0);
+    if !flush || straight_outs == 0 {
+        let outs = if flush { DrawType::FlushDraw.outs() } else { straight_outs };
+        return equity_from_outs(outs, streets);
+    }
+
+    let shared = straight_outs / 4;
+    let flush_only = (DrawType::FlushDraw.outs() - shared) as f32;
+    let straight_only = (straight_outs - shared) as f32;
+    let both = match streets {
+        0 => 0.0,
+        1 => shared as f32 / 46.0,
+        // A shared out on either card, or one card completing each draw.
+        _ => {
+            equity_from_outs(shared, 2) + 2.0 * flush_only * straight_only / (47.0 * 46.0)
+        }
+    };
+    equity_from_outs(DrawType::FlushDraw.outs(), streets) + equity_from_outs(straight_outs, streets) - both
+}
+
 /// Approximate flop equity for a given draw type (2 streets remaining).
 pub fn draw_equity_flop(dt: DrawType) -> f32 {
     match dt {
@@ -290,11 +1216,455 @@ pub fn hero_has_straight_draw(hand: [Card; 2], board: &[Card]) -> bool {
     if !has_straight_draw(board) { return false; }
     let board_ranks: Vec<u8> = board.iter().map(|c| c.rank.0).collect();
     hand.iter().any(|hc| board_ranks.iter().any(|&br| {
-        let diff = if hc.rank.0 > br { hc.rank.0 - br } else { br - hc.rank.0 };
+        let diff = hc.rank.0.abs_diff(br);
         diff <= 3
     }))
 }
 
+/// Hero holds two cards of one suit and exactly one board card shares it —
+/// a flop runner-runner flush draw.
+pub fn has_backdoor_flush_draw(hand: [Card; 2], board: &[Card]) -> bool {
+    hand[0].suit == hand[1].suit && board_suit_counts(board)[suit_index(hand[0].suit)] == 1
+}
+
+/// Hero's two hole cards plus one board card are three distinct ranks inside
+/// a single five-rank straight window (ace also plays low), so two running
+/// cards can complete a straight.
+pub fn has_backdoor_straight_draw(hand: [Card; 2], board: &[Card]) -> bool {
+    let low = |r: u8| if r == 14 { 1 } else { r };
+    let fits = |ranks: [u8; 3]| {
+        let mut r = ranks;
+        r.sort_unstable();
+        r[0] != r[1] && r[1] != r[2] && r[2] - r[0] <= 4
+    };
+    let (a, b) = (hand[0].rank.0, hand[1].rank.0);
+    board.iter().any(|c| {
+        let r = c.rank.0;
+        fits([a, b, r]) || fits([low(a), low(b), low(r)])
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Hand vs board (postflop made-hand strength)
+//
+// `classify_hero_hand_vs_board()` says what hero's hole cards make with the
+// board: a set, two pair, top pair and kicker quality, and so on.  Only hands
+// that use at least one hole card count — a straight or flush that is
+// entirely on the board leaves hero with whatever the hole cards add.
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandVsBoardStrength {
+    /// Trips with the highest board rank.
+    TopSet,
+    /// Trips with a board rank between the highest and lowest.
+    MiddleSet,
+    /// Trips with the lowest board rank.
+    BottomSet,
+    /// Five or more cards of one suit, using a hole card.
+    Flush,
+    /// Five consecutive ranks, higher than any straight on the board alone.
+    Straight,
+    /// Each hole card pairs a different board card.
+    TwoPair,
+    /// Hole card pairs the top board card with the best available kicker.
+    TopPairTopKicker,
+    /// Hole card pairs the top board card with any other kicker.
+    TopPairWeakKicker,
+    /// Pairs a board card below the top one, or a pocket pair between board cards.
+    MiddlePair,
+    /// Pairs the lowest board card, or a pocket pair below every board card.
+    BottomPair,
+    /// Pocket pair above every board card.
+    OverPair,
+    /// Nothing better than high card.
+    NoPair,
+}
+
+impl std::fmt::Display for HandVsBoardStrength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            HandVsBoardStrength::TopSet            => "top set",
+            HandVsBoardStrength::MiddleSet         => "middle set",
+            HandVsBoardStrength::BottomSet         => "bottom set",
+            HandVsBoardStrength::Flush             => "flush",
+            HandVsBoardStrength::Straight          => "straight",
+            HandVsBoardStrength::TwoPair           => "two pair",
+            HandVsBoardStrength::TopPairTopKicker  => "top pair, top kicker",
+            HandVsBoardStrength::TopPairWeakKicker => "top pair, weak kicker",
+            HandVsBoardStrength::MiddlePair        => "middle pair",
+            HandVsBoardStrength::BottomPair        => "bottom pair",
+            HandVsBoardStrength::OverPair          => "overpair",
+            HandVsBoardStrength::NoPair            => "no pair",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Highest card of the best straight among `ranks` (ace plays high or low).
+fn straight_high(ranks: &[u8]) -> Option<u8> {
+    let has = |r: u8| ranks.contains(&r) || (r == 1 && ranks.contains(&14));
+    (5..=14u8).rev().find(|&hi| (hi - 4..=hi).all(has))
+}
+
+/// Classify what hero's hole cards make with a board of 1–5 cards.
+///
+/// Checked strongest first: flush, straight, set/trips, two pair, overpair,
+/// top pair, middle pair, bottom pair.
+pub fn classify_hero_hand_vs_board(hand: [Card; 2], board: &[Card]) -> HandVsBoardStrength {
+    let all: Vec<Card> = hand.iter().chain(board).copied().collect();
+
+    let mut suit_counts = [0u8; 4];
+    for c in &all { suit_counts[suit_index(c.suit)] += 1; }
+    if hand.iter().any(|c| suit_counts[suit_index(c.suit)] >= 5) {
+        return HandVsBoardStrength::Flush;
+    }
+
+    let all_ranks: Vec<u8> = all.iter().map(|c| c.rank.0).collect();
+    let board_ranks: Vec<u8> = board.iter().map(|c| c.rank.0).collect();
+    if let Some(hi) = straight_high(&all_ranks) {
+        if straight_high(&board_ranks).is_none_or(|b| hi > b) {
+            return HandVsBoardStrength::Straight;
+        }
+    }
+
+    // Distinct board ranks, highest first.
+    let mut distinct = board_ranks.clone();
+    distinct.sort_unstable_by(|a, b| b.cmp(a));
+    distinct.dedup();
+    let (Some(&top), Some(&bottom)) = (distinct.first(), distinct.last()) else {
+        return if hand[0].rank == hand[1].rank {
+            HandVsBoardStrength::OverPair
+        } else {
+            HandVsBoardStrength::NoPair
+        };
+    };
+    let on_board = |r: u8| board_ranks.iter().filter(|&&b| b == r).count();
+    let (h1, h2) = (hand[0].rank.0, hand[1].rank.0);
+    let pocket_pair = h1 == h2;
+
+    // Sets (pocket pair + board card) and trips (hole card + board pair).
+    let trips_rank = if pocket_pair && on_board(h1) >= 1 {
+        Some(h1)
+    } else {
+        [h1, h2].into_iter().find(|&r| on_board(r) >= 2)
+    };
+    if let Some(r) = trips_rank {
+        return if r == top {
+            HandVsBoardStrength::TopSet
+        } else if r == bottom {
+            HandVsBoardStrength::BottomSet
+        } else {
+            HandVsBoardStrength::MiddleSet
+        };
+    }
+
+    if pocket_pair {
+        return if h1 > top {
+            HandVsBoardStrength::OverPair
+        } else if h1 < bottom {
+            HandVsBoardStrength::BottomPair
+        } else {
+            HandVsBoardStrength::MiddlePair
+        };
+    }
+
+    match (on_board(h1) > 0, on_board(h2) > 0) {
+        (true, true) => HandVsBoardStrength::TwoPair,
+        (false, false) => HandVsBoardStrength::NoPair,
+        (paired_first, _) => {
+            let (paired, kicker) = if paired_first { (h1, h2) } else { (h2, h1) };
+            if paired == top {
+                // The best kicker is the highest rank not already on the board.
+                let best_kicker = (2..=14u8).rev().find(|&r| on_board(r) == 0).unwrap_or(14);
+                if kicker >= best_kicker {
+                    HandVsBoardStrength::TopPairTopKicker
+                } else {
+                    HandVsBoardStrength::TopPairWeakKicker
+                }
+            } else if paired == bottom {
+                HandVsBoardStrength::BottomPair
+            } else {
+                HandVsBoardStrength::MiddlePair
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Pair-type predicates
+//
+// Unlike `classify_hero_hand_vs_board()` these ignore flushes and straights,
+// and at most one of them holds: a set is never also top pair, and two pair
+// is never also top pair.
+// ---------------------------------------------------------------------------
+
+/// How many board cards have rank `r`.
+fn board_count(board: &[Card], r: Rank) -> usize {
+    board.iter().filter(|c| c.rank == r).count()
+}
+
+/// One hole card pairs the highest board card; the other card pairs nothing.
+/// A pocket pair is never top pair — with a matching board card it is a set.
+pub fn hero_has_top_pair(hand: [Card; 2], board: &[Card]) -> bool {
+    let Some(top) = board.iter().map(|c| c.rank).max() else { return false };
+    let [a, b] = hand;
+    a.rank != b.rank
+        && board_count(board, top) == 1
+        && match (a.rank == top, b.rank == top) {
+            (true, false) => board_count(board, b.rank) == 0,
+            (false, true) => board_count(board, a.rank) == 0,
+            _ => false,
+        }
+}
+
+/// A pocket pair higher than every board card.
+pub fn hero_has_overpair(hand: [Card; 2], board: &[Card]) -> bool {
+    let [a, b] = hand;
+    a.rank == b.rank && !board.is_empty() && board.iter().all(|c| c.rank < a.rank)
+}
+
+/// A pocket pair that matches a board card.  Trips made with one hole card
+/// and a paired board do not count.
+pub fn hero_has_set(hand: [Card; 2], board: &[Card]) -> bool {
+    let [a, b] = hand;
+    a.rank == b.rank && board_count(board, a.rank) >= 1
+}
+
+/// Each hole card pairs a different board card, once.
+pub fn hero_has_two_pair(hand: [Card; 2], board: &[Card]) -> bool {
+    let [a, b] = hand;
+    a.rank != b.rank && board_count(board, a.rank) == 1 && board_count(board, b.rank) == 1
+}
+
+// ---------------------------------------------------------------------------
+// Outs
+//
+// An out is an unseen card that completes hero's draw: it gives hero a flush
+// (or a straight, when hero has no flush) that uses a hole card.  Each card is
+// counted once, so a combo draw never double-counts the straight-and-flush
+// cards.
+// ---------------------------------------------------------------------------
+
+/// 2 = hero has a flush, 1 = a straight, 0 = neither.
+fn completed_draw(hand: [Card; 2], board: &[Card]) -> u8 {
+    match classify_hero_hand_vs_board(hand, board) {
+        HandVsBoardStrength::Flush    => 2,
+        HandVsBoardStrength::Straight => 1,
+        _                             => 0,
+    }
+}
+
+/// Number of unseen cards that complete hero's flush or straight draw on
+/// the next card.  Returns 0 on a full (5-card) board.
+pub fn count_outs(hand: [Card; 2], board: &[Card]) -> u8 {
+    out_cards(hand, board).len() as u8
+}
+
+/// The unseen cards [`count_outs`] counts, clubs to spades and low to high
+/// within a suit.  Empty on a full (5-card) board.
+pub fn out_cards(hand: [Card; 2], board: &[Card]) -> Vec<Card> {
+    if board.len() >= 5 {
+        return Vec::new();
+    }
+    let current = completed_draw(hand, board);
+    let mut next_board = board.to_vec();
+    let mut outs = Vec::new();
+    for suit in Suit::all() {
+        for r in 2..=14u8 {
+            let card = Card { rank: Rank(r), suit };
+            if hand.contains(&card) || board.contains(&card) {
+                continue;
+            }
+            next_board.push(card);
+            if completed_draw(hand, &next_board) > current {
+                outs.push(card);
+            }
+            next_board.pop();
+        }
+    }
+    outs
+}
+
+// ---------------------------------------------------------------------------
+// Five-card evaluation
+//
+// `evaluate_five_cards()` scores a 5-card hand as a `HandRank`:
+//   category × 1_000_000 + tie-break ranks in base 15
+// Tie-break ranks are ordered by group size then rank (e.g. the trips of a
+// full house before the pair), so comparing two `HandRank`s compares the
+// hands.  A wheel (A-2-3-4-5) scores as a 5-high straight.
+// ---------------------------------------------------------------------------
+
+/// Poker hand categories, weakest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MadeHand {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+impl MadeHand {
+    const ALL: [MadeHand; 9] = [
+        MadeHand::HighCard, MadeHand::OnePair, MadeHand::TwoPair,
+        MadeHand::ThreeOfAKind, MadeHand::Straight, MadeHand::Flush,
+        MadeHand::FullHouse, MadeHand::FourOfAKind, MadeHand::StraightFlush,
+    ];
+
+    /// Lower-case name used in text: `"high card"` … `"straight flush"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            MadeHand::HighCard      => "high card",
+            MadeHand::OnePair       => "one pair",
+            MadeHand::TwoPair       => "two pair",
+            MadeHand::ThreeOfAKind  => "three of a kind",
+            MadeHand::Straight      => "straight",
+            MadeHand::Flush         => "flush",
+            MadeHand::FullHouse     => "full house",
+            MadeHand::FourOfAKind   => "four of a kind",
+            MadeHand::StraightFlush => "straight flush",
+        }
+    }
+}
+
+/// Comparable strength of a 5-card hand — higher is better.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandRank(pub u32);
+
+impl HandRank {
+    const CATEGORY_BASE: u32 = 1_000_000;
+
+    /// The hand category (pair, flush, …).
+    pub fn category(self) -> MadeHand {
+        MadeHand::ALL[(self.0 / Self::CATEGORY_BASE) as usize]
+    }
+}
+
+/// Score a 5-card hand.
+pub fn evaluate_five_cards(cards: [Card; 5]) -> HandRank {
+    let mut counts = [0u8; 15];
+    for c in &cards { counts[c.rank.0 as usize] += 1; }
+
+    // Ranks ordered by (count desc, rank desc).
+    let mut groups: Vec<(u8, u8)> = (2..=14u8)
+        .filter(|&r| counts[r as usize] > 0)
+        .map(|r| (counts[r as usize], r))
+        .collect();
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+
+    let flush = cards.iter().all(|c| c.suit == cards[0].suit);
+    let ranks: Vec<u8> = cards.iter().map(|c| c.rank.0).collect();
+    let straight = if groups.len() == 5 { straight_high(&ranks) } else { None };
+
+    let by_group: Vec<u8> = groups.iter().map(|g| g.1).collect();
+    let (top, second) = (groups[0].0, groups.get(1).map_or(0, |g| g.0));
+
+    let (category, tiebreak) = match (straight, flush, top, second) {
+        (Some(hi), true, _, _)  => (MadeHand::StraightFlush, vec![hi]),
+        (_, _, 4, _)            => (MadeHand::FourOfAKind, by_group),
+        (_, _, 3, 2)            => (MadeHand::FullHouse, by_group),
+        (_, true, _, _)         => (MadeHand::Flush, by_group),
+        (Some(hi), false, _, _) => (MadeHand::Straight, vec![hi]),
+        (_, _, 3, _)            => (MadeHand::ThreeOfAKind, by_group),
+        (_, _, 2, 2)            => (MadeHand::TwoPair, by_group),
+        (_, _, 2, _)            => (MadeHand::OnePair, by_group),
+        _                       => (MadeHand::HighCard, by_group),
+    };
+
+    let mut value = 0u32;
+    for i in 0..5 {
+        value = value * 15 + tiebreak.get(i).copied().unwrap_or(0) as u32;
+    }
+    HandRank(category as u32 * HandRank::CATEGORY_BASE + value)
+}
+
+impl HandRank {
+    /// Tie-break ranks, most significant first (0 = unused slot).
+    fn tiebreak(self) -> [u8; 5] {
+        let mut v = self.0 % Self::CATEGORY_BASE;
+        let mut out = [0u8; 5];
+        for slot in out.iter_mut().rev() {
+            *slot = (v % 15) as u8;
+            v /= 15;
+        }
+        out
+    }
+}
+/// Compare two 5-card hands at showdown: `Greater` when `a` wins, `Equal`
+/// for a split pot.
+pub fn compare_hands(a: [Card; 5], b: [Card; 5]) -> std::cmp::Ordering {
+    evaluate_five_cards(a).cmp(&evaluate_five_cards(b))
+}
+
+/// Rank name for descriptions: "Ace", "Ten", "Two".
+fn rank_name(r: u8) -> &'static str {
+    match r {
+        2 => "Two", 3 => "Three", 4 => "Four", 5 => "Five", 6 => "Six",
+        7 => "Seven", 8 => "Eight", 9 => "Nine", 10 => "Ten",
+        11 => "Jack", 12 => "Queen", 13 => "King", 14 => "Ace",
+        _ => "?",
+    }
+}
+
+/// Plural rank name: "Aces", "Sixes".
+fn rank_plural(r: u8) -> String {
+    match r {
+        6 => "Sixes".to_string(),
+        _ => format!("{}s", rank_name(r)),
+    }
+}
+
+/// Human-readable name of a 5-card hand, e.g.
+/// "Two pair, Kings and Fives with Ace kicker" or "Straight, Five high".
+pub fn hand_description(cards: [Card; 5]) -> String {
+    let rank = evaluate_five_cards(cards);
+    let [a, b, c, ..] = rank.tiebreak();
+    match rank.category() {
+        MadeHand::StraightFlush if a == 14 => "Royal flush".to_string(),
+        MadeHand::StraightFlush => format!("Straight flush, {} high", rank_name(a)),
+        MadeHand::FourOfAKind   => format!("Four of a kind, {} with {} kicker", rank_plural(a), rank_name(b)),
+        MadeHand::FullHouse     => format!("Full house, {} full of {}", rank_plural(a), rank_plural(b)),
+        MadeHand::Flush         => format!("Flush, {} high", rank_name(a)),
+        MadeHand::Straight      => format!("Straight, {} high", rank_name(a)),
+        MadeHand::ThreeOfAKind  => format!("Three of a kind, {} with {} kicker", rank_plural(a), rank_name(b)),
+        MadeHand::TwoPair       => format!("Two pair, {} and {} with {} kicker", rank_plural(a), rank_plural(b), rank_name(c)),
+        MadeHand::OnePair       => format!("Pair of {} with {} kicker", rank_plural(a), rank_name(b)),
+        MadeHand::HighCard      => format!("{} high with {} kicker", rank_name(a), rank_name(b)),
+    }
+}
+
+/// Best 5-card hand out of hero's 2 hole cards and a 5-card board.
+///
+/// Tries all C(7,5) = 21 combinations.
+pub fn best_hand_from_seven(hand: [Card; 2], board: [Card; 5]) -> ([Card; 5], HandRank) {
+    let all = [hand[0], hand[1], board[0], board[1], board[2], board[3], board[4]];
+    let mut best: Option<([Card; 5], HandRank)> = None;
+    // Choose the two cards to leave out.
+    for skip_a in 0..7 {
+        for skip_b in skip_a + 1..7 {
+            let mut five = [all[0]; 5];
+            let mut n = 0;
+            for (i, &c) in all.iter().enumerate() {
+                if i != skip_a && i != skip_b {
+                    five[n] = c;
+                    n += 1;
+                }
+            }
+            let rank = evaluate_five_cards(five);
+            if best.is_none_or(|(_, b)| rank > b) {
+                best = Some((five, rank));
+            }
+        }
+    }
+    best.expect("21 combinations evaluated")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,6 +1695,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn pot_odds_calculation() {
         // 100 pot, 50 call → need 33% equity
         let eq = required_equity(50, 100);
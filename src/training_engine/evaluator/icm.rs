@@ -0,0 +1,87 @@
+//! Independent Chip Model (ICM) equity.
+//!
+//! ICM converts tournament chip counts into shares of the prize pool.  Each
+//! player's chance of finishing first is their share of the chips; the
+//! remaining places are filled recursively among the players left
+//! (Malmuth–Harville).  Chips above the average are therefore worth less than
+//! their face value — the reason the chip leader's equity trails their chip
+//! fraction.
+
+use std::fmt;
+
+/// Payout fractions by finishing place, e.g. `vec![0.50, 0.30, 0.20]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ICMPayoutTable {
+    pub places: Vec<f64>,
+}
+
+/// Error returned by [`icm_equity`] for an unusable payout table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ICMError {
+    /// More paid places than players, or no paid places at all.
+    PayoutMismatch,
+    /// Payout fractions do not add up to 1.0.
+    PayoutsDoNotSumToOne,
+}
+
+impl fmt::Display for ICMError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ICMError::PayoutMismatch       => write!(f, "paid places must be between 1 and the number of players"),
+            ICMError::PayoutsDoNotSumToOne => write!(f, "payout fractions must sum to 1.0"),
+        }
+    }
+}
+
+impl std::error::Error for ICMError {}
+
+/// ICM equity of each player, as a fraction of the prize pool, in the same
+/// order as `chip_counts`.
+///
+/// Players with no chips finish behind everyone else; if every stack is empty
+/// nobody can be ranked and all equities are 0.
+pub fn icm_equity(chip_counts: &[u32], table: &ICMPayoutTable) -> Result<Vec<f64>, ICMError> {
+    let places = &table.places;
+    if places.is_empty() || places.len() > chip_counts.len() {
+        return Err(ICMError::PayoutMismatch);
+    }
+    if (places.iter().sum::<f64>() - 1.0).abs() > 1e-6 {
+        return Err(ICMError::PayoutsDoNotSumToOne);
+    }
+
+    let mut equity = vec![0.0; chip_counts.len()];
+    let mut remaining = vec![true; chip_counts.len()];
+    finish_places(chip_counts, places, 0, 1.0, &mut remaining, &mut equity);
+    Ok(equity)
+}
+
+/// Distributes `places[place..]` among the `remaining` players, given that
+/// the current finishing order has probability `prob`.
+fn finish_places(
+    chips: &[u32],
+    places: &[f64],
+    place: usize,
+    prob: f64,
+    remaining: &mut [bool],
+    equity: &mut [f64],
+) {
+    let total: u64 = chips
+        .iter()
+        .zip(remaining.iter())
+        .filter(|(_, &r)| r)
+        .map(|(&c, _)| c as u64)
+        .sum();
+    if place == places.len() || total == 0 {
+        return;
+    }
+    for i in 0..chips.len() {
+        if !remaining[i] || chips[i] == 0 {
+            continue;
+        }
+        let p = prob * chips[i] as f64 / total as f64;
+        equity[i] += p * places[place];
+        remaining[i] = false;
+        finish_places(chips, places, place + 1, p, remaining, equity);
+        remaining[i] = true;
+    }
+}
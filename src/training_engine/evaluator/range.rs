@@ -0,0 +1,175 @@
+//! Hand-range notation: `"AA,KK,AKs,AQs"`, `"88+"`, `"ATs+"`.
+//!
+//! A [`RangeString`] is parsed from comma-separated tokens and stored as a set
+//! of hand classes (`AA`, `AKs`, `AKo`).  Combos are expanded on demand:
+//! a pair is 6 combos, a suited hand 4, an offsuit hand 12.
+//!
+//! Supported tokens:
+//! - `"AA"` — one pocket pair; `"88+"` — that pair and every higher pair.
+//! - `"AKs"` / `"AKo"` — suited / offsuit only; `"AK"` — both.
+//! - `"ATs+"` — raises the low card up to one below the high card
+//!   (`ATs, AJs, AQs, AKs`).
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::training_engine::models::{Card, Hand, Rank, Suit};
+
+const SUITS: [Suit; 4] = Suit::all();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Shape {
+    Pair,
+    Suited,
+    Offsuit,
+}
+
+/// One starting-hand class, high rank first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct HandClass {
+    hi: u8,
+    lo: u8,
+    shape: Shape,
+}
+
+impl HandClass {
+    fn of(hand: Hand) -> HandClass {
+        let (hi, lo) = (hand.high_card().rank.0, hand.low_card().rank.0);
+        let shape = if hand.is_pair() {
+            Shape::Pair
+        } else if hand.is_suited() {
+            Shape::Suited
+        } else {
+            Shape::Offsuit
+        };
+        HandClass { hi, lo, shape }
+    }
+
+    fn combo_count(self) -> usize {
+        match self.shape {
+            Shape::Pair    => 6,
+            Shape::Suited  => 4,
+            Shape::Offsuit => 12,
+        }
+    }
+
+    fn combos(self) -> Vec<[Card; 2]> {
+        let card = |r, suit| Card { rank: Rank(r), suit };
+        let mut out = Vec::with_capacity(self.combo_count());
+        for (i, &s1) in SUITS.iter().enumerate() {
+            for (j, &s2) in SUITS.iter().enumerate() {
+                let keep = match self.shape {
+                    Shape::Pair    => i < j,
+                    Shape::Suited  => i == j,
+                    Shape::Offsuit => i != j,
+                };
+                if keep {
+                    out.push([card(self.hi, s1), card(self.lo, s2)]);
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Error returned when a range token cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRangeError {
+    /// The input contained no tokens.
+    Empty,
+    /// A comma-separated token is not valid hand notation.
+    InvalidToken(String),
+}
+
+impl fmt::Display for ParseRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRangeError::Empty           => write!(f, "empty range"),
+            ParseRangeError::InvalidToken(t) => write!(f, "invalid range token '{t}'"),
+        }
+    }
+}
+
+impl std::error::Error for ParseRangeError {}
+
+/// A set of starting hands parsed from standard range notation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeString {
+    classes: BTreeSet<HandClass>,
+}
+
+impl RangeString {
+    /// Whether `hand` (in either card order) belongs to the range.
+    pub fn contains(&self, hand: [Card; 2]) -> bool {
+        self.classes.contains(&HandClass::of(hand.into()))
+    }
+
+    /// Number of two-card combinations in the range.
+    pub fn combo_count(&self) -> usize {
+        self.classes.iter().map(|c| c.combo_count()).sum()
+    }
+
+    /// Number of distinct hand classes (`AK` counts as `AKs` + `AKo`).
+    pub fn hand_type_count(&self) -> usize {
+        self.classes.len()
+    }
+
+    /// Every combination in the range, high card first.
+    pub fn to_vec(&self) -> Vec<[Card; 2]> {
+        self.classes.iter().flat_map(|c| c.combos()).collect()
+    }
+}
+
+fn parse_token(token: &str, classes: &mut BTreeSet<HandClass>) -> Option<()> {
+    let chars: Vec<char> = token.chars().collect();
+    let (body, plus) = match chars.split_last() {
+        Some((&'+', rest)) => (rest, true),
+        _ => (&chars[..], false),
+    };
+    let (r1, r2, suffix) = match *body {
+        [a, b]    => (a, b, None),
+        [a, b, s] => (a, b, Some(s.to_ascii_lowercase())),
+        _ => return None,
+    };
+    let (a, b) = (Rank::from_char(r1)?.0, Rank::from_char(r2)?.0);
+    let (hi, lo) = (a.max(b), a.min(b));
+
+    if hi == lo {
+        if suffix.is_some() {
+            return None;
+        }
+        let top = if plus { 14 } else { hi };
+        classes.extend((hi..=top).map(|r| HandClass { hi: r, lo: r, shape: Shape::Pair }));
+        return Some(());
+    }
+
+    let shapes: &[Shape] = match suffix {
+        None      => &[Shape::Suited, Shape::Offsuit],
+        Some('s') => &[Shape::Suited],
+        Some('o') => &[Shape::Offsuit],
+        Some(_)   => return None,
+    };
+    let top = if plus { hi - 1 } else { lo };
+    for l in lo..=top {
+        classes.extend(shapes.iter().map(|&shape| HandClass { hi, lo: l, shape }));
+    }
+    Some(())
+}
+
+/// Parses comma-separated tokens; whitespace around tokens is ignored.
+impl FromStr for RangeString {
+    type Err = ParseRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut classes = BTreeSet::new();
+        for token in s.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            parse_token(token, &mut classes)
+                .ok_or_else(|| ParseRangeError::InvalidToken(token.to_string()))?;
+        }
+        if classes.is_empty() {
+            return Err(ParseRangeError::Empty);
+        }
+        Ok(RangeString { classes })
+    }
+}
@@ -1,66 +1,87 @@
 //! Single entry point for scenario generation.
 //!
-//! `generate_training()` is the only public function in the crate.  It:
+//! `generate_training()` is the main entry point.  It:
 //!
 //! 1. Creates a deterministic or entropy-based RNG from the request seed.
 //! 2. Generates a unique scenario ID (2-letter prefix + 8-hex-digit suffix).
 //! 3. Dispatches to the correct topic generator based on `TrainingTopic`.
+//! 4. Checks the scenario with `TrainingScenario::validate()`; failures and
+//!    generator errors come back as `Err(GenerationError)`.
 //!
 //! The RNG is consumed by `make_scenario_id` first (one `next_u32` call),
 //! then passed into the topic generator.  This ordering is load-bearing —
 //! changing it would break determinism tests.
+//!
+//! `generate_hand_sequence()` follows one hand from preflop to the river,
+//! dealing it from one deck and putting it on top of each street's deck.
+//! `generate_curriculum()` builds fixed, seeded request lists for study plans.
 
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use rand::RngCore;
+use serde::{Deserialize, Serialize};
 
 use crate::training_engine::{
-    models::{TopicSelector, TrainingRequest, TrainingScenario, TrainingTopic},
+    deck::{CardDealer, Deck},
+    models::{
+        DifficultyLevel, GenerationError, Street, TextStyle, TopicSelector, TrainingRequest,
+        TrainingScenario, TrainingTopic,
+    },
     topics,
 };
 
 /// Generate a unique scenario ID: `"{PREFIX}-{8 hex digits}"`.
 ///
-/// Consumes one `next_u32()` call from the RNG.  The 2-letter prefix
-/// identifies the topic (e.g. "PF" for PreflopDecision, "CB" for c-bet).
+/// Consumes one `next_u32()` call from the RNG.  The prefix comes from
+/// [`TrainingTopic::prefix`] (e.g. "PF-" for PreflopDecision, "CB-" for c-bet).
 fn make_scenario_id(topic: TrainingTopic, rng: &mut impl RngCore) -> String {
-    let prefix = match topic {
-        TrainingTopic::PreflopDecision          => "PF",
-        TrainingTopic::PostflopContinuationBet  => "CB",
-        TrainingTopic::PotOddsAndEquity         => "PO",
-        TrainingTopic::BluffSpot                => "BL",
-        TrainingTopic::ICMAndTournamentDecision => "IC",
-        TrainingTopic::TurnBarrelDecision       => "TB",
-        TrainingTopic::CheckRaiseSpot           => "CR",
-        TrainingTopic::SemiBluffDecision        => "SB",
-        TrainingTopic::AntiLimperIsolation      => "AL",
-        TrainingTopic::RiverValueBet            => "RV",
-        TrainingTopic::SqueezePlay              => "SQ",
-        TrainingTopic::BigBlindDefense          => "BD",
-        TrainingTopic::ThreeBetPotCbet          => "3B",
-        TrainingTopic::RiverCallOrFold          => "RF",
-        TrainingTopic::TurnProbeBet             => "PB",
-        TrainingTopic::DelayedCbet              => "DC",
-    };
-    format!("{}-{:08X}", prefix, rng.next_u32())
+    format!("{}{:08X}", topic.prefix(), rng.next_u32())
 }
 
+/// Times a street selector re-rolls before falling back to a filtered pick.
+const MAX_REPICKS: usize = 10;
+
 /// Resolve a [`TopicSelector`] to a concrete [`TrainingTopic`].
 ///
 /// For `TopicSelector::Topic(t)` this is a no-op.  For
-/// `TopicSelector::Street(s)` the RNG picks a random topic from that street.
-fn resolve_topic(selector: TopicSelector, rng: &mut impl Rng) -> TrainingTopic {
+/// `TopicSelector::Street(s)` the RNG picks a random topic from that street,
+/// re-rolling up to [`MAX_REPICKS`] times while the pick is in `excluded`.
+/// If it still lands on an excluded topic, it picks among the street's
+/// allowed topics, or among all allowed topics when the whole street is
+/// excluded.  With nothing excluded only the first roll is made, so seeded
+/// output is unchanged.
+fn resolve_topic(selector: TopicSelector, excluded: &[TrainingTopic], rng: &mut impl Rng) -> TrainingTopic {
     match selector {
         TopicSelector::Topic(t) => t,
         TopicSelector::Street(s) => {
             let topics = s.topics();
-            topics[rng.gen_range(0..topics.len())]
+            let mut pick = topics[rng.gen_range(0..topics.len())];
+            for _ in 0..MAX_REPICKS {
+                if !excluded.contains(&pick) {
+                    return pick;
+                }
+                pick = topics[rng.gen_range(0..topics.len())];
+            }
+            if !excluded.contains(&pick) {
+                return pick;
+            }
+            let allowed = |pool: &[TrainingTopic]| -> Vec<TrainingTopic> {
+                pool.iter().copied().filter(|t| !excluded.contains(t)).collect()
+            };
+            let mut fallback = allowed(topics);
+            if fallback.is_empty() {
+                fallback = allowed(&TrainingTopic::ALL);
+            }
+            if fallback.is_empty() {
+                return pick;
+            }
+            fallback[rng.gen_range(0..fallback.len())]
         }
     }
 }
 
 /// Generate a complete poker training scenario.
 ///
-/// This is the crate's single public entry point.  Pass a [`TrainingRequest`]
+/// This is the crate's main public entry point.  Pass a [`TrainingRequest`]
 /// with a topic (or street), difficulty, optional seed, and text style.
 /// Returns a fully-built [`TrainingScenario`] ready for display.
 ///
@@ -68,62 +89,405 @@ fn resolve_topic(selector: TopicSelector, rng: &mut impl Rng) -> TrainingTopic {
 /// pick a random topic from that street — still fully deterministic when
 /// a seed is provided.
 ///
-/// The 16 topics are dispatched to 4 street-grouped modules:
-/// - `topics::preflop` — T1, T5, T9, T11, T12
-/// - `topics::flop`    — T2, T3, T7, T8, T13
-/// - `topics::turn`    — T6, T15, T16
-/// - `topics::river`   — T4, T10, T14
-pub fn generate_training(request: TrainingRequest) -> TrainingScenario {
+/// The 38 topics are dispatched to 4 street-grouped modules:
+/// - `topics::preflop` — T1, T5, T9, T11, T12, T18, T20, T23, T24, T25, T26, T27, T29, T32, T34
+/// - `topics::flop`    — T2, T3, T7, T8, T13, T17, T19, T22, T28, T30, T35
+/// - `topics::turn`    — T6, T15, T16, T31, T36
+/// - `topics::river`   — T4, T10, T14, T21, T33, T37, T38
+///
+/// # Errors
+/// [`GenerationError`] if a generator fails, or if the result breaks one of
+/// the invariants checked by [`TrainingScenario::validate`].
+pub fn generate_training(request: TrainingRequest) -> Result<TrainingScenario, GenerationError> {
     let mut rng: StdRng = match request.rng_seed {
         Some(seed) => StdRng::seed_from_u64(seed),
         None       => StdRng::from_entropy(),
     };
 
     // Resolve street selector to a concrete topic (consumes RNG for Street mode).
-    let topic = resolve_topic(request.topic, &mut rng);
+    let topic = resolve_topic(request.topic, &request.excluded_topics, &mut rng);
 
     let scenario_id = make_scenario_id(topic, &mut rng);
-    let ts = request.text_style;
+    dispatch(topic, &mut rng, request.difficulty, scenario_id, request.text_style, request.is_bounty)
+}
+
+/// Generate one scenario per request, in order.
+///
+/// # Errors
+/// The first [`GenerationError`], if any request fails.
+pub fn generate_batch(requests: Vec<TrainingRequest>) -> Result<Vec<TrainingScenario>, GenerationError> {
+    requests.into_iter().map(generate_training).collect()
+}
+
+/// [`generate_batch`] spread across rayon's thread pool.
+///
+/// Every request carries its own seed and RNG, so the output is identical to
+/// [`generate_batch`] on the same input, in the same order.
+///
+/// # Errors
+/// A [`GenerationError`] if any request fails.
+#[cfg(feature = "rayon")]
+pub fn generate_batch_par(requests: Vec<TrainingRequest>) -> Result<Vec<TrainingScenario>, GenerationError> {
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+    requests.into_par_iter().map(generate_training).collect()
+}
 
-    match topic {
+/// [`generate_training`] followed by [`TrainingScenario::to_bytes`].
+///
+/// A [`GenerationError`] is reported as a custom `bincode` error carrying its
+/// message.
+#[cfg(feature = "bincode")]
+pub fn generate_training_bytes(request: TrainingRequest) -> Result<Vec<u8>, bincode::Error> {
+    generate_training(request)
+        .map_err(|e| bincode::ErrorKind::Custom(e.to_string()))?
+        .to_bytes()
+}
+
+/// Turn any [`TrainingScenario::validate`] failures into an error.
+fn check_invariants(scenario: TrainingScenario) -> Result<TrainingScenario, GenerationError> {
+    let errors = scenario.validate();
+    if errors.is_empty() {
+        return Ok(scenario);
+    }
+    let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    Err(GenerationError::InvariantViolation(format!(
+        "{}: {}",
+        scenario.scenario_id,
+        messages.join("; ")
+    )))
+}
+
+/// Shuffle a deck, run the generator for `topic` with an already-prepared
+/// RNG and ID, then check the result's invariants.
+fn dispatch(
+    topic: TrainingTopic,
+    rng: &mut StdRng,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    ts: TextStyle,
+    is_bounty: bool,
+) -> Result<TrainingScenario, GenerationError> {
+    let mut deck = Deck::new_shuffled(rng);
+    dispatch_on(topic, rng, &mut deck, difficulty, scenario_id, ts, is_bounty)
+}
+
+/// [`dispatch`] with a deck the caller has already shuffled.
+fn dispatch_on(
+    topic: TrainingTopic,
+    rng: &mut StdRng,
+    dealer: &mut Deck,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    ts: TextStyle,
+    is_bounty: bool,
+) -> Result<TrainingScenario, GenerationError> {
+    let scenario = match topic {
         // Preflop topics
         TrainingTopic::PreflopDecision =>
-            topics::preflop::generate(&mut rng, request.difficulty, scenario_id, ts),
+            topics::preflop::generate(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::ICMAndTournamentDecision if is_bounty =>
+            topics::preflop::generate_icm_bounty(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::ICMAndTournamentDecision =>
-            topics::preflop::generate_icm(&mut rng, request.difficulty, scenario_id, ts),
+            topics::preflop::generate_icm(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::AntiLimperIsolation =>
-            topics::preflop::generate_anti_limper(&mut rng, request.difficulty, scenario_id, ts),
+            topics::preflop::generate_anti_limper(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::SqueezePlay =>
-            topics::preflop::generate_squeeze(&mut rng, request.difficulty, scenario_id, ts),
+            topics::preflop::generate_squeeze(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::BigBlindDefense =>
-            topics::preflop::generate_bb_defense(&mut rng, request.difficulty, scenario_id, ts),
+            topics::preflop::generate_bb_defense(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::SetMining =>
+            topics::preflop::generate_set_mining(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::ThreeBetDefense =>
+            topics::preflop::generate_3bet_defense(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::ChipLeaderPlay =>
+            topics::preflop::generate_chip_leader(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::BlindsVsBlinds =>
+            topics::preflop::generate_blinds_vs_blinds(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::ShortStackStrategy =>
+            topics::preflop::generate_short_stack(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::BlindStealing =>
+            topics::preflop::generate_blind_stealing(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::HeadsUpTournament =>
+            topics::preflop::generate_hu_tournament(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::SuitedConnectors =>
+            topics::preflop::generate_suited_connectors(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::PokerMath =>
+            topics::preflop::generate_poker_math(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::BeginnerHandRankings =>
+            topics::preflop::generate_hand_ranking(rng, dealer, difficulty, scenario_id, ts),
 
         // Flop topics
         TrainingTopic::PostflopContinuationBet =>
-            topics::flop::generate_cbet(&mut rng, request.difficulty, scenario_id, ts),
+            topics::flop::generate_cbet(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::PotOddsAndEquity =>
-            topics::flop::generate_pot_odds(&mut rng, request.difficulty, scenario_id, ts),
+            topics::flop::generate_pot_odds(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::CheckRaiseSpot =>
-            topics::flop::generate_check_raise(&mut rng, request.difficulty, scenario_id, ts),
+            topics::flop::generate_check_raise(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::SemiBluffDecision =>
-            topics::flop::generate_semi_bluff(&mut rng, request.difficulty, scenario_id, ts),
+            topics::flop::generate_semi_bluff(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::ThreeBetPotCbet =>
-            topics::flop::generate_3bet_cbet(&mut rng, request.difficulty, scenario_id, ts),
+            topics::flop::generate_3bet_cbet(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::StackOffDecision =>
+            topics::flop::generate_stack_off(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::ContinuationBetDefense =>
+            topics::flop::generate_cbet_defense(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::ExploitativePlay =>
+            topics::flop::generate_exploitative(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::ProtectionBet =>
+            topics::flop::generate_protection_bet(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::PotControl =>
+            topics::flop::generate_pot_control(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::DrawCounting =>
+            topics::flop::generate_draw_counting(rng, dealer, difficulty, scenario_id, ts),
 
         // Turn topics
         TrainingTopic::TurnBarrelDecision =>
-            topics::turn::generate_barrel(&mut rng, request.difficulty, scenario_id, ts),
+            topics::turn::generate_barrel(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::TurnProbeBet =>
-            topics::turn::generate_probe(&mut rng, request.difficulty, scenario_id, ts),
+            topics::turn::generate_probe(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::DelayedCbet =>
-            topics::turn::generate_delayed_cbet(&mut rng, request.difficulty, scenario_id, ts),
+            topics::turn::generate_delayed_cbet(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::TurnDraw =>
+            topics::turn::generate_turn_draw(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::FoldEquityBluff =>
+            topics::turn::generate_fold_equity_bluff(rng, dealer, difficulty, scenario_id, ts),
 
         // River topics
         TrainingTopic::BluffSpot =>
-            topics::river::generate_bluff(&mut rng, request.difficulty, scenario_id, ts),
+            topics::river::generate_bluff(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::RiverValueBet =>
-            topics::river::generate_value_bet(&mut rng, request.difficulty, scenario_id, ts),
+            topics::river::generate_value_bet(rng, dealer, difficulty, scenario_id, ts),
         TrainingTopic::RiverCallOrFold =>
-            topics::river::generate_call_or_fold(&mut rng, request.difficulty, scenario_id, ts),
+            topics::river::generate_call_or_fold(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::TripleBarrel =>
+            topics::river::generate_triple_barrel(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::HandReading =>
+            topics::river::generate_hand_reading(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::LayDown =>
+            topics::river::generate_lay_down(rng, dealer, difficulty, scenario_id, ts),
+        TrainingTopic::WhenToGiveUp =>
+            topics::river::generate_give_up(rng, dealer, difficulty, scenario_id, ts),
+    }?;
+    check_invariants(scenario)
+}
+
+/// Generate one hand followed across all four streets.
+///
+/// `topics` must hold a Preflop, Flop, Turn and River topic, in that order.
+/// Scenario IDs share an `"HS-{8 hex digits}"` prefix followed by `-S1` …
+/// `-S4`.
+///
+/// One shuffled deck deals the whole hand: the preflop topic deals hero's
+/// hole cards from it (and may pick them with `deal_matching`), then the
+/// 5-card runout comes off the same deck.  Each later street gets its own
+/// deck with the hole cards and that street's board (3 / 4 / 5 cards) put
+/// on top, so a topic that deals straight off the top plays exactly this
+/// hand.
+///
+/// # Errors
+/// [`GenerationError::InvalidTopicConstraint`] if a topic is on the wrong
+/// street for its slot or does not
+/// [support sequences](TrainingTopic::supports_hand_sequence), or any error
+/// from generating a street.
+pub fn generate_hand_sequence(
+    seed: u64,
+    topics: [TrainingTopic; 4],
+    difficulty: DifficultyLevel,
+    style: TextStyle,
+) -> Result<[TrainingScenario; 4], GenerationError> {
+    const STREETS: [Street; 4] = [Street::Preflop, Street::Flop, Street::Turn, Street::River];
+    for (topic, street) in topics.iter().zip(STREETS) {
+        if topic.street() != street {
+            return Err(GenerationError::InvalidTopicConstraint(format!(
+                "{topic} is a {} topic, but this slot needs a {street} topic",
+                topic.street()
+            )));
+        }
+        if !topic.supports_hand_sequence() {
+            return Err(GenerationError::InvalidTopicConstraint(format!(
+                "{topic} picks its own cards and cannot follow a hand across streets"
+            )));
+        }
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let prefix = format!("HS-{:08X}", rng.next_u32());
+
+    let mut deck = Deck::new_shuffled(&mut rng);
+    let id = format!("{prefix}-S1");
+    let preflop = dispatch_on(topics[0], &mut rng, &mut deck, difficulty, id, style, false)?;
+    let mut hand = preflop.table_setup.hero_hand.to_vec();
+    hand.extend(deck.deal_community_cards(Street::River)?);
+
+    let mut scenarios: Vec<TrainingScenario> = Vec::with_capacity(4);
+    scenarios.push(preflop);
+    for (step, (topic, street)) in topics.into_iter().zip(STREETS).enumerate().skip(1) {
+        let mut dealer = Deck::new_shuffled(&mut rng);
+        dealer.put_on_top(&hand[..2 + street.board_card_count()]);
+        let id = format!("{prefix}-S{}", step + 1);
+        scenarios.push(dispatch_on(topic, &mut rng, &mut dealer, difficulty, id, style, false)?);
+    }
+    Ok(scenarios.try_into().expect("one scenario per street"))
+}
+
+/// Properties a scenario must have for [`generate_until`].  `None` fields
+/// are not checked; [`ScenarioFilter::default`] accepts everything.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScenarioFilter {
+    pub topic: Option<TrainingTopic>,
+    /// Inclusive lower bound on [`TableSetup::spr`](crate::training_engine::TableSetup::spr).
+    pub min_spr: Option<f32>,
+    /// Inclusive upper bound on the SPR.
+    pub max_spr: Option<f32>,
+    pub required_street: Option<Street>,
+    pub branch_key_contains: Option<String>,
+}
+
+impl TrainingScenario {
+    /// Whether the scenario passes every check set in `f`.
+    pub fn matches_filter(&self, f: &ScenarioFilter) -> bool {
+        let spr = self.table_setup.spr();
+        f.topic.is_none_or(|t| self.topic == t)
+            && f.min_spr.is_none_or(|min| spr >= min)
+            && f.max_spr.is_none_or(|max| spr <= max)
+            && f.required_street.is_none_or(|s| self.street() == s)
+            && f.branch_key_contains.as_deref().is_none_or(|k| self.branch_key.contains(k))
     }
 }
+
+/// Generate scenarios until one matches `filter`, trying at most
+/// `max_attempts` seeds.
+///
+/// Attempts use the request's seed, then seed + 1, seed + 2, …  (a request
+/// without a seed starts from a random one).  Attempts that fail to generate
+/// count as misses.  Returns `None` if no attempt matched.
+pub fn generate_until(
+    request: TrainingRequest,
+    filter: &ScenarioFilter,
+    max_attempts: u32,
+) -> Option<TrainingScenario> {
+    let start = request.rng_seed.unwrap_or_else(|| StdRng::from_entropy().next_u64());
+    (0..max_attempts as u64)
+        .filter_map(|n| {
+            generate_training(TrainingRequest {
+                rng_seed: Some(start.wrapping_add(n)),
+                ..request.clone()
+            })
+            .ok()
+        })
+        .find(|s| s.matches_filter(filter))
+}
+
+/// A stage of the built-in study plan returned by [`generate_curriculum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CurriculumLevel {
+    /// Opening ranges and pot odds only.
+    Novice,
+    /// Core preflop and single-street postflop decisions.
+    Beginner,
+    /// Adds multi-street lines, check-raises and river calls.
+    Intermediate,
+    /// Every topic, at Advanced difficulty.
+    Advanced,
+}
+
+/// All 38 topics in teaching order: preflop first, then one street at a time,
+/// each street starting with its most fundamental spot.
+const PEDAGOGICAL_ORDER: [TrainingTopic; 38] = [
+    TrainingTopic::BeginnerHandRankings,
+    TrainingTopic::PreflopDecision,
+    TrainingTopic::PokerMath,
+    TrainingTopic::BlindStealing,
+    TrainingTopic::BigBlindDefense,
+    TrainingTopic::BlindsVsBlinds,
+    TrainingTopic::SetMining,
+    TrainingTopic::SuitedConnectors,
+    TrainingTopic::AntiLimperIsolation,
+    TrainingTopic::SqueezePlay,
+    TrainingTopic::ThreeBetDefense,
+    TrainingTopic::ICMAndTournamentDecision,
+    TrainingTopic::ChipLeaderPlay,
+    TrainingTopic::ShortStackStrategy,
+    TrainingTopic::HeadsUpTournament,
+    TrainingTopic::DrawCounting,
+    TrainingTopic::PotOddsAndEquity,
+    TrainingTopic::PostflopContinuationBet,
+    TrainingTopic::ProtectionBet,
+    TrainingTopic::ContinuationBetDefense,
+    TrainingTopic::ExploitativePlay,
+    TrainingTopic::SemiBluffDecision,
+    TrainingTopic::CheckRaiseSpot,
+    TrainingTopic::ThreeBetPotCbet,
+    TrainingTopic::StackOffDecision,
+    TrainingTopic::PotControl,
+    TrainingTopic::TurnBarrelDecision,
+    TrainingTopic::TurnDraw,
+    TrainingTopic::FoldEquityBluff,
+    TrainingTopic::DelayedCbet,
+    TrainingTopic::TurnProbeBet,
+    TrainingTopic::RiverValueBet,
+    TrainingTopic::RiverCallOrFold,
+    TrainingTopic::HandReading,
+    TrainingTopic::LayDown,
+    TrainingTopic::WhenToGiveUp,
+    TrainingTopic::BluffSpot,
+    TrainingTopic::TripleBarrel,
+];
+
+/// A pre-ordered list of requests for one curriculum level.
+///
+/// | Level | Requests | Difficulty |
+/// |-------|----------|------------|
+/// | `Novice` | 5 × T1 Preflop Decision, 3 × T3 Pot Odds | Beginner |
+/// | `Beginner` | T1, T12, T9, T3, T2, T6, T10, T4 | Beginner |
+/// | `Intermediate` | the teaching order without T5, T13 and T15 (35 topics) | Intermediate |
+/// | `Advanced` | all 38 topics in teaching order | Advanced |
+///
+/// Requests use Simple text, except `Advanced` which uses Technical.  The
+/// n-th request of a level is seeded with `base + n`, where the base is
+/// 1000 × (level index + 1), so a curriculum is the same on every call.
+pub fn generate_curriculum(level: CurriculumLevel) -> Vec<TrainingRequest> {
+    use TrainingTopic::*;
+    let (topics, difficulty, text_style): (Vec<TrainingTopic>, _, _) = match level {
+        CurriculumLevel::Novice => (
+            [[PreflopDecision; 5].as_slice(), &[PotOddsAndEquity; 3]].concat(),
+            DifficultyLevel::Beginner,
+            TextStyle::Simple,
+        ),
+        CurriculumLevel::Beginner => (
+            vec![
+                PreflopDecision, BigBlindDefense, AntiLimperIsolation, PotOddsAndEquity,
+                PostflopContinuationBet, TurnBarrelDecision, RiverValueBet, BluffSpot,
+            ],
+            DifficultyLevel::Beginner,
+            TextStyle::Simple,
+        ),
+        CurriculumLevel::Intermediate => (
+            PEDAGOGICAL_ORDER
+                .into_iter()
+                .filter(|t| !matches!(t, ICMAndTournamentDecision | ThreeBetPotCbet | TurnProbeBet))
+                .collect(),
+            DifficultyLevel::Intermediate,
+            TextStyle::Simple,
+        ),
+        CurriculumLevel::Advanced => (
+            PEDAGOGICAL_ORDER.to_vec(),
+            DifficultyLevel::Advanced,
+            TextStyle::Technical,
+        ),
+    };
+
+    let base = 1000 * (level as u64 + 1);
+    topics
+        .into_iter()
+        .enumerate()
+        .map(|(n, topic)| TrainingRequest {
+            topic: topic.into(),
+            difficulty,
+            rng_seed: Some(base + n as u64),
+            text_style,
+            is_bounty: false,
+            excluded_topics: Vec::new(),
+        })
+        .collect()
+}
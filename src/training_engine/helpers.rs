@@ -6,27 +6,97 @@
 //!
 //! ## RNG ordering
 //!
-//! `deal()` shuffles the deck and draws hero + board in a fixed order.  This is
-//! correct for most topics, but topics with pre-deck RNG calls (T1 preflop,
-//! T5 ICM, T6 turn barrel) use `Deck::new_shuffled()` directly to preserve
-//! their specific RNG call sequence — changing the order would break
-//! determinism tests.
+//! The generator shuffles one deck per scenario before calling a topic, so
+//! the shuffle is always the first RNG draw.  `deal()` draws hero + board from
+//! that dealer in a fixed order; preflop topics and those that deal in several
+//! steps (T6 turn barrel) call the dealer directly.  Changing the order would
+//! break determinism tests and `generate_hand_sequence()`.
 
 use rand::Rng;
 use crate::training_engine::{
-    deck::Deck,
+    deck::CardDealer,
+    evaluator::{classify_draw_combined, DrawType},
     models::*,
 };
 
-/// Deal hero hand (2 cards) + board cards from a freshly shuffled deck.
+/// Deal hero hand (2 cards) + board cards from `dealer`.
 ///
 /// This is the standard deal sequence used by most topics.  Returns
 /// `(hero_hand, board)` where both are guaranteed disjoint and unique.
-pub fn deal<R: Rng>(rng: &mut R, board_cards: usize) -> ([Card; 2], Vec<Card>) {
-    let mut deck = Deck::new_shuffled(rng);
-    let hand = [deck.deal(), deck.deal()];
-    let board = deck.deal_n(board_cards);
-    (hand, board)
+pub fn deal<D: CardDealer>(
+    dealer: &mut D,
+    board_cards: usize,
+) -> Result<([Card; 2], Vec<Card>), GenerationError> {
+    let hand = [dealer.deal()?, dealer.deal()?];
+    let board = dealer.deal_n(board_cards)?;
+    Ok((hand, board))
+}
+
+/// Hero's hole cards and the two board cards that make `draw`, dealt from
+/// `dealer`.  The ranks are planned with the RNG until
+/// `classify_draw_combined` reports exactly that draw; each card is then the
+/// next one in the deck with its rank (and, for flush draws, the first
+/// card's suit).  Call it before anything else is dealt, so every planned
+/// card is still in the deck.
+pub fn deal_draw<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    draw: DrawType,
+) -> Result<([Card; 2], [Card; 2]), GenerationError> {
+    let expected: &[DrawType] = match draw {
+        DrawType::ComboDraw => &[DrawType::OESD, DrawType::FlushDraw],
+        _ => std::slice::from_ref(&draw),
+    };
+    let suited = matches!(draw, DrawType::FlushDraw | DrawType::ComboDraw);
+    let ranks = loop {
+        let mut ranks: Vec<u8> = match draw {
+            DrawType::OESD | DrawType::ComboDraw => {
+                let low = rng.gen_range(3..=10u8);
+                (low..low + 4).collect()
+            }
+            DrawType::GutShot => {
+                let low = rng.gen_range(2..=10u8);
+                let gap = low + rng.gen_range(1..=3);
+                (low..low + 5).filter(|&r| r != gap).collect()
+            }
+            DrawType::FlushDraw => {
+                let mut r: Vec<u8> = Vec::new();
+                while r.len() < 4 {
+                    let x = rng.gen_range(2..=14u8);
+                    if !r.contains(&x) { r.push(x); }
+                }
+                r
+            }
+        };
+        // Any two of the four ranks go to hero.
+        for i in (1..ranks.len()).rev() {
+            ranks.swap(i, rng.gen_range(0..=i));
+        }
+        // Straight draws depend only on the ranks, so any one suit (suited
+        // draws) or four different suits stand in for the dealt cards.
+        let plan: Vec<Card> = ranks
+            .iter()
+            .zip(Suit::all())
+            .map(|(&r, s)| Card { rank: Rank(r), suit: if suited { Suit::Clubs } else { s } })
+            .collect();
+        if classify_draw_combined([plan[0], plan[1]], &plan[2..]) == expected {
+            break ranks;
+        }
+    };
+
+    let mut cards: Vec<Card> = Vec::with_capacity(4);
+    for r in ranks {
+        let card = dealer.deal_matching(|c| {
+            c.rank.0 == r
+                && match cards.first() {
+                    Some(first) if suited => c.suit == first.suit,
+                    // Four cards of one suit would add a flush draw.
+                    _ => cards.len() < 3 || cards.iter().any(|d| d.suit != c.suit),
+                }
+        })?;
+        cards.push(card);
+    }
+    Ok(([cards[0], cards[1]], [cards[2], cards[3]]))
 }
 
 /// Format hero hand as string (e.g. "AcKs").
@@ -52,35 +122,85 @@ pub fn styled(ts: TextStyle, simple: String, technical: String) -> String {
 
 /// Build one answer option.
 ///
-/// `is_correct` is set automatically by comparing `id == correct`.
-/// The explanation is chosen by `TextStyle` via `styled()`.
+/// `is_correct` is set automatically by comparing `id == correct`; the rating
+/// is `Correct` in that case and `wrong` otherwise.
+/// The explanation is chosen by `TextStyle` via `styled()`; no hint is set.
 pub fn answer(
-    id: &str, text: impl Into<String>, correct: &str,
+    id: &str, text: impl Into<String>, correct: &str, wrong: AnswerRating,
     ts: TextStyle, simple: String, tech: String,
 ) -> AnswerOption {
     AnswerOption {
         id: id.to_string(),
         text: text.into(),
         is_correct: id == correct,
+        rating: if id == correct { AnswerRating::Correct } else { wrong },
+        hint: None,
         explanation: styled(ts, simple, tech),
     }
 }
 
+/// Grade option `id` on a ladder of increasingly aggressive actions
+/// (A = most passive).  One step from the correct option is `Plausible`,
+/// two steps a `Mistake`, further a `Blunder`.
+pub fn ladder_rating(id: &str, correct: &str) -> AnswerRating {
+    let step = |s: &str| s.bytes().next().unwrap_or(b'A');
+    match step(id).abs_diff(step(correct)) {
+        0 => AnswerRating::Correct,
+        1 => AnswerRating::Plausible,
+        2 => AnswerRating::Mistake,
+        _ => AnswerRating::Blunder,
+    }
+}
+
+/// Grade option `id` of a Fold (A) / Call (B) / Raise (C) question.
+///
+/// A wrong fold is rated `fold`; swapping call and raise is `Plausible`;
+/// continuing when folding is correct is a `Mistake`.
+pub fn fold_call_raise_rating(id: &str, correct: &str, fold: AnswerRating) -> AnswerRating {
+    match (correct, id) {
+        _ if id == correct => AnswerRating::Correct,
+        (_, "A") => fold,
+        ("B", "C") | ("C", "B") => AnswerRating::Plausible,
+        _ => AnswerRating::Mistake,
+    }
+}
+
 /// Build a standard 2-player heads-up setup.
 pub fn heads_up(
     hero_pos: Position, villain_pos: Position,
     hero_stack: u32, villain_stack: u32,
 ) -> Vec<PlayerState> {
     vec![
-        PlayerState { seat: 1, position: villain_pos, stack: villain_stack, is_hero: false, is_active: true },
-        PlayerState { seat: 2, position: hero_pos, stack: hero_stack, is_hero: true, is_active: true },
+        PlayerState { seat: 1, position: villain_pos, stack: villain_stack, is_hero: false, is_active: true, bounty: None },
+        PlayerState { seat: 2, position: hero_pos, stack: hero_stack, is_hero: true, is_active: true, bounty: None },
     ]
 }
 
+/// Pot after a `bet` goes in on top of `pot` — a bet, or a call of one.
+pub fn pot_after_call(pot: u32, bet: u32) -> u32 {
+    pot + bet
+}
+
+/// Pot after a raise to `raise_to` over a `previous_bet` already in `pot`.
+///
+/// The raiser puts in the full raise-to amount, so the result is
+/// `pot + raise_to`; `previous_bet` only bounds the raise from below.
+pub fn pot_after_raise(pot: u32, previous_bet: u32, raise_to: u32) -> u32 {
+    debug_assert!(raise_to >= previous_bet, "raise to {raise_to} is below the {previous_bet} bet");
+    pot + raise_to
+}
+
+/// Smallest stack among active players (0 if none are active or one is
+/// all-in).
+pub fn effective_stack(players: &[PlayerState]) -> u32 {
+    players.iter().filter(|p| p.is_active).map(|p| p.stack).min().unwrap_or(0)
+}
+
 /// Assemble the final [`TrainingScenario`] from all its parts.
 ///
 /// This is the last call in every topic generator — it bundles hero hand,
 /// board, players, pot, question, and answers into the output struct.
+#[allow(clippy::too_many_arguments)]
 pub fn scenario(
     id: String, topic: TrainingTopic, branch_key: impl Into<String>,
     game_type: GameType, hero_pos: Position, hero_hand: [Card; 2],
@@ -99,8 +219,23 @@ pub fn scenario(
             players,
             pot_size: pot,
             current_bet: bet,
+            antes: None,
         },
         question,
         answers,
+        key_concepts: key_concepts(topic, &[]),
+        villain_tendency: None,
+    }
+}
+
+/// The topic's [`TrainingTopic::key_concepts`] followed by any `extra`
+/// branch-specific concepts it does not already list.
+pub fn key_concepts(topic: TrainingTopic, extra: &[&str]) -> Vec<String> {
+    let mut concepts: Vec<String> = topic.key_concepts().iter().map(|c| c.to_string()).collect();
+    for &c in extra {
+        if !concepts.iter().any(|k| k == c) {
+            concepts.push(c.to_string());
+        }
     }
+    concepts
 }
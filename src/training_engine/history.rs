@@ -0,0 +1,87 @@
+//! Hand history — a record of scenarios and the answers given to them.
+//!
+//! A [`HandHistory`] collects one [`HandHistoryEntry`] per drill, so a session
+//! can be saved as JSON, reloaded later, and reviewed for the spots the player
+//! got wrong.
+
+use serde::{Deserialize, Serialize};
+
+use super::models::TrainingScenario;
+
+/// One drilled scenario and the option chosen for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandHistoryEntry {
+    pub scenario: TrainingScenario,
+    /// ID of the chosen option; `None` if the scenario was skipped.
+    pub answer_id: Option<String>,
+    /// Seconds since the Unix epoch when the entry was recorded; `None` where
+    /// no clock is available (wasm32-unknown-unknown).
+    pub timestamp_secs: Option<u64>,
+}
+
+impl HandHistoryEntry {
+    /// True when an answer was given and it is the correct option.
+    pub fn is_correct(&self) -> bool {
+        self.answer_id
+            .as_deref()
+            .is_some_and(|id| self.scenario.is_answered_correctly(id))
+    }
+}
+
+/// An ordered sequence of drilled scenarios.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandHistory {
+    pub entries: Vec<HandHistoryEntry>,
+}
+
+impl HandHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `scenario` with the chosen option, stamped with the current time.
+    pub fn record(&mut self, scenario: TrainingScenario, answer_id: Option<&str>) {
+        self.entries.push(HandHistoryEntry {
+            scenario,
+            answer_id: answer_id.map(str::to_string),
+            timestamp_secs: now_secs(),
+        });
+    }
+
+    /// Number of entries answered correctly.
+    pub fn correct_count(&self) -> usize {
+        self.entries.iter().filter(|e| e.is_correct()).count()
+    }
+
+    /// Entries answered with a wrong option (skipped entries are excluded).
+    pub fn incorrect_entries(&self) -> Vec<&HandHistoryEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.answer_id.is_some() && !e.is_correct())
+            .collect()
+    }
+
+    /// Serializes the whole history as a JSON string.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("hand history is always serializable")
+    }
+
+    /// Parses a history previously produced by [`HandHistory::to_json`].
+    pub fn from_json(s: &str) -> Result<HandHistory, serde_json::Error> {
+        serde_json::from_str(s)
+    }
+}
+
+/// Seconds since the Unix epoch.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+fn now_secs() -> Option<u64> {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now().duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+/// `SystemTime::now()` panics on wasm32-unknown-unknown, so entries recorded
+/// there carry no timestamp.
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+fn now_secs() -> Option<u64> {
+    None
+}
@@ -8,21 +8,39 @@
 //! | `deck`      | 52-card deck with Fisher-Yates shuffle and deterministic dealing |
 //! | `evaluator` | Board texture, draw classification, pot-odds math, hand strength |
 //! | `helpers`   | Shared builder functions that eliminate boilerplate across topics |
-//! | `generator` | Single entry point `generate_training()` — dispatches to topics |
-//! | `topics`    | 16 topic generators grouped by street (preflop, flop, turn, river) |
+//! | `generator` | Entry points `generate_training()` and `generate_hand_sequence()` — dispatch to topics |
+//! | `history`   | `HandHistory`: recorded scenarios and answers, saved as JSON |
+//! | `selector`  | `BalancedSelector`: round-robin requests over every topic |
+//! | `session`   | `AdaptiveSession`: per-topic difficulty that follows the player's streaks |
+//! | `topics`    | 38 topic generators grouped by street (preflop, flop, turn, river) |
 
 pub mod deck;
 pub mod evaluator;
 pub mod generator;
 pub mod helpers;
+pub mod history;
 pub mod models;
+pub mod selector;
+pub mod session;
 pub mod topics;
 
 // Re-export the public API surface so callers can use
 // `training_engine::generate_training` without reaching into sub-modules.
-pub use generator::generate_training;
+pub use generator::{
+    generate_batch, generate_curriculum, generate_hand_sequence, generate_training, generate_until,
+    CurriculumLevel, ScenarioFilter,
+};
+#[cfg(feature = "rayon")]
+pub use generator::generate_batch_par;
+#[cfg(feature = "bincode")]
+pub use generator::generate_training_bytes;
+pub use history::{HandHistory, HandHistoryEntry};
 pub use models::{
-    AnswerOption, DifficultyLevel, GameType, PlayerState,
+    anki_deck_from_scenarios, topics_for_concept, ActionSequence, AnswerOption, AnswerRating,
+    BetSize, DifficultyLevel, GameType, GenerationError, PlayerState, ParseRequestError,
     Position, Street, TableSetup, TextStyle, TopicSelector, TrainingRequest,
-    TrainingScenario, TrainingTopic,
+    TrainingRequestBuilder, TrainingScenario, TrainingTopic, ValidationError, VillainAction,
+    VillainTendency,
 };
+pub use selector::BalancedSelector;
+pub use session::AdaptiveSession;
@@ -8,6 +8,7 @@
 //! the wire as JSON without any conversion layer.
 
 use std::fmt;
+use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -37,11 +38,74 @@ impl fmt::Display for Suit {
     }
 }
 
+/// Red (diamonds, hearts) or black (clubs, spades), for two- and
+/// four-colour card displays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SuitColor {
+    Red,
+    Black,
+}
+
+impl Suit {
+    /// All four suits in canonical order: clubs, diamonds, hearts, spades.
+    pub const fn all() -> [Suit; 4] {
+        [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]
+    }
+
+    pub fn color(self) -> SuitColor {
+        match self {
+            Suit::Diamonds | Suit::Hearts => SuitColor::Red,
+            Suit::Clubs | Suit::Spades => SuitColor::Black,
+        }
+    }
+
+    /// Suit symbol for rich-text display: `♣`, `♦`, `♥`, `♠`.
+    pub fn unicode_symbol(self) -> &'static str {
+        match self {
+            Suit::Clubs => "♣",
+            Suit::Diamonds => "♦",
+            Suit::Hearts => "♥",
+            Suit::Spades => "♠",
+        }
+    }
+
+    /// Plural lower-case name for text: `"clubs"`, `"diamonds"`, `"hearts"`, `"spades"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Suit::Clubs => "clubs",
+            Suit::Diamonds => "diamonds",
+            Suit::Hearts => "hearts",
+            Suit::Spades => "spades",
+        }
+    }
+
+    /// Parse a suit letter (`c`, `d`, `h`, `s`), case-insensitive.
+    pub fn from_char(c: char) -> Option<Suit> {
+        match c.to_ascii_lowercase() {
+            'c' => Some(Suit::Clubs),
+            'd' => Some(Suit::Diamonds),
+            'h' => Some(Suit::Hearts),
+            's' => Some(Suit::Spades),
+            _ => None,
+        }
+    }
+}
+
 /// Rank 2..=14 where 14 = Ace.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Rank(pub u8);
 
 impl Rank {
+    /// Parse a rank symbol (`2`–`9`, `T`, `J`, `Q`, `K`, `A`), case-insensitive.
+    pub fn from_char(c: char) -> Option<Rank> {
+        let r = match c.to_ascii_uppercase() {
+            d @ '2'..='9' => d as u8 - b'0',
+            'T' => 10, 'J' => 11, 'Q' => 12, 'K' => 13, 'A' => 14,
+            _ => return None,
+        };
+        Some(Rank(r))
+    }
+
     pub fn symbol(self) -> &'static str {
         match self.0 {
             2 => "2", 3 => "3", 4 => "4", 5 => "5", 6 => "6",
@@ -50,6 +114,11 @@ impl Rank {
             _ => "?",
         }
     }
+
+    /// Ten or higher — an honour card.  Same test as [`Card::is_broadway`].
+    pub fn is_honor(self) -> bool {
+        self.0 >= 10
+    }
 }
 
 impl fmt::Display for Rank {
@@ -64,12 +133,112 @@ pub struct Card {
     pub suit: Suit,
 }
 
+impl Card {
+    /// Broadway card: T, J, Q, K or A.
+    pub fn is_broadway(self) -> bool {
+        self.rank.is_honor()
+    }
+
+    /// Card that can make a wheel (A-2-3-4-5): an Ace or a 2–5.
+    pub fn is_wheel_card(self) -> bool {
+        self.rank.0 <= 5 || self.rank.0 == 14
+    }
+}
+
 impl fmt::Display for Card {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}{}", self.rank, self.suit)
     }
 }
 
+/// Hero's two hole cards, with poker-specific helpers.
+///
+/// Converts to and from `[Card; 2]`, so generators can keep storing plain
+/// arrays in [`TableSetup::hero_hand`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Hand([Card; 2]);
+
+impl Hand {
+    /// Both cards share a suit.
+    pub fn is_suited(self) -> bool {
+        self.0[0].suit == self.0[1].suit
+    }
+
+    /// Both cards share a rank (pocket pair).
+    pub fn is_pair(self) -> bool {
+        self.0[0].rank == self.0[1].rank
+    }
+
+    /// The higher-ranked card (the first card for a pair).
+    pub fn high_card(self) -> Card {
+        if self.0[0].rank >= self.0[1].rank { self.0[0] } else { self.0[1] }
+    }
+
+    /// The lower-ranked card (the second card for a pair).
+    pub fn low_card(self) -> Card {
+        if self.0[0].rank >= self.0[1].rank { self.0[1] } else { self.0[0] }
+    }
+
+    /// Canonical starting-hand notation: `"AKs"`, `"QJo"`, `"77"`.
+    ///
+    /// Ranks are written high card first regardless of input order.
+    pub fn notation(self) -> String {
+        let (hi, lo) = (self.high_card().rank, self.low_card().rank);
+        let kind = if self.is_pair() { "" } else if self.is_suited() { "s" } else { "o" };
+        format!("{hi}{lo}{kind}")
+    }
+}
+
+impl From<[Card; 2]> for Hand {
+    fn from(cards: [Card; 2]) -> Self {
+        Hand(cards)
+    }
+}
+
+impl From<Hand> for [Card; 2] {
+    fn from(hand: Hand) -> Self {
+        hand.0
+    }
+}
+
+/// Error returned when parsing a [`Card`] from a string such as `"Ah"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseCardError {
+    /// Input was not exactly two characters (rank + suit).
+    InvalidLength(usize),
+    /// The first character is not a rank symbol.
+    InvalidRank(char),
+    /// The second character is not a suit letter.
+    InvalidSuit(char),
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCardError::InvalidLength(n) => write!(f, "expected 2 characters, got {n}"),
+            ParseCardError::InvalidRank(c)   => write!(f, "invalid rank '{c}'"),
+            ParseCardError::InvalidSuit(c)   => write!(f, "invalid suit '{c}'"),
+        }
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+/// Parses standard notation: rank then suit, e.g. `"Ah"`, `"Tc"`, `"2D"`.
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        let [r, su] = chars[..] else {
+            return Err(ParseCardError::InvalidLength(chars.len()));
+        };
+        let rank = Rank::from_char(r).ok_or(ParseCardError::InvalidRank(r))?;
+        let suit = Suit::from_char(su).ok_or(ParseCardError::InvalidSuit(su))?;
+        Ok(Card { rank, suit })
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Table / game metadata
 //
@@ -83,6 +252,10 @@ impl fmt::Display for Card {
 pub enum GameType {
     CashGame,
     Tournament,
+    /// Single-table sit & go: fixed 9-player field, top 3 paid.
+    SitAndGo,
+    /// 3-player hyper-turbo with a randomised prize pool.
+    SpinAndGo,
 }
 
 impl fmt::Display for GameType {
@@ -90,6 +263,8 @@ impl fmt::Display for GameType {
         match self {
             GameType::CashGame => write!(f, "Cash Game"),
             GameType::Tournament => write!(f, "Tournament"),
+            GameType::SitAndGo => write!(f, "Sit & Go"),
+            GameType::SpinAndGo => write!(f, "Spin & Go"),
         }
     }
 }
@@ -97,6 +272,9 @@ impl fmt::Display for GameType {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Position {
     UTG,  // Under the Gun
+    UTG1, // Under the Gun +1 (9-max)
+    UTG2, // Under the Gun +2 (9-max)
+    LJ,   // Lojack (9-max)
     HJ,   // Hijack
     CO,   // Cutoff
     BTN,  // Button
@@ -108,6 +286,9 @@ impl fmt::Display for Position {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
             Position::UTG  => "UTG",
+            Position::UTG1 => "UTG+1",
+            Position::UTG2 => "UTG+2",
+            Position::LJ   => "Lojack",
             Position::HJ   => "Hijack",
             Position::CO   => "Cutoff",
             Position::BTN  => "Button",
@@ -118,26 +299,88 @@ impl fmt::Display for Position {
     }
 }
 
+/// Every seat in preflop action order (UTG first, BB last).
+const POSITIONS_9MAX: [Position; 9] = [
+    Position::UTG, Position::UTG1, Position::UTG2, Position::LJ, Position::HJ,
+    Position::CO, Position::BTN, Position::SB, Position::BB,
+];
+
+/// The six seats of a 6-max table, in preflop action order.
+const POSITIONS_6MAX: [Position; 6] = [
+    Position::UTG, Position::HJ, Position::CO, Position::BTN, Position::SB, Position::BB,
+];
+
 impl Position {
+    /// All 9 positions in preflop action order.
+    pub fn all() -> &'static [Position] {
+        &POSITIONS_9MAX
+    }
+
+    /// Seats used at a 6-max or 9-max table, in preflop action order.
+    ///
+    /// # Panics
+    /// If `size` is not 6 or 9.
+    pub fn positions_for_table_size(size: usize) -> &'static [Position] {
+        match size {
+            6 => &POSITIONS_6MAX,
+            9 => &POSITIONS_9MAX,
+            n => panic!("unsupported table size {n}: expected 6 or 9"),
+        }
+    }
+
     /// Is this position considered "in position" (acts last postflop)?
     pub fn is_late(self) -> bool {
         matches!(self, Position::CO | Position::BTN)
     }
+
+    /// UTG, UTG+1 or UTG+2.
+    pub fn is_early(self) -> bool {
+        matches!(self, Position::UTG | Position::UTG1 | Position::UTG2)
+    }
+
+    /// Lojack or Hijack.
+    pub fn is_middle(self) -> bool {
+        matches!(self, Position::LJ | Position::HJ)
+    }
+
+    /// Small or big blind.
+    pub fn is_blind(self) -> bool {
+        matches!(self, Position::SB | Position::BB)
+    }
+
+    /// Acts after `other` on every postflop street.
+    ///
+    /// Postflop the blinds act first (SB, then BB) and the Button last.
+    pub fn is_in_position_vs(self, other: Position) -> bool {
+        self.postflop_order() > other.postflop_order()
+    }
+
+    /// 0 = first to act postflop (SB) … 8 = last (BTN).
+    fn postflop_order(self) -> usize {
+        match self {
+            Position::SB => 0,
+            Position::BB => 1,
+            p => POSITIONS_9MAX.iter().position(|&q| q == p).expect("every position is listed") + 2,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PlayerState {
     pub seat: u8,
     pub position: Position,
     pub stack: u32,
     pub is_hero: bool,
     pub is_active: bool,
+    /// Bounty on this player's head in a knockout tournament; `None` otherwise.
+    #[serde(default)]
+    pub bounty: Option<u32>,
 }
 
 // ---------------------------------------------------------------------------
 // Training request / response types
 //
-// TrainingTopic — the 16 poker skills the engine can drill.
+// TrainingTopic — the 38 poker skills the engine can drill.
 // DifficultyLevel — controls stack depth ranges and bet-size variance.
 // TextStyle — Simple (beginner-friendly) vs Technical (poker jargon).
 // TrainingRequest — input to `generate_training()`.
@@ -161,6 +404,36 @@ pub enum Street {
 }
 
 impl Street {
+    /// Number of community cards on the board at this street: 0 / 3 / 4 / 5.
+    pub fn board_card_count(self) -> usize {
+        match self {
+            Street::Preflop => 0,
+            Street::Flop    => 3,
+            Street::Turn    => 4,
+            Street::River   => 5,
+        }
+    }
+
+    /// The following street, or `None` after the river.
+    pub fn next(self) -> Option<Street> {
+        match self {
+            Street::Preflop => Some(Street::Flop),
+            Street::Flop    => Some(Street::Turn),
+            Street::Turn    => Some(Street::River),
+            Street::River   => None,
+        }
+    }
+
+    /// The preceding street, or `None` before the flop.
+    pub fn prev(self) -> Option<Street> {
+        match self {
+            Street::Preflop => None,
+            Street::Flop    => Some(Street::Preflop),
+            Street::Turn    => Some(Street::Flop),
+            Street::River   => Some(Street::Turn),
+        }
+    }
+
     /// All training topics that belong to this street.
     pub fn topics(self) -> &'static [TrainingTopic] {
         match self {
@@ -170,6 +443,16 @@ impl Street {
                 TrainingTopic::AntiLimperIsolation,
                 TrainingTopic::SqueezePlay,
                 TrainingTopic::BigBlindDefense,
+                TrainingTopic::SetMining,
+                TrainingTopic::ThreeBetDefense,
+                TrainingTopic::ChipLeaderPlay,
+                TrainingTopic::BlindsVsBlinds,
+                TrainingTopic::ShortStackStrategy,
+                TrainingTopic::BlindStealing,
+                TrainingTopic::HeadsUpTournament,
+                TrainingTopic::SuitedConnectors,
+                TrainingTopic::PokerMath,
+                TrainingTopic::BeginnerHandRankings,
             ],
             Street::Flop => &[
                 TrainingTopic::PostflopContinuationBet,
@@ -177,16 +460,28 @@ impl Street {
                 TrainingTopic::CheckRaiseSpot,
                 TrainingTopic::SemiBluffDecision,
                 TrainingTopic::ThreeBetPotCbet,
+                TrainingTopic::StackOffDecision,
+                TrainingTopic::ContinuationBetDefense,
+                TrainingTopic::ExploitativePlay,
+                TrainingTopic::ProtectionBet,
+                TrainingTopic::PotControl,
+                TrainingTopic::DrawCounting,
             ],
             Street::Turn => &[
                 TrainingTopic::TurnBarrelDecision,
                 TrainingTopic::TurnProbeBet,
                 TrainingTopic::DelayedCbet,
+                TrainingTopic::TurnDraw,
+                TrainingTopic::FoldEquityBluff,
             ],
             Street::River => &[
                 TrainingTopic::BluffSpot,
                 TrainingTopic::RiverValueBet,
                 TrainingTopic::RiverCallOrFold,
+                TrainingTopic::TripleBarrel,
+                TrainingTopic::HandReading,
+                TrainingTopic::LayDown,
+                TrainingTopic::WhenToGiveUp,
             ],
         }
     }
@@ -203,13 +498,13 @@ impl fmt::Display for Street {
     }
 }
 
-/// The 16 poker skills the engine can generate drills for.
+/// The 38 poker skills the engine can generate drills for.
 ///
 /// Topics are grouped by street in the source code:
-/// - **Preflop** (preflop.rs): T1, T5, T9, T11, T12
-/// - **Flop** (flop.rs): T2, T3, T7, T8, T13
-/// - **Turn** (turn.rs): T6, T15, T16
-/// - **River** (river.rs): T4, T10, T14
+/// - **Preflop** (preflop.rs): T1, T5, T9, T11, T12, T18, T20, T23, T24, T25, T26, T27, T29, T32, T34
+/// - **Flop** (flop.rs): T2, T3, T7, T8, T13, T17, T19, T22, T28, T30, T35
+/// - **Turn** (turn.rs): T6, T15, T16, T31, T36
+/// - **River** (river.rs): T4, T10, T14, T21, T33, T37, T38
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TrainingTopic {
     /// T1  (PF-) Open-raise vs fold based on hand strength and position.
@@ -244,9 +539,144 @@ pub enum TrainingTopic {
     TurnProbeBet,
     /// T16 (DC-) Delayed c-bet on the turn after checking back the flop IP.
     DelayedCbet,
+    /// T17 (SO-) Committing the stack at low SPR in a 3-bet pot.
+    StackOffDecision,
+    /// T18 (SM-) Calling an open with a small pair to flop a set.
+    SetMining,
+    /// T19 (CD-) Facing a flop c-bet as the BB caller.
+    ContinuationBetDefense,
+    /// T20 (TD-) Facing a blind 3-bet after opening from CO or BTN.
+    ThreeBetDefense,
+    /// T21 (TR-) Firing a third barrel on the river after betting flop and turn.
+    TripleBarrel,
+    /// T22 (EX-) Deviating from the balanced line against a villain read.
+    ExploitativePlay,
+    /// T23 (CL-) Tournament chip leader facing a shove from a shorter stack.
+    ChipLeaderPlay,
+    /// T24 (BV-) Folded to the SB: fold, limp or raise against the BB alone.
+    BlindsVsBlinds,
+    /// T25 (SS-) Cash-game push or fold with 10–19 BB effective.
+    ShortStackStrategy,
+    /// T26 (ST-) Folded to CO, BTN or SB: steal the blinds or fold.
+    BlindStealing,
+    /// T27 (HU-) Winner-take-all heads-up push/fold on the button.
+    HeadsUpTournament,
+    /// T28 (PR-) Top pair OOP on the flop: bet to deny draws a free card, or check a dry board.
+    ProtectionBet,
+    /// T29 (SC-) 54s–98s facing an open: implied odds, position and stack depth.
+    SuitedConnectors,
+    /// T30 (PC-) Weak top pair in position: check back at medium SPR, bet when low SPR commits the stack.
+    PotControl,
+    /// T31 (DR-) A missed draw facing a turn bet: one-street equity (rule of 2) decides fold, call or raise.
+    TurnDraw,
+    /// T32 (PM-) Combinatorics and probability quiz: combos, flop odds, draw odds and pot-odds prices.
+    PokerMath,
+    /// T33 (HR-) Villain's three-street line narrows their range: name the most likely holding.
+    HandReading,
+    /// T34 (RK-) Which of two 5-card hands wins: hand categories first, then kickers.
+    BeginnerHandRankings,
+    /// T35 (OU-) Count the outs of hero's flop draw: gutshot 4, open-ender 8, flush draw 9, combo draw 15.
+    DrawCounting,
+    /// T36 (FE-) Hero's turn draw faces a bet and the question states villain's fold frequency; raise when the semi-bluff's EV is positive.
+    FoldEquityBluff,
+    /// T37 (LD-) Hero's strong non-nut river hand is check-raised; call small raises, fold big ones (reverse implied odds).
+    LayDown,
+    /// T38 (GU-) Hero's air was called on flop and turn; check the river unless hero holds the nut-flush blocker.
+    WhenToGiveUp,
 }
 
 impl TrainingTopic {
+    /// Every topic in canonical order (T1 → T38).
+    pub const ALL: [TrainingTopic; 38] = [
+        TrainingTopic::PreflopDecision,
+        TrainingTopic::PostflopContinuationBet,
+        TrainingTopic::PotOddsAndEquity,
+        TrainingTopic::BluffSpot,
+        TrainingTopic::ICMAndTournamentDecision,
+        TrainingTopic::TurnBarrelDecision,
+        TrainingTopic::CheckRaiseSpot,
+        TrainingTopic::SemiBluffDecision,
+        TrainingTopic::AntiLimperIsolation,
+        TrainingTopic::RiverValueBet,
+        TrainingTopic::SqueezePlay,
+        TrainingTopic::BigBlindDefense,
+        TrainingTopic::ThreeBetPotCbet,
+        TrainingTopic::RiverCallOrFold,
+        TrainingTopic::TurnProbeBet,
+        TrainingTopic::DelayedCbet,
+        TrainingTopic::StackOffDecision,
+        TrainingTopic::SetMining,
+        TrainingTopic::ContinuationBetDefense,
+        TrainingTopic::ThreeBetDefense,
+        TrainingTopic::TripleBarrel,
+        TrainingTopic::ExploitativePlay,
+        TrainingTopic::ChipLeaderPlay,
+        TrainingTopic::BlindsVsBlinds,
+        TrainingTopic::ShortStackStrategy,
+        TrainingTopic::BlindStealing,
+        TrainingTopic::HeadsUpTournament,
+        TrainingTopic::ProtectionBet,
+        TrainingTopic::SuitedConnectors,
+        TrainingTopic::PotControl,
+        TrainingTopic::TurnDraw,
+        TrainingTopic::PokerMath,
+        TrainingTopic::HandReading,
+        TrainingTopic::BeginnerHandRankings,
+        TrainingTopic::DrawCounting,
+        TrainingTopic::FoldEquityBluff,
+        TrainingTopic::LayDown,
+        TrainingTopic::WhenToGiveUp,
+    ];
+
+    /// [`TrainingTopic::ALL`] as a function.
+    pub const fn all() -> [TrainingTopic; 38] {
+        Self::ALL
+    }
+
+    /// Scenario-ID prefix including the dash, e.g. `"PF-"` or `"3B-"`.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            TrainingTopic::PreflopDecision          => "PF-",
+            TrainingTopic::PostflopContinuationBet  => "CB-",
+            TrainingTopic::PotOddsAndEquity         => "PO-",
+            TrainingTopic::BluffSpot                => "BL-",
+            TrainingTopic::ICMAndTournamentDecision => "IC-",
+            TrainingTopic::TurnBarrelDecision       => "TB-",
+            TrainingTopic::CheckRaiseSpot           => "CR-",
+            TrainingTopic::SemiBluffDecision        => "SB-",
+            TrainingTopic::AntiLimperIsolation      => "AL-",
+            TrainingTopic::RiverValueBet            => "RV-",
+            TrainingTopic::SqueezePlay              => "SQ-",
+            TrainingTopic::BigBlindDefense          => "BD-",
+            TrainingTopic::ThreeBetPotCbet          => "3B-",
+            TrainingTopic::RiverCallOrFold          => "RF-",
+            TrainingTopic::TurnProbeBet             => "PB-",
+            TrainingTopic::DelayedCbet              => "DC-",
+            TrainingTopic::StackOffDecision         => "SO-",
+            TrainingTopic::SetMining                => "SM-",
+            TrainingTopic::ContinuationBetDefense   => "CD-",
+            TrainingTopic::ThreeBetDefense          => "TD-",
+            TrainingTopic::TripleBarrel             => "TR-",
+            TrainingTopic::ExploitativePlay         => "EX-",
+            TrainingTopic::ChipLeaderPlay           => "CL-",
+            TrainingTopic::BlindsVsBlinds           => "BV-",
+            TrainingTopic::ShortStackStrategy       => "SS-",
+            TrainingTopic::BlindStealing            => "ST-",
+            TrainingTopic::HeadsUpTournament        => "HU-",
+            TrainingTopic::ProtectionBet            => "PR-",
+            TrainingTopic::SuitedConnectors         => "SC-",
+            TrainingTopic::PotControl               => "PC-",
+            TrainingTopic::TurnDraw                 => "DR-",
+            TrainingTopic::PokerMath                => "PM-",
+            TrainingTopic::HandReading              => "HR-",
+            TrainingTopic::BeginnerHandRankings     => "RK-",
+            TrainingTopic::DrawCounting             => "OU-",
+            TrainingTopic::FoldEquityBluff          => "FE-",
+            TrainingTopic::LayDown                  => "LD-",
+            TrainingTopic::WhenToGiveUp             => "GU-",
+        }
+    }
+
     /// Which street this topic belongs to.
     pub fn street(self) -> Street {
         match self {
@@ -254,23 +684,96 @@ impl TrainingTopic {
             | TrainingTopic::ICMAndTournamentDecision
             | TrainingTopic::AntiLimperIsolation
             | TrainingTopic::SqueezePlay
-            | TrainingTopic::BigBlindDefense => Street::Preflop,
+            | TrainingTopic::BigBlindDefense
+            | TrainingTopic::SetMining
+            | TrainingTopic::ThreeBetDefense
+            | TrainingTopic::ChipLeaderPlay
+            | TrainingTopic::BlindsVsBlinds
+            | TrainingTopic::ShortStackStrategy
+            | TrainingTopic::BlindStealing
+            | TrainingTopic::HeadsUpTournament
+            | TrainingTopic::SuitedConnectors
+            | TrainingTopic::PokerMath
+            | TrainingTopic::BeginnerHandRankings => Street::Preflop,
 
             TrainingTopic::PostflopContinuationBet
             | TrainingTopic::PotOddsAndEquity
             | TrainingTopic::CheckRaiseSpot
             | TrainingTopic::SemiBluffDecision
-            | TrainingTopic::ThreeBetPotCbet => Street::Flop,
+            | TrainingTopic::ThreeBetPotCbet
+            | TrainingTopic::StackOffDecision
+            | TrainingTopic::ContinuationBetDefense
+            | TrainingTopic::ExploitativePlay
+            | TrainingTopic::ProtectionBet
+            | TrainingTopic::PotControl
+            | TrainingTopic::DrawCounting => Street::Flop,
 
             TrainingTopic::TurnBarrelDecision
             | TrainingTopic::TurnProbeBet
-            | TrainingTopic::DelayedCbet => Street::Turn,
+            | TrainingTopic::DelayedCbet
+            | TrainingTopic::TurnDraw
+            | TrainingTopic::FoldEquityBluff => Street::Turn,
 
             TrainingTopic::BluffSpot
             | TrainingTopic::RiverValueBet
-            | TrainingTopic::RiverCallOrFold => Street::River,
+            | TrainingTopic::RiverCallOrFold
+            | TrainingTopic::TripleBarrel
+            | TrainingTopic::HandReading
+            | TrainingTopic::LayDown
+            | TrainingTopic::WhenToGiveUp => Street::River,
         }
     }
+
+    /// Whether the topic can be one street of a
+    /// [`generate_hand_sequence`](crate::training_engine::generate_hand_sequence).
+    ///
+    /// `false` for postflop topics that pick hero's hand or the board to fit
+    /// the lesson, so they cannot play the cards put on top of their deck.
+    /// Every preflop topic qualifies: the sequence takes its hole cards from
+    /// the preflop scenario, however it chose them.
+    pub fn supports_hand_sequence(self) -> bool {
+        !matches!(
+            self,
+            TrainingTopic::StackOffDecision
+                | TrainingTopic::ProtectionBet
+                | TrainingTopic::PotControl
+                | TrainingTopic::DrawCounting
+                | TrainingTopic::TurnDraw
+                | TrainingTopic::FoldEquityBluff
+                | TrainingTopic::HandReading
+                | TrainingTopic::LayDown
+                | TrainingTopic::WhenToGiveUp
+        )
+    }
+
+    /// 2–4 complementary topics to study next (see `RELATED_TOPICS`).
+    pub fn related_topics(self) -> Vec<TrainingTopic> {
+        RELATED_TOPICS
+            .iter()
+            .find(|(t, _)| *t == self)
+            .map_or_else(Vec::new, |(_, related)| related.to_vec())
+    }
+
+    /// Poker concepts every scenario of this topic teaches (see `TOPIC_CONCEPTS`).
+    ///
+    /// Generators may add branch-specific concepts on top, so a scenario's
+    /// [`TrainingScenario::key_concepts`] is a superset of this list.
+    pub fn key_concepts(self) -> &'static [&'static str] {
+        TOPIC_CONCEPTS
+            .iter()
+            .find(|(t, _)| *t == self)
+            .map_or(&[], |(_, concepts)| concepts)
+    }
+}
+
+/// Every topic whose scenarios always teach `concept` (e.g. `"pot_odds"`), in
+/// canonical order.  Concept names are `snake_case`; an unknown name gives an
+/// empty list.
+pub fn topics_for_concept(concept: &str) -> Vec<TrainingTopic> {
+    TrainingTopic::ALL
+        .into_iter()
+        .filter(|t| t.key_concepts().contains(&concept))
+        .collect()
 }
 
 impl fmt::Display for TrainingTopic {
@@ -292,11 +795,125 @@ impl fmt::Display for TrainingTopic {
             TrainingTopic::RiverCallOrFold           => "River Call or Fold",
             TrainingTopic::TurnProbeBet              => "Turn Probe Bet",
             TrainingTopic::DelayedCbet               => "Delayed C-Bet",
+            TrainingTopic::StackOffDecision          => "Stack-Off Decision",
+            TrainingTopic::SetMining                 => "Set Mining",
+            TrainingTopic::ContinuationBetDefense    => "C-Bet Defense",
+            TrainingTopic::ThreeBetDefense           => "3-Bet Defense",
+            TrainingTopic::TripleBarrel              => "Triple Barrel",
+            TrainingTopic::ExploitativePlay          => "Exploitative Play",
+            TrainingTopic::ChipLeaderPlay            => "Chip Leader Play",
+            TrainingTopic::BlindsVsBlinds            => "Blinds vs Blinds",
+            TrainingTopic::ShortStackStrategy        => "Short Stack Strategy",
+            TrainingTopic::BlindStealing             => "Blind Stealing",
+            TrainingTopic::HeadsUpTournament         => "Heads-Up Tournament",
+            TrainingTopic::ProtectionBet             => "Protection Bet",
+            TrainingTopic::SuitedConnectors          => "Suited Connectors",
+            TrainingTopic::PotControl                => "Pot Control",
+            TrainingTopic::TurnDraw                  => "Turn Draw",
+            TrainingTopic::PokerMath                 => "Poker Math",
+            TrainingTopic::HandReading               => "Hand Reading",
+            TrainingTopic::BeginnerHandRankings      => "Beginner Hand Rankings",
+            TrainingTopic::DrawCounting              => "Draw Counting",
+            TrainingTopic::FoldEquityBluff           => "Fold Equity Bluff",
+            TrainingTopic::LayDown                   => "Lay Down",
+            TrainingTopic::WhenToGiveUp              => "When to Give Up",
         };
         write!(f, "{}", s)
     }
 }
 
+/// Topics that complement each one, most closely related first — the next
+/// steps in a learning path.  Every topic is listed and appears in at least
+/// one other topic's list.
+const RELATED_TOPICS: [(TrainingTopic, &[TrainingTopic]); 38] = {
+    use TrainingTopic::*;
+    [
+        (PreflopDecision,          &[BigBlindDefense, SqueezePlay, AntiLimperIsolation, ICMAndTournamentDecision]),
+        (PostflopContinuationBet,  &[ThreeBetPotCbet, TurnBarrelDecision, DelayedCbet, ContinuationBetDefense]),
+        (PotOddsAndEquity,         &[SemiBluffDecision, CheckRaiseSpot, RiverCallOrFold, ProtectionBet]),
+        (BluffSpot,                &[RiverCallOrFold, SemiBluffDecision, RiverValueBet, FoldEquityBluff]),
+        (ICMAndTournamentDecision, &[PreflopDecision, BigBlindDefense, ChipLeaderPlay]),
+        (TurnBarrelDecision,       &[PostflopContinuationBet, DelayedCbet, BluffSpot, TripleBarrel]),
+        (CheckRaiseSpot,           &[BigBlindDefense, SemiBluffDecision, TurnProbeBet, DrawCounting]),
+        (SemiBluffDecision,        &[PotOddsAndEquity, CheckRaiseSpot, BluffSpot, TurnDraw]),
+        (AntiLimperIsolation,      &[PreflopDecision, SqueezePlay, BlindsVsBlinds]),
+        (RiverValueBet,            &[BluffSpot, RiverCallOrFold, TurnBarrelDecision, LayDown]),
+        (SqueezePlay,              &[AntiLimperIsolation, ThreeBetPotCbet, BigBlindDefense, ThreeBetDefense]),
+        (BigBlindDefense,          &[CheckRaiseSpot, SqueezePlay, PotOddsAndEquity, SetMining]),
+        (ThreeBetPotCbet,          &[PostflopContinuationBet, SqueezePlay, StackOffDecision]),
+        (RiverCallOrFold,          &[PotOddsAndEquity, BluffSpot, RiverValueBet, HandReading]),
+        (TurnProbeBet,             &[CheckRaiseSpot, DelayedCbet, TurnBarrelDecision]),
+        (DelayedCbet,              &[PostflopContinuationBet, TurnProbeBet, TurnBarrelDecision]),
+        (StackOffDecision,         &[ThreeBetPotCbet, PotOddsAndEquity, RiverValueBet, ShortStackStrategy]),
+        (SetMining,                &[BigBlindDefense, PotOddsAndEquity, SqueezePlay, SuitedConnectors]),
+        (ContinuationBetDefense,   &[PostflopContinuationBet, CheckRaiseSpot, BigBlindDefense, ExploitativePlay]),
+        (ThreeBetDefense,          &[PreflopDecision, SqueezePlay, ThreeBetPotCbet]),
+        (TripleBarrel,             &[TurnBarrelDecision, BluffSpot, RiverValueBet, WhenToGiveUp]),
+        (ExploitativePlay,         &[PostflopContinuationBet, ContinuationBetDefense, RiverCallOrFold]),
+        (ChipLeaderPlay,           &[ICMAndTournamentDecision, BigBlindDefense, PotOddsAndEquity, HeadsUpTournament]),
+        (BlindsVsBlinds,           &[BigBlindDefense, AntiLimperIsolation, PreflopDecision, BlindStealing]),
+        (ShortStackStrategy,       &[ICMAndTournamentDecision, PreflopDecision, StackOffDecision]),
+        (BlindStealing,            &[PreflopDecision, BigBlindDefense, BlindsVsBlinds]),
+        (HeadsUpTournament,        &[ICMAndTournamentDecision, ShortStackStrategy, ChipLeaderPlay]),
+        (ProtectionBet,            &[PostflopContinuationBet, PotOddsAndEquity, SemiBluffDecision, PotControl]),
+        (SuitedConnectors,         &[SetMining, PreflopDecision, SemiBluffDecision]),
+        (PotControl,               &[StackOffDecision, ProtectionBet, DelayedCbet]),
+        (TurnDraw,                 &[PotOddsAndEquity, SemiBluffDecision, TurnBarrelDecision, PokerMath]),
+        (PokerMath,                &[PotOddsAndEquity, SetMining, TurnDraw, BeginnerHandRankings]),
+        (HandReading,              &[RiverCallOrFold, TripleBarrel, ExploitativePlay]),
+        (BeginnerHandRankings,     &[PokerMath, HandReading, RiverCallOrFold]),
+        (DrawCounting,             &[PotOddsAndEquity, PokerMath, TurnDraw]),
+        (FoldEquityBluff,          &[SemiBluffDecision, TurnDraw, BluffSpot]),
+        (LayDown,                  &[RiverCallOrFold, RiverValueBet, HandReading]),
+        (WhenToGiveUp,             &[BluffSpot, TripleBarrel, HandReading]),
+    ]
+};
+
+/// Concepts each topic always teaches, most central first.
+const TOPIC_CONCEPTS: [(TrainingTopic, &[&str]); 38] = {
+    use TrainingTopic::*;
+    [
+        (PreflopDecision,          &["hand_selection", "position_advantage"]),
+        (PostflopContinuationBet,  &["continuation_bet", "board_texture", "range_advantage"]),
+        (PotOddsAndEquity,         &["pot_odds", "draw_equity"]),
+        (BluffSpot,                &["fold_equity", "bluffing", "blockers"]),
+        (ICMAndTournamentDecision, &["icm", "push_fold"]),
+        (TurnBarrelDecision,       &["barreling", "fold_equity", "board_texture"]),
+        (CheckRaiseSpot,           &["check_raise", "board_texture", "fold_equity"]),
+        (SemiBluffDecision,        &["semi_bluff", "draw_equity", "fold_equity"]),
+        (AntiLimperIsolation,      &["isolation", "position_advantage"]),
+        (RiverValueBet,            &["value_betting", "bet_sizing"]),
+        (SqueezePlay,              &["squeeze", "fold_equity", "position_advantage"]),
+        (BigBlindDefense,          &["pot_odds", "mdf", "hand_selection"]),
+        (ThreeBetPotCbet,          &["continuation_bet", "spr", "range_advantage"]),
+        (RiverCallOrFold,          &["bluff_catching", "pot_odds", "mdf"]),
+        (TurnProbeBet,             &["probe_bet", "fold_equity"]),
+        (DelayedCbet,              &["continuation_bet", "board_texture", "position_advantage"]),
+        (StackOffDecision,         &["spr", "stack_commitment", "bet_sizing"]),
+        (SetMining,                &["set_mining", "implied_odds", "stack_depth"]),
+        (ContinuationBetDefense,   &["continuation_bet", "mdf", "board_texture"]),
+        (ThreeBetDefense,          &["three_bet", "position_advantage", "hand_selection"]),
+        (TripleBarrel,             &["barreling", "bluffing", "value_betting"]),
+        (ExploitativePlay,         &["exploitative_play", "player_reads", "continuation_bet"]),
+        (ChipLeaderPlay,           &["chip_leader", "icm", "stack_pressure"]),
+        (BlindsVsBlinds,           &["blind_vs_blind", "position_advantage", "hand_selection"]),
+        (ShortStackStrategy,       &["push_fold", "fold_equity", "stack_depth"]),
+        (BlindStealing,            &["blind_stealing", "position_advantage", "fold_equity"]),
+        (HeadsUpTournament,        &["heads_up", "push_fold", "nash_equilibrium"]),
+        (ProtectionBet,            &["protection", "equity_denial", "board_texture"]),
+        (SuitedConnectors,         &["implied_odds", "position_advantage", "hand_selection"]),
+        (PotControl,               &["pot_control", "spr", "hand_strength"]),
+        (TurnDraw,                 &["draw_equity", "rule_of_2_and_4", "semi_bluff"]),
+        (PokerMath,                &["combinatorics", "probability", "draw_equity"]),
+        (HandReading,              &["hand_reading", "range_narrowing", "bet_sizing"]),
+        (BeginnerHandRankings,     &["hand_rankings", "showdown", "kickers"]),
+        (DrawCounting,             &["outs", "draw_equity", "probability"]),
+        (FoldEquityBluff,          &["fold_equity", "semi_bluff", "draw_equity"]),
+        (LayDown,                  &["reverse_implied_odds", "hand_reading", "board_texture"]),
+        (WhenToGiveUp,             &["fold_equity", "range_narrowing", "blockers"]),
+    ]
+};
+
 /// Controls stack-depth ranges and bet-size variance.
 ///
 /// `Beginner` is the default — fixed stacks, narrow bet sizes, predictable
@@ -309,6 +926,37 @@ pub enum DifficultyLevel {
     Advanced,
 }
 
+impl DifficultyLevel {
+    /// One level up, or `None` at `Advanced`.
+    pub fn harder(self) -> Option<DifficultyLevel> {
+        DifficultyLevel::from_u8(self.as_u8() + 1)
+    }
+
+    /// One level down, or `None` at `Beginner`.
+    pub fn easier(self) -> Option<DifficultyLevel> {
+        self.as_u8().checked_sub(1).and_then(DifficultyLevel::from_u8)
+    }
+
+    /// `0` for Beginner, `1` for Intermediate, `2` for Advanced.
+    pub fn as_u8(self) -> u8 {
+        match self {
+            DifficultyLevel::Beginner     => 0,
+            DifficultyLevel::Intermediate => 1,
+            DifficultyLevel::Advanced     => 2,
+        }
+    }
+
+    /// Inverse of [`DifficultyLevel::as_u8`]; `None` above 2.
+    pub fn from_u8(u: u8) -> Option<DifficultyLevel> {
+        match u {
+            0 => Some(DifficultyLevel::Beginner),
+            1 => Some(DifficultyLevel::Intermediate),
+            2 => Some(DifficultyLevel::Advanced),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for DifficultyLevel {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -333,6 +981,76 @@ pub enum TextStyle {
     Technical,
 }
 
+/// Error returned when parsing a [`TrainingRequest`] or one of its fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRequestError {
+    /// Not a [`TrainingTopic`] variant name, display name or prefix.
+    InvalidTopic(String),
+    /// Not `Beginner`, `Intermediate` or `Advanced`.
+    InvalidDifficulty(String),
+    /// The field at this 0-based position is missing, unexpected or malformed
+    /// (2 = seed, 3 = text style).
+    InvalidField(usize),
+}
+
+impl fmt::Display for ParseRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRequestError::InvalidTopic(t)      => write!(f, "invalid topic '{t}'"),
+            ParseRequestError::InvalidDifficulty(d) => write!(f, "invalid difficulty '{d}'"),
+            ParseRequestError::InvalidField(i)      => write!(f, "invalid field at position {i}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseRequestError {}
+
+/// Parses a variant name (`"BluffSpot"`), a display name (`"Bluff Spot"`) or
+/// a scenario-ID prefix (`"BL-"`), ignoring ASCII case.
+impl FromStr for TrainingTopic {
+    type Err = ParseRequestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        TrainingTopic::ALL
+            .into_iter()
+            .find(|t| {
+                [format!("{t:?}"), t.to_string(), t.prefix().to_string()]
+                    .iter()
+                    .any(|name| name.eq_ignore_ascii_case(s))
+            })
+            .ok_or_else(|| ParseRequestError::InvalidTopic(s.to_string()))
+    }
+}
+
+/// Parses `"Beginner"`, `"Intermediate"` or `"Advanced"`, ignoring ASCII case.
+impl FromStr for DifficultyLevel {
+    type Err = ParseRequestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "beginner"     => Ok(DifficultyLevel::Beginner),
+            "intermediate" => Ok(DifficultyLevel::Intermediate),
+            "advanced"     => Ok(DifficultyLevel::Advanced),
+            _ => Err(ParseRequestError::InvalidDifficulty(s.to_string())),
+        }
+    }
+}
+
+/// Parses `"Simple"` or `"Technical"`, ignoring ASCII case.  Errors are
+/// reported as the text-style field of a request, `InvalidField(3)`.
+impl FromStr for TextStyle {
+    type Err = ParseRequestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "simple"    => Ok(TextStyle::Simple),
+            "technical" => Ok(TextStyle::Technical),
+            _ => Err(ParseRequestError::InvalidField(3)),
+        }
+    }
+}
+
 /// Choose what to drill: a specific topic or a random topic from a street.
 ///
 /// ```ignore
@@ -385,9 +1103,11 @@ impl From<Street> for TopicSelector {
 ///     difficulty: DifficultyLevel::Advanced,
 ///     rng_seed: Some(42),
 ///     text_style: TextStyle::Technical,
+///     is_bounty: false,
+///     excluded_topics: Vec::new(),
 /// }
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TrainingRequest {
     /// What to drill — a specific topic or any topic from a street.
     pub topic: TopicSelector,
@@ -402,6 +1122,16 @@ pub struct TrainingRequest {
     /// Defaults to `Simple` (plain English).
     #[serde(default)]
     pub text_style: TextStyle,
+    /// Progressive-knockout tournament: T5 puts a bounty on every player and
+    /// adds the bounty EV to its Technical explanations.  Ignored by other
+    /// topics.  Defaults to `false`.
+    #[serde(default)]
+    pub is_bounty: bool,
+    /// Topics a [`TopicSelector::Street`] must not pick, e.g. ones already
+    /// mastered.  A specific [`TopicSelector::Topic`] is always honoured.
+    /// Defaults to empty.
+    #[serde(default)]
+    pub excluded_topics: Vec<TrainingTopic>,
 }
 
 impl TrainingRequest {
@@ -413,6 +1143,121 @@ impl TrainingRequest {
             difficulty: DifficultyLevel::default(),
             rng_seed: None,
             text_style: TextStyle::default(),
+            is_bounty: false,
+            excluded_topics: Vec::new(),
+        }
+    }
+
+    /// Skip `excluded` when resolving a street selector.
+    ///
+    /// The street is re-rolled up to 10 times; if every topic on it is
+    /// excluded, any non-excluded topic from another street is used instead.
+    pub fn exclude_topics(mut self, excluded: Vec<TrainingTopic>) -> Self {
+        self.excluded_topics = excluded;
+        self
+    }
+
+    /// Start a [`TrainingRequestBuilder`] with every field at its default.
+    pub fn builder() -> TrainingRequestBuilder {
+        TrainingRequestBuilder::default()
+    }
+}
+
+/// Parses `"topic,difficulty,seed,style"`, e.g.
+/// `"PreflopDecision,Intermediate,42,Technical"`.
+///
+/// Only the topic is required; omitted trailing fields take the same defaults
+/// as [`TrainingRequest::new`].  Whitespace around fields is ignored.
+impl FromStr for TrainingRequest {
+    type Err = ParseRequestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split(',').map(str::trim).collect();
+        if fields.len() > 4 {
+            return Err(ParseRequestError::InvalidField(4));
+        }
+        let mut request = TrainingRequest::new(fields[0].parse::<TrainingTopic>()?);
+        if let Some(d) = fields.get(1) {
+            request.difficulty = d.parse()?;
+        }
+        if let Some(seed) = fields.get(2) {
+            request.rng_seed = Some(seed.parse().map_err(|_| ParseRequestError::InvalidField(2))?);
+        }
+        if let Some(style) = fields.get(3) {
+            request.text_style = style.parse()?;
+        }
+        Ok(request)
+    }
+}
+
+/// Fluent builder for [`TrainingRequest`].
+///
+/// Only the fields you set differ from the defaults, so there is no need to
+/// spell out all four fields of the struct literal:
+///
+/// ```ignore
+/// TrainingRequest::builder()
+///     .topic(TrainingTopic::BluffSpot)
+///     .seed(42)
+///     .build()
+/// ```
+///
+/// `topic` is required — `build()` panics with "topic is required" if it was
+/// never set.
+#[derive(Debug, Clone, Default)]
+pub struct TrainingRequestBuilder {
+    topic: Option<TopicSelector>,
+    difficulty: DifficultyLevel,
+    rng_seed: Option<u64>,
+    text_style: TextStyle,
+    is_bounty: bool,
+    excluded_topics: Vec<TrainingTopic>,
+}
+
+impl TrainingRequestBuilder {
+    /// What to drill — accepts `TrainingTopic` or `Street`.
+    pub fn topic(&mut self, topic: impl Into<TopicSelector>) -> &mut Self {
+        self.topic = Some(topic.into());
+        self
+    }
+
+    pub fn difficulty(&mut self, difficulty: DifficultyLevel) -> &mut Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Fix the RNG seed for reproducible output.
+    pub fn seed(&mut self, seed: u64) -> &mut Self {
+        self.rng_seed = Some(seed);
+        self
+    }
+
+    pub fn style(&mut self, text_style: TextStyle) -> &mut Self {
+        self.text_style = text_style;
+        self
+    }
+
+    /// Generate bounty (PKO) tournament spots where supported.
+    pub fn bounty(&mut self, is_bounty: bool) -> &mut Self {
+        self.is_bounty = is_bounty;
+        self
+    }
+
+    /// Topics a street selector must not pick (see [`TrainingRequest::exclude_topics`]).
+    pub fn exclude(&mut self, excluded: Vec<TrainingTopic>) -> &mut Self {
+        self.excluded_topics = excluded;
+        self
+    }
+
+    /// Assemble the request.  Panics if `topic()` was never called.
+    pub fn build(&self) -> TrainingRequest {
+        TrainingRequest {
+            topic: self.topic.expect("topic is required"),
+            difficulty: self.difficulty,
+            rng_seed: self.rng_seed,
+            text_style: self.text_style,
+            is_bounty: self.is_bounty,
+            excluded_topics: self.excluded_topics.clone(),
         }
     }
 }
@@ -421,7 +1266,7 @@ impl TrainingRequest {
 ///
 /// `board` length depends on the street: 0 (preflop), 3 (flop), 4 (turn), 5 (river).
 /// `current_bet` is 0 when hero is first to act; non-zero when facing a villain bet.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TableSetup {
     pub game_type: GameType,
     pub hero_position: Position,
@@ -433,6 +1278,79 @@ pub struct TableSetup {
     pub pot_size: u32,
     /// The bet hero must call (0 = hero acts first / no bet to face).
     pub current_bet: u32,
+    /// Total ante posted preflop in chips (e.g. a 1 BB big-blind ante), already
+    /// included in `pot_size`; `None` in games without antes.
+    #[serde(default)]
+    pub antes: Option<u32>,
+}
+
+impl TableSetup {
+    /// Street implied by the number of board cards.
+    ///
+    /// # Panics
+    /// If the board has a length other than 0, 3, 4 or 5.
+    pub fn street(&self) -> Street {
+        match self.board.len() {
+            0 => Street::Preflop,
+            3 => Street::Flop,
+            4 => Street::Turn,
+            5 => Street::River,
+            n => panic!("invalid board length {n}: expected 0, 3, 4 or 5 cards"),
+        }
+    }
+
+    /// Smallest stack among active players (0 if none are active).
+    pub fn effective_stack(&self) -> u32 {
+        crate::training_engine::helpers::effective_stack(&self.players)
+    }
+
+    /// Stack-to-pot ratio: `effective_stack() / pot_size`.
+    ///
+    /// Infinite (or NaN) when `pot_size` is 0.
+    pub fn spr(&self) -> f32 {
+        self.effective_stack() as f32 / self.pot_size as f32
+    }
+}
+
+/// Graded quality of an answer option, beyond plain right/wrong.
+///
+/// The correct option is always `Correct`.  Wrong options are graded by how
+/// much they cost at the table: a close alternative is `Plausible`, a clear
+/// error is a `Mistake`, and a costly error (e.g. folding a premium hand) is
+/// a `Blunder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AnswerRating {
+    /// The best play.
+    Correct,
+    /// Reasonable, but gives up some EV versus the best play.
+    Plausible,
+    /// A clear error.
+    Mistake,
+    /// A costly error.
+    Blunder,
+}
+
+impl AnswerRating {
+    /// Points awarded for choosing an option with this rating: 100 / 60 / 20 / 0.
+    pub fn score(self) -> u32 {
+        match self {
+            AnswerRating::Correct   => 100,
+            AnswerRating::Plausible => 60,
+            AnswerRating::Mistake   => 20,
+            AnswerRating::Blunder   => 0,
+        }
+    }
+}
+
+impl fmt::Display for AnswerRating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerRating::Correct   => write!(f, "Correct"),
+            AnswerRating::Plausible => write!(f, "Plausible"),
+            AnswerRating::Mistake   => write!(f, "Mistake"),
+            AnswerRating::Blunder   => write!(f, "Blunder"),
+        }
+    }
 }
 
 /// One answer choice. Exactly one per scenario has `is_correct: true`.
@@ -440,7 +1358,7 @@ pub struct TableSetup {
 /// `explanation` is a dynamically generated string (not a static template) that
 /// explains *why* this option is correct or incorrect, adapted to the dealt cards
 /// and the active `TextStyle`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AnswerOption {
     /// Short ID shown in the UI (e.g. "A", "B", "C").
     pub id: String,
@@ -448,16 +1366,227 @@ pub struct AnswerOption {
     pub text: String,
     /// True for exactly one answer per scenario.
     pub is_correct: bool,
+    /// Graded quality — `Correct` exactly when `is_correct` is true.
+    pub rating: AnswerRating,
+    /// Short clue (≤ 40 words) shown before the reveal — guides without
+    /// giving the answer away.
+    pub hint: Option<String>,
     /// Why this choice is right or wrong — changes with cards and TextStyle.
     pub explanation: String,
 }
 
+/// Why [`generate_training`](super::generate_training) could not produce a
+/// scenario.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenerationError {
+    /// A generator tried to deal more than 52 cards.
+    DeckExhausted,
+    /// The request asks for something no scenario of the topic can satisfy.
+    InvalidTopicConstraint(String),
+    /// The generated scenario failed [`TrainingScenario::validate`].
+    InvariantViolation(String),
+}
+
+impl fmt::Display for GenerationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenerationError::DeckExhausted             => write!(f, "deck exhausted"),
+            GenerationError::InvalidTopicConstraint(m) => write!(f, "invalid topic constraint: {m}"),
+            GenerationError::InvariantViolation(m)     => write!(f, "scenario invariant violated: {m}"),
+        }
+    }
+}
+
+impl std::error::Error for GenerationError {}
+
+/// A broken scenario invariant, reported by [`TrainingScenario::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Not exactly one answer has `is_correct: true` (holds the count found).
+    CorrectAnswerCount(usize),
+    /// Two or more answers share this ID.
+    DuplicateAnswerId(String),
+    /// Hero's two hole cards are both this card.
+    DuplicateHoleCard(Card),
+    /// This board card is also one of hero's hole cards.
+    BoardOverlapsHand(Card),
+    /// This card appears on the board more than once.
+    DuplicateBoardCard(Card),
+    /// The board size does not match the topic's street.
+    BoardLength { expected: usize, found: usize },
+    /// The question text is empty.
+    EmptyQuestion,
+    /// The branch key is empty.
+    EmptyBranchKey,
+    /// The pot is 0 chips.
+    EmptyPot,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::CorrectAnswerCount(n) =>
+                write!(f, "expected exactly 1 correct answer, found {n}"),
+            ValidationError::DuplicateAnswerId(id) => write!(f, "answer ID '{id}' is used more than once"),
+            ValidationError::DuplicateHoleCard(c)  => write!(f, "hero holds {c} twice"),
+            ValidationError::BoardOverlapsHand(c)  => write!(f, "board card {c} is also in hero's hand"),
+            ValidationError::DuplicateBoardCard(c) => write!(f, "board card {c} appears more than once"),
+            ValidationError::BoardLength { expected, found } =>
+                write!(f, "expected {expected} board cards for this topic, found {found}"),
+            ValidationError::EmptyQuestion         => write!(f, "question is empty"),
+            ValidationError::EmptyBranchKey        => write!(f, "branch key is empty"),
+            ValidationError::EmptyPot              => write!(f, "pot size is 0"),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// A villain read that makes the best play deviate from the balanced (GTO)
+/// line.  Carried on [`TrainingScenario::villain_tendency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VillainTendency {
+    /// Folds to about 80% of flop c-bets.
+    FoldsToCbet80Pct,
+    /// Calls far too often — a calling station.
+    CallsFrequently,
+    /// Only bets with a made hand.
+    NeverBluffs,
+    /// Overbets only with the nuts.
+    AlwaysOverbetsNuts,
+}
+
+impl VillainTendency {
+    /// Every tendency, in declaration order.
+    pub const ALL: [VillainTendency; 4] = [
+        VillainTendency::FoldsToCbet80Pct,
+        VillainTendency::CallsFrequently,
+        VillainTendency::NeverBluffs,
+        VillainTendency::AlwaysOverbetsNuts,
+    ];
+}
+
+impl fmt::Display for VillainTendency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            VillainTendency::FoldsToCbet80Pct   => "folds to 80% of c-bets",
+            VillainTendency::CallsFrequently    => "calls far too often",
+            VillainTendency::NeverBluffs        => "never bluffs",
+            VillainTendency::AlwaysOverbetsNuts => "overbets only with the nuts",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Size of a villain bet relative to the pot, for hand-reading lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BetSize {
+    /// About a third of the pot.
+    Small,
+    /// About three quarters of the pot.
+    Large,
+    /// More than the pot.
+    Overbet,
+}
+
+impl BetSize {
+    /// Bet as a fraction of the pot: 0.33 / 0.75 / 1.25.
+    pub fn fraction(self) -> f32 {
+        match self {
+            BetSize::Small   => 0.33,
+            BetSize::Large   => 0.75,
+            BetSize::Overbet => 1.25,
+        }
+    }
+}
+
+impl fmt::Display for BetSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            BetSize::Small   => "small",
+            BetSize::Large   => "large",
+            BetSize::Overbet => "an overbet",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// What villain did on one postflop street.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VillainAction {
+    /// Checked, and the street checked through.
+    Check,
+    /// Bet, and hero called (on the river: hero has yet to act).
+    Bet(BetSize),
+    /// Checked, then called hero's bet.
+    CheckCall,
+    /// Checked, then raised hero's bet, and hero called.
+    CheckRaise,
+}
+
+impl VillainAction {
+    /// Short code used in branch keys: `X`, `BS` / `BL` / `BO`, `XC`, `XR`.
+    pub fn code(self) -> &'static str {
+        match self {
+            VillainAction::Check                 => "X",
+            VillainAction::Bet(BetSize::Small)   => "BS",
+            VillainAction::Bet(BetSize::Large)   => "BL",
+            VillainAction::Bet(BetSize::Overbet) => "BO",
+            VillainAction::CheckCall             => "XC",
+            VillainAction::CheckRaise            => "XR",
+        }
+    }
+}
+
+/// Villain's betting line through a hand, read street by street in hand
+/// reading spots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ActionSequence {
+    /// `true` when villain raised preflop, `false` when villain called.
+    pub villain_raised: bool,
+    pub flop: VillainAction,
+    pub turn: VillainAction,
+    pub river: VillainAction,
+}
+
+impl ActionSequence {
+    /// The three postflop actions with their street.
+    pub fn streets(&self) -> [(Street, VillainAction); 3] {
+        [(Street::Flop, self.flop), (Street::Turn, self.turn), (Street::River, self.river)]
+    }
+
+    /// Compact, stable key for the line, e.g. `"R-BS-BL-X"` (R = raised
+    /// preflop, C = called).
+    pub fn code(&self) -> String {
+        let pre = if self.villain_raised { "R" } else { "C" };
+        format!("{pre}-{}-{}-{}", self.flop.code(), self.turn.code(), self.river.code())
+    }
+}
+
+impl fmt::Display for ActionSequence {
+    /// E.g. "raised preflop, bet small on the flop, bet large on the turn,
+    /// checked the river".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if self.villain_raised { "raised preflop" } else { "called preflop" })?;
+        for (street, action) in self.streets() {
+            let street = street.to_string().to_lowercase();
+            match action {
+                VillainAction::Check      => write!(f, ", checked the {street}")?,
+                VillainAction::Bet(size)  => write!(f, ", bet {size} on the {street}")?,
+                VillainAction::CheckCall  => write!(f, ", check-called the {street}")?,
+                VillainAction::CheckRaise => write!(f, ", check-raised the {street}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
 /// The complete output of [`generate_training`](super::generate_training).
 ///
 /// Contains everything a UI needs: the table state, a question, and all
 /// answer options (exactly one correct). The `scenario_id` is unique per
 /// generation and the `branch_key` is stable across seeds.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TrainingScenario {
     /// Unique ID with a 2-letter topic prefix, e.g. `"PF-3A1C8F02"`.
     pub scenario_id: String,
@@ -472,4 +1601,359 @@ pub struct TrainingScenario {
     pub question: String,
     /// All answer choices — exactly one has `is_correct: true`.
     pub answers: Vec<AnswerOption>,
+    /// Poker concepts the spot exercises, e.g. `"pot_odds"` or `"spr"` — the
+    /// topic's [`TrainingTopic::key_concepts`] plus any the branch adds.
+    #[serde(default)]
+    pub key_concepts: Vec<String>,
+    /// The villain read an exploitative spot is built on; `None` for spots
+    /// played against a balanced opponent.
+    #[serde(default)]
+    pub villain_tendency: Option<VillainTendency>,
+}
+
+impl TrainingScenario {
+    /// The single correct option.
+    ///
+    /// # Panics
+    /// If no option is marked correct (a generator bug).
+    pub fn correct_answer(&self) -> &AnswerOption {
+        self.answers
+            .iter()
+            .find(|a| a.is_correct)
+            .unwrap_or_else(|| panic!("scenario {} has no correct answer", self.scenario_id))
+    }
+
+    /// True if `given_id` (surrounding whitespace ignored) is the correct option's ID.
+    pub fn is_answered_correctly(&self, given_id: &str) -> bool {
+        given_id.trim() == self.correct_answer().id
+    }
+
+    /// Street of the scenario, derived from the board (see [`TableSetup::street`]).
+    pub fn street(&self) -> Street {
+        self.table_setup.street()
+    }
+
+    /// Points for answering with option `id` (see [`AnswerRating::score`]).
+    ///
+    /// Returns 0 for an ID that matches no option.
+    pub fn score_answer(&self, id: &str) -> u32 {
+        self.answers
+            .iter()
+            .find(|a| a.id == id)
+            .map_or(0, |a| a.rating.score())
+    }
+
+    /// Hint attached to the correct option, if any.
+    pub fn correct_answer_hint(&self) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| a.is_correct)
+            .and_then(|a| a.hint.as_deref())
+    }
+
+    /// Content hash for spotting duplicate spots across seeds.
+    ///
+    /// Covers the topic, branch key, cards, pot, bet, seat stacks and each
+    /// option's ID and correctness — the spot itself, not its wording — so the
+    /// same deal hashes alike in either [`TextStyle`].  The hash is 64-bit
+    /// FNV-1a over a fixed text encoding, so it is stable across builds and
+    /// can be stored.
+    pub fn scenario_hash(&self) -> u64 {
+        let t = &self.table_setup;
+        let cards = |cs: &[Card]| cs.iter().map(|c| c.to_string()).collect::<String>();
+        let seats: Vec<String> = t.players.iter().map(|p| format!("{}:{}", p.position, p.stack)).collect();
+        let options: Vec<String> = self.answers.iter().map(|a| format!("{}:{}", a.id, a.is_correct)).collect();
+        let key = format!(
+            "{}|{}|{}|{}|{}|{}|{}|{}",
+            self.topic.prefix(),
+            self.branch_key,
+            cards(&t.hero_hand),
+            cards(&t.board),
+            t.pot_size,
+            t.current_bet,
+            seats.join(","),
+            options.join(","),
+        );
+        key.bytes().fold(0xcbf2_9ce4_8422_2325, |h, b| (h ^ b as u64).wrapping_mul(0x0100_0000_01b3))
+    }
+
+    /// A request for the next decision in the hand after `action_taken`.
+    ///
+    /// `action_taken` is an option ID (`"A"`) or action text (`"Call"`,
+    /// `"Raise to 6 BB"`), matched case-insensitively.  Raises, bets and
+    /// all-ins continue the aggressor's line, calls and checks the passive
+    /// line, and a fold (or the end of the river) starts a new hand:
+    ///
+    /// | Scenario | Aggressive | Passive | Fold |
+    /// |----------|------------|---------|------|
+    /// | T1, T9 | T2 C-bet | T7 Check-raise | T1 |
+    /// | T11, T12 | T13 3-bet pot c-bet | T3 Pot odds (T11), T7 (T12) | T1 |
+    /// | T5 | T5 (next hand) | T5 | T5 |
+    /// | Flop (T2, T3, T7, T8, T13) | T6 Turn barrel | T16 Delayed c-bet after an IP check, else T15 Probe | T1 |
+    /// | Turn (T6, T15, T16) | T10 River value bet | T14 River call or fold | T1 |
+    /// | River (T4, T10, T14) | T1 | T1 | T1 |
+    ///
+    /// The scenario does not record its seed, so the follow-up seed is
+    /// [`scenario_hash`](Self::scenario_hash) plus 1 / 2 / 3 for an
+    /// aggressive / passive / fold action — the same spot and action always
+    /// lead to the same next scenario.  Difficulty and text style are the
+    /// defaults; override them on the returned request as needed.
+    pub fn follow_up(&self, action_taken: &str) -> TrainingRequest {
+        use TrainingTopic::*;
+        let action = action_taken.trim();
+        let text = self
+            .answers
+            .iter()
+            .find(|a| a.id.eq_ignore_ascii_case(action))
+            .map_or(action, |a| a.text.as_str())
+            .to_ascii_lowercase();
+        let aggressive = ["raise", "bet", "all-in", "squeeze", "probe", "shove", "push"]
+            .iter()
+            .any(|k| text.contains(k));
+        let fold = !aggressive && text.contains("fold");
+
+        let (next, offset) = if fold {
+            (if self.topic == ICMAndTournamentDecision { ICMAndTournamentDecision } else { PreflopDecision }, 3)
+        } else {
+            let next = match (self.topic, aggressive) {
+                (ICMAndTournamentDecision, _) => ICMAndTournamentDecision,
+                (PreflopDecision | AntiLimperIsolation, true) => PostflopContinuationBet,
+                (SqueezePlay | BigBlindDefense, true) => ThreeBetPotCbet,
+                (SqueezePlay, false) => PotOddsAndEquity,
+                (PreflopDecision | AntiLimperIsolation | BigBlindDefense, false) => CheckRaiseSpot,
+                (PostflopContinuationBet | ThreeBetPotCbet, false) => DelayedCbet,
+                (t, true) if t.street() == Street::Flop => TurnBarrelDecision,
+                (t, false) if t.street() == Street::Flop => TurnProbeBet,
+                (t, true) if t.street() == Street::Turn => RiverValueBet,
+                (t, false) if t.street() == Street::Turn => RiverCallOrFold,
+                _ => PreflopDecision,
+            };
+            (next, if aggressive { 1 } else { 2 })
+        };
+        TrainingRequest {
+            rng_seed: Some(self.scenario_hash().wrapping_add(offset)),
+            ..TrainingRequest::new(next)
+        }
+    }
+
+    /// Topics that complement this one (see [`TrainingTopic::related_topics`]).
+    pub fn related_topics(&self) -> Vec<TrainingTopic> {
+        self.topic.related_topics()
+    }
+
+    /// How hard this particular spot is, from 0.0 (obvious) to 1.0 (razor-thin).
+    ///
+    /// Blends four signals:
+    /// - how close the best wrong option is to the correct one (60%): a
+    ///   `Plausible` alternative means the decision is close, while `Mistake`
+    ///   and `Blunder` alternatives are clearly separated;
+    /// - the number of options (20%): two is the minimum, four or more the maximum;
+    /// - SPR (10%): shallow stacks make commitment decisions simpler, and the
+    ///   weight saturates at an SPR of 10;
+    /// - board draws (10%): [`board_draw_count`](crate::training_engine::evaluator::board_draw_count),
+    ///   saturating at 3.
+    pub fn complexity_score(&self) -> f32 {
+        let closeness = self
+            .answers
+            .iter()
+            .filter(|a| !a.is_correct)
+            .map(|a| match a.rating {
+                AnswerRating::Correct | AnswerRating::Plausible => 1.0,
+                AnswerRating::Mistake => 0.1,
+                AnswerRating::Blunder => 0.0,
+            })
+            .fold(0.0_f32, f32::max);
+        let options = ((self.answers.len() as f32 - 2.0) / 2.0).clamp(0.0, 1.0);
+        let depth = (self.table_setup.spr() / 10.0).clamp(0.0, 1.0);
+        let draws = crate::training_engine::evaluator::board_draw_count(&self.table_setup.board);
+        let draws = (draws as f32 / 3.0).min(1.0);
+        (0.6 * closeness + 0.2 * options + 0.1 * depth + 0.1 * draws).clamp(0.0, 1.0)
+    }
+
+    /// Check the invariants every generated scenario should hold; an empty
+    /// result means the scenario is well-formed.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        let correct = self.answers.iter().filter(|a| a.is_correct).count();
+        if correct != 1 {
+            errors.push(ValidationError::CorrectAnswerCount(correct));
+        }
+        for (i, a) in self.answers.iter().enumerate() {
+            let first = self.answers.iter().position(|b| b.id == a.id) == Some(i);
+            let repeated = self.answers[i + 1..].iter().any(|b| b.id == a.id);
+            if first && repeated {
+                errors.push(ValidationError::DuplicateAnswerId(a.id.clone()));
+            }
+        }
+
+        let setup = &self.table_setup;
+        if setup.hero_hand[0] == setup.hero_hand[1] {
+            errors.push(ValidationError::DuplicateHoleCard(setup.hero_hand[0]));
+        }
+        errors.extend(
+            setup.board
+                .iter()
+                .filter(|c| setup.hero_hand.contains(c))
+                .map(|&c| ValidationError::BoardOverlapsHand(c)),
+        );
+        for (i, c) in setup.board.iter().enumerate() {
+            if setup.board[..i].contains(c) && !setup.board[i + 1..].contains(c) {
+                errors.push(ValidationError::DuplicateBoardCard(*c));
+            }
+        }
+        let expected = self.topic.street().board_card_count();
+        if setup.board.len() != expected {
+            errors.push(ValidationError::BoardLength { expected, found: setup.board.len() });
+        }
+
+        if self.question.is_empty() {
+            errors.push(ValidationError::EmptyQuestion);
+        }
+        if self.branch_key.is_empty() {
+            errors.push(ValidationError::EmptyBranchKey);
+        }
+        if setup.pot_size == 0 {
+            errors.push(ValidationError::EmptyPot);
+        }
+        errors
+    }
+}
+
+#[cfg(feature = "markdown")]
+impl TrainingScenario {
+    /// Render the scenario as Markdown: the question, hand and board in code
+    /// blocks, the options as a lettered list, and the correct answer with its
+    /// explanation inside a collapsed `<details>` block.
+    pub fn to_markdown(&self) -> String {
+        let cards = |cs: &[Card]| cs.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(" ");
+        let board = if self.table_setup.board.is_empty() {
+            "(preflop — no board)".to_string()
+        } else {
+            cards(&self.table_setup.board)
+        };
+        let options: String = self
+            .answers
+            .iter()
+            .map(|a| format!("- **{}.** {}\n", a.id, a.text))
+            .collect();
+        let correct = self.correct_answer();
+
+        format!(
+            "# {topic} — `{id}`\n\n\
+             ## Question\n\n{question}\n\n\
+             **Hand**\n\n```\n{hand}\n```\n\n\
+             **Board**\n\n```\n{board}\n```\n\n\
+             ## Answers\n\n{options}\n\
+             <details>\n<summary>Show answer</summary>\n\n\
+             **{cid}. {ctext}** — {explanation}\n\n</details>\n",
+            topic = self.topic,
+            id = self.scenario_id,
+            question = self.question,
+            hand = cards(&self.table_setup.hero_hand),
+            cid = correct.id,
+            ctext = correct.text,
+            explanation = correct.explanation,
+        )
+    }
+}
+
+impl TrainingScenario {
+    /// One Anki note in Anki's tab-separated import format: `front\tback`.
+    ///
+    /// The front is the question followed by the board and hero's hand; the
+    /// back is the correct option's text and explanation.  Tabs and line
+    /// breaks inside either side become spaces, so the row always has exactly
+    /// one tab.
+    pub fn to_anki_export(&self) -> String {
+        let flat = |s: &str| s.replace(['\t', '\n', '\r'], " ");
+        let cards = |cs: &[Card]| cs.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(" ");
+        let board = if self.table_setup.board.is_empty() {
+            "none (preflop)".to_string()
+        } else {
+            cards(&self.table_setup.board)
+        };
+        let hand = Hand::from(self.table_setup.hero_hand);
+        let correct = self.correct_answer();
+        format!(
+            "{} Board: {board}. Hand: {} ({}).\t{} — {}",
+            flat(&self.question),
+            cards(&self.table_setup.hero_hand),
+            hand.notation(),
+            flat(&correct.text),
+            flat(&correct.explanation),
+        )
+    }
+}
+
+impl TrainingScenario {
+    /// Column names for [`to_csv_row`](Self::to_csv_row), comma-separated,
+    /// without a trailing newline.
+    pub fn csv_headers() -> &'static str {
+        "scenario_id,topic,branch_key,difficulty,street,question,correct_answer_id"
+    }
+
+    /// Difficulty guessed from the effective stack, for exports that only
+    /// have the scenario.  Beginner spots mostly start at 100 BB, so 95–100 BB
+    /// left reads as Beginner; Intermediate stacks stay within 60–150 BB, and
+    /// anything shallower or deeper reads as Advanced.  Short-stack topics
+    /// use other bands, so treat this as a hint rather than the request's
+    /// actual level.
+    pub fn inferred_difficulty(&self) -> DifficultyLevel {
+        // Every generator plays a 2-chip big blind.
+        match self.table_setup.effective_stack() / 2 {
+            95..=100 => DifficultyLevel::Beginner,
+            60..=150 => DifficultyLevel::Intermediate,
+            _        => DifficultyLevel::Advanced,
+        }
+    }
+
+    /// One CSV record (RFC 4180, no trailing newline) in the
+    /// [`csv_headers`](Self::csv_headers) layout.  Fields holding a comma,
+    /// quote or line break are quoted, with inner quotes doubled.
+    /// Explanations are left out to keep rows short.
+    pub fn to_csv_row(&self) -> String {
+        let field = |s: &str| {
+            if s.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", s.replace('"', "\"\""))
+            } else {
+                s.to_string()
+            }
+        };
+        [
+            field(&self.scenario_id),
+            field(&self.topic.to_string()),
+            field(&self.branch_key),
+            self.inferred_difficulty().to_string(),
+            self.street().to_string(),
+            field(&self.question),
+            field(&self.correct_answer().id),
+        ]
+        .join(",")
+    }
+}
+
+/// An Anki import file: a `#separator:tab` / `#deck:PokerDrill` header, then
+/// one [`TrainingScenario::to_anki_export`] row per scenario.
+pub fn anki_deck_from_scenarios(scenarios: &[TrainingScenario]) -> String {
+    let mut out = String::from("#separator:tab\n#deck:PokerDrill\n");
+    for s in scenarios {
+        out.push_str(&s.to_anki_export());
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(feature = "bincode")]
+impl TrainingScenario {
+    /// Encode the scenario with `bincode` — much smaller than the JSON form
+    /// when storing many records.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, bincode::Error> {
+        bincode::serialize(self)
+    }
+
+    /// Decode a scenario produced by [`TrainingScenario::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<TrainingScenario, bincode::Error> {
+        bincode::deserialize(bytes)
+    }
 }
@@ -0,0 +1,40 @@
+//! Stateful topic selection for study schedules.
+//!
+//! [`TopicSelector`](super::models::TopicSelector) picks one topic per request
+//! and has no memory, so a random street selector can drill the same topic
+//! several times in a row.  [`BalancedSelector`] instead walks every topic in
+//! turn, so 38 consecutive requests cover each topic exactly once.
+
+use std::cell::Cell;
+
+use super::models::{DifficultyLevel, TextStyle, TrainingRequest, TrainingTopic};
+
+/// Hands out requests in round-robin order over [`TrainingTopic::ALL`].
+///
+/// The n-th request (counting from 0) uses seed `rng_seed_offset + n`, so a
+/// selector built with the same offset always yields the same schedule.
+#[derive(Debug, Clone, Default)]
+pub struct BalancedSelector {
+    rng_seed_offset: u64,
+    counter: Cell<usize>,
+}
+
+impl BalancedSelector {
+    pub fn new(rng_seed_offset: u64) -> Self {
+        Self { rng_seed_offset, counter: Cell::new(0) }
+    }
+
+    /// The next request in the rotation; advances the counter.
+    pub fn next_request(&self, difficulty: DifficultyLevel, style: TextStyle) -> TrainingRequest {
+        let n = self.counter.get();
+        self.counter.set(n.wrapping_add(1));
+        TrainingRequest {
+            topic: TrainingTopic::ALL[n % TrainingTopic::ALL.len()].into(),
+            difficulty,
+            rng_seed: Some(self.rng_seed_offset.wrapping_add(n as u64)),
+            text_style: style,
+            is_bounty: false,
+            excluded_topics: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Adaptive practice — difficulty that follows the player's results.
+//!
+//! An [`AdaptiveSession`] records every answered scenario in a
+//! [`HandHistory`] and keeps one [`DifficultyLevel`] per topic.  Five correct
+//! answers in a row on a topic move it one level harder; three misses in a row
+//! move it one level easier.  Correct answers to trivial spots (see
+//! [`TrainingScenario::complexity_score`]) do not count towards stepping up.
+//! Each new request drills the topic with the lowest recent accuracy.
+
+use std::collections::HashMap;
+
+use super::history::HandHistory;
+use super::models::{DifficultyLevel, TextStyle, TrainingRequest, TrainingScenario, TrainingTopic};
+
+/// Correct answers in a row that step a topic up one level.
+pub const STEP_UP_STREAK: u32 = 5;
+/// Wrong answers in a row that step a topic down one level.
+pub const STEP_DOWN_STREAK: u32 = 3;
+/// Complexity below which a correct answer keeps, but does not extend, a streak.
+pub const TRIVIAL_COMPLEXITY: f32 = 0.3;
+
+/// A practice session that picks the weakest topic and adjusts its difficulty.
+#[derive(Debug, Clone)]
+pub struct AdaptiveSession {
+    session: HandHistory,
+    difficulty_per_topic: HashMap<TrainingTopic, DifficultyLevel>,
+    /// Answers per topic considered when ranking accuracy.
+    window_size: usize,
+    /// Current run per topic: positive for correct answers, negative for misses.
+    streaks: HashMap<TrainingTopic, i32>,
+    next_seed: u64,
+    text_style: TextStyle,
+}
+
+impl AdaptiveSession {
+    /// Start at Beginner on every topic.  Requests are seeded `seed`,
+    /// `seed + 1`, … so a session replays identically.
+    pub fn new(window_size: usize, seed: u64, text_style: TextStyle) -> Self {
+        Self {
+            session: HandHistory::new(),
+            difficulty_per_topic: HashMap::new(),
+            window_size: window_size.max(1),
+            streaks: HashMap::new(),
+            next_seed: seed,
+            text_style,
+        }
+    }
+
+    /// Current difficulty for `topic`.
+    pub fn difficulty(&self, topic: TrainingTopic) -> DifficultyLevel {
+        self.difficulty_per_topic.get(&topic).copied().unwrap_or_default()
+    }
+
+    /// Every scenario recorded so far.
+    pub fn history(&self) -> &HandHistory {
+        &self.session
+    }
+
+    /// Fraction of the last `window_size` answers on `topic` that were
+    /// correct, or `None` if the topic has not been answered yet.
+    pub fn recent_accuracy(&self, topic: TrainingTopic) -> Option<f64> {
+        let recent: Vec<bool> = self
+            .session
+            .entries
+            .iter()
+            .rev()
+            .filter(|e| e.scenario.topic == topic && e.answer_id.is_some())
+            .take(self.window_size)
+            .map(|e| e.is_correct())
+            .collect();
+        if recent.is_empty() {
+            return None;
+        }
+        Some(recent.iter().filter(|&&c| c).count() as f64 / recent.len() as f64)
+    }
+
+    /// A request for the weakest topic at its current difficulty.
+    ///
+    /// Unanswered topics come first, then the lowest recent accuracy; ties go
+    /// to the earlier topic in [`TrainingTopic::ALL`].
+    pub fn next_request(&mut self) -> TrainingRequest {
+        let weakest = TrainingTopic::ALL
+            .into_iter()
+            .min_by(|&a, &b| {
+                let acc = |t| self.recent_accuracy(t).unwrap_or(-1.0);
+                acc(a).total_cmp(&acc(b))
+            })
+            .expect("at least one topic");
+        let seed = self.next_seed;
+        self.next_seed = self.next_seed.wrapping_add(1);
+        TrainingRequest {
+            topic: weakest.into(),
+            difficulty: self.difficulty(weakest),
+            rng_seed: Some(seed),
+            text_style: self.text_style,
+            is_bounty: false,
+            excluded_topics: Vec::new(),
+        }
+    }
+
+    /// Record the answer to `scenario` (`None` = skipped) and update the
+    /// topic's streak, stepping its difficulty when a streak completes.
+    ///
+    /// A correct answer to a trivial spot ends a run of misses but does not
+    /// add to a run of correct answers.
+    pub fn record(&mut self, scenario: TrainingScenario, answer_id: Option<&str>) {
+        let topic = scenario.topic;
+        let trivial = scenario.complexity_score() < TRIVIAL_COMPLEXITY;
+        self.session.record(scenario, answer_id);
+        let entry = self.session.entries.last().expect("just recorded");
+        if entry.answer_id.is_none() {
+            return;
+        }
+        let correct = entry.is_correct();
+        let current = self.difficulty(topic);
+
+        let streak = self.streaks.entry(topic).or_insert(0);
+        *streak = match (correct, *streak) {
+            (true, s) if trivial => s.max(0),
+            (true, s) if s > 0 => s + 1,
+            (true, _) => 1,
+            (false, s) if s < 0 => s - 1,
+            (false, _) => -1,
+        };
+
+        let stepped = if *streak >= STEP_UP_STREAK as i32 {
+            Some(current.harder())
+        } else if *streak <= -(STEP_DOWN_STREAK as i32) {
+            Some(current.easier())
+        } else {
+            None
+        };
+        if let Some(next) = stepped {
+            *streak = 0;
+            self.difficulty_per_topic.insert(topic, next.unwrap_or(current));
+        }
+    }
+}
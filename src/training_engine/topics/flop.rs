@@ -1,6 +1,8 @@
-//! Flop topic generators: c-bet, pot odds, check-raise, semi-bluff, 3-bet pot c-bet.
+//! Flop topic generators: c-bet, pot odds, check-raise, semi-bluff, 3-bet pot c-bet,
+//! stack-off, c-bet defense, exploitative play, protection bet, pot control,
+//! draw counting.
 //!
-//! All five topics deal a 3-card flop and ask hero what to do.  Board texture
+//! All eleven topics deal a 3-card flop and ask hero what to do.  Board texture
 //! (from `evaluator::board_texture`) is the primary driver for c-bet sizing.
 //! Draw classification (from `evaluator::classify_draw`) drives pot-odds,
 //! semi-bluff, and check-raise decisions.
@@ -18,15 +20,35 @@
 //!   the pot immediately or with a made hand on a later street.
 //! - **T13 3-Bet Pot C-Bet** — C-bet sizing in a 3-bet pot (smaller SPR, higher
 //!   stakes): bet on favourable textures, check back weak hands.
+//! - **T17 Stack-Off** — At SPR 1.5–4 in a 3-bet pot, compare the equity needed
+//!   to call a shove after betting with hero's equity: commit or bet-fold.
+//! - **T19 C-Bet Defense** — BB facing a BTN c-bet: the T7 decision framed as
+//!   the defender, with the minimum defense frequency for the bet size.
+//! - **T22 Exploitative Play** — A villain read (over-folder, calling station,
+//!   never bluffs, overbets only the nuts) changes the balanced answer.
+//! - **T28 Protection Bet** — OOP with top pair: check dry boards, bet 50% into
+//!   one board draw and 75% into two or more to deny free cards.
+//! - **T30 Pot Control** — IP with top pair, weak kicker: check back at SPR 3–6,
+//!   where the hand cannot call a shove after betting; bet below SPR 2.
+//! - **T35 Draw Counting** — Name the out count of hero's draw: gutshot 4,
+//!   open-ender 8, flush draw 9, combo draw 15.
 
 use rand::Rng;
 use crate::training_engine::{
+    deck::CardDealer,
     evaluator::{
-        board_texture, classify_draw, draw_equity_flop, has_flush_draw, has_straight_draw,
-        hero_has_flush_draw, hero_has_straight_draw, required_equity,
-        BoardTexture, DrawType,
+        board_draw_count, board_texture, break_even_equity, calculate_pot_odds, cbet_size_recommendation,
+        classify_board_paired, classify_hero_hand_vs_board, classify_draw,
+        classify_draw_combined, commit_threshold_spr, count_outs, adjusted_equity, draw_equity_flop,
+        equity_from_outs, equity_of_combined_draws, ev_of_bet, expected_value,
+        has_backdoor_flush_draw, has_flush_draw, has_backdoor_straight_draw, hero_has_flush_draw, hero_has_straight_draw, implied_odds,
+        minimum_defense_frequency, out_cards, pot_committed, range_advantage, set_mining_ev,
+        villain_range_width, BoardTexture, DrawType, HandVsBoardStrength, RangeAdvantage,
+    },
+    helpers::{
+        answer, board_str, deal, deal_draw, fold_call_raise_rating, hand_str, heads_up, key_concepts,
+        ladder_rating, pot_after_call, scenario,
     },
-    helpers::{board_str, deal, hand_str},
     models::*,
 };
 
@@ -45,17 +67,51 @@ fn draw_simple_label(dt: DrawType) -> &'static str {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// Shared helper — the draw a T3 / T8 spot is built around
+// Hero's own draw from classify_draw_combined, with flush + straight merged
+// into ComboDraw.  Random hole cards often miss the board entirely; those
+// spots keep the board's draw (classify_draw), as the topics always did.
+// ═══════════════════════════════════════════════════════════════════════════════
+
+fn spot_draw_type(hand: [Card; 2], board: &[Card]) -> DrawType {
+    let draws = classify_draw_combined(hand, board);
+    let flush = draws.contains(&DrawType::FlushDraw);
+    match draws.iter().find(|&&d| d != DrawType::FlushDraw) {
+        Some(_) if flush => DrawType::ComboDraw,
+        Some(&straight) => straight,
+        None if flush => DrawType::FlushDraw,
+        None => classify_draw(board),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Shared helper — backdoor draw note for Technical text style
+// Used by generate_cbet (T2) and generate_check_raise (T7): runner-runner
+// draws add a few percent of equity that shifts close betting decisions.
+// ═══════════════════════════════════════════════════════════════════════════════
+
+fn backdoor_note(hand: [Card; 2], board: &[Card]) -> &'static str {
+    match (has_backdoor_flush_draw(hand, board), has_backdoor_straight_draw(hand, board)) {
+        (true, true)   => " You also hold backdoor flush and straight draws (~4% extra equity).",
+        (true, false)  => " You also hold a backdoor flush draw (~2–4% extra equity).",
+        (false, true)  => " You also hold a backdoor straight draw (~2–4% extra equity).",
+        (false, false) => "",
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // T2 — Postflop Continuation Bet (CB-)
 // ═══════════════════════════════════════════════════════════════════════════════
 
-pub fn generate_cbet<R: Rng>(
+pub fn generate_cbet<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let (hero_hand, board) = deal(rng, 3);
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, board) = deal(dealer, 3)?;
 
     let texture = board_texture(&board);
 
@@ -78,6 +134,7 @@ pub fn generate_cbet<R: Rng>(
             stack,
             is_hero: false,
             is_active: true,
+            bounty: None,
         },
         PlayerState {
             seat: 2,
@@ -85,12 +142,12 @@ pub fn generate_cbet<R: Rng>(
             stack,
             is_hero: true,
             is_active: true,
+            bounty: None,
         },
     ];
 
-    // Range advantage flag (simplified: hero has range adv from late pos on low boards)
-    let lowest_rank = board.iter().map(|c| c.rank.0).min().unwrap_or(14);
-    let hero_has_range_adv = hero_pos.is_late() && lowest_rank <= 8;
+    // The BB defends wide, so hero's CO/BTN range only dominates A- and K-high boards.
+    let hero_has_range_adv = range_advantage(hero_pos, Position::BB, &board) == RangeAdvantage::Hero;
 
     let board_s = board_str(&board);
     let hand_s = hand_str(hero_hand);
@@ -125,11 +182,30 @@ pub fn generate_cbet<R: Rng>(
         ),
     };
 
-    let answers = build_cbet_answers(
+    let spr = stack as f32 / pot as f32;
+    let recommended = cbet_size_recommendation(texture, spr, hero_pos);
+    let mut answers = build_cbet_answers(
         &hand_s, &pos_str, &texture_str, &board_s,
-        texture.clone(), hero_has_range_adv, pot, stack_bb, difficulty,
+        texture, hero_has_range_adv, pot, stack_bb, spr, recommended, difficulty,
         text_style,
     );
+    if text_style == TextStyle::Technical {
+        let mut note = format!(
+            "{} Across your whole range, c-bet about {:.0}% of {texture_str} boards.",
+            backdoor_note(hero_hand, &board),
+            texture.cbet_frequency() * 100.0
+        );
+        if let Some(r) = classify_board_paired(&board) {
+            note.push_str(&format!(
+                " The board is paired ({}s): few hands connect with it, so neither range \
+                 holds many two pairs or sets.",
+                Rank(r)
+            ));
+        }
+        for a in &mut answers {
+            a.explanation.push_str(&note);
+        }
+    }
 
     let table_setup = TableSetup {
         game_type: GameType::CashGame,
@@ -139,18 +215,22 @@ pub fn generate_cbet<R: Rng>(
         players,
         pot_size: pot,
         current_bet: 0,
+        antes: None,
     };
 
-    TrainingScenario {
+    Ok(TrainingScenario {
         scenario_id,
         topic: TrainingTopic::PostflopContinuationBet,
         branch_key,
         table_setup,
         question,
         answers,
-    }
+        key_concepts: key_concepts(TrainingTopic::PostflopContinuationBet, &[]),
+        villain_tendency: None,
+    })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_cbet_answers(
     hand_str: &str,
     pos_str: &str,
@@ -160,19 +240,59 @@ fn build_cbet_answers(
     hero_range_adv: bool,
     pot: u32,
     stack_bb: u32,
+    spr: f32,
+    recommended: f32,
     _difficulty: DifficultyLevel,
     text_style: TextStyle,
 ) -> Vec<AnswerOption> {
-    // On dry boards with range advantage, 33% is often optimal.
-    // On wet boards, larger sizing or check is better.
+    // When betting is right, the size is the option nearest the recommended
+    // fraction (see `cbet_size_recommendation`): ~33% on dry boards, ~75% on
+    // wet ones, larger again at low SPR.  Dry boards without range advantage
+    // are checked.
+    let bet_id = [("B", 0.33f32), ("C", 0.75), ("D", 1.25)]
+        .into_iter()
+        .min_by(|a, b| (a.1 - recommended).abs().total_cmp(&(b.1 - recommended).abs()))
+        .map_or("B", |(id, _)| id);
     let (correct_id, check_rationale, small_rationale, large_rationale, overbet_rationale) =
         match texture {
+            BoardTexture::Dry if hero_range_adv && bet_id != "B" => (
+                bet_id,
+                match text_style {
+                    TextStyle::Simple => "Checking wastes your edge. With so few chips left behind, bet big now and get the money in.".to_string(),
+                    TextStyle::Technical => format!(
+                        "Checking with {hand_str} on a {texture_str} board ({board_str}) wastes your \
+                         range advantage from {pos_str}. At SPR {spr:.1} you should bet big enough \
+                         to commit the stack by the river."
+                    ),
+                },
+                match text_style {
+                    TextStyle::Simple => "A small bet is too little here. With so few chips left behind, a bigger bet sets up getting all-in by the river.".to_string(),
+                    TextStyle::Technical => format!(
+                        "A 33% pot c-bet is too small at SPR {spr:.1}. It leaves an awkward stack \
+                         behind, where a larger bet sets up a natural all-in over the next two streets."
+                    ),
+                },
+                match text_style {
+                    TextStyle::Simple => "Correct. Bet big. The board is dry and you have the edge, and with so few chips left behind a big bet puts the rest in play.".to_string(),
+                    TextStyle::Technical => format!(
+                        "A 75% pot c-bet is correct at SPR {spr:.1}. With range advantage on a \
+                         {texture_str} board the recommended size rises to ~{:.0}% pot, enough to \
+                         commit the shallow stack.",
+                        recommended * 100.0
+                    ),
+                },
+                match text_style {
+                    TextStyle::Simple => "Overbetting is more than you need — a big bet already commits your chips.".to_string(),
+                    TextStyle::Technical => format!(
+                        "An overbet on a {texture_str} board is more than needed: at SPR {spr:.1} a \
+                         75% pot bet already commits the stack while keeping villain's weaker hands in."
+                    ),
+                },
+            ),
             BoardTexture::Dry if hero_range_adv => (
                 "B",
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "Checking gives your opponent a free card. You're in a strong position here — a small bet is the better play."
-                    ),
+                    TextStyle::Simple => "Checking gives your opponent a free card. You're in a strong position here — a small bet is the better play.".to_string(),
                     TextStyle::Technical => format!(
                         "Checking with {hand_str} on a {texture_str} board ({board_str}) sacrifices \
                          fold equity and gives villain a free card. From {pos_str} with range \
@@ -180,9 +300,7 @@ fn build_cbet_answers(
                     ),
                 },
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "Correct. A small bet works well here. The board is dry (no likely draws), so a cheap bet is enough to keep the pressure on and collect chips."
-                    ),
+                    TextStyle::Simple => "Correct. A small bet works well here. The board is dry (no likely draws), so a cheap bet is enough to keep the pressure on and collect chips.".to_string(),
                     TextStyle::Technical => format!(
                         "A 33% pot c-bet on a {texture_str} board is correct here. It exploits your \
                          range advantage from {pos_str}, applies pressure at low risk, and denies \
@@ -190,9 +308,7 @@ fn build_cbet_answers(
                     ),
                 },
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "Betting this big on a dry board is too much. A small bet gets the same job done for less risk."
-                    ),
+                    TextStyle::Simple => "Betting this big on a dry board is too much. A small bet gets the same job done for less risk.".to_string(),
                     TextStyle::Technical => format!(
                         "A 75% pot sizing on a {texture_str} board is unnecessarily large. Villain \
                          folds hands you beat and calls with hands that have equity, making this \
@@ -200,9 +316,7 @@ fn build_cbet_answers(
                     ),
                 },
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "Overbetting on a dry board is too aggressive — a small bet achieves the same goal more cheaply."
-                    ),
+                    TextStyle::Simple => "Overbetting on a dry board is too aggressive — a small bet achieves the same goal more cheaply.".to_string(),
                     TextStyle::Technical => format!(
                         "An overbet on a {texture_str} board from {pos_str} is exploitable. \
                          Villain's calling range will have enough equity against an overbet that \
@@ -213,9 +327,7 @@ fn build_cbet_answers(
             BoardTexture::Dry => (
                 "A",
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "Correct. Check here. The board is dry (no likely draws) and you don't have a big advantage. A free card costs you little and lets you see how the hand develops."
-                    ),
+                    TextStyle::Simple => "Correct. Check here. The board is dry (no likely draws) and you don't have a big advantage. A free card costs you little and lets you see how the hand develops.".to_string(),
                     TextStyle::Technical => format!(
                         "Checking with {hand_str} on a {texture_str} board ({board_str}) from \
                          {pos_str} is correct when you lack clear range advantage. A check allows \
@@ -223,9 +335,7 @@ fn build_cbet_answers(
                     ),
                 },
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "A small bet can work but checking is safer here — you don't have a clear advantage on this board."
-                    ),
+                    TextStyle::Simple => "A small bet can work but checking is safer here — you don't have a clear advantage on this board.".to_string(),
                     TextStyle::Technical => format!(
                         "A small c-bet can work here but without range advantage you may be \
                          building a pot when your range is symmetric with villain's. Checking \
@@ -233,9 +343,7 @@ fn build_cbet_answers(
                     ),
                 },
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "Betting big here over-commits your chips. Check or fold is better when you don't have the advantage."
-                    ),
+                    TextStyle::Simple => "Betting big here over-commits your chips. Check or fold is better when you don't have the advantage.".to_string(),
                     TextStyle::Technical => format!(
                         "A 75% pot c-bet on a {texture_str} board without range advantage \
                          over-commits with a polarized-looking line when your range may not \
@@ -243,9 +351,7 @@ fn build_cbet_answers(
                     ),
                 },
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "Overbetting without an advantage on this board is a big mistake. Check instead."
-                    ),
+                    TextStyle::Simple => "Overbetting without an advantage on this board is a big mistake. Check instead.".to_string(),
                     TextStyle::Technical => format!(
                         "Overbetting a {texture_str} board without a clear nut advantage is a \
                          leak. Reserve overbets for boards where your range is significantly \
@@ -254,11 +360,9 @@ fn build_cbet_answers(
                 },
             ),
             BoardTexture::SemiWet | BoardTexture::Wet => (
-                "C",
+                bet_id,
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "Checking here lets your opponent draw to a better hand for free. Bet to make them pay."
-                    ),
+                    TextStyle::Simple => "Checking here lets your opponent draw to a better hand for free. Bet to make them pay.".to_string(),
                     TextStyle::Technical => format!(
                         "Checking on a {texture_str} board ({board_str}) surrenders too much \
                          equity and gives villain free cards with flush/straight draws. From \
@@ -266,9 +370,7 @@ fn build_cbet_answers(
                     ),
                 },
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "A small bet is too cheap — your opponent can afford to call and try to improve. Bet bigger to make it expensive."
-                    ),
+                    TextStyle::Simple => "A small bet is too cheap — your opponent can afford to call and try to improve. Bet bigger to make it expensive.".to_string(),
                     TextStyle::Technical => format!(
                         "A 33% pot bet on a {texture_str} board is too small — it gives villain \
                          correct pot odds to call with flush draws (~35% equity) without paying \
@@ -276,22 +378,18 @@ fn build_cbet_answers(
                     ),
                 },
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "Correct. Bet big! The board has draws (possible flush or straight). Make your opponent pay a lot to try to beat you."
-                    ),
+                    TextStyle::Simple => "Correct. Bet big! The board has draws (possible flush or straight). Make your opponent pay a lot to try to beat you.".to_string(),
                     TextStyle::Technical => format!(
                         "A 75% pot c-bet on a {texture_str} board is correct. It charges draws \
                          incorrect pot odds ({:.0}% required equity vs ~35% actual for flush draw), \
                          protects your hand, and maintains fold equity against weak pairs.",
-                        crate::training_engine::evaluator::required_equity(
+                        break_even_equity(
                             (pot as f32 * 0.75) as u32, pot
                         ) * 100.0
                     ),
                 },
                 match text_style {
-                    TextStyle::Simple => format!(
-                        "An overbet on the first three cards is usually too much too soon. A big bet (75%) already does the job."
-                    ),
+                    TextStyle::Simple => "An overbet on the first three cards is usually too much too soon. A big bet (75%) already does the job.".to_string(),
                     TextStyle::Technical => format!(
                         "An overbet on a {texture_str} board with {stack_bb} BB remaining can \
                          be used as a polarized bluff, but is generally reserved for the river \
@@ -302,29 +400,42 @@ fn build_cbet_answers(
             ),
         };
 
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Look at how connected the cards are and whose hands they are likely to help.",
+        TextStyle::Technical => "Consider: board texture and range advantage.",
+    }.to_string());
+
     vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Check".to_string(),
             is_correct: correct_id == "A",
+            rating: ladder_rating("A", correct_id),
+            hint: hint.clone(),
             explanation: check_rationale,
         },
         AnswerOption {
             id: "B".to_string(),
             text: "Bet small".to_string(),
             is_correct: correct_id == "B",
+            rating: ladder_rating("B", correct_id),
+            hint: hint.clone(),
             explanation: small_rationale,
         },
         AnswerOption {
             id: "C".to_string(),
             text: "Bet large".to_string(),
             is_correct: correct_id == "C",
+            rating: ladder_rating("C", correct_id),
+            hint: hint.clone(),
             explanation: large_rationale,
         },
         AnswerOption {
             id: "D".to_string(),
             text: "Overbet".to_string(),
             is_correct: false,
+            rating: ladder_rating("D", correct_id),
+            hint: hint.clone(),
             explanation: overbet_rationale,
         },
     ]
@@ -344,23 +455,16 @@ fn pot_odds_equity(draw: DrawType, streets: u8) -> f32 {
     }
 }
 
-pub fn generate_pot_odds<R: Rng>(
+pub fn generate_pot_odds<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let (hero_hand, board) = deal(rng, 3);
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, board) = deal(dealer, 3)?;
 
-    // Determine draw type from the actual board (best effort) or assign randomly
-    let flush = has_flush_draw(&board);
-    let straight = has_straight_draw(&board);
-    let draw_type = match (flush, straight) {
-        (true, true)  => DrawType::ComboDraw,
-        (true, false) => DrawType::FlushDraw,
-        (false, true) => DrawType::OESD,
-        _             => DrawType::GutShot,
-    };
+    let draw_type = spot_draw_type(hero_hand, &board);
 
     let bb = 2u32;
     let (pot_bb, bet_pct) = match difficulty {
@@ -380,9 +484,22 @@ pub fn generate_pot_odds<R: Rng>(
     let bet = (pot as f32 * bet_pct).round() as u32;
     let streets_remaining: u8 = 2; // flop scenario, two streets to come
 
-    let req_eq = required_equity(bet, pot);
-    let actual_eq = pot_odds_equity(draw_type, streets_remaining);
-    let should_call = actual_eq >= req_eq;
+    let odds = calculate_pot_odds(bet, pot);
+    let req_eq = odds.break_even_equity;
+    // Two draws at once overlap, so their combined equity is below the sum.
+    let hero_draws = classify_draw_combined(hero_hand, &board);
+    let actual_eq = if hero_draws.len() > 1 {
+        equity_of_combined_draws(&hero_draws, streets_remaining)
+    } else {
+        pot_odds_equity(draw_type, streets_remaining)
+    };
+    // Hero defends the big blind out of position and realizes less of the draw.
+    let stack = 200u32;
+    let stack_bb = stack / bb;
+    let realized_eq = adjusted_equity(actual_eq, false, stack_bb);
+    let should_call = realized_eq >= req_eq;
+    // Same model as req_eq: win `pot`, risk `bet` — positive exactly when calling is right.
+    let call_ev = expected_value(realized_eq, pot, bet);
 
     let draw_name = match draw_type {
         DrawType::FlushDraw => "FlushDraw",
@@ -398,6 +515,32 @@ pub fn generate_pot_odds<R: Rng>(
 
     let draw_type_label = format!("{}", draw_type);
     let draw_type_simple_label = draw_simple_label(draw_type);
+    let villain_pos = Position::BTN;
+    // Villain opened from the Button: a wide range bets the flop with plenty of air.
+    let range_note = format!(
+        " Villain's {villain_pos} opening range is wide (~{:.0}% of hands), so the bet \
+         does not always mean a made hand.",
+        villain_range_width(villain_pos) * 100.0
+    );
+
+    // Implied odds: count one more bet of the same size won when the draw hits.
+    let implied_eq = implied_odds(bet, pot, bet);
+    let mut implied_note = format!(
+        " Implied odds: winning one more {bet}-chip bet when you hit would lower the \
+         requirement to {:.1}% ({bet}/{}), but that payoff is not guaranteed, so the \
+         direct price decides.",
+        implied_eq * 100.0,
+        pot + 2 * bet
+    );
+    if Hand::from(hero_hand).is_pair() {
+        let ev = set_mining_ev(bet, pot, stack, 1);
+        implied_note.push_str(&format!(
+            " For comparison, set mining with this pair preflop for the same {bet} chips \
+             heads-up with {stack} behind is worth {ev:+.1} chips — the 5% rule wants a call \
+             of at most {} chips and two or more opponents.",
+            stack / 20
+        ));
+    }
 
     let question = match text_style {
         TextStyle::Simple => format!(
@@ -424,19 +567,24 @@ pub fn generate_pot_odds<R: Rng>(
             )
         },
         TextStyle::Technical => format!(
-            "Call analysis: Pot after call = {} chips. You are calling {bet} chips. \
-             Required equity = {bet}/{} = {:.1}%. \
-             Approximate {draw_type_label} equity with 2 streets = {:.1}%. \
-             {} Therefore calling {} correct here.",
-            pot + bet,
-            pot + bet,
+            "Call analysis: Pot after call = {} chips. You are calling {bet} chips \
+             into {pot}: pot odds {odds}. Required equity = {bet}/{} = {:.1}%. \
+             Approximate {draw_type_label} equity with 2 streets = {:.1}%; out of \
+             position {stack_bb} BB deep you realize only about {:.1}%. \
+             {} EV of calling = {:.1}% × {pot} − {:.1}% × {bet} = {call_ev:+.1} chips. \
+             Therefore calling {} correct here.{range_note}{implied_note}",
+            pot_after_call(pot, bet),
+            pot_after_call(pot, bet),
             req_eq * 100.0,
             actual_eq * 100.0,
+            realized_eq * 100.0,
             if should_call {
-                "Your equity EXCEEDS the required equity."
+                "Your realized equity EXCEEDS the required equity."
             } else {
-                "Your equity is BELOW the required equity."
+                "Your realized equity is BELOW the required equity."
             },
+            realized_eq * 100.0,
+            (1.0 - realized_eq) * 100.0,
             if should_call { "IS" } else { "is NOT" },
         ),
     };
@@ -448,17 +596,17 @@ pub fn generate_pot_odds<R: Rng>(
                 actual_eq * 100.0
             )
         } else {
-            format!(
-                "Folding is wrong here — your hand has a good enough chance of improving to make this call worth it."
-            )
+            "Folding is wrong here — your hand has a good enough chance of improving to make this call worth it.".to_string()
         },
         TextStyle::Technical => format!(
             "Fold analysis: You need {:.1}% equity to call (calling {bet} into a pot of {} chips). \
-             Your {draw_type_label} has approximately {:.1}% equity with 2 cards to come. \
-             {} Folding {} correct.",
+             Your {draw_type_label} has approximately {:.1}% equity with 2 cards to come, \
+             about {:.1}% realized out of position. \
+             {} Folding {} correct.{range_note}{implied_note}",
             req_eq * 100.0,
-            pot + bet,
+            pot_after_call(pot, bet),
             actual_eq * 100.0,
+            realized_eq * 100.0,
             if !should_call {
                 "Since your equity is below the break-even threshold, folding preserves chips."
             } else {
@@ -469,27 +617,43 @@ pub fn generate_pot_odds<R: Rng>(
         ),
     };
 
+    // A draw within a few points of break-even is only a marginal error.
+    let miss = if (realized_eq - req_eq).abs() < 0.05 {
+        AnswerRating::Plausible
+    } else {
+        AnswerRating::Mistake
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Compare how often your draw comes in with the price you are being asked to pay.",
+        TextStyle::Technical => "Consider: required equity (call / final pot) versus draw equity.",
+    }.to_string());
+
     let answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Call".to_string(),
             is_correct: should_call,
+            rating: if should_call { AnswerRating::Correct } else { miss },
+            hint: hint.clone(),
             explanation: call_explanation,
         },
         AnswerOption {
             id: "B".to_string(),
             text: "Fold".to_string(),
             is_correct: !should_call,
+            rating: if !should_call { AnswerRating::Correct } else { miss },
+            hint: hint.clone(),
             explanation: fold_explanation,
         },
     ];
 
     let players = vec![
         PlayerState {
-            seat: 1, position: Position::BTN, stack: 200, is_hero: false, is_active: true,
+            seat: 1, position: villain_pos, stack, is_hero: false, is_active: true, bounty: None,
         },
         PlayerState {
-            seat: 2, position: hero_pos, stack: 200, is_hero: true, is_active: true,
+            seat: 2, position: hero_pos, stack, is_hero: true, is_active: true, bounty: None,
         },
     ];
 
@@ -501,16 +665,20 @@ pub fn generate_pot_odds<R: Rng>(
         players,
         pot_size: pot,
         current_bet: bet,
+        antes: None,
     };
 
-    TrainingScenario {
+    let set_mining: &[&str] = if Hand::from(hero_hand).is_pair() { &["set_mining"] } else { &[] };
+    Ok(TrainingScenario {
         scenario_id,
         topic: TrainingTopic::PotOddsAndEquity,
         branch_key,
         table_setup,
         question,
         answers,
-    }
+        key_concepts: key_concepts(TrainingTopic::PotOddsAndEquity, set_mining),
+        villain_tendency: None,
+    })
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -569,13 +737,14 @@ fn is_combo_draw(hand: [Card; 2], board: &[Card]) -> bool {
     hero_has_flush_draw(hand, board) && hero_has_straight_draw(hand, board)
 }
 
-pub fn generate_check_raise<R: Rng>(
+pub fn generate_check_raise<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let (hero_hand, board) = deal(rng, 3);
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, board) = deal(dealer, 3)?;
 
     let board_favour = classify_board(&board);
     let interaction = classify_hand_interaction(hero_hand, &board);
@@ -652,13 +821,9 @@ pub fn generate_check_raise<R: Rng>(
     let fold_exp = match text_style {
         TextStyle::Simple => if matches!((board_favour, interaction),
             (BoardFavour::IPFavorable, HandInteraction::Weak)) {
-            format!(
-                "Correct — fold. You have nothing and the cards favour your opponent's hand. Putting more chips in would be throwing them away."
-            )
+            "Correct — fold. You have nothing and the cards favour your opponent's hand. Putting more chips in would be throwing them away.".to_string()
         } else {
-            format!(
-                "Folding here is too cautious — you have enough of a hand to continue. Call or raise."
-            )
+            "Folding here is too cautious — you have enough of a hand to continue. Call or raise.".to_string()
         },
         TextStyle::Technical => if matches!((board_favour, interaction),
             (BoardFavour::IPFavorable, HandInteraction::Weak)) {
@@ -683,13 +848,9 @@ pub fn generate_check_raise<R: Rng>(
             )
         } else if matches!((board_favour, interaction),
             (BoardFavour::BBFavorable, HandInteraction::Strong)) {
-            format!(
-                "Just calling here leaves money on the table. You have a strong hand on a board that favours you — raise to build the pot!"
-            )
+            "Just calling here leaves money on the table. You have a strong hand on a board that favours you — raise to build the pot!".to_string()
         } else {
-            format!(
-                "Just calling here is too passive. With a powerful draw, raise to put maximum pressure on your opponent."
-            )
+            "Just calling here is too passive. With a powerful draw, raise to put maximum pressure on your opponent.".to_string()
         },
         TextStyle::Technical => if correct == "B" {
             format!(
@@ -723,12 +884,8 @@ pub fn generate_check_raise<R: Rng>(
             (_, HandInteraction::Draw, "C") => format!(
                 "Correct — raise to {cr_size} chips! You have a powerful draw with about a 54% chance of winning. Raising wins the pot immediately if your opponent folds, and builds a big pot when they call."
             ),
-            (BoardFavour::IPFavorable, _, _) => format!(
-                "Raising here is a bluff into your opponent's strong card range. They're unlikely to fold and you risk a lot of chips with a weak hand."
-            ),
-            _ => format!(
-                "Raising without a very strong hand or a powerful draw is too aggressive here. Call instead."
-            ),
+            (BoardFavour::IPFavorable, _, _) => "Raising here is a bluff into your opponent's strong card range. They're unlikely to fold and you risk a lot of chips with a weak hand.".to_string(),
+            _ => "Raising without a very strong hand or a powerful draw is too aggressive here. Call instead.".to_string(),
         },
         TextStyle::Technical => match (board_favour, interaction, correct) {
             (BoardFavour::BBFavorable, HandInteraction::Strong, "C") => format!(
@@ -759,23 +916,48 @@ pub fn generate_check_raise<R: Rng>(
         },
     };
 
+    let (fold_exp, call_exp, cr_exp) = match text_style {
+        TextStyle::Simple    => (fold_exp, call_exp, cr_exp),
+        TextStyle::Technical => {
+            let note = backdoor_note(hero_hand, &board);
+            (fold_exp + note, call_exp + note, cr_exp + note)
+        }
+    };
+
+    // Check-raising into an IP-favourable board is worse than a passive error.
+    let rate = |id: &str| match (correct, id) {
+        ("B", "C") if board_favour == BoardFavour::IPFavorable => AnswerRating::Mistake,
+        _ => fold_call_raise_rating(id, correct, AnswerRating::Mistake),
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Who do these cards help more — you or the player who bet? And how strong is your hand?",
+        TextStyle::Technical => "Consider: which range the board favours and how your hand interacts with it.",
+    }.to_string());
+
     let answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Fold".to_string(),
             is_correct: correct == "A",
+            rating: rate("A"),
+            hint: hint.clone(),
             explanation: fold_exp,
         },
         AnswerOption {
             id: "B".to_string(),
             text: "Call".to_string(),
             is_correct: correct == "B",
+            rating: rate("B"),
+            hint: hint.clone(),
             explanation: call_exp,
         },
         AnswerOption {
             id: "C".to_string(),
             text: format!("Raise to {} BB", cr_size / bb),
             is_correct: correct == "C",
+            rating: rate("C"),
+            hint: hint.clone(),
             explanation: cr_exp,
         },
     ];
@@ -787,6 +969,7 @@ pub fn generate_check_raise<R: Rng>(
             stack,
             is_hero: true,
             is_active: true,
+            bounty: None,
         },
         PlayerState {
             seat: 2,
@@ -794,6 +977,7 @@ pub fn generate_check_raise<R: Rng>(
             stack,
             is_hero: false,
             is_active: true,
+            bounty: None,
         },
     ];
 
@@ -805,31 +989,35 @@ pub fn generate_check_raise<R: Rng>(
         players,
         pot_size: pot,
         current_bet: villain_bet,
+        antes: None,
     };
 
-    TrainingScenario {
+    Ok(TrainingScenario {
         scenario_id,
         topic: TrainingTopic::CheckRaiseSpot,
         branch_key,
         table_setup,
         question,
         answers,
-    }
+        key_concepts: key_concepts(TrainingTopic::CheckRaiseSpot, &[]),
+        villain_tendency: None,
+    })
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // T8 — Semi-Bluff Decision (SB-)
 // ═══════════════════════════════════════════════════════════════════════════════
 
-pub fn generate_semi_bluff<R: Rng>(
+pub fn generate_semi_bluff<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let (hero_hand, board) = deal(rng, 3);
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, board) = deal(dealer, 3)?;
 
-    let draw_type = classify_draw(&board);
+    let draw_type = spot_draw_type(hero_hand, &board);
 
     let bb = 2u32;
     let (stack_bb, pot_bb) = match difficulty {
@@ -861,6 +1049,10 @@ pub fn generate_semi_bluff<R: Rng>(
     let hand_s = hand_str(hero_hand);
     let pos_str = format!("{}", hero_pos);
     let equity = draw_equity_flop(draw_type);
+    // Calling wins the pot plus villain's bet; the raise assumes villain folds ~40% of the time.
+    let facing_pot = pot_after_call(pot, villain_bet);
+    let call_ev = expected_value(equity, facing_pot, villain_bet);
+    let raise_ev = ev_of_bet(0.40, facing_pot, raise_size, equity);
     let position_label = if hero_is_ip { "in position" } else { "out of position" };
     let position_label_simple = if hero_is_ip { "acting last (good position)" } else { "acting first (tough position)" };
 
@@ -901,9 +1093,7 @@ pub fn generate_semi_bluff<R: Rng>(
 
     let fold_exp = match text_style {
         TextStyle::Simple => if matches!(draw_type, DrawType::GutShot) {
-            format!(
-                "Correct — fold. An inside straight draw only wins about 17% of the time (roughly 1 in 6). The price to call is too high for those odds. Save your chips."
-            )
+            "Correct — fold. An inside straight draw only wins about 17% of the time (roughly 1 in 6). The price to call is too high for those odds. Save your chips.".to_string()
         } else {
             format!(
                 "Folding is a mistake — your {draw_type_simple_label} wins often enough to continue."
@@ -913,11 +1103,11 @@ pub fn generate_semi_bluff<R: Rng>(
             format!(
                 "Correct. A gutshot (~17% equity) gives you roughly 4 outs. \
                  To call {villain_bet} chips into a {}-chip pot you need {:.1}% equity — \
-                 your draw falls well short at 17%. Even with implied odds, a gutshot \
-                 rarely justifies the call, and raising as a semi-bluff risks too many \
-                 chips with insufficient raw equity.",
-                pot + villain_bet,
-                villain_bet as f32 / (pot + villain_bet) as f32 * 100.0
+                 your draw falls well short at 17% (EV of calling: {call_ev:+.1} chips). \
+                 Even with implied odds, a gutshot rarely justifies the call, and raising \
+                 as a semi-bluff risks too many chips with insufficient raw equity.",
+                facing_pot,
+                villain_bet as f32 / facing_pot as f32 * 100.0
             )
         } else {
             format!(
@@ -931,12 +1121,8 @@ pub fn generate_semi_bluff<R: Rng>(
 
     let call_exp = match text_style {
         TextStyle::Simple => match (draw_type, hero_is_ip, correct) {
-            (DrawType::FlushDraw, true, "B") => format!(
-                "Correct — call. You have a flush draw (~35% chance) and you're in good position (acting last). Call and see the next card — if you hit your flush you can bet big."
-            ),
-            (DrawType::FlushDraw, false, "B") | (DrawType::OESD, _, "B") => format!(
-                "Correct — call. Your draw wins enough of the time to make calling worth it here. Just calling is safer than raising when you're acting first."
-            ),
+            (DrawType::FlushDraw, true, "B") => "Correct — call. You have a flush draw (~35% chance) and you're in good position (acting last). Call and see the next card — if you hit your flush you can bet big.".to_string(),
+            (DrawType::FlushDraw, false, "B") | (DrawType::OESD, _, "B") => "Correct — call. Your draw wins enough of the time to make calling worth it here. Just calling is safer than raising when you're acting first.".to_string(),
             _ => format!(
                 "Calling is an option, but raising with your {draw_type_simple_label} puts more pressure on your opponent and wins the pot more often."
             ),
@@ -945,15 +1131,16 @@ pub fn generate_semi_bluff<R: Rng>(
             (DrawType::FlushDraw, true, "B") => format!(
                 "Correct. Calling with a {draw_type_label} (~{:.0}% equity) from {pos_str} (IP) is \
                  the best play. You have position to control the pot on future streets — check \
-                 back or bet when you hit, give up cheaply when you miss. Raising risks bloating \
-                 the pot without the positional advantage needed to navigate it well.",
+                 back or bet when you hit, give up cheaply when you miss (EV of calling: \
+                 {call_ev:+.1} chips). Raising risks bloating the pot without the positional \
+                 advantage needed to navigate it well.",
                 equity * 100.0
             ),
             (DrawType::FlushDraw, false, "B") | (DrawType::OESD, _, "B") => format!(
                 "Correct. Calling with a {draw_type_label} (~{:.0}% equity) {position_label} is correct \
                  here. Your stack depth ({stack_bb} BB) and/or position make a semi-bluff raise \
                  suboptimal — calling lets you realise equity without bloating the pot OOP or \
-                 risking a re-raise at shallow depth.",
+                 risking a re-raise at shallow depth (EV of calling: {call_ev:+.1} chips).",
                 equity * 100.0
             ),
             _ => format!(
@@ -973,9 +1160,7 @@ pub fn generate_semi_bluff<R: Rng>(
             (DrawType::OESD, _, "C") => format!(
                 "Correct — raise to {raise_size} chips! A straight draw on both ends wins about 32% of the time, plus raising might make your opponent fold right now. The raise pays off whether they fold or call."
             ),
-            _ => format!(
-                "Raising here is too risky. Your draw doesn't win often enough to justify putting in so many chips. Just call."
-            ),
+            _ => "Raising here is too risky. Your draw doesn't win often enough to justify putting in so many chips. Just call.".to_string(),
         },
         TextStyle::Technical => match (draw_type, hero_is_ip, correct) {
             (DrawType::ComboDraw, _, "C") => format!(
@@ -983,14 +1168,16 @@ pub fn generate_semi_bluff<R: Rng>(
                  {draw_type_label} on {board_s} is the highest-EV play. Your combo draw has ~54% \
                  equity — you are a slight favourite! Raising wins the pot outright when villain \
                  folds (~40% of the time) and builds a large pot when villain calls into your \
-                 equity edge. Never just call with a combo draw when you can apply maximum pressure."
+                 equity edge — EV of raising {raise_ev:+.1} chips vs {call_ev:+.1} for calling. \
+                 Never just call with a combo draw when you can apply maximum pressure."
             ),
             (DrawType::OESD, _, "C") => format!(
                 "Correct. Raising to {raise_size} chips (2.5\u{00d7} villain's {villain_bet}) with an \
                  {draw_type_label} at {stack_bb} BB depth is correct. Your OESD has ~32% equity plus \
                  significant fold equity: villain must fold hands like top pair to avoid getting \
-                 stacked. At {stack_bb} BB the semi-bluff raise sets up a profitable shove on \
-                 the turn or a clean check when you miss."
+                 stacked. With ~40% folds the raise is worth {raise_ev:+.1} chips vs \
+                 {call_ev:+.1} for calling. At {stack_bb} BB the semi-bluff raise sets up a \
+                 profitable shove on the turn or a clean check when you miss."
             ),
             _ => format!(
                 "Raising to {raise_size} chips as a semi-bluff with a {draw_type_label} \
@@ -1000,26 +1187,55 @@ pub fn generate_semi_bluff<R: Rng>(
         },
     };
 
-    let answers = vec![
+    // Folding a combo draw throws away a near-favourite.
+    let rate = |id: &str| {
+        let fold = if draw_type == DrawType::ComboDraw {
+            AnswerRating::Blunder
+        } else {
+            AnswerRating::Mistake
+        };
+        fold_call_raise_rating(id, correct, fold)
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "How often does your draw come in, and could a raise make them fold right now?",
+        TextStyle::Technical => "Consider: draw equity, fold equity and stack depth.",
+    }.to_string());
+
+    let mut answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Fold".to_string(),
             is_correct: correct == "A",
+            rating: rate("A"),
+            hint: hint.clone(),
             explanation: fold_exp,
         },
         AnswerOption {
             id: "B".to_string(),
             text: "Call".to_string(),
             is_correct: correct == "B",
+            rating: rate("B"),
+            hint: hint.clone(),
             explanation: call_exp,
         },
         AnswerOption {
             id: "C".to_string(),
             text: format!("Raise to {} BB", raise_size / bb),
             is_correct: correct == "C",
+            rating: rate("C"),
+            hint: hint.clone(),
             explanation: raise_exp,
         },
     ];
+    if text_style == TextStyle::Technical && board_texture(&board).is_draw_heavy() {
+        for a in &mut answers {
+            a.explanation.push_str(
+                " This board is draw-heavy, so villain's betting range holds many draws too: \
+                 a raise meets more calls and re-raises than on a drier flop.",
+            );
+        }
+    }
 
     let players = vec![
         PlayerState {
@@ -1028,6 +1244,7 @@ pub fn generate_semi_bluff<R: Rng>(
             stack,
             is_hero: false,
             is_active: true,
+            bounty: None,
         },
         PlayerState {
             seat: 2,
@@ -1035,6 +1252,7 @@ pub fn generate_semi_bluff<R: Rng>(
             stack,
             is_hero: true,
             is_active: true,
+            bounty: None,
         },
     ];
 
@@ -1046,16 +1264,19 @@ pub fn generate_semi_bluff<R: Rng>(
         players,
         pot_size: pot,
         current_bet: villain_bet,
+        antes: None,
     };
 
-    TrainingScenario {
+    Ok(TrainingScenario {
         scenario_id,
         topic: TrainingTopic::SemiBluffDecision,
         branch_key,
         table_setup,
         question,
         answers,
-    }
+        key_concepts: key_concepts(TrainingTopic::SemiBluffDecision, &[]),
+        villain_tendency: None,
+    })
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -1094,13 +1315,14 @@ impl std::fmt::Display for FlopStrength {
     }
 }
 
-pub fn generate_3bet_cbet<R: Rng>(
+pub fn generate_3bet_cbet<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let (hero_hand, board) = deal(rng, 3);
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, board) = deal(dealer, 3)?;
 
     let texture  = if rng.gen_bool(0.5) { FlopTexture::Dry } else { FlopTexture::Wet };
     let fstrength = if rng.gen_bool(0.5) { FlopStrength::Strong } else { FlopStrength::Weak };
@@ -1117,6 +1339,15 @@ pub fn generate_3bet_cbet<R: Rng>(
 
     // SPR is low in 3-bet pots — stacks commit quickly
     let spr = stack as f32 / pot as f32;
+    let commit_note = if pot_committed(stack, pot) {
+        format!(
+            " At SPR {spr:.1} (below {}) you are pot-committed: any bet effectively \
+             puts your whole stack in play.",
+            commit_threshold_spr()
+        )
+    } else {
+        String::new()
+    };
 
     let small_bet = (pot as f32 * 0.33).round() as u32;
     let large_bet = (pot as f32 * 0.67).round() as u32;
@@ -1154,19 +1385,22 @@ pub fn generate_3bet_cbet<R: Rng>(
         ),
     };
 
-    let answers = vec![
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "The pot is already big. How strong is your hand, and do these cards help your opponent?",
+        TextStyle::Technical => "Consider: low SPR, board texture and your hand strength.",
+    }.to_string());
+
+    let mut answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Check back".to_string(),
             is_correct: correct == "A",
+            rating: ladder_rating("A", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match (texture, fstrength) {
-                    (_, FlopStrength::Weak) => format!(
-                        "Correct — check. Your hand is weak here. No need to bet — see the next card for free."
-                    ),
-                    _ => format!(
-                        "Checking here gives up on a hand worth betting. Take the initiative."
-                    ),
+                    (_, FlopStrength::Weak) => "Correct — check. Your hand is weak here. No need to bet — see the next card for free.".to_string(),
+                    _ => "Checking here gives up on a hand worth betting. Take the initiative.".to_string(),
                 },
                 TextStyle::Technical => match (texture, fstrength) {
                     (_, FlopStrength::Weak) => format!(
@@ -1174,13 +1408,13 @@ pub fn generate_3bet_cbet<R: Rng>(
                          this 3-bet pot. With SPR ~{spr:.1}, any c-bet represents a meaningful \
                          commitment — if villain check-raises, folding is costly and calling risks \
                          stacking off with poor equity. Check to keep the pot small and preserve \
-                         the option to bluff a favourable turn card."
+                         the option to bluff a favourable turn card.{commit_note}"
                     ),
                     _ => format!(
                         "Checking a {fstrength} in this 3-bet pot surrenders value. The low SPR \
                          ({spr:.1}) means bets are decisive — extract now while you have the \
                          equity lead. Villain's BB calling range is wide and misses the board \
-                         frequently. C-bet to build the pot you are likely to win."
+                         frequently. C-bet to build the pot you are likely to win.{commit_note}"
                     ),
                 },
             },
@@ -1189,17 +1423,13 @@ pub fn generate_3bet_cbet<R: Rng>(
             id: "B".to_string(),
             text: format!("C-bet small ({small_bet} chips ~33%)"),
             is_correct: correct == "B",
+            rating: ladder_rating("B", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match (texture, fstrength) {
-                    (FlopTexture::Dry, FlopStrength::Strong) => format!(
-                        "Correct — bet small. The board is dry (no likely draws). A small bet is enough to collect chips and keep pressure on."
-                    ),
-                    (FlopTexture::Wet, FlopStrength::Strong) => format!(
-                        "A small bet isn't enough on this type of board. Bet bigger or check."
-                    ),
-                    _ => format!(
-                        "A small bet isn't enough on this type of board. Bet bigger or check."
-                    ),
+                    (FlopTexture::Dry, FlopStrength::Strong) => "Correct — bet small. The board is dry (no likely draws). A small bet is enough to collect chips and keep pressure on.".to_string(),
+                    (FlopTexture::Wet, FlopStrength::Strong) => "A small bet isn't enough on this type of board. Bet bigger or check.".to_string(),
+                    _ => "A small bet isn't enough on this type of board. Bet bigger or check.".to_string(),
                 },
                 TextStyle::Technical => match (texture, fstrength) {
                     (FlopTexture::Dry, FlopStrength::Strong) => format!(
@@ -1207,18 +1437,18 @@ pub fn generate_3bet_cbet<R: Rng>(
                          is optimal. Dry boards miss villain's wide BB calling range frequently, \
                          so a small probe achieves two goals: it extracts value from any pair or \
                          draw while — given SPR ~{spr:.1} — starting to build toward a natural \
-                         stack commitment. Villain must act immediately with limited backdoor outs."
+                         stack commitment. Villain must act immediately with limited backdoor outs.{commit_note}"
                     ),
                     (FlopTexture::Wet, FlopStrength::Strong) => format!(
                         "A small c-bet on a {texture} board undersizes the protection needed. \
                          Villain has many draws (flush draws, straight draws) that can call 33% \
                          cheaply and realise equity. A larger bet (~67%) forces them to pay the \
-                         correct price and extracts more from made hands that call."
+                         correct price and extracts more from made hands that call.{commit_note}"
                     ),
                     _ => format!(
                         "C-betting 33% with a {fstrength} is still a significant commitment at \
                          SPR ~{spr:.1}. Any bet is hard to walk back in a 3-bet pot. Check back \
-                         and reassess on the turn."
+                         and reassess on the turn.{commit_note}"
                     ),
                 },
             },
@@ -1227,47 +1457,56 @@ pub fn generate_3bet_cbet<R: Rng>(
             id: "C".to_string(),
             text: format!("C-bet large ({large_bet} chips ~67%)"),
             is_correct: correct == "C",
+            rating: ladder_rating("C", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match (texture, fstrength) {
-                    (FlopTexture::Wet, FlopStrength::Strong) => format!(
-                        "Correct — bet big! The board has possible draws. Make your opponent pay dearly to chase them."
-                    ),
-                    (FlopTexture::Dry, FlopStrength::Strong) => format!(
-                        "Betting big here is too much — a check or small bet fits this situation better."
-                    ),
-                    _ => format!(
-                        "Betting big here is too much — a check or small bet fits this situation better."
-                    ),
+                    (FlopTexture::Wet, FlopStrength::Strong) => "Correct — bet big! The board has possible draws. Make your opponent pay dearly to chase them.".to_string(),
+                    (FlopTexture::Dry, FlopStrength::Strong) => "Betting big here is too much — a check or small bet fits this situation better.".to_string(),
+                    _ => "Betting big here is too much — a check or small bet fits this situation better.".to_string(),
                 },
                 TextStyle::Technical => match (texture, fstrength) {
                     (FlopTexture::Wet, FlopStrength::Strong) => format!(
                         "Correct. A large c-bet (~67% pot) with a {fstrength} on a {texture} board \
                          is the highest-EV line. Wet boards give villain flush draws, straight draws, \
                          and top pairs. Betting large charges every draw immediately, denies cheap \
-                         equity, and naturally commits the remaining stack at SPR ~{spr:.1}."
+                         equity, and naturally commits the remaining stack at SPR ~{spr:.1}.{commit_note}"
                     ),
                     (FlopTexture::Dry, FlopStrength::Strong) => format!(
                         "A large c-bet on a {texture} board slightly over-bets the situation. \
                          Dry boards rarely hit villain's calling range — a smaller bet (33%) \
                          achieves the same fold equity while sizing more accurately to the \
-                         low-draw texture. Save larger sizings for boards with more draws."
+                         low-draw texture. Save larger sizings for boards with more draws.{commit_note}"
                     ),
                     _ => format!(
                         "C-betting 67% with a {fstrength} at SPR ~{spr:.1} puts a large portion \
                          of your stack in with poor equity. If called or raised, you have little \
-                         fold equity and a tough decision. Check back instead."
+                         fold equity and a tough decision. Check back instead.{commit_note}"
                     ),
                 },
             },
         },
     ];
+    if text_style == TextStyle::Technical && matches!(fstrength, FlopStrength::Strong) {
+        let board_texture = match texture {
+            FlopTexture::Dry => BoardTexture::Dry,
+            FlopTexture::Wet => BoardTexture::Wet,
+        };
+        let note = format!(
+            " For reference, cbet_size_recommendation puts a c-bet here at ~{:.0}% pot.",
+            cbet_size_recommendation(board_texture, spr, hero_pos) * 100.0
+        );
+        for a in &mut answers {
+            a.explanation.push_str(&note);
+        }
+    }
 
     let players = vec![
-        PlayerState { seat: 1, position: Position::BB, stack, is_hero: false, is_active: true },
-        PlayerState { seat: 2, position: hero_pos,     stack, is_hero: true,  is_active: true },
+        PlayerState { seat: 1, position: Position::BB, stack, is_hero: false, is_active: true, bounty: None },
+        PlayerState { seat: 2, position: hero_pos,     stack, is_hero: true,  is_active: true, bounty: None },
     ];
 
-    TrainingScenario {
+    Ok(TrainingScenario {
         scenario_id,
         topic: TrainingTopic::ThreeBetPotCbet,
         branch_key,
@@ -1279,8 +1518,1044 @@ pub fn generate_3bet_cbet<R: Rng>(
             players,
             pot_size:      pot,
             current_bet:   0,
+            antes:         None,
         },
         question,
         answers,
+        key_concepts: key_concepts(TrainingTopic::ThreeBetPotCbet, &[]),
+        villain_tendency: None,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// T17 — Stack-Off Decision (SO-)
+//
+// Hero 3-bet from the BTN with two cards ten or higher, the CO called, and the
+// CO checks the flop.  Hero holds at least a pair: the last flop card is the
+// next one in the deck that gives hero one.  SPR is 1.5–4.0, so a bet commits
+// a large share of the stack.  Hero bets half pot
+// and must plan for a shove: calling the rest needs
+//   (stack − bet) / (pot + 2 × stack)
+// equity.  Hands whose equity against a stacking-off range clears that price
+// commit — shove when SPR < 2, bet-call otherwise — and the rest bet-fold.
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Hero's flop hand in a stack-off spot, grouped from `HandVsBoardStrength`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StackOffStrength {
+    /// Sets, straights, flushes and two pair.
+    Strong,
+    /// Overpair or top pair with the best kicker.
+    TopPair,
+    /// Top pair with a weaker kicker.
+    WeakTopPair,
+    /// Middle pair, bottom pair or nothing.
+    Weak,
+}
+
+impl StackOffStrength {
+    pub(crate) fn from_hand(hand: [Card; 2], board: &[Card]) -> Self {
+        use HandVsBoardStrength::*;
+        match classify_hero_hand_vs_board(hand, board) {
+            TopSet | MiddleSet | BottomSet | Flush | Straight | TwoPair => StackOffStrength::Strong,
+            TopPairTopKicker | OverPair => StackOffStrength::TopPair,
+            TopPairWeakKicker => StackOffStrength::WeakTopPair,
+            MiddlePair | BottomPair | NoPair => StackOffStrength::Weak,
+        }
+    }
+
+    /// Rough equity against the range that raises all-in over hero's bet.
+    pub(crate) fn equity_vs_shove(self) -> f32 {
+        match self {
+            StackOffStrength::Strong      => 0.80,
+            StackOffStrength::TopPair     => 0.55,
+            StackOffStrength::WeakTopPair => 0.30,
+            StackOffStrength::Weak        => 0.20,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            StackOffStrength::Strong      => "Strong",
+            StackOffStrength::TopPair     => "TopPair",
+            StackOffStrength::WeakTopPair => "WeakTopPair",
+            StackOffStrength::Weak        => "Weak",
+        }
+    }
+}
+
+/// Equity hero needs to call a shove after betting `bet` into `pot` with
+/// `stack` behind: the call of `stack − bet` wins a final pot of
+/// `pot + 2 × stack`.
+pub(crate) fn stack_off_required_equity(pot: u32, stack: u32, bet: u32) -> f32 {
+    (stack - bet) as f32 / (pot + 2 * stack) as f32
+}
+
+pub fn generate_stack_off<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    // A 3-betting range: two cards ten or higher.
+    let broadway = |c: &Card| c.rank.0 >= 10;
+    let hero_hand = [dealer.deal_matching(broadway)?, dealer.deal_matching(broadway)?];
+    let mut board = dealer.deal_n(2)?;
+    // Air has no stack-off plan, so the last flop card gives hero a pair or better.
+    let last = dealer.deal_matching(|&c| {
+        classify_hero_hand_vs_board(hero_hand, &[board[0], board[1], c]) != HandVsBoardStrength::NoPair
+    })?;
+    board.push(last);
+
+    let bb = 2u32;
+    let pot_bb = match difficulty {
+        DifficultyLevel::Beginner     => 20u32,
+        DifficultyLevel::Intermediate => rng.gen_range(12..=40),
+        DifficultyLevel::Advanced     => rng.gen_range(8..=60),
+    };
+    // Stack chosen against the pot so SPR lands in [1.5, 4.0].
+    let stack_bb = rng.gen_range(pot_bb * 3 / 2..=pot_bb * 4);
+    let pot = pot_bb * bb;
+    let stack = stack_bb * bb;
+    let spr = stack as f32 / pot as f32;
+
+    let strength = StackOffStrength::from_hand(hero_hand, &board);
+    let made = classify_hero_hand_vs_board(hero_hand, &board);
+    let bet = (pot as f32 * 0.5).round() as u32;
+    let required = stack_off_required_equity(pot, stack, bet);
+    let equity = strength.equity_vs_shove();
+    let commits = equity >= required;
+
+    let spr_bucket = if spr < 2.0 { "Low" } else if spr < 3.0 { "Mid" } else { "High" };
+    // A = bet-fold, B = bet-call, C = shove: passive to aggressive.
+    let correct = match (commits, spr < 2.0) {
+        (false, _)    => "A",
+        (true, false) => "B",
+        (true, true)  => "C",
+    };
+    let branch_key = format!("StackOff:{}:{spr_bucket}", strength.label());
+
+    let hero_pos = Position::BTN;
+    let villain_pos = Position::CO;
+    let hs = hand_str(hero_hand);
+    let bs = board_str(&board);
+    let call_amount = stack - bet;
+    let final_pot = pot + 2 * stack;
+
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "You re-raised before the flop from the Button and one player called. \
+             The flop is {bs} and you have {hs} ({made}). Pot: {pot} chips. \
+             You both have {stack} chips left. Your opponent checks. You plan to bet \
+             {bet} chips. If they go all-in, will you call? Choose your plan."
+        ),
+        TextStyle::Technical => format!(
+            "3-bet pot, BTN vs CO. Flop {bs}, you hold {hs} ({made}). Pot {pot} chips \
+             ({pot_bb} BB), effective stack {stack} chips — SPR {spr:.1}. CO checks. \
+             Do you bet {bet} (half pot) and fold to a shove, bet and call a shove, \
+             or shove {stack} now?"
+        ),
+    };
+
+    let arithmetic = format!(
+        "SPR = {stack}/{pot} = {spr:.1}. After a {bet}-chip bet a shove leaves \
+         {call_amount} to call into a final pot of {final_pot}: {call_amount}/{final_pot} \
+         = {:.0}% equity needed, against roughly {:.0}% for {made} versus a \
+         stacking-off range.",
+        required * 100.0,
+        equity * 100.0
+    );
+    let verdict = if commits {
+        "The price is good enough, so this hand is committed."
+    } else {
+        "The price is too high, so this hand must be able to let go."
+    };
+
+    let simple = |id: &str| -> String {
+        match (id, correct) {
+            ("A", "A") => "Correct — bet, but fold if they go all-in. Your hand is not strong enough \
+                           to risk your whole stack."
+                .to_string(),
+            ("B", "B") => "Correct — bet and call an all-in. Your hand is strong enough that you \
+                           are happy to get all your chips in."
+                .to_string(),
+            ("C", "C") => "Correct — go all-in now. Your stack is small next to the pot, so a \
+                           normal bet would commit you anyway."
+                .to_string(),
+            (_, "A") => "Too risky — this hand is not strong enough to play for your whole stack.".to_string(),
+            ("A", _) => "Folding to an all-in here gives up too much — you have a strong hand \
+                         and the pot is already big."
+                .to_string(),
+            ("B", "C") => "Close, but with so few chips behind just put them all in now.".to_string(),
+            _ => "Close, but with this many chips behind a normal bet keeps more worse hands in.".to_string(),
+        }
+    };
+    let technical = |id: &str| -> String {
+        let plan = match id {
+            "A" => "Bet-fold",
+            "B" => "Bet-call",
+            _   => "Shove",
+        };
+        let why = match (id, correct) {
+            _ if id == correct => format!("Correct. {verdict}"),
+            (_, "A") => format!("Wrong: {verdict}"),
+            ("A", _) => format!("Folding to a shove is wrong: {verdict}"),
+            ("C", "B") => "Committing is right, but at this SPR a normal bet still leaves \
+                           room for villain to put the rest in with worse."
+                .to_string(),
+            _ => "Committing is right, but below SPR 2 a half-pot bet already commits \
+                  you — shoving denies villain a cheap look."
+                .to_string(),
+        };
+        format!("{plan} with {hs} ({}): {arithmetic} {why}", strength.label())
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "After you bet, would you be happy to call if your opponent goes all-in?",
+        TextStyle::Technical => "Consider: the equity needed to call a shove after betting, versus your hand's equity.",
+    }.to_string());
+
+    let mut answers: Vec<AnswerOption> = [
+        ("A", format!("Bet {bet}, fold to a shove")),
+        ("B", format!("Bet {bet}, call a shove")),
+        ("C", format!("Shove {stack}")),
+    ]
+    .into_iter()
+    .map(|(id, text)| {
+        answer(id, text, correct, ladder_rating(id, correct), text_style, simple(id), technical(id))
+    })
+    .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
     }
+
+    let players = heads_up(hero_pos, villain_pos, stack, stack);
+    Ok(scenario(scenario_id, TrainingTopic::StackOffDecision, branch_key, GameType::CashGame,
+        hero_pos, hero_hand, board, players, pot, 0, question, answers))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// T19 — Continuation Bet Defense (CD-)
+//
+// The defender's side of T2: hero called the Button's open from the BB and
+// faces a flop c-bet.  Board favour and hand interaction reuse the T7
+// classifiers, and the correct action mirrors T7 — raise strong hands on
+// boards that favour the BB and every combo draw, fold air on boards that
+// favour the raiser, call everything else.  Technical text adds the minimum
+// defense frequency for the c-bet size.
+// ═══════════════════════════════════════════════════════════════════════════════
+
+pub fn generate_cbet_defense<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, board) = deal(dealer, 3)?;
+
+    let board_favour = classify_board(&board);
+    let interaction = classify_hand_interaction(hero_hand, &board);
+    let combo = is_combo_draw(hero_hand, &board);
+
+    let bb = 2u32;
+    // Single-raised pot: BTN open called by the BB, so the flop pot is 5–7 BB.
+    let (stack_bb, pot_bb, cbet_pct) = match difficulty {
+        DifficultyLevel::Beginner     => (100u32, 6u32, 50u32),
+        DifficultyLevel::Intermediate => (rng.gen_range(60..=120), rng.gen_range(5..=7), rng.gen_range(33..=75)),
+        DifficultyLevel::Advanced     => (rng.gen_range(25..=200), rng.gen_range(5..=9), rng.gen_range(25..=100)),
+    };
+    let pot = pot_bb * bb;
+    let stack = stack_bb * bb;
+    let cbet = (pot * cbet_pct / 100).max(bb);
+    let raise_to = cbet * 3;
+
+    let favour_key = match board_favour {
+        BoardFavour::BBFavorable => "BBFav",
+        BoardFavour::IPFavorable => "IPFav",
+    };
+    let interaction_key = match (interaction, combo) {
+        (HandInteraction::Strong, _)   => "Strong",
+        (HandInteraction::Draw, true)  => "ComboDraw",
+        (HandInteraction::Draw, false) => "Draw",
+        (HandInteraction::Weak, _)     => "Weak",
+    };
+    let branch_key = format!("CbetDefense:{favour_key}:{interaction_key}");
+
+    // Same decision as T7.
+    let correct: &str = match (board_favour, interaction) {
+        (BoardFavour::BBFavorable, HandInteraction::Strong) => "C",
+        (_, HandInteraction::Draw) if combo                 => "C",
+        (BoardFavour::IPFavorable, HandInteraction::Weak)   => "A",
+        _                                                   => "B",
+    };
+
+    let hero_pos = Position::BB;
+    let villain_pos = Position::BTN;
+    let hand_s = hand_str(hero_hand);
+    let board_s = board_str(&board);
+    let favour_str = match board_favour {
+        BoardFavour::BBFavorable => "low/connected — it favours your BB calling range",
+        BoardFavour::IPFavorable => "high/dry — it favours the Button's opening range",
+    };
+    let interaction_str = match (interaction, combo) {
+        (HandInteraction::Strong, _)   => "a pair with the board",
+        (HandInteraction::Draw, true)  => "a combo draw",
+        (HandInteraction::Draw, false) => "a draw",
+        (HandInteraction::Weak, _)     => "no pair and no draw",
+    };
+    let mdf = minimum_defense_frequency(cbet, pot) * 100.0;
+
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "You called a raise from the Big Blind. The flop is {board_s} and you have {hand_s}. \
+             You checked and the Button bet {cbet} chips into {pot}. Stack: {stack} chips. \
+             What do you do?"
+        ),
+        TextStyle::Technical => format!(
+            "C-bet defense. BB vs BTN single-raised pot. Flop {board_s}; you hold {hand_s} \
+             ({interaction_key}). Button c-bets {cbet} into {pot} ({cbet_pct}% pot). Stack \
+             {stack} chips ({stack_bb} BB). Fold, call, or check-raise to {raise_to}?"
+        ),
+    };
+
+    let simple = |id: &str| -> String {
+        match (id, correct) {
+            ("A", "A") => "Correct — fold. You have nothing and these cards suit the raiser. \
+                           Let this one go.".to_string(),
+            ("B", "B") => "Correct — call. Your hand is worth another card, but not worth \
+                           raising.".to_string(),
+            ("C", "C") => format!(
+                "Correct — raise to {raise_to} chips. Your hand is strong enough to make \
+                 the Button pay, and they often bet with nothing here."
+            ),
+            ("A", _) => "Folding gives up too easily. The Button bets with lots of weak hands \
+                         here — your hand is worth continuing.".to_string(),
+            ("B", _) if correct == "A" => "Calling with nothing on a board that suits the \
+                                           raiser just loses more chips.".to_string(),
+            ("B", _) => "Just calling is too passive — raise to build the pot while you're \
+                         ahead or have a big draw.".to_string(),
+            _ => "Raising here is too much. Your hand isn't strong enough to build a big pot \
+                  out of position.".to_string(),
+        }
+    };
+    let technical = |id: &str| -> String {
+        let reason = match (id, correct) {
+            _ if id == correct => match correct {
+                "A" => "Correct. With no pair and no draw on a board that favours the raiser, \
+                        you are at the bottom of your range — this is the part you fold."
+                    .to_string(),
+                "B" => "Correct. Your hand has enough equity to continue but not enough to \
+                        build a large pot out of position. Call and re-evaluate on the turn."
+                    .to_string(),
+                _ if combo => format!(
+                    "Correct. A combo draw has ~54% equity against a c-betting range. Raising \
+                     to {raise_to} wins the pot now when the Button folds and builds it when \
+                     they call."
+                ),
+                _ => format!(
+                    "Correct. The board hits your range and you have a made hand: check-raise \
+                     to {raise_to} for value and protection."
+                ),
+            },
+            ("A", _) => "Folding is too tight: the Button c-bets much of its range here, and \
+                         your hand is part of the range you must defend."
+                .to_string(),
+            ("B", "A") => "Calling with air on a board that favours the raiser invests chips \
+                           with almost no equity."
+                .to_string(),
+            ("B", _) => "Calling is too passive: a raise extracts more and denies the Button a \
+                         cheap turn."
+                .to_string(),
+            _ => "Check-raising over-commits chips out of position without the equity or \
+                  board advantage to back it up."
+                .to_string(),
+        };
+        format!(
+            "{reason} The board is {favour_str}; you hold {interaction_str}. Against a \
+             {cbet_pct}% pot c-bet the BB must continue with about {mdf:.0}% of its range (MDF).{}",
+            backdoor_note(hero_hand, &board)
+        )
+    };
+
+    // Check-raising into an IP-favourable board is worse than a passive error.
+    let rate = |id: &str| match (correct, id) {
+        ("B", "C") if board_favour == BoardFavour::IPFavorable => AnswerRating::Mistake,
+        _ => fold_call_raise_rating(id, correct, AnswerRating::Mistake),
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Does this flop suit you or the raiser? And did it help your hand?",
+        TextStyle::Technical => "Consider: board favour, your hand's interaction, and the MDF for this bet size.",
+    }.to_string());
+
+    let mut answers: Vec<AnswerOption> = [
+        ("A", "Fold".to_string()),
+        ("B", format!("Call {cbet}")),
+        ("C", format!("Check-raise to {raise_to}")),
+    ]
+    .into_iter()
+    .map(|(id, text)| answer(id, text, correct, rate(id), text_style, simple(id), technical(id)))
+    .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
+    }
+
+    let players = heads_up(hero_pos, villain_pos, stack, stack);
+    Ok(scenario(scenario_id, TrainingTopic::ContinuationBetDefense, branch_key, GameType::CashGame,
+        hero_pos, hero_hand, board, players, pot, cbet, question, answers))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// T22 — Exploitative Play (EX-)
+//
+// The same flop spots as T2/T19, but the villain has a known leak.  Hero's
+// dealt hand is bucketed, then a tendency is drawn from those that change the
+// balanced answer for that bucket, so every scenario teaches a deviation:
+//
+//   BB checks to hero (A check, B bet 33%, C bet 75%)
+//     FoldsToCbet80Pct — Strong: trap (C→A); Medium: bet to take it (A→B)
+//     CallsFrequently  — TopPair: bet big (B→C); Medium: thin value (A→B);
+//                        Air: stop bluffing (B→A)
+//   BB leads into hero (A fold, B call, C raise)
+//     NeverBluffs        — 75% lead: fold bluff-catchers (B→A)
+//     AlwaysOverbetsNuts — 150% lead: fold one pair (B→A), call sets (C→B)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Hero's flop holding in an exploitative spot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExploitHolding {
+    /// Two pair or better.
+    Strong,
+    /// Top pair or an overpair.
+    TopPair,
+    /// Middle or bottom pair.
+    Medium,
+    /// No pair.
+    Air,
+}
+
+impl ExploitHolding {
+    fn from_hand(hand: [Card; 2], board: &[Card]) -> Self {
+        use HandVsBoardStrength::*;
+        match classify_hero_hand_vs_board(hand, board) {
+            TopSet | MiddleSet | BottomSet | Flush | Straight | TwoPair => ExploitHolding::Strong,
+            TopPairTopKicker | TopPairWeakKicker | OverPair => ExploitHolding::TopPair,
+            MiddlePair | BottomPair => ExploitHolding::Medium,
+            NoPair => ExploitHolding::Air,
+        }
+    }
+
+    /// Tendencies whose exploit changes the balanced answer for this holding.
+    fn deviating_tendencies(self) -> &'static [VillainTendency] {
+        use VillainTendency::*;
+        match self {
+            ExploitHolding::Strong  => &[FoldsToCbet80Pct, AlwaysOverbetsNuts],
+            ExploitHolding::TopPair => &[CallsFrequently, NeverBluffs, AlwaysOverbetsNuts],
+            ExploitHolding::Medium  => &[FoldsToCbet80Pct, CallsFrequently, NeverBluffs],
+            ExploitHolding::Air     => &[CallsFrequently],
+        }
+    }
+}
+
+/// True when villain bets into hero (the tendency is about villain's bets);
+/// false when villain checks and hero chooses a c-bet size.
+pub(crate) fn villain_leads(tendency: VillainTendency) -> bool {
+    matches!(tendency, VillainTendency::NeverBluffs | VillainTendency::AlwaysOverbetsNuts)
+}
+
+/// `(balanced, exploitative)` answer IDs for a holding against a tendency.
+pub(crate) fn exploit_decision(tendency: VillainTendency, holding: ExploitHolding) -> (&'static str, &'static str) {
+    use ExploitHolding::*;
+    let gto = if villain_leads(tendency) {
+        // Facing a lead: raise the nuts, call one pair / bluff-catchers, fold air.
+        match holding {
+            Strong           => "C",
+            TopPair | Medium => "B",
+            Air              => "A",
+        }
+    } else {
+        // Checked to: bet big with strong hands, small with top pair and air
+        // (range c-bet), check medium pairs.
+        match holding {
+            Strong        => "C",
+            TopPair | Air => "B",
+            Medium        => "A",
+        }
+    };
+    let exploit = match (tendency, holding) {
+        (VillainTendency::FoldsToCbet80Pct, Strong)    => "A",
+        (VillainTendency::FoldsToCbet80Pct, Medium)    => "B",
+        (VillainTendency::CallsFrequently, TopPair)    => "C",
+        (VillainTendency::CallsFrequently, Medium)     => "B",
+        (VillainTendency::CallsFrequently, Air)        => "A",
+        (VillainTendency::NeverBluffs, TopPair | Medium) => "A",
+        (VillainTendency::AlwaysOverbetsNuts, Strong)  => "B",
+        (VillainTendency::AlwaysOverbetsNuts, TopPair) => "A",
+        _ => gto,
+    };
+    (gto, exploit)
+}
+
+pub fn generate_exploitative<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, board) = deal(dealer, 3)?;
+    let holding = ExploitHolding::from_hand(hero_hand, &board);
+    let candidates = holding.deviating_tendencies();
+    let tendency = candidates[rng.gen_range(0..candidates.len())];
+    let (gto, correct) = exploit_decision(tendency, holding);
+    let leads = villain_leads(tendency);
+
+    let bb = 2u32;
+    let (pot_bb, stack_bb) = match difficulty {
+        DifficultyLevel::Beginner     => (6u32, 100u32),
+        DifficultyLevel::Intermediate => (rng.gen_range(5..=8), rng.gen_range(60..=120)),
+        DifficultyLevel::Advanced     => (rng.gen_range(5..=12), rng.gen_range(40..=200)),
+    };
+    let pot = pot_bb * bb;
+    let stack = stack_bb * bb;
+    let small = (pot as f32 * 0.33).round() as u32;
+    let big = (pot as f32 * 0.75).round() as u32;
+    let lead = match tendency {
+        VillainTendency::AlwaysOverbetsNuts => pot * 3 / 2,
+        _                                   => big,
+    };
+    let raise_to = lead * 3;
+
+    let holding_key = format!("{holding:?}");
+    let branch_key = format!("Exploit:{tendency:?}:{holding_key}");
+
+    let hero_pos = Position::BTN;
+    let villain_pos = Position::BB;
+    let hs = hand_str(hero_hand);
+    let bs = board_str(&board);
+    let made = classify_hero_hand_vs_board(hero_hand, &board);
+
+    let options: [(&str, String); 3] = if leads {
+        [("A", "Fold".to_string()), ("B", format!("Call {lead}")), ("C", format!("Raise to {raise_to}"))]
+    } else {
+        [("A", "Check".to_string()), ("B", format!("Bet {small} (~33%)")), ("C", format!("Bet {big} (~75%)"))]
+    };
+    let option_text = |id: &str| options.iter().find(|(o, _)| *o == id).map(|(_, t)| t.clone()).unwrap_or_default();
+
+    let action = if leads {
+        format!("The Big Blind bets {lead} into the {pot}-chip pot.")
+    } else {
+        "The Big Blind checks to you.".to_string()
+    };
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "You raised before the flop from the Button and the Big Blind called. Flop: {bs}. \
+             You have {hs}. You have noticed this opponent {tendency}. {action} Pot: {pot} \
+             chips. Stack: {stack} chips. What do you do?"
+        ),
+        TextStyle::Technical => format!(
+            "Exploitative adjustment. BTN vs BB, flop {bs}; you hold {hs} ({made}). Read: villain \
+             {tendency}. {action} Pot {pot} ({pot_bb} BB), stack {stack}. What is the max-EV play \
+             against this opponent?"
+        ),
+    };
+
+    let why = match (tendency, holding) {
+        (VillainTendency::FoldsToCbet80Pct, ExploitHolding::Strong) =>
+            "Betting folds out almost everything; checking lets them bluff or catch a piece and pay you later.",
+        (VillainTendency::FoldsToCbet80Pct, _) =>
+            "They fold far too often, so a small bet takes the pot immediately with a hand that would rather not face more cards.",
+        (VillainTendency::CallsFrequently, ExploitHolding::TopPair) =>
+            "They call with worse pairs and draws, so size up for value.",
+        (VillainTendency::CallsFrequently, ExploitHolding::Medium) =>
+            "They call with worse pairs and overcards, so a small bet is now thin value.",
+        (VillainTendency::CallsFrequently, _) =>
+            "A bluff only works if they fold, and they don't — give up with air.",
+        (VillainTendency::NeverBluffs, _) =>
+            "A player who never bluffs only bets hands that beat a bluff-catcher, so calling just pays them off.",
+        (VillainTendency::AlwaysOverbetsNuts, ExploitHolding::Strong) =>
+            "Their overbet is the nuts, so raising only gets action from better — call and re-evaluate.",
+        (VillainTendency::AlwaysOverbetsNuts, _) =>
+            "Their overbet is the nuts; one pair is drawing nearly dead.",
+    };
+
+    let simple = |id: &str| -> String {
+        if id == correct {
+            format!("Correct — {}. {why}", option_text(id).to_lowercase())
+        } else if id == gto {
+            format!("This is the normal play, but not against this opponent. {why}")
+        } else {
+            format!("Not here. {why}")
+        }
+    };
+    let technical = |id: &str| -> String {
+        let head = if id == correct {
+            format!("Correct. Against a player who {tendency}, deviate from the balanced line.")
+        } else if id == gto {
+            "This is the balanced (GTO) line, but it leaves EV on the table against this read."
+                .to_string()
+        } else {
+            "Neither the balanced line nor the exploit.".to_string()
+        };
+        format!(
+            "{head} {why} Balanced play with {made} here is \"{}\"; the exploit is \"{}\".",
+            option_text(gto),
+            option_text(correct)
+        )
+    };
+
+    let rate = |id: &str| -> AnswerRating {
+        if id == gto { AnswerRating::Plausible } else { AnswerRating::Mistake }
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "What does this opponent do too often, and how can you make money from it?",
+        TextStyle::Technical => "Consider: what the read says about villain's range, and how the balanced line changes.",
+    }.to_string());
+
+    let mut answers: Vec<AnswerOption> = options
+        .iter()
+        .map(|(id, text)| answer(id, text.clone(), correct, rate(id), text_style, simple(id), technical(id)))
+        .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
+    }
+
+    let players = heads_up(hero_pos, villain_pos, stack, stack);
+    let current_bet = if leads { lead } else { 0 };
+    Ok(TrainingScenario {
+        villain_tendency: Some(tendency),
+        ..scenario(scenario_id, TrainingTopic::ExploitativePlay, branch_key, GameType::CashGame,
+            hero_pos, hero_hand, board, players, pot, current_bet, question, answers)
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// T28 — Protection Bet (PR-)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// T28 — Protection Bet (PR-).
+///
+/// Hero opened from the CO, the BTN called, and hero is first to act on the
+/// flop with top pair.  The board and hero's kicker come from the dealer,
+/// then the next card in the deck that pairs the top board card.  With no
+/// draws on the board hero checks; one draw calls for a 50% bet, two or more
+/// for 75%.
+///
+/// RNG order: sizing.
+pub fn generate_protection_bet<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let board = dealer.deal_n(3)?;
+    let kicker = dealer.deal()?;
+    let top = board.iter().map(|c| c.rank).max().unwrap_or(Rank(14));
+    let seen = board.iter().chain([&kicker]).filter(|c| c.rank == top).count();
+    // Only a trips board plus a kicker of the same rank leaves none in the deck.
+    let paired = if seen == 4 { dealer.deal()? } else { dealer.deal_matching(|c| c.rank == top)? };
+    let hero_hand = [paired, kicker];
+
+    let strength = classify_hero_hand_vs_board(hero_hand, &board);
+    let draws = board_draw_count(&board);
+    let correct = match draws {
+        0 => "A",
+        1 => "B",
+        _ => "C",
+    };
+    let branch_key = format!("Protection:{strength:?}:{draws}");
+
+    let bb = 2u32;
+    let (pot_bb, stack_bb) = match difficulty {
+        DifficultyLevel::Beginner     => (6u32, 100u32),
+        DifficultyLevel::Intermediate => (rng.gen_range(5..=8), rng.gen_range(60..=120)),
+        DifficultyLevel::Advanced     => (rng.gen_range(5..=12), rng.gen_range(40..=200)),
+    };
+    let pot = pot_bb * bb;
+    let stack = stack_bb * bb;
+    let half = pot / 2;
+    let big = (pot as f32 * 0.75).round() as u32;
+
+    // The draw that gains most from a free card.
+    let draw = if has_flush_draw(&board) { DrawType::FlushDraw } else { DrawType::OESD };
+    let turn_eq = draw.equity(1);
+    let river_eq = draw.equity(2);
+    let donated = pot as f32 * turn_eq;
+
+    let hs = hand_str(hero_hand);
+    let bs = board_str(&board);
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "You raised before the flop from the Cutoff and the Button called. Flop: {bs}. You \
+             have {hs} — top pair. You act first. Pot: {pot} chips. Stack: {stack} chips. \
+             What do you do?"
+        ),
+        TextStyle::Technical => format!(
+            "Protection spot. CO vs BTN, single-raised pot, flop {bs} ({draws} board draw{}). \
+             You hold {hs} ({strength}), OOP and first to act. Pot {pot}, {stack} effective. \
+             Check, bet {half} (50%), or bet {big} (75%)?",
+            if draws == 1 { "" } else { "s" }
+        ),
+    };
+
+    let draw_note = if draws == 0 {
+        "The board is dry: no flush or straight draws, so a free card rarely beats you, and \
+         a bet is called mainly by hands that are ahead."
+            .to_string()
+    } else {
+        format!(
+            "Giving a {draw} {:.0}% equity to the river free of charge (it hits {:.0}% on the \
+             turn alone) reduces your expected value by about {donated:.0} chips of the {pot}-chip \
+             pot. {}",
+            river_eq * 100.0,
+            turn_eq * 100.0,
+            if draws >= 2 {
+                "With several draws possible, bet big so every one of them pays a bad price."
+            } else {
+                "One draw is possible, so a half-pot bet charges it without bloating the pot."
+            }
+        )
+    };
+
+    let simple = |id: &str| -> String {
+        match (id, correct) {
+            ("A", "A") => "Correct — check. There are no draws on this board, so you don't need to protect your hand.".to_string(),
+            ("B", "B") => "Correct — bet half the pot. Make anyone chasing a draw pay to see the next card.".to_string(),
+            ("C", "C") => "Correct — bet big. Lots of draws are possible, and you don't want to give any of them a free card.".to_string(),
+            ("A", _) => "Checking gives drawing hands a free card. Bet to make them pay.".to_string(),
+            (_, "A") => "There is little to protect against here, and a bet mostly gets called by better hands. Check.".to_string(),
+            ("B", _) => "With this many draws, a small bet gives them too good a price. Bet bigger.".to_string(),
+            _ => "A smaller bet is enough with just one draw possible.".to_string(),
+        }
+    };
+    let technical = |id: &str| -> String {
+        let verdict = if id == correct {
+            match correct {
+                "A" => format!("Correct. With {strength} on a dry board there is little to protect; check and keep villain's weaker hands in."),
+                "B" => format!("Correct. With {strength}, bet 50% to deny equity to the single draw."),
+                _ => format!("Correct. With {strength} on a wet board, bet 75% to charge every draw."),
+            }
+        } else {
+            match (id, correct) {
+                ("A", _) => "Checking forfeits protection.".to_string(),
+                (_, "A") => "A bet here is not protection — there is nothing to protect against.".to_string(),
+                ("B", _) => "Half pot under-charges this many draws.".to_string(),
+                _ => "75% over-charges a single draw and bloats the pot.".to_string(),
+            }
+        };
+        format!("{verdict} {draw_note}")
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "What cards could come on the turn that would beat you?",
+        TextStyle::Technical => "Consider: the number of draws on board and the equity a free card gives them.",
+    }.to_string());
+
+    let options = [
+        ("A", "Check".to_string()),
+        ("B", format!("Bet {half} (~50%)")),
+        ("C", format!("Bet {big} (~75%)")),
+    ];
+    let mut answers: Vec<AnswerOption> = options
+        .iter()
+        .map(|(id, text)| {
+            answer(id, text.clone(), correct, ladder_rating(id, correct), text_style, simple(id), technical(id))
+        })
+        .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
+    }
+
+    let players = heads_up(Position::CO, Position::BTN, stack, stack);
+    Ok(scenario(scenario_id, TrainingTopic::ProtectionBet, branch_key, GameType::CashGame,
+        Position::CO, hero_hand, board, players, pot, 0, question, answers))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// T30 — Pot Control (PC-)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// T30 — Pot Control (PC-).
+///
+/// Hero opened from the BTN, the BB called and checks the flop.  Hero holds
+/// top pair with a weak kicker.  Every card comes from the dealer via
+/// `deal_matching`: an unpaired board, the next card pairing its top rank,
+/// and a 2–9 kicker off the board in a different suit, so the hand is never
+/// a flush.
+///
+/// The decision reuses the T17 stack-off arithmetic: after a half-pot bet, a
+/// shove must be called with weak top pair's ~30% equity.  Below SPR 2 that
+/// price is met, so hero bets; at SPR 3–6 it is not, so hero checks back.
+///
+/// RNG order: SPR bucket → SPR → pot.
+pub fn generate_pot_control<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let mut board: Vec<Card> = Vec::with_capacity(3);
+    for _ in 0..3 {
+        let card = dealer.deal_matching(|c| board.iter().all(|b| b.rank != c.rank))?;
+        board.push(card);
+    }
+    let top = board.iter().map(|c| c.rank).max().unwrap_or(Rank(14));
+    let paired = dealer.deal_matching(|c| c.rank == top)?;
+    let kicker = dealer.deal_matching(|c| {
+        (2..=9).contains(&c.rank.0) && c.suit != paired.suit && board.iter().all(|b| b.rank != c.rank)
+    })?;
+    let hero_hand = [paired, kicker];
+    let strength = classify_hero_hand_vs_board(hero_hand, &board);
+
+    // SPR in tenths: low is 1.2–1.9, medium 3.0–6.0.
+    let low = rng.gen_bool(0.5);
+    let spr_x10 = match (difficulty, low) {
+        (DifficultyLevel::Beginner, true)  => 15u32,
+        (DifficultyLevel::Beginner, false) => 45,
+        (_, true)                          => rng.gen_range(12..=19),
+        (_, false)                         => rng.gen_range(30..=60),
+    };
+    let bb = 2u32;
+    let pot_bb = match difficulty {
+        DifficultyLevel::Beginner     => 10u32,
+        DifficultyLevel::Intermediate => rng.gen_range(8..=20),
+        DifficultyLevel::Advanced     => rng.gen_range(6..=30),
+    };
+    let pot = pot_bb * bb;
+    let stack = pot * spr_x10 / 10;
+    let spr = stack as f32 / pot as f32;
+    let spr_bucket = if spr < 2.0 { "Low" } else { "Medium" };
+
+    let bet = pot / 2;
+    let required = stack_off_required_equity(pot, stack, bet);
+    let equity = StackOffStrength::WeakTopPair.equity_vs_shove();
+    let commits = equity >= required;
+    // A = check back, B = bet half pot, C = bet pot.
+    let correct = if commits { "B" } else { "A" };
+    let branch_key = format!("PotControl:{strength:?}:{spr_bucket}");
+
+    let hs = hand_str(hero_hand);
+    let bs = board_str(&board);
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "You raised before the flop from the Button and the Big Blind called. Flop: {bs}. \
+             You have {hs} — top pair, but a weak kicker. Your opponent checks. Pot: {pot} \
+             chips. You both have {stack} chips left. What do you do?"
+        ),
+        TextStyle::Technical => format!(
+            "Pot control. BTN vs BB, single-raised pot, flop {bs}. You hold {hs} ({strength}); \
+             BB checks. Pot {pot}, {stack} effective — SPR {spr:.1}. Check back, bet {bet} \
+             (50%), or bet {pot} (pot)?"
+        ),
+    };
+
+    let arithmetic = format!(
+        "SPR {spr:.1}: after a {bet}-chip bet, calling a shove costs {} into a final pot of {} \
+         — {:.0}% equity needed, against roughly {:.0}% for {strength} versus a raising range.",
+        stack - bet,
+        pot + 2 * stack,
+        required * 100.0,
+        equity * 100.0
+    );
+    let verdict = if commits {
+        "The price is met, so the hand is committed: bet for value now and get called by \
+         worse pairs and draws while the stack is small."
+    } else {
+        "The price is not met, so a bet can only be a bet-fold: better hands call or raise, \
+         worse hands fold, and the pot grows while your equity against an unknown range is \
+         marginal. Checking back keeps the pot small, reaches showdown cheaply, and lets \
+         villain bluff later streets."
+    };
+
+    let simple = |id: &str| -> String {
+        match (id, correct) {
+            ("A", "A") => "Correct — check. Your hand is good, but not good enough to play a big pot. \
+                           Keep the pot small."
+                .to_string(),
+            ("B", "B") => "Correct — bet. Your stack is small next to the pot, so you are happy to \
+                           get all your chips in with top pair."
+                .to_string(),
+            ("A", _) => "With so few chips behind, don't slow down — bet while you are ahead.".to_string(),
+            ("B", _) => "A bet here gets called by better hands and folds out worse ones. Check \
+                         to keep the pot small."
+                .to_string(),
+            (_, "A") => "A big bet builds a big pot with a hand that can't stand a raise. Check.".to_string(),
+            _ => "Betting is right, but half the pot is enough — it already commits you.".to_string(),
+        }
+    };
+    let technical = |id: &str| -> String {
+        let why = match (id, correct) {
+            _ if id == correct => format!("Correct. {verdict}"),
+            ("A", _) => format!("Pot control is for medium SPR. {verdict}"),
+            ("B", _) => format!("Betting is wrong. {verdict}"),
+            (_, "A") => format!("A pot-sized bet inflates the pot with marginal equity. {verdict}"),
+            _ => "Betting is right, but half pot already commits you and keeps more worse hands calling."
+                .to_string(),
+        };
+        format!("{why} {arithmetic}")
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "If you bet and your opponent raises all-in, could you call?",
+        TextStyle::Technical => "Consider: the SPR and whether weak top pair could call a shove after betting.",
+    }.to_string());
+
+    let options = [
+        ("A", "Check back".to_string()),
+        ("B", format!("Bet {bet} (~50%)")),
+        ("C", format!("Bet {pot} (pot)")),
+    ];
+    let mut answers: Vec<AnswerOption> = options
+        .iter()
+        .map(|(id, text)| {
+            answer(id, text.clone(), correct, ladder_rating(id, correct), text_style, simple(id), technical(id))
+        })
+        .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
+    }
+
+    let players = heads_up(Position::BTN, Position::BB, stack, stack);
+    Ok(scenario(scenario_id, TrainingTopic::PotControl, branch_key, GameType::CashGame,
+        Position::BTN, hero_hand, board, players, pot, 0, question, answers))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// T35 — Draw Counting (OU-)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// "any of the 9 remaining hearts, plus any other 9 or 4 (6 cards)" — the
+/// cards in `outs`, flush cards first when hero holds a flush draw.
+fn describe_outs(outs: &[Card], flush: Option<Suit>) -> String {
+    let mut parts = Vec::new();
+    if let Some(suit) = flush {
+        let n = outs.iter().filter(|c| c.suit == suit).count();
+        parts.push(format!("any of the {n} remaining {}", suit.name()));
+    }
+    let straight: Vec<&Card> = outs.iter().filter(|c| Some(c.suit) != flush).collect();
+    if !straight.is_empty() {
+        let mut ranks: Vec<Rank> = straight.iter().map(|c| c.rank).collect();
+        ranks.sort_unstable_by(|a, b| b.cmp(a));
+        ranks.dedup();
+        let names: Vec<String> = ranks.iter().map(|r| r.to_string()).collect();
+        let any = if flush.is_some() { "any other" } else { "any" };
+        parts.push(format!("{any} {} ({} cards)", names.join(" or "), straight.len()));
+    }
+    parts.join(", plus ")
+}
+
+/// T35 — Draw Counting (OU-).
+///
+/// Hero called on the Button and the BB checks the flop.  Hero's cards and
+/// two board cards come from [`deal_draw`]; the third flop card is the next
+/// one in the deck that neither pairs hero or the board nor changes the draw.
+/// Hero names the out count: 4 / 8 / 9 / 15.
+///
+/// Beginner draws flush draws and open-enders, Intermediate adds gutshots,
+/// Advanced adds combo draws.
+///
+/// RNG order: draw type → draw ranks.
+pub fn generate_draw_counting<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let pool: &[DrawType] = match difficulty {
+        DifficultyLevel::Beginner     => &[DrawType::FlushDraw, DrawType::OESD],
+        DifficultyLevel::Intermediate => &[DrawType::FlushDraw, DrawType::OESD, DrawType::GutShot],
+        DifficultyLevel::Advanced     => &DrawType::all(),
+    };
+    let draw = pool[rng.gen_range(0..pool.len())];
+    let (hero_hand, core) = deal_draw(rng, dealer, draw)?;
+    let expected = classify_draw_combined(hero_hand, &core);
+    let mut board = core.to_vec();
+    let third = dealer.deal_matching(|&c| {
+        let mut next = board.clone();
+        next.push(c);
+        !hero_hand.iter().chain(&board).any(|h| h.rank == c.rank)
+            && classify_draw_combined(hero_hand, &next) == expected
+            && count_outs(hero_hand, &next) == draw.outs()
+    })?;
+    board.push(third);
+
+    let outs = out_cards(hero_hand, &board);
+    let n = outs.len();
+    let flush = matches!(draw, DrawType::FlushDraw | DrawType::ComboDraw).then_some(hero_hand[0].suit);
+    let cards = describe_outs(&outs, flush);
+    let correct = match draw {
+        DrawType::GutShot   => "A",
+        DrawType::OESD      => "B",
+        DrawType::FlushDraw => "C",
+        DrawType::ComboDraw => "D",
+    };
+    let branch_key = format!("DrawCounting:{draw:?}:{n}");
+
+    let hs = hand_str(hero_hand);
+    let bs = board_str(&board);
+    let pot = 12u32;
+    let stack = 200 - pot / 2;
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "You called before the flop on the Button. Flop: {bs}. You have {hs} and your \
+             opponent checks. How many outs do you have to improve your hand?"
+        ),
+        TextStyle::Technical => format!(
+            "Counting outs. BTN vs BB, flop {bs}, you hold {hs}; BB checks. How many outs \
+             complete your draw on the turn?"
+        ),
+    };
+
+    let simple = format!("Your outs: {cards}. That is {n} outs.");
+    let technical = format!(
+        "Outs: {cards} — {n} in all from your {draw}. Rule of 2 and 4: about {}% on the turn and {}% \
+         by the river; exactly {:.1}% and {:.1}%.",
+        2 * n,
+        4 * n,
+        equity_from_outs(n as u8, 1) * 100.0,
+        equity_from_outs(n as u8, 2) * 100.0,
+    );
+    let miss = |id: &str| -> &'static str {
+        match id {
+            "A" => "4 outs is a gutshot: one rank fills the straight.",
+            "B" => "8 outs is an open-ender: two ranks fill the straight.",
+            "C" => "9 outs is a flush draw: the 13 cards of the suit minus the 4 you see.",
+            _   => "15 outs is a flush draw plus an open-ender, with the overlap counted once.",
+        }
+    };
+    let explain = |id: &str, body: &str| -> String {
+        if id == correct { format!("Correct. {body}") } else { format!("{} {body}", miss(id)) }
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Which cards would give you a straight or a flush? Count them.",
+        TextStyle::Technical => "Consider: which ranks and suits complete the draw, and which are already visible.",
+    }.to_string());
+
+    let options = [
+        ("A", "4 outs (gutshot)"),
+        ("B", "8 outs (open-ended straight draw)"),
+        ("C", "9 outs (flush draw)"),
+        ("D", "15 outs (combo draw)"),
+    ];
+    let mut answers: Vec<AnswerOption> = options
+        .iter()
+        .map(|&(id, text)| {
+            answer(id, text, correct, ladder_rating(id, correct), text_style,
+                explain(id, &simple), explain(id, &technical))
+        })
+        .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
+    }
+
+    let players = heads_up(Position::BTN, Position::BB, stack, stack);
+    Ok(scenario(scenario_id, TrainingTopic::DrawCounting, branch_key, GameType::CashGame,
+        Position::BTN, hero_hand, board, players, pot, 0, question, answers))
 }
@@ -4,21 +4,26 @@
 //! Every public function follows the same signature:
 //!
 //! ```ignore
-//! pub fn generate_<name><R: Rng>(
+//! pub fn generate_<name><R: Rng, D: CardDealer>(
 //!     rng: &mut R,
+//!     dealer: &mut D,
 //!     difficulty: DifficultyLevel,
 //!     scenario_id: String,
 //!     text_style: TextStyle,
-//! ) -> TrainingScenario
+//! ) -> Result<TrainingScenario, GenerationError>
 //! ```
 //!
+//! Cards come from `dealer` — a shuffled [`Deck`](super::deck::Deck) in
+//! production, or a [`MockDeck`](super::deck::MockDeck) with a fixed order in
+//! tests — and every other random choice from `rng`.
+//!
 //! The generator dispatches to these via `generator.rs`.
 
-/// T1 (PF-), T5 (IC-), T9 (AL-), T11 (SQ-), T12 (BD-)
+/// T1 (PF-), T5 (IC-), T9 (AL-), T11 (SQ-), T12 (BD-), T18 (SM-), T20 (TD-), T23 (CL-), T24 (BV-), T25 (SS-), T26 (ST-), T27 (HU-), T29 (SC-), T32 (PM-), T34 (RK-)
 pub mod preflop;
-/// T2 (CB-), T3 (PO-), T7 (CR-), T8 (SB-), T13 (3B-)
+/// T2 (CB-), T3 (PO-), T7 (CR-), T8 (SB-), T13 (3B-), T17 (SO-), T19 (CD-), T22 (EX-), T28 (PR-), T30 (PC-), T35 (OU-)
 pub mod flop;
-/// T6 (TB-), T15 (PB-), T16 (DC-)
+/// T6 (TB-), T15 (PB-), T16 (DC-), T31 (DR-), T36 (FE-)
 pub mod turn;
-/// T4 (BL-), T10 (RV-), T14 (RF-)
+/// T4 (BL-), T10 (RV-), T14 (RF-), T21 (TR-), T33 (HR-), T37 (LD-), T38 (GU-)
 pub mod river;
@@ -1,7 +1,9 @@
 //! Preflop topic generators: open-raise, ICM push/fold, anti-limper isolation,
-//! squeeze play, and big blind defense.
+//! squeeze play, big blind defense, set mining, 3-bet defense, chip leader play,
+//! blinds vs blinds, short-stack push/fold, blind stealing, heads-up tournament
+//! push/fold, suited connectors, poker math, and hand rankings.
 //!
-//! All five topics have an empty board (0 community cards).  Hand strength is
+//! All fifteen topics have an empty board (0 community cards).  Hand strength is
 //! classified using `evaluator::classify_hand()` (5-category system).
 //!
 //! ## Topics in this file
@@ -11,7 +13,8 @@
 //!   (OpenRaise / FacingOpen / ThreeBetPot).
 //! - **T5 ICM & Tournament** (`generate_icm`) — Push or fold in a tournament
 //!   setting.  Uses a PushTier system: base threshold per tournament stage,
-//!   adjusted by hand strength.
+//!   adjusted by hand strength, with the chip-EV `nash_push_threshold_bb`
+//!   quoted for comparison.
 //! - **T9 Anti-Limper Isolation** (`generate_anti_limper`) — Iso-raise a limper,
 //!   overlimp, or fold.  Premium/Strong hands always iso-raise; trash always
 //!   folds.
@@ -21,14 +24,48 @@
 //! - **T12 Big Blind Defense** (`generate_bb_defense`) — Facing a single raise
 //!   from BB: 3-bet strong hands, call playable hands exploiting the BB
 //!   discount, fold trash.
+//! - **T18 Set Mining** (`generate_set_mining`) — Call an open with 22–66 only
+//!   when the effective stack is at least 10× the call.
+//! - **T20 3-Bet Defense** (`generate_3bet_defense`) — CO/BTN opener facing a
+//!   blind 3-bet: 4-bet premiums, call in position, fold the rest.
+//! - **T23 Chip Leader Play** (`generate_chip_leader`) — Tournament chip leader
+//!   in the BB facing a shove: call short stacks wide, fold marginal hands to
+//!   the other big stack.
+//! - **T24 Blinds vs Blinds** (`generate_blinds_vs_blinds`) — Folded to the SB:
+//!   raise strong and playable hands, complete marginal ones, fold trash;
+//!   Intermediate/Advanced options add the flop plan.
+//! - **T25 Short Stack Strategy** (`generate_short_stack`) — Cash-game push or
+//!   fold with 10–19 BB: shoving ranges widen with position and shorter stacks.
+//! - **T26 Blind Stealing** (`generate_blind_stealing`) — Folded to CO, BTN or
+//!   SB: steal with any hand inside the seat's opening width, ranked by
+//!   `evaluator::starting_hand_percentile`.
+//! - **T27 Heads-Up Tournament** (`generate_hu_tournament`) — Winner-take-all
+//!   HU SNG: the button shoves or folds by a simplified Nash chart.
+//! - **T29 Suited Connectors** (`generate_suited_connectors`) — 54s–98s facing
+//!   an open: call in position or from the BB at 20× the call, fold from the SB
+//!   or shallow; Advanced options add the flop plan.
+//! - **T32 Poker Math** (`generate_poker_math`) — A combinatorics or
+//!   probability quiz from `MATH_QUESTIONS`; wrong answers are common
+//!   misconceptions.
+//! - **T34 Beginner Hand Rankings** (`generate_hand_ranking`) — Which of two
+//!   5-card hands wins: different categories at Beginner, a kicker battle at
+//!   Advanced.
 
 use rand::Rng;
 use crate::training_engine::{
-    deck::Deck,
-    evaluator::{classify_hand, hand_category_name, HandCategory},
+    deck::CardDealer,
+    evaluator::{
+        bb_defense_range_width, classify_hand, commit_threshold_spr, minimum_defense_frequency,
+        normalized_hand_notation, pot_committed, set_mining_ev, starting_hand_percentile,
+        villain_range_width, SET_ON_FLOP,
+        compare_hands, evaluate_five_cards, hand_description, suit_index, MadeHand,
+        icm::{icm_equity, ICMPayoutTable},
+        classify_push_tier, nash_push_threshold_bb, HandCategory, PositionRange, PushTier,
+    },
+    helpers::{deal, fold_call_raise_rating, heads_up, key_concepts, pot_after_call, pot_after_raise},
     models::{
-        AnswerOption, Card, DifficultyLevel, GameType, PlayerState,
-        Position, TableSetup, TextStyle, TrainingScenario, TrainingTopic,
+        AnswerOption, AnswerRating, Card, DifficultyLevel, GameType, GenerationError,
+        PlayerState, Position, Rank, Suit, TableSetup, TextStyle, TrainingScenario, TrainingTopic,
     },
 };
 
@@ -51,13 +88,9 @@ fn select_spot<R: Rng>(rng: &mut R) -> PreflopSpot {
     }
 }
 
-const POSITIONS_6MAX: &[Position] = &[
-    Position::UTG, Position::HJ, Position::CO,
-    Position::BTN, Position::SB, Position::BB,
-];
-
 fn random_position<R: Rng>(rng: &mut R) -> Position {
-    POSITIONS_6MAX[rng.gen_range(0..POSITIONS_6MAX.len())]
+    let seats = Position::positions_for_table_size(6);
+    seats[rng.gen_range(0..seats.len())]
 }
 
 fn stack_for_difficulty<R: Rng>(rng: &mut R, diff: DifficultyLevel) -> u32 {
@@ -68,45 +101,55 @@ fn stack_for_difficulty<R: Rng>(rng: &mut R, diff: DifficultyLevel) -> u32 {
     }
 }
 
+/// Rating for folding a hand that should continue: throwing away a Premium or
+/// Strong hand is a blunder, anything weaker a mistake.
+fn fold_rating(cat: HandCategory) -> AnswerRating {
+    match cat {
+        HandCategory::Premium | HandCategory::Strong => AnswerRating::Blunder,
+        _ => AnswerRating::Mistake,
+    }
+}
+
 /// Backward-compatible entry point — delegates to [`generate_open`].
-pub fn generate<R: Rng>(
+pub fn generate<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    generate_open(rng, difficulty, scenario_id, text_style)
+) -> Result<TrainingScenario, GenerationError> {
+    generate_open(rng, dealer, difficulty, scenario_id, text_style)
 }
 
 /// T1 — Preflop Decision (PF-).
 ///
 /// Always generates a 6-max table.
 ///
-/// RNG order: gen_range(0..3) → position → stack → Deck::new_shuffled
-/// → deal×2 → per-player stacks → build_spot (no further rng).
-pub fn generate_open<R: Rng>(
+/// RNG order: gen_range(0..3) → position
+/// → stack → per-player stacks → build_spot (no further rng).
+pub fn generate_open<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_cards: [Card; 2] = [dealer.deal()?, dealer.deal()?];
+
     let spot = select_spot(rng);
     let hero_pos = random_position(rng);
     let effective_stack = stack_for_difficulty(rng, difficulty);
 
-    let mut deck = Deck::new_shuffled(rng);
-    let hero_cards: [Card; 2] = [deck.deal(), deck.deal()];
-
     let cat = classify_hand(hero_cards);
     let pos_type = if hero_pos.is_late() { "IP" } else { "OOP" };
     let branch_key = match spot {
-        PreflopSpot::ThreeBetPot => format!("ThreeBetPot:{}", hand_category_name(cat)),
-        PreflopSpot::OpenRaise   => format!("OpenRaise:{}:{}", hand_category_name(cat), pos_type),
-        PreflopSpot::FacingOpen  => format!("FacingOpen:{}:{}", hand_category_name(cat), pos_type),
+        PreflopSpot::ThreeBetPot => format!("ThreeBetPot:{}", cat.name()),
+        PreflopSpot::OpenRaise   => format!("OpenRaise:{}:{}", cat.name(), pos_type),
+        PreflopSpot::FacingOpen  => format!("FacingOpen:{}:{}", cat.name(), pos_type),
     };
 
     // Build player list (6-max)
-    let players: Vec<PlayerState> = POSITIONS_6MAX
+    let players: Vec<PlayerState> = Position::positions_for_table_size(6)
         .iter()
         .enumerate()
         .map(|(i, &pos)| PlayerState {
@@ -119,6 +162,7 @@ pub fn generate_open<R: Rng>(
             },
             is_hero: pos == hero_pos,
             is_active: true,
+            bounty: None,
         })
         .collect();
 
@@ -134,18 +178,23 @@ pub fn generate_open<R: Rng>(
         players,
         pot_size,
         current_bet,
+        antes: None,
     };
 
-    TrainingScenario {
+    let spr_concept: &[&str] = if matches!(spot, PreflopSpot::ThreeBetPot) { &["spr"] } else { &[] };
+    Ok(TrainingScenario {
         scenario_id,
         topic: TrainingTopic::PreflopDecision,
         branch_key,
         table_setup,
         question,
         answers,
-    }
+        key_concepts: key_concepts(TrainingTopic::PreflopDecision, spr_concept),
+        villain_tendency: None,
+    })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_spot<R: Rng>(
     _rng: &mut R,
     spot: PreflopSpot,
@@ -157,8 +206,8 @@ fn build_spot<R: Rng>(
     text_style: TextStyle,
 ) -> (u32, u32, String, Vec<AnswerOption>) {
     let cat = classify_hand(hand);
-    let cat_name = hand_category_name(cat);
-    let hand_str = format!("{}{}", hand[0], hand[1]);
+    let cat_name = cat.name();
+    let hand_str = normalized_hand_notation(hand);
     let pos_str = format!("{}", pos);
     let stack_bb = stack / bb;
 
@@ -199,11 +248,27 @@ fn build_spot<R: Rng>(
                          ({stack_bb} BB) with insufficient equity. A fold is better.")
             };
 
-            let answers = vec![
+            // Folding is graded by hand strength; limping a premium hand at least
+            // gets money in with the best of it.
+            let rate = |id: &str| match id {
+                _ if id == correct => AnswerRating::Correct,
+                "A" => fold_rating(cat),
+                "C" if cat == HandCategory::Premium => AnswerRating::Plausible,
+                _ => AnswerRating::Mistake,
+            };
+
+            let hint = Some(match text_style {
+                TextStyle::Simple    => "Think about how strong your hand is and where you sit at the table.",
+                TextStyle::Technical => "Consider: hand category and table position.",
+            }.to_string());
+
+            let mut answers = vec![
                 AnswerOption {
                     id: "A".to_string(),
                     text: "Fold".to_string(),
                     is_correct: correct == "A",
+                    rating: rate("A"),
+                    hint: hint.clone(),
                     explanation: match text_style {
                         TextStyle::Simple => if correct == "A" {
                             format!("Correct. {hand_str} is too weak for {pos_str}. Folding saves your chips for a better hand.")
@@ -220,6 +285,8 @@ fn build_spot<R: Rng>(
                     id: "B".to_string(),
                     text: format!("Raise to {} BB", open_size / bb),
                     is_correct: correct == "B",
+                    rating: rate("B"),
+                    hint: hint.clone(),
                     explanation: match text_style {
                         TextStyle::Simple => if correct == "B" {
                             format!("Raise! {hand_str} is a good hand in {pos_str}. Bet {open_size} chips and take control of the pot.")
@@ -237,10 +304,10 @@ fn build_spot<R: Rng>(
                     id: "C".to_string(),
                     text: "Call".to_string(),
                     is_correct: false,
+                    rating: rate("C"),
+                    hint: hint.clone(),
                     explanation: match text_style {
-                        TextStyle::Simple => format!(
-                            "Just calling the big blind here is a bad idea. It lets everyone in cheaply, and you lose control of the hand. Either raise or fold."
-                        ),
+                        TextStyle::Simple => "Just calling the big blind here is a bad idea. It lets everyone in cheaply, and you lose control of the hand. Either raise or fold.".to_string(),
                         TextStyle::Technical => format!(
                             "Limping with {hand_str} from {pos_str}: In most cash game formats \
                              limping is a leak — it invites multiway pots with no initiative, \
@@ -249,13 +316,23 @@ fn build_spot<R: Rng>(
                     },
                 },
             ];
+            let open_width = PositionRange::standard_6max().get(pos);
+            if text_style == TextStyle::Technical && open_width > 0.0 {
+                let note = format!(
+                    " At 6-max, {pos_str} opens about {:.0}% of hands.",
+                    open_width * 100.0
+                );
+                for a in &mut answers {
+                    a.explanation.push_str(&note);
+                }
+            }
             (pot, 0, q, answers)
         }
 
         // ---- Hero faces a single raise ------------------------------------
         PreflopSpot::FacingOpen => {
             let raiser_size = if stack_bb >= 40 { bb * 3 } else { bb * 2 };
-            let pot = bb / 2 + bb + raiser_size; // SB + BB + open
+            let pot = pot_after_raise(bb / 2 + bb, bb, raiser_size); // SB + BB + open
             let three_bet = raiser_size * 3;
             let q = match text_style {
                 TextStyle::Simple => format!(
@@ -304,11 +381,20 @@ fn build_spot<R: Rng>(
                          You risk a 4-bet or playing a large pot with insufficient hand strength.")
             };
 
+            let rate = |id: &str| fold_call_raise_rating(id, correct, fold_rating(cat));
+
+            let hint = Some(match text_style {
+                TextStyle::Simple    => "Someone already raised. How does your hand hold up against them, and where are you sitting?",
+                TextStyle::Technical => "Consider: hand category against a raising range, and position.",
+            }.to_string());
+
             let answers = vec![
                 AnswerOption {
                     id: "A".to_string(),
                     text: "Fold".to_string(),
                     is_correct: correct == "A",
+                    rating: rate("A"),
+                    hint: hint.clone(),
                     explanation: match text_style {
                         TextStyle::Simple => if correct == "A" {
                             format!("Correct. {hand_str} from {pos_str} isn't strong enough to call or re-raise here. Save your chips.")
@@ -324,6 +410,8 @@ fn build_spot<R: Rng>(
                     id: "B".to_string(),
                     text: "Call".to_string(),
                     is_correct: correct == "B",
+                    rating: rate("B"),
+                    hint: hint.clone(),
                     explanation: match text_style {
                         TextStyle::Simple => if correct == "B" {
                             format!("Correct. Call with {hand_str} from {pos_str}. You have a decent hand and a good position — see the flop.")
@@ -341,6 +429,8 @@ fn build_spot<R: Rng>(
                     id: "C".to_string(),
                     text: format!("Raise to {} BB", three_bet / bb),
                     is_correct: correct == "C",
+                    rating: rate("C"),
+                    hint: hint.clone(),
                     explanation: match text_style {
                         TextStyle::Simple => if correct == "C" {
                             format!("Re-raise! {hand_str} from {pos_str} is strong enough to bet big. This builds the pot when you have the best hand.")
@@ -361,8 +451,22 @@ fn build_spot<R: Rng>(
         PreflopSpot::ThreeBetPot => {
             let hero_open = bb * 3;
             let three_bet_size = hero_open * 3;
-            let pot = bb / 2 + bb + hero_open + three_bet_size;
+            let pot = pot_after_raise(pot_after_raise(bb / 2 + bb, bb, hero_open), hero_open, three_bet_size);
             let four_bet = three_bet_size * 3;
+            // After calling the 3-bet, what is left behind vs the pot?
+            let behind = stack.saturating_sub(three_bet_size);
+            let pot_after_call = pot_after_call(pot, three_bet_size - hero_open);
+            let commit_note = if pot_committed(behind, pot_after_call) {
+                format!(
+                    " Calling leaves {} BB behind in a {} BB pot (SPR below {}) — you are \
+                     pot-committed, so decide now whether you are willing to get it all in.",
+                    behind / bb,
+                    pot_after_call / bb,
+                    commit_threshold_spr()
+                )
+            } else {
+                String::new()
+            };
             let q = match text_style {
                 TextStyle::Simple => format!(
                     "You bet {} big blinds with {hand_str} from {pos_str} \
@@ -410,16 +514,29 @@ fn build_spot<R: Rng>(
                          with negative EV.")
             };
 
+            // 4-betting a merely playable hand is worse than a neighbouring error.
+            let rate = |id: &str| match id {
+                "C" if cat == HandCategory::Playable => AnswerRating::Mistake,
+                _ => fold_call_raise_rating(id, correct, fold_rating(cat)),
+            };
+
+            let hint = Some(match text_style {
+                TextStyle::Simple    => "A re-raise usually means a strong hand. How does yours compare?",
+                TextStyle::Technical => "Consider: your hand category against a 3-bet range and the stack-to-pot ratio.",
+            }.to_string());
+
             let answers = vec![
                 AnswerOption {
                     id: "A".to_string(),
                     text: "Fold".to_string(),
                     is_correct: correct == "A",
+                    rating: rate("A"),
+                    hint: hint.clone(),
                     explanation: match text_style {
                         TextStyle::Simple => if correct == "A" {
                             format!("Correct. {hand_str} can't beat your opponent's re-raise range profitably. Let this one go.")
                         } else {
-                            format!("Folding here is too cautious — you have enough of a hand to continue.")
+                            "Folding here is too cautious — you have enough of a hand to continue.".to_string()
                         },
                         TextStyle::Technical => format!(
                             "Folding {hand_str} ({cat_name}) vs 3-bet: {fold_body}"
@@ -430,15 +547,17 @@ fn build_spot<R: Rng>(
                     id: "B".to_string(),
                     text: "Call".to_string(),
                     is_correct: correct == "B",
+                    rating: rate("B"),
+                    hint: hint.clone(),
                     explanation: match text_style {
                         TextStyle::Simple => if correct == "B" {
                             format!("Correct. Call and see the flop. {hand_str} has good enough potential and you keep the pot manageable.")
                         } else {
-                            format!("Just calling here wastes the opportunity — re-raise for value with this strong hand.")
+                            "Just calling here wastes the opportunity — re-raise for value with this strong hand.".to_string()
                         },
                         TextStyle::Technical => format!(
                             "Calling the 3-bet with {hand_str} ({cat_name}) from {pos_str}: \
-                             {call_body}"
+                             {call_body}{commit_note}"
                         ),
                     },
                 },
@@ -446,6 +565,8 @@ fn build_spot<R: Rng>(
                     id: "C".to_string(),
                     text: format!("Raise to {} BB", four_bet / bb),
                     is_correct: correct == "C",
+                    rating: rate("C"),
+                    hint: hint.clone(),
                     explanation: match text_style {
                         TextStyle::Simple => if correct == "C" {
                             format!("Correct. Re-raise again! {hand_str} is a premium hand. Build the pot — you have the best of it here.")
@@ -453,7 +574,7 @@ fn build_spot<R: Rng>(
                             format!("Re-raising here puts too many chips at risk with {hand_str}. Call or fold instead.")
                         },
                         TextStyle::Technical => format!(
-                            "4-betting with {hand_str} ({cat_name}): {fourbet_body}"
+                            "4-betting with {hand_str} ({cat_name}): {fourbet_body}{commit_note}"
                         ),
                     },
                 },
@@ -467,12 +588,16 @@ fn build_spot<R: Rng>(
 // T5 — ICM & Tournament Decision (IC-)
 // ═══════════════════════════════════════════════════════════════════════════
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TournamentStage {
     EarlyLevels,
     MiddleStages,
     Bubble,
     FinalTable,
+    /// A specific spot, e.g. 15 left with 9 paid.  Push thresholds and risk
+    /// premium are interpolated between the fixed stages by
+    /// `paid_spots / players_remaining`.  Never produced by `random_stage`.
+    Custom { players_remaining: u32, paid_spots: u32 },
 }
 
 impl std::fmt::Display for TournamentStage {
@@ -482,10 +607,54 @@ impl std::fmt::Display for TournamentStage {
             TournamentStage::MiddleStages => write!(f, "Middle Stages"),
             TournamentStage::Bubble       => write!(f, "Bubble"),
             TournamentStage::FinalTable   => write!(f, "Final Table"),
+            TournamentStage::Custom { players_remaining, paid_spots } => {
+                write!(f, "Custom ({players_remaining} left, {paid_spots} paid)")
+            }
         }
     }
 }
 
+/// Bubble ratio (`paid / remaining`) each fixed stage is anchored at, in
+/// increasing order.  A `Custom` ratio is placed between its two neighbours.
+const STAGE_RATIOS: [(TournamentStage, f32); 4] = [
+    (TournamentStage::EarlyLevels,  0.10),
+    (TournamentStage::MiddleStages, 0.15),
+    (TournamentStage::Bubble,       0.20),
+    (TournamentStage::FinalTable,   0.33),
+];
+
+/// Evaluates `value` for `stage`, linearly interpolating `Custom` stages
+/// between the fixed stages around its bubble ratio (clamped at both ends).
+fn stage_value(stage: TournamentStage, value: fn(TournamentStage) -> f32) -> f32 {
+    let TournamentStage::Custom { players_remaining, paid_spots } = stage else {
+        return value(stage);
+    };
+    let ratio = paid_spots as f32 / players_remaining.max(1) as f32;
+    let (first, last) = (STAGE_RATIOS[0], STAGE_RATIOS[STAGE_RATIOS.len() - 1]);
+    if ratio <= first.1 {
+        return value(first.0);
+    }
+    if ratio >= last.1 {
+        return value(last.0);
+    }
+    // ratio is strictly inside the anchors, so 1 <= i < len.
+    let i = STAGE_RATIOS.iter().position(|&(_, r)| ratio <= r).unwrap_or(STAGE_RATIOS.len() - 1);
+    let ((lo, lo_r), (hi, hi_r)) = (STAGE_RATIOS[i - 1], STAGE_RATIOS[i]);
+    let t = (ratio - lo_r) / (hi_r - lo_r);
+    value(lo) + t * (value(hi) - value(lo))
+}
+
+/// Approximate ICM risk premium (percent) at `stage`.
+pub fn icm_risk_premium_pct(stage: TournamentStage) -> f32 {
+    stage_value(stage, |s| match s {
+        TournamentStage::Bubble       => 20.0,
+        TournamentStage::FinalTable   => 15.0,
+        TournamentStage::MiddleStages => 8.0,
+        TournamentStage::EarlyLevels  => 3.0,
+        TournamentStage::Custom { .. } => unreachable!("custom stages are interpolated"),
+    })
+}
+
 fn random_stage<R: Rng>(rng: &mut R) -> TournamentStage {
     match rng.gen_range(0..4) {
         0 => TournamentStage::EarlyLevels,
@@ -495,43 +664,17 @@ fn random_stage<R: Rng>(rng: &mut R) -> TournamentStage {
     }
 }
 
-/// Hand strength tiers for push/fold (simplified).
-#[derive(Debug, Clone, Copy)]
-enum PushTier {
-    Premium,  // AA, KK, QQ, AKs — always push short stacks
-    Strong,   // JJ, TT, AQ, AK — push at moderate depths
-    Playable, // Mid pairs, suited broadways — push only when short
-    Weak,     // Everything else — only push when desperate
-}
-
-fn classify_push_tier(hand: [Card; 2]) -> PushTier {
-    let (r1, r2) = {
-        let mut ranks = [hand[0].rank.0, hand[1].rank.0];
-        ranks.sort_unstable_by(|a, b| b.cmp(a));
-        (ranks[0], ranks[1])
-    };
-    let suited = hand[0].suit == hand[1].suit;
-    let pair = r1 == r2;
-
-    if pair && r1 >= 12 { return PushTier::Premium; }       // QQ+
-    if r1 == 14 && r2 == 13 && suited { return PushTier::Premium; } // AKs
-    if pair && r1 >= 10 { return PushTier::Strong; }         // JJ, TT
-    if r1 == 14 && r2 >= 12 { return PushTier::Strong; }    // AK, AQ
-    if pair && r1 >= 7 { return PushTier::Playable; }        // 77-99
-    if r1 == 14 && r2 >= 10 && suited { return PushTier::Playable; } // ATs+
-    if r1 >= 12 && r2 >= 11 && suited { return PushTier::Playable; } // KQs, KJs, QJs
-    PushTier::Weak
-}
-
 /// Simplified ICM pressure: base threshold in BB modified by hand strength.
 /// Real ICM requires knowing payouts; here we use simplified thresholds.
 fn push_threshold_bb(stage: TournamentStage, tier: PushTier) -> u32 {
-    let base = match stage {
-        TournamentStage::EarlyLevels  => 20,
-        TournamentStage::MiddleStages => 15,
-        TournamentStage::Bubble       => 10,
-        TournamentStage::FinalTable   => 12,
-    };
+    let base = stage_value(stage, |s| match s {
+        TournamentStage::EarlyLevels  => 20.0,
+        TournamentStage::MiddleStages => 15.0,
+        TournamentStage::Bubble       => 10.0,
+        TournamentStage::FinalTable   => 12.0,
+        TournamentStage::Custom { .. } => unreachable!("custom stages are interpolated"),
+    })
+    .round() as u32;
     // Premium hands can push at deeper stacks; weak hands need more desperation
     match tier {
         PushTier::Premium  => base + 8,
@@ -543,15 +686,61 @@ fn push_threshold_bb(stage: TournamentStage, tier: PushTier) -> u32 {
 
 /// T5 — ICM & Tournament Decision (IC-).
 ///
-/// RNG order: gen_range(0..4) for stage → hero_stack → villain_stack →
-/// players_remaining → Deck::new_shuffled → deal×2.
-pub fn generate_icm<R: Rng>(
+/// RNG order: gen_range(0..4) for stage →
+/// hero_stack → villain_stack → players_remaining → ante flag.
+pub fn generate_icm<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, _) = deal(dealer, 0)?;
+    let stage = random_stage(rng);
+    icm_scenario(rng, hero_hand, difficulty, scenario_id, text_style, stage, false)
+}
+
+/// T5 in a progressive-knockout tournament: every player carries a bounty.
+///
+/// Same RNG order as [`generate_icm`], with hero's and villain's bounties
+/// drawn right after the deal.
+pub fn generate_icm_bounty<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, _) = deal(dealer, 0)?;
     let stage = random_stage(rng);
+    icm_scenario(rng, hero_hand, difficulty, scenario_id, text_style, stage, true)
+}
+
+/// T5 at a caller-chosen `stage` — the way to drill a `Custom` bubble.
+///
+/// A `Custom` stage takes its player and paid counts from the variant instead
+/// of drawing `players_remaining`.
+pub fn generate_icm_at_stage<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+    stage: TournamentStage,
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, _) = deal(dealer, 0)?;
+    icm_scenario(rng, hero_hand, difficulty, scenario_id, text_style, stage, false)
+}
+
+fn icm_scenario<R: Rng>(
+    rng: &mut R,
+    hero_hand: [Card; 2],
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+    stage: TournamentStage,
+    is_bounty: bool,
+) -> Result<TrainingScenario, GenerationError> {
     let bb = 100u32; // tournament chips, 100 = 1 BB
 
     let hero_stack_bb = match difficulty {
@@ -569,15 +758,41 @@ pub fn generate_icm<R: Rng>(
         TournamentStage::MiddleStages => rng.gen_range(25..=60),
         TournamentStage::Bubble       => rng.gen_range(10..=18),
         TournamentStage::FinalTable   => rng.gen_range(3..=9),
+        TournamentStage::Custom { players_remaining, .. } => players_remaining,
     };
 
-    let paid_spots = (players_remaining as f32 * 0.15).ceil() as u32;
+    let paid_spots = match stage {
+        TournamentStage::Custom { paid_spots, .. } => paid_spots,
+        _ => (players_remaining as f32 * 0.15).ceil() as u32,
+    };
 
-    let mut deck = Deck::new_shuffled(rng);
-    let hero_hand: [Card; 2] = [deck.deal(), deck.deal()];
     let hero_pos = Position::BTN;
     let pos_str = format!("{}", hero_pos);
-    let hand_str = format!("{}{}", hero_hand[0], hero_hand[1]);
+    let hand_str = normalized_hand_notation(hero_hand);
+
+    // PKO bounties in dollars, $10–$100 in $5 steps.
+    let bounties = is_bounty.then(|| (rng.gen_range(2..=20u32) * 5, rng.gen_range(2..=20u32) * 5));
+    let (bounty_intro, bounty_note) = match bounties {
+        Some((hero_bounty, villain_bounty)) => (
+            format!(" Bounty tournament: villain's bounty is ${villain_bounty}, yours is ${hero_bounty}."),
+            if hero_stack >= villain_stack {
+                format!(
+                    " Winning the all-in captures villain's ${villain_bounty} bounty — at roughly \
+                     50% when called, that adds ~${} EV to the shove.",
+                    villain_bounty / 2
+                )
+            } else {
+                format!(
+                    " Villain covers you, so their ${villain_bounty} bounty is out of reach, \
+                     while your own ${hero_bounty} bounty widens villain's calling range."
+                )
+            },
+        ),
+        None => (String::new(), String::new()),
+    };
+
+    // Half the spots are played with a 1 BB big-blind ante.
+    let antes = rng.gen_bool(0.5).then_some(bb);
 
     let push_tier = classify_push_tier(hero_hand);
     let threshold = push_threshold_bb(stage, push_tier);
@@ -588,18 +803,29 @@ pub fn generate_icm<R: Rng>(
         TournamentStage::MiddleStages => "Middle",
         TournamentStage::Bubble       => "Bubble",
         TournamentStage::FinalTable   => "FinalTable",
+        TournamentStage::Custom { .. } => "Custom",
     };
     let branch_key = format!("{}:{}", stage_name, if should_push { "Push" } else { "Fold" });
 
-    let pot = bb + bb / 2; // standard antes + blinds estimate
-
-    let risk_premium_pct: f32 = match stage {
-        TournamentStage::Bubble       => 20.0,
-        TournamentStage::FinalTable   => 15.0,
-        TournamentStage::MiddleStages => 8.0,
-        TournamentStage::EarlyLevels  => 3.0,
+    let blinds = bb + bb / 2;
+    let pot = blinds + antes.unwrap_or(0);
+    let ante_note = match antes {
+        Some(ante) => format!(
+            " With a {}BB ante, pot is {:.1} BB preflop vs {:.1} BB without.",
+            ante / bb,
+            pot as f32 / bb as f32,
+            blinds as f32 / bb as f32
+        ),
+        None => String::new(),
     };
 
+    let risk_premium_pct = icm_risk_premium_pct(stage);
+    let nash_bb = nash_push_threshold_bb(hero_hand, hero_pos);
+    let nash_note = format!(
+        " In chip EV, nash_push_threshold_bb puts the {pos_str} shove with {hand_str} at up to \
+         {nash_bb} BB; at {stage} the ICM-adjusted line is {threshold} BB."
+    );
+
     let question = match text_style {
         TextStyle::Simple => format!(
             "Tournament: {stage}. {players_remaining} players left, top {paid_spots} get paid. \
@@ -611,7 +837,7 @@ pub fn generate_icm<R: Rng>(
             "Tournament: {stage}. {players_remaining} players remain, top {paid_spots} paid. \
              You hold {hand_str} on the {pos_str} with {hero_stack_bb} BB. \
              Villain on the BB has {villain_stack_bb} BB. \
-             Action folds to you. Do you shove all-in or fold?"
+             Action folds to you.{ante_note}{bounty_intro} Do you shove all-in or fold?"
         ),
     };
 
@@ -639,7 +865,7 @@ pub fn generate_icm<R: Rng>(
             format!("Going all-in too early at {hero_stack_bb} big blinds risks your tournament life needlessly. You still have time to find a better spot.")
         },
         TextStyle::Technical => format!(
-            "Shoving {hero_stack_bb} BB with {hand_str} from {pos_str} during {stage}: {push_body}"
+            "Shoving {hero_stack_bb} BB with {hand_str} from {pos_str} during {stage}: {push_body}{nash_note}{bounty_note}"
         ),
     };
 
@@ -665,21 +891,37 @@ pub fn generate_icm<R: Rng>(
             format!("Folding here is wrong — with {hero_stack_bb} big blinds your stack is getting dangerously low. You need to shove while you still have some chips to be scary.")
         },
         TextStyle::Technical => format!(
-            "Folding {hand_str} from {pos_str} with {hero_stack_bb} BB during {stage}: {fold_body}"
+            "Folding {hand_str} from {pos_str} with {hero_stack_bb} BB during {stage}: {fold_body}{nash_note}"
         ),
     };
 
+    // Within a few BB of the push threshold the wrong choice is only marginal.
+    let miss = if hero_stack_bb.abs_diff(threshold) <= 3 {
+        AnswerRating::Plausible
+    } else {
+        AnswerRating::Mistake
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Count your big blinds and think about how close you are to the prize money.",
+        TextStyle::Technical => "Consider: stack depth in BB, tournament stage and ICM risk premium.",
+    }.to_string());
+
     let answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "All-in".to_string(),
             is_correct: should_push,
+            rating: if should_push { AnswerRating::Correct } else { miss },
+            hint: hint.clone(),
             explanation: push_explanation,
         },
         AnswerOption {
             id: "B".to_string(),
             text: "Fold".to_string(),
             is_correct: !should_push,
+            rating: if !should_push { AnswerRating::Correct } else { miss },
+            hint: hint.clone(),
             explanation: fold_explanation,
         },
     ];
@@ -691,6 +933,7 @@ pub fn generate_icm<R: Rng>(
             stack: villain_stack,
             is_hero: false,
             is_active: true,
+            bounty: bounties.map(|(_, v)| v),
         },
         PlayerState {
             seat: 2,
@@ -698,6 +941,7 @@ pub fn generate_icm<R: Rng>(
             stack: hero_stack,
             is_hero: true,
             is_active: true,
+            bounty: bounties.map(|(h, _)| h),
         },
     ];
 
@@ -709,18 +953,224 @@ pub fn generate_icm<R: Rng>(
         players,
         pot_size: pot,
         current_bet: 0,
+        antes,
     };
 
-    TrainingScenario {
+    let extra: Vec<&str> = [
+        (matches!(stage, TournamentStage::Bubble), "bubble"),
+        (is_bounty, "bounty"),
+        (antes.is_some(), "antes"),
+    ]
+    .into_iter()
+    .filter_map(|(applies, c)| applies.then_some(c))
+    .collect();
+    Ok(TrainingScenario {
         scenario_id,
         topic: TrainingTopic::ICMAndTournamentDecision,
         branch_key,
         table_setup,
         question,
         answers,
+        key_concepts: key_concepts(TrainingTopic::ICMAndTournamentDecision, &extra),
+        villain_tendency: None,
+    })
+}
+
+/// Starting stack of each player in a 9-player sit & go.
+const SNG_STARTING_STACK: u32 = 1500;
+
+/// Hero's ICM numbers for a shove from the button that the big blind calls.
+struct SngShove {
+    /// Hero's share of the prize pool after folding.
+    equity_fold: f64,
+    /// Equity a called shove needs to beat folding, in prize-pool terms.
+    required_icm: f64,
+    /// Equity a called shove needs in chip terms.
+    required_chip: f64,
+}
+
+/// `stacks` are CO, BTN (hero), SB, BB (villain) before the blinds move.
+fn sng_shove_icm(stacks: [u32; 4], bb: u32) -> SngShove {
+    let table = ICMPayoutTable { places: vec![0.50, 0.30, 0.20] };
+    let hero_equity = |s: [u32; 4]| icm_equity(&s, &table).expect("valid SNG payout table")[1];
+    let [co, hero, sb, villain] = stacks;
+    let dead = bb / 2; // the small blind folds
+    let eff = hero.min(villain);
+
+    let equity_fold = hero_equity([co, hero, sb - dead, villain + dead]);
+    let equity_win  = hero_equity([co, hero + eff + dead, sb - dead, villain - eff]);
+    let equity_lose = hero_equity([co, hero - eff, sb - dead, villain + eff + dead]);
+
+    SngShove {
+        equity_fold,
+        required_icm: (equity_fold - equity_lose) / (equity_win - equity_lose),
+        required_chip: eff as f64 / (2 * eff + dead) as f64,
     }
 }
 
+/// T5 variant — 9-player sit & go on the money bubble (IC-).
+///
+/// Four players remain and three are paid (50/30/20).  Hero is on the Button
+/// and it folds to them; the Big Blind is the only caller considered.  The
+/// push threshold starts from the `Bubble` stage and shrinks by the ratio of
+/// chip-EV to ICM required equity for a called shove at these stacks.
+///
+/// RNG order: hero_stack → 3 stack weights; hero's cards come from `dealer`.
+pub fn generate_icm_sng<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let bb = 200u32; // 100/200 blinds
+    let total = SNG_STARTING_STACK * 9;
+
+    let hero_stack_bb = match difficulty {
+        DifficultyLevel::Beginner     => rng.gen_range(6..=15u32),
+        DifficultyLevel::Intermediate => rng.gen_range(4..=20),
+        DifficultyLevel::Advanced     => rng.gen_range(3..=25),
+    };
+    let hero_stack = hero_stack_bb * bb;
+
+    // Split the remaining chips between CO, SB and BB.
+    let weights: [u32; 3] = [rng.gen_range(2..=10), rng.gen_range(2..=10), rng.gen_range(2..=10)];
+    let rest = total - hero_stack;
+    let weight_sum: u32 = weights.iter().sum();
+    let co_stack = rest * weights[0] / weight_sum;
+    let sb_stack = rest * weights[1] / weight_sum;
+    let villain_stack = rest - co_stack - sb_stack;
+    let villain_stack_bb = villain_stack / bb;
+
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
+    let hand_str = normalized_hand_notation(hero_hand);
+
+    let icm = sng_shove_icm([co_stack, hero_stack, sb_stack, villain_stack], bb);
+    let premium_pts = (icm.required_icm - icm.required_chip) * 100.0;
+    let base = push_threshold_bb(TournamentStage::Bubble, classify_push_tier(hero_hand));
+    let threshold = (base as f64 * icm.required_chip / icm.required_icm).round() as u32;
+    let should_push = hero_stack_bb <= threshold;
+
+    let branch_key = format!("SNGBubble:{}", if should_push { "Push" } else { "Fold" });
+
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "Sit & Go bubble: 4 players left, top 3 get paid. \
+             You have {hand_str} on the Button with {hero_stack_bb} big blinds. \
+             Your opponent in the Big Blind has {villain_stack_bb} big blinds. \
+             Everyone else folded. Go all-in or fold?"
+        ),
+        TextStyle::Technical => format!(
+            "9-player SNG bubble (50/30/20): 4 left, 3 paid. Stacks: CO {} BB, \
+             BTN (you) {hero_stack_bb} BB, SB {} BB, BB {villain_stack_bb} BB. \
+             You hold {hand_str}. Action folds to you. Do you shove all-in or fold?",
+            co_stack / bb,
+            sb_stack / bb
+        ),
+    };
+
+    let icm_body = format!(
+        "ICM: folding keeps {:.1}% of the prize pool. A called shove needs {:.1}% equity \
+         vs {:.1}% in chip terms — a {premium_pts:.1}-point bubble risk premium — so the \
+         push threshold for this hand tightens from {base} to {threshold} BB.",
+        icm.equity_fold * 100.0,
+        icm.required_icm * 100.0,
+        icm.required_chip * 100.0
+    );
+
+    let push_explanation = match text_style {
+        TextStyle::Simple => if should_push {
+            format!("Correct — go all-in! With {hero_stack_bb} big blinds you can't afford to wait; even on the bubble this hand is strong enough to shove.")
+        } else {
+            format!("Going all-in here risks busting one spot from the money. With {hero_stack_bb} big blinds you can wait for a better hand.")
+        },
+        TextStyle::Technical => format!(
+            "Shoving {hero_stack_bb} BB with {hand_str}: {icm_body} {}",
+            if should_push {
+                "You are inside the threshold — shove."
+            } else {
+                "You are outside the threshold — the bust risk outweighs the chips you win."
+            }
+        ),
+    };
+    let fold_explanation = match text_style {
+        TextStyle::Simple => if !should_push {
+            format!("Correct — fold. Busting now pays nothing, and with {hero_stack_bb} big blinds you can let the shorter stacks bust first.")
+        } else {
+            format!("Folding is too tight — at {hero_stack_bb} big blinds the blinds will eat your stack before anyone else busts.")
+        },
+        TextStyle::Technical => format!(
+            "Folding {hand_str} with {hero_stack_bb} BB: {icm_body} {}",
+            if !should_push {
+                "Folding protects your equity — correct."
+            } else {
+                "Folding gives up a profitable shove even after the ICM adjustment."
+            }
+        ),
+    };
+
+    // Within a few BB of the push threshold the wrong choice is only marginal.
+    let miss = if hero_stack_bb.abs_diff(threshold) <= 3 {
+        AnswerRating::Plausible
+    } else {
+        AnswerRating::Mistake
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "One more player busting puts you in the money. How badly do you need chips?",
+        TextStyle::Technical => "Consider: the ICM risk premium on a 4-left, 3-paid bubble and your stack in BB.",
+    }.to_string());
+
+    let answers = vec![
+        AnswerOption {
+            id: "A".to_string(),
+            text: "All-in".to_string(),
+            is_correct: should_push,
+            rating: if should_push { AnswerRating::Correct } else { miss },
+            hint: hint.clone(),
+            explanation: push_explanation,
+        },
+        AnswerOption {
+            id: "B".to_string(),
+            text: "Fold".to_string(),
+            is_correct: !should_push,
+            rating: if !should_push { AnswerRating::Correct } else { miss },
+            hint: hint.clone(),
+            explanation: fold_explanation,
+        },
+    ];
+
+    let seat = |seat, position, stack, is_hero| PlayerState {
+        seat, position, stack, is_hero, is_active: true, bounty: None,
+    };
+    let players = vec![
+        seat(1, Position::CO, co_stack, false),
+        seat(2, Position::BTN, hero_stack, true),
+        seat(3, Position::SB, sb_stack, false),
+        seat(4, Position::BB, villain_stack, false),
+    ];
+
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::ICMAndTournamentDecision,
+        branch_key,
+        table_setup: TableSetup {
+            game_type: GameType::SitAndGo,
+            hero_position: Position::BTN,
+            hero_hand,
+            board: vec![],
+            players,
+            pot_size: bb + bb / 2,
+            current_bet: 0,
+            antes: None,
+        },
+        question,
+        answers,
+        key_concepts: key_concepts(TrainingTopic::ICMAndTournamentDecision, &["bubble"]),
+        villain_tendency: None,
+    })
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // T9 — Anti-Limper Isolation (AL-)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -748,16 +1198,16 @@ fn al_position_label(pos: Position) -> &'static str {
 
 /// T9 — Anti-Limper Isolation (AL-).
 ///
-/// RNG order: Deck::new_shuffled → deal×2 → gen_range(0..3) for position →
+/// RNG order: gen_range(0..3) for position →
 /// gen_range(1..=3) for limper count → stack.
-pub fn generate_anti_limper<R: Rng>(
+pub fn generate_anti_limper<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let mut deck = Deck::new_shuffled(rng);
-    let hero_hand: [Card; 2] = [deck.deal(), deck.deal()];
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
 
     let hero_pos = match rng.gen_range(0..3) {
         0 => Position::CO,
@@ -781,7 +1231,7 @@ pub fn generate_anti_limper<R: Rng>(
     let iso_bb = iso_raise_bb(limper_count);
     let iso_chips = iso_bb * bb;
 
-    let hand_str = format!("{}{}", hero_hand[0], hero_hand[1]);
+    let hand_str = normalized_hand_notation(hero_hand);
     let pos_str = al_position_label(hero_pos);
     let limper_word = if limper_count == 1 { "limper" } else { "limpers" };
     let pos_qualifier = if ip { "in position" } else { "out of position" };
@@ -854,9 +1304,7 @@ pub fn generate_anti_limper<R: Rng>(
                 "Correct — just call. With {hand_str} from the Small Blind (you'll act first all game), raising is risky. Call cheaply and see if you hit the flop."
             ),
             _ => if ip {
-                format!(
-                    "Just calling here wastes your positional advantage. You're acting last — raise to take control and play heads-up."
-                )
+                "Just calling here wastes your positional advantage. You're acting last — raise to take control and play heads-up.".to_string()
             } else {
                 format!(
                     "Just calling here is too passive with {hand_str}. Raise — you have a strong enough hand to take control."
@@ -935,6 +1383,7 @@ pub fn generate_anti_limper<R: Rng>(
             stack,
             is_hero: false,
             is_active: true,
+            bounty: None,
         },
         PlayerState {
             seat: 2,
@@ -942,26 +1391,44 @@ pub fn generate_anti_limper<R: Rng>(
             stack,
             is_hero: true,
             is_active: true,
+            bounty: None,
         },
     ];
 
+    // Folding a playable hand from the SB is only a small leak.
+    let rate = |id: &str| {
+        let fold = if correct == "B" { AnswerRating::Plausible } else { fold_rating(cat) };
+        fold_call_raise_rating(id, correct, fold)
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Players who just call usually have weak hands. How good is yours, and will you act last after the flop?",
+        TextStyle::Technical => "Consider: hand category, position, and the limpers' capped range.",
+    }.to_string());
+
     let answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Fold".to_string(),
             is_correct: correct == "A",
+            rating: rate("A"),
+            hint: hint.clone(),
             explanation: fold_exp,
         },
         AnswerOption {
             id: "B".to_string(),
             text: "Call".to_string(),
             is_correct: correct == "B",
+            rating: rate("B"),
+            hint: hint.clone(),
             explanation: overlimp_exp,
         },
         AnswerOption {
             id: "C".to_string(),
             text: format!("Raise to {} BB", iso_bb),
             is_correct: correct == "C",
+            rating: rate("C"),
+            hint: hint.clone(),
             explanation: iso_exp,
         },
     ];
@@ -974,16 +1441,19 @@ pub fn generate_anti_limper<R: Rng>(
         players,
         pot_size: pot,
         current_bet: bb, // the limp amount
+        antes: None,
     };
 
-    TrainingScenario {
+    Ok(TrainingScenario {
         scenario_id,
         topic: TrainingTopic::AntiLimperIsolation,
         branch_key,
         table_setup,
         question,
         answers,
-    }
+        key_concepts: key_concepts(TrainingTopic::AntiLimperIsolation, &[]),
+        villain_tendency: None,
+    })
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1010,16 +1480,16 @@ impl std::fmt::Display for HoleStrength {
 
 /// T11 — Squeeze Play (SQ-).
 ///
-/// RNG order: Deck::new_shuffled → deal×2 → gen_range(0..3) for strength →
+/// RNG order: gen_range(0..3) for strength →
 /// callers → sizing.
-pub fn generate_squeeze<R: Rng>(
+pub fn generate_squeeze<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let mut deck = Deck::new_shuffled(rng);
-    let hero_hand: [Card; 2] = [deck.deal(), deck.deal()];
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
 
     let strength = match rng.gen_range(0..3) {
         0 => HoleStrength::Premium,
@@ -1067,7 +1537,7 @@ pub fn generate_squeeze<R: Rng>(
 
     let hero_pos   = Position::BTN;
     let opener_pos = Position::UTG;
-    let hand_str   = format!("{}{}", hero_hand[0], hero_hand[1]);
+    let hand_str   = normalized_hand_notation(hero_hand);
     let caller_str = if callers == 1 {
         "1 caller".to_string()
     } else {
@@ -1090,19 +1560,30 @@ pub fn generate_squeeze<R: Rng>(
         ),
     };
 
+    let rate = |id: &str| {
+        let fold = match strength {
+            HoleStrength::Premium => AnswerRating::Blunder,
+            _                     => AnswerRating::Plausible,
+        };
+        fold_call_raise_rating(id, correct, fold)
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "A raise and a call are already in. Is your hand good enough to re-raise, or better for seeing a cheap flop?",
+        TextStyle::Technical => "Consider: dead money, hand playability multiway, and implied odds.",
+    }.to_string());
+
     let answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Fold".to_string(),
             is_correct: correct == "A",
+            rating: rate("A"),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
-                    HoleStrength::Weak => format!(
-                        "Correct — fold. Your hand isn't strong enough to enter a large pot against multiple active players."
-                    ),
-                    _ => format!(
-                        "Folding here is too cautious — you have a good enough hand to re-raise or call."
-                    ),
+                    HoleStrength::Weak => "Correct — fold. Your hand isn't strong enough to enter a large pot against multiple active players.".to_string(),
+                    _ => "Folding here is too cautious — you have a good enough hand to re-raise or call.".to_string(),
                 },
                 TextStyle::Technical => match strength {
                     HoleStrength::Weak => format!(
@@ -1123,17 +1604,13 @@ pub fn generate_squeeze<R: Rng>(
             id: "B".to_string(),
             text: format!("Call ({open_bb} BB)"),
             is_correct: correct == "B",
+            rating: rate("B"),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
-                    HoleStrength::Speculative => format!(
-                        "Correct — call. With a hand that plays well in big pots, you can call and try to hit a big hand on the flop."
-                    ),
-                    HoleStrength::Premium => format!(
-                        "Just calling isn't the best play here — re-raise to take control and thin the field."
-                    ),
-                    HoleStrength::Weak => format!(
-                        "Just calling isn't the best play here — re-raise to take control and thin the field."
-                    ),
+                    HoleStrength::Speculative => "Correct — call. With a hand that plays well in big pots, you can call and try to hit a big hand on the flop.".to_string(),
+                    HoleStrength::Premium => "Just calling isn't the best play here — re-raise to take control and thin the field.".to_string(),
+                    HoleStrength::Weak => "Just calling isn't the best play here — re-raise to take control and thin the field.".to_string(),
                 },
                 TextStyle::Technical => match strength {
                     HoleStrength::Speculative => format!(
@@ -1159,6 +1636,8 @@ pub fn generate_squeeze<R: Rng>(
             id: "C".to_string(),
             text: format!("Squeeze to {squeeze} chips ({squeeze_bb} BB)"),
             is_correct: correct == "C",
+            rating: rate("C"),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
                     HoleStrength::Premium => format!(
@@ -1196,11 +1675,11 @@ pub fn generate_squeeze<R: Rng>(
     ];
 
     let players = vec![
-        PlayerState { seat: 1, position: opener_pos, stack, is_hero: false, is_active: true },
-        PlayerState { seat: 2, position: hero_pos,   stack, is_hero: true,  is_active: true },
+        PlayerState { seat: 1, position: opener_pos, stack, is_hero: false, is_active: true, bounty: None },
+        PlayerState { seat: 2, position: hero_pos,   stack, is_hero: true,  is_active: true, bounty: None },
     ];
 
-    TrainingScenario {
+    Ok(TrainingScenario {
         scenario_id,
         topic: TrainingTopic::SqueezePlay,
         branch_key,
@@ -1212,10 +1691,13 @@ pub fn generate_squeeze<R: Rng>(
             players,
             pot_size:      pot,
             current_bet:   open_bb * bb,
+            antes:         None,
         },
         question,
         answers,
-    }
+        key_concepts: key_concepts(TrainingTopic::SqueezePlay, &[]),
+        villain_tendency: None,
+    })
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1242,16 +1724,16 @@ impl std::fmt::Display for DefenseStrength {
 
 /// T12 — Big Blind Defense (BD-).
 ///
-/// RNG order: Deck::new_shuffled → deal×2 → gen_range(0..3) for strength →
+/// RNG order: gen_range(0..3) for strength →
 /// gen_range(0..3) for villain_pos → sizing.
-pub fn generate_bb_defense<R: Rng>(
+pub fn generate_bb_defense<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let mut deck = Deck::new_shuffled(rng);
-    let hero_hand: [Card; 2] = [deck.deal(), deck.deal()];
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
 
     let strength = match rng.gen_range(0..3) {
         0 => DefenseStrength::Strong,
@@ -1299,7 +1781,7 @@ pub fn generate_bb_defense<R: Rng>(
     }.to_string();
 
     let hero_pos = Position::BB;
-    let hand_str = format!("{}{}", hero_hand[0], hero_hand[1]);
+    let hand_str = normalized_hand_notation(hero_hand);
 
     let question = match text_style {
         TextStyle::Simple => format!(
@@ -1317,19 +1799,32 @@ pub fn generate_bb_defense<R: Rng>(
         ),
     };
 
-    let answers = vec![
+    let rate = |id: &str| {
+        let fold = match strength {
+            DefenseStrength::Strong => AnswerRating::Blunder,
+            _                       => AnswerRating::Mistake,
+        };
+        fold_call_raise_rating(id, correct, fold)
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "You already have a big blind in the pot, so calling is cheaper. Is your hand worth it?",
+        TextStyle::Technical => "Consider: the BB price discount, hand category and the raiser's position.",
+    }.to_string());
+
+    let mut answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Fold".to_string(),
             is_correct: correct == "A",
+            rating: rate("A"),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
                     DefenseStrength::Weak => format!(
                         "Correct — fold. Even with your money already in, {hand_str} isn't strong enough to continue against this raise."
                     ),
-                    _ => format!(
-                        "Folding here throws away money you already put in. You have a playable hand — call at minimum."
-                    ),
+                    _ => "Folding here throws away money you already put in. You have a playable hand — call at minimum.".to_string(),
                 },
                 TextStyle::Technical => match strength {
                     DefenseStrength::Weak => format!(
@@ -1350,17 +1845,15 @@ pub fn generate_bb_defense<R: Rng>(
             id: "B".to_string(),
             text: format!("Call ({raise_bb} BB)"),
             is_correct: correct == "B",
+            rating: rate("B"),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
                     DefenseStrength::Playable => format!(
                         "Correct — call! You're already part-way in with the Big Blind and {hand_str} can see a flop at a discount. Don't fold this away."
                     ),
-                    DefenseStrength::Strong => format!(
-                        "Just calling is too passive — re-raise with this strong hand to build the pot."
-                    ),
-                    DefenseStrength::Weak => format!(
-                        "Just calling is too passive — re-raise with this strong hand to build the pot."
-                    ),
+                    DefenseStrength::Strong => "Just calling is too passive — re-raise with this strong hand to build the pot.".to_string(),
+                    DefenseStrength::Weak => "Just calling is too passive — re-raise with this strong hand to build the pot.".to_string(),
                 },
                 TextStyle::Technical => match strength {
                     DefenseStrength::Playable => format!(
@@ -1389,6 +1882,8 @@ pub fn generate_bb_defense<R: Rng>(
             id: "C".to_string(),
             text: format!("3-bet to {three_bet} chips ({three_bet_bb} BB)"),
             is_correct: correct == "C",
+            rating: rate("C"),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
                     DefenseStrength::Strong => format!(
@@ -1423,12 +1918,24 @@ pub fn generate_bb_defense<R: Rng>(
         },
     ];
 
+    if text_style == TextStyle::Technical {
+        let range_note = format!(
+            " {villain_pos} opens roughly {:.0}% of hands; against a {raise_bb} BB open the \
+             BB defends about {:.0}%.",
+            villain_range_width(villain_pos) * 100.0,
+            bb_defense_range_width(villain_pos, raise_bb) * 100.0,
+        );
+        for a in &mut answers {
+            a.explanation.push_str(&range_note);
+        }
+    }
+
     let players = vec![
-        PlayerState { seat: 1, position: villain_pos, stack, is_hero: false, is_active: true },
-        PlayerState { seat: 2, position: hero_pos,    stack, is_hero: true,  is_active: true },
+        PlayerState { seat: 1, position: villain_pos, stack, is_hero: false, is_active: true, bounty: None },
+        PlayerState { seat: 2, position: hero_pos,    stack, is_hero: true,  is_active: true, bounty: None },
     ];
 
-    TrainingScenario {
+    Ok(TrainingScenario {
         scenario_id,
         topic: TrainingTopic::BigBlindDefense,
         branch_key,
@@ -1440,8 +1947,1958 @@ pub fn generate_bb_defense<R: Rng>(
             players,
             pot_size:      pot,
             current_bet:   raise_bb * bb,
+            antes:         None,
         },
         question,
         answers,
-    }
+        key_concepts: key_concepts(TrainingTopic::BigBlindDefense, &[]),
+        villain_tendency: None,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// T18 — Set Mining (SM-)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Stack-to-call multiple at which calling a raise purely to flop a set
+/// becomes profitable: a set comes about once in 8.5 flops and must win
+/// enough of the remaining stack to pay for the misses.
+pub const SET_MINING_MIN_RATIO: u32 = 10;
+
+/// T18 — Set Mining (SM-).
+///
+/// Hero holds 22–66 on the Button facing an open.  The first card comes
+/// from the dealer and fixes one suit; the pair rank and the second suit
+/// come from the RNG.
+///
+/// RNG order: pair rank → second suit → villain_pos → callers → sizing.
+pub fn generate_set_mining<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let first = dealer.deal_matching(|c| (2..=6).contains(&c.rank.0))?;
+    let rank = first.rank;
+    let hero_hand = [first, dealer.deal_matching(|c| c.rank == rank)?];
+
+    let villain_pos = match rng.gen_range(0..3) {
+        0 => Position::UTG,
+        1 => Position::HJ,
+        _ => Position::CO,
+    };
+    // Zero or one caller (from the CO) between the raiser and hero.
+    let callers = if villain_pos == Position::CO { 0 } else { rng.gen_range(0..=1u32) };
+
+    let bb = 2u32;
+    let (raise_bb, stack_bb) = match difficulty {
+        DifficultyLevel::Beginner => (
+            3u32,
+            if rng.gen_bool(0.5) { rng.gen_range(12..=20u32) } else { rng.gen_range(60..=100) },
+        ),
+        DifficultyLevel::Intermediate => (rng.gen_range(2..=4), rng.gen_range(15..=80)),
+        DifficultyLevel::Advanced     => (rng.gen_range(2..=6), rng.gen_range(10..=120)),
+    };
+    let call  = raise_bb * bb;
+    let stack = stack_bb * bb;
+    // Blinds (1.5 BB) + the open + each caller.
+    let pot   = 3 + call * (1 + callers);
+    let ratio = stack / call;
+    let opponents = 1 + callers;
+
+    let correct = if stack >= SET_MINING_MIN_RATIO * call { "B" } else { "A" };
+    let branch_key = format!("SetMining:{}:{ratio}", rank.symbol());
+
+    let hero_pos = Position::BTN;
+    let hand_str = normalized_hand_notation(hero_hand);
+    let ratio_f = stack as f32 / call as f32;
+    let ev = set_mining_ev(call, pot, stack, opponents as u8);
+    let caller_note = if callers == 0 { "" } else { " One player called." };
+
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "Before the flop. You have {hand_str} on the Button. {villain_pos} raised to \
+             {raise_bb} big blinds.{caller_note} Calling costs {call} chips and you have \
+             {stack} chips. Pot: {pot} chips. Do you call hoping to hit three of a kind?"
+        ),
+        TextStyle::Technical => format!(
+            "Set mining. You hold {hand_str} on the BTN. {villain_pos} opens to {raise_bb} BB.\
+             {caller_note} Call {call} chips with {stack} effective ({stack_bb} BB), pot {pot}. \
+             Call to set-mine or fold?"
+        ),
+    };
+
+    let implied = format!(
+        "stack/call = {stack}/{call} = {ratio_f:.1}; need at least {SET_MINING_MIN_RATIO} to be \
+         profitable. You flop a set about {:.0}% of the time, so the other {:.0}% of calls \
+         must be paid back by the stack you win when you hit (set_mining_ev {ev:+.1} chips \
+         against {opponents} opponent{}).",
+        SET_ON_FLOP * 100.0,
+        (1.0 - SET_ON_FLOP) * 100.0,
+        if opponents == 1 { "" } else { "s" },
+    );
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "You will only hit three of a kind about once every 8 or 9 tries. Are the stacks deep enough to pay for the misses?",
+        TextStyle::Technical => "Consider: the stack-to-call ratio and the 10× rule for set mining.",
+    }.to_string());
+
+    let answers = vec![
+        AnswerOption {
+            id: "A".to_string(),
+            text: "Fold".to_string(),
+            is_correct: correct == "A",
+            rating: if correct == "A" { AnswerRating::Correct } else { AnswerRating::Mistake },
+            hint: hint.clone(),
+            explanation: match text_style {
+                TextStyle::Simple => if correct == "A" {
+                    format!(
+                        "Correct — fold. You only have {ratio} times the call behind. Even when \
+                         you hit, you can't win enough chips to make up for all the misses."
+                    )
+                } else {
+                    format!(
+                        "Folding is too tight. With {ratio} times the call behind, hitting three \
+                         of a kind wins enough to pay for the times you miss."
+                    )
+                },
+                TextStyle::Technical => if correct == "A" {
+                    format!("Correct. Set mining is unprofitable here: {implied}")
+                } else {
+                    format!("Folding passes up a profitable set-mine: {implied}")
+                },
+            },
+        },
+        AnswerOption {
+            id: "B".to_string(),
+            text: format!("Call {call} chips"),
+            is_correct: correct == "B",
+            rating: if correct == "B" { AnswerRating::Correct } else { AnswerRating::Mistake },
+            hint,
+            explanation: match text_style {
+                TextStyle::Simple => if correct == "B" {
+                    format!(
+                        "Correct — call. You have {ratio} times the call behind, so when you hit \
+                         three of a kind you can win a big pot."
+                    )
+                } else {
+                    format!(
+                        "Calling costs too much here. With only {ratio} times the call behind, \
+                         the times you hit can't pay for the times you miss."
+                    )
+                },
+                TextStyle::Technical => if correct == "B" {
+                    format!("Correct. The stacks are deep enough to set-mine: {implied}")
+                } else {
+                    format!("Calling is -EV with stacks this shallow: {implied}")
+                },
+            },
+        },
+    ];
+
+    let mut players = vec![
+        PlayerState { seat: 1, position: villain_pos,   stack, is_hero: false, is_active: true, bounty: None },
+    ];
+    if callers == 1 {
+        players.push(PlayerState { seat: 2, position: Position::CO,  stack, is_hero: false, is_active: true, bounty: None });
+    }
+    players.push(PlayerState { seat: 3, position: hero_pos,      stack, is_hero: true, is_active: true, bounty: None });
+
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::SetMining,
+        branch_key,
+        table_setup: TableSetup {
+            game_type:     GameType::CashGame,
+            hero_position: hero_pos,
+            hero_hand,
+            board:         vec![],
+            players,
+            pot_size:      pot,
+            current_bet:   call,
+            antes:         None,
+        },
+        question,
+        answers,
+        key_concepts: key_concepts(TrainingTopic::SetMining, &[]),
+        villain_tendency: None,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// T20 — 3-Bet Defense (TD-)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Rough 3-bet range width (share of hands) for a blind 3-betting `opener`.
+/// The blinds 3-bet wider against the Button than against the Cutoff, and
+/// the BB slightly wider than the SB, which must also play the hand OOP
+/// against the BB behind.
+fn blind_three_bet_width(villain: Position, opener: Position) -> f32 {
+    match (villain, opener) {
+        (Position::SB, Position::CO) => 0.08,
+        (_,            Position::CO) => 0.09,
+        (Position::SB, _)            => 0.11,
+        _                            => 0.13,
+    }
+}
+
+/// Approximate solver frequencies (call, 4-bet) for an opener facing a
+/// 3-bet from the blinds, as shares of the opening range.
+fn three_bet_defense_frequencies(villain: Position, opener: Position) -> (f32, f32) {
+    match (villain, opener) {
+        (Position::SB, Position::CO) => (0.25, 0.10),
+        (_,            Position::CO) => (0.27, 0.10),
+        (Position::SB, _)            => (0.32, 0.12),
+        _                            => (0.35, 0.13),
+    }
+}
+
+/// T20 — 3-Bet Defense (TD-).
+///
+/// Hero opened from CO or BTN and a blind 3-bets.  Premiums 4-bet; strong
+/// hands call, or 4-bet against the widest (BB vs BTN) range; playable hands
+/// call in position against a range of 10% or wider; the rest fold.
+///
+/// RNG order: hero_pos → villain_pos → sizing.
+pub fn generate_3bet_defense<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
+    let cat = classify_hand(hero_hand);
+
+    let hero_pos    = if rng.gen_bool(0.5) { Position::CO } else { Position::BTN };
+    let villain_pos = if rng.gen_bool(0.5) { Position::SB } else { Position::BB };
+
+    let bb = 2u32;
+    let stack_bb = match difficulty {
+        DifficultyLevel::Beginner     => 100u32,
+        DifficultyLevel::Intermediate => rng.gen_range(60..=150),
+        DifficultyLevel::Advanced     => rng.gen_range(40..=200),
+    };
+    // Open 2.5 BB; the blinds 3-bet out of position to ~4× the open.
+    let open = 5u32;
+    let three_bet = match difficulty {
+        DifficultyLevel::Beginner => 20u32,
+        _                         => rng.gen_range(18..=24),
+    };
+    let four_bet = three_bet * 9 / 4;
+    let stack = stack_bb * bb;
+    // Open + 3-bet + the other blind's dead money.
+    let dead = if villain_pos == Position::SB { bb } else { bb / 2 };
+    let pot = open + three_bet + dead;
+    let to_call = three_bet - open;
+
+    let width = blind_three_bet_width(villain_pos, hero_pos);
+    let (call_freq, four_bet_freq) = three_bet_defense_frequencies(villain_pos, hero_pos);
+
+    // A = fold, B = call, C = 4-bet.
+    let correct: &str = match cat {
+        HandCategory::Premium                     => "C",
+        HandCategory::Strong if width >= 0.12     => "C",
+        HandCategory::Strong                      => "B",
+        HandCategory::Playable if width >= 0.10   => "B",
+        _                                         => "A",
+    };
+    let branch_key = format!("3BetDefense:{hero_pos:?}:{villain_pos:?}:{}", cat.name());
+
+    let hand_s = normalized_hand_notation(hero_hand);
+
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "Before the flop. You raised to {} big blinds from the {hero_pos} with {hand_s}. \
+             The {villain_pos} re-raised to {} big blinds. Pot: {pot} chips. Stack: {stack} \
+             chips. What do you do?",
+            open as f32 / bb as f32, three_bet / bb
+        ),
+        TextStyle::Technical => format!(
+            "3-bet defense. You open {hand_s} ({cat}) from the {hero_pos} to {open} chips; \
+             the {villain_pos} 3-bets to {three_bet}. Pot {pot}, {to_call} to call, {stack} \
+             effective ({stack_bb} BB). You will be in position postflop. Fold, call, or \
+             4-bet to {four_bet}?"
+        ),
+    };
+
+    let range_note = format!(
+        "The {villain_pos} 3-bets about {:.0}% of hands against a {hero_pos} open; solvers \
+         defend by calling about {:.0}% and 4-betting about {:.0}% of the opening range.",
+        width * 100.0, call_freq * 100.0, four_bet_freq * 100.0
+    );
+
+    let simple = |id: &str| -> String {
+        match (id, correct) {
+            ("A", "A") => format!("Correct — fold. {hand_s} isn't strong enough to keep playing against a re-raise."),
+            ("B", "B") => format!("Correct — call. {hand_s} is good enough to see a flop, and you'll act last after it."),
+            ("C", "C") => format!("Correct — raise again to {} big blinds. {hand_s} is one of the best hands here.", four_bet / bb),
+            ("A", _) => format!("Folding {hand_s} is too cautious — it plays well against this re-raise."),
+            (_, "A") => format!("{hand_s} is too weak to continue against a re-raise. Fold and wait for a better spot."),
+            ("B", _) => "Just calling is too passive with such a strong hand — raise again.".to_string(),
+            _ => "Raising again is too much — calling and playing the flop in position is better.".to_string(),
+        }
+    };
+    let technical = |id: &str| -> String {
+        let verdict = match (id, correct) {
+            _ if id == correct => match correct {
+                "A" => format!("Correct. A {cat} hand is dominated too often by this 3-bet range to continue."),
+                "B" => format!("Correct. A {cat} hand plays well in position against this range; calling keeps its dominated hands in."),
+                _ => format!("Correct. A {cat} hand is ahead of this 3-bet range: 4-bet for value."),
+            },
+            ("A", _) => format!("Folding a {cat} hand over-folds against a blind 3-bet and lets it profit with any two cards."),
+            (_, "A") => format!("Continuing with a {cat} hand against this range loses chips, even in position."),
+            ("B", _) => format!("Calling with a {cat} hand gives up value; it should 4-bet against this range."),
+            _ => format!("4-betting a {cat} hand turns it into a bluff; calling in position realises its equity better."),
+        };
+        format!("{verdict} {range_note}")
+    };
+
+    let rate = |id: &str| fold_call_raise_rating(id, correct, fold_rating(cat));
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "How strong is your hand against someone who re-raised? You will act last after the flop.",
+        TextStyle::Technical => "Consider: your hand category, the blind's 3-bet range width, and position.",
+    }.to_string());
+
+    let answers: Vec<AnswerOption> = [
+        ("A", "Fold".to_string()),
+        ("B", format!("Call {to_call}")),
+        ("C", format!("4-bet to {four_bet}")),
+    ]
+    .into_iter()
+    .map(|(id, text)| AnswerOption {
+        id: id.to_string(),
+        text,
+        is_correct: id == correct,
+        rating: rate(id),
+        hint: hint.clone(),
+        explanation: match text_style {
+            TextStyle::Simple    => simple(id),
+            TextStyle::Technical => technical(id),
+        },
+    })
+    .collect();
+
+    let players = vec![
+        PlayerState { seat: 1, position: villain_pos, stack, is_hero: false, is_active: true, bounty: None },
+        PlayerState { seat: 2, position: hero_pos,    stack, is_hero: true,  is_active: true, bounty: None },
+    ];
+
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::ThreeBetDefense,
+        branch_key,
+        table_setup: TableSetup {
+            game_type:     GameType::CashGame,
+            hero_position: hero_pos,
+            hero_hand,
+            board:         vec![],
+            players,
+            pot_size:      pot,
+            current_bet:   three_bet,
+            antes:         None,
+        },
+        question,
+        answers,
+        key_concepts: key_concepts(TrainingTopic::ThreeBetDefense, &[]),
+        villain_tendency: None,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// T23 — Chip Leader Play (CL-)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Size of the stack that shoves into the chip leader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PusherStack {
+    /// 6–12 BB: shoving wide out of desperation.
+    Short,
+    /// 15–25 BB: risking their tournament life, so shoving tighter.
+    Medium,
+    /// The second-biggest stack at the table, up to half of hero's.
+    Big,
+}
+
+/// Whether the chip leader should call a shove from `pusher` with `cat`.
+/// Short stacks are called with anything but trash, medium stacks with
+/// playable hands or better, and the other big stack only with strong hands.
+fn chip_leader_calls(pusher: PusherStack, cat: HandCategory) -> bool {
+    match pusher {
+        PusherStack::Short  => cat != HandCategory::Trash,
+        PusherStack::Medium => matches!(cat, HandCategory::Premium | HandCategory::Strong | HandCategory::Playable),
+        PusherStack::Big    => matches!(cat, HandCategory::Premium | HandCategory::Strong),
+    }
+}
+
+/// T23 — Chip Leader Play (CL-).
+///
+/// Hero is the tournament chip leader in the BB, with at least twice the
+/// chips of anyone else at the table.  A short, medium or big stack shoves
+/// from CO, BTN or SB; a third player folded from UTG.  Hero calls or folds.
+///
+/// RNG order: hero_stack → pusher stack type → pusher stack → UTG stack
+/// → pusher position.
+pub fn generate_chip_leader<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
+    let cat = classify_hand(hero_hand);
+
+    let bb = 100u32; // tournament chips, 100 = 1 BB
+    let hero_bb = match difficulty {
+        DifficultyLevel::Beginner     => rng.gen_range(80..=100u32),
+        DifficultyLevel::Intermediate => rng.gen_range(60..=120),
+        DifficultyLevel::Advanced     => rng.gen_range(55..=150),
+    };
+    let pusher = match difficulty {
+        DifficultyLevel::Beginner => if rng.gen_bool(0.5) { PusherStack::Short } else { PusherStack::Big },
+        _ => match rng.gen_range(0..3) {
+            0 => PusherStack::Short,
+            1 => PusherStack::Medium,
+            _ => PusherStack::Big,
+        },
+    };
+    // Hero always covers the second-biggest stack at least twice.
+    let big_range = hero_bb * 2 / 5..=hero_bb / 2;
+    let villain_bb = match pusher {
+        PusherStack::Short  => rng.gen_range(6..=12u32),
+        PusherStack::Medium => rng.gen_range(15..=25),
+        PusherStack::Big    => rng.gen_range(big_range.clone()),
+    };
+    let other_bb = match pusher {
+        PusherStack::Big => rng.gen_range(15..=25u32),
+        _                => rng.gen_range(big_range),
+    };
+    let villain_pos = [Position::CO, Position::BTN, Position::SB][rng.gen_range(0..3)];
+    let next_bb = villain_bb.max(other_bb);
+
+    let (hero_stack, villain_stack, other_stack) = (hero_bb * bb, villain_bb * bb, other_bb * bb);
+    // The shove plus both blinds (the SB's blind is part of its shove).
+    let pot = villain_stack + if villain_pos == Position::SB { bb } else { bb + bb / 2 };
+    let to_call = villain_stack - bb;
+    let required = to_call as f32 / (pot + to_call) as f32 * 100.0;
+    let risk_pct = villain_stack as f32 / hero_stack as f32 * 100.0;
+
+    let calls = chip_leader_calls(pusher, cat);
+    let branch_key = format!("ChipLeader:{pusher:?}:{}", cat.name());
+
+    let hand_s = normalized_hand_notation(hero_hand);
+    let pusher_s = match pusher {
+        PusherStack::Short  => "short",
+        PusherStack::Medium => "medium",
+        PusherStack::Big    => "big",
+    };
+
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "Tournament. You are the chip leader with {hero_bb} big blinds, sitting in the Big \
+             Blind with {hand_s}. The {villain_pos} goes all-in for {villain_bb} big blinds. \
+             The next biggest stack at the table has {next_bb} big blinds. Call or fold?"
+        ),
+        TextStyle::Technical => format!(
+            "Tournament. You are the chip leader in the BB with {hero_bb} BB ({:.1}× the \
+             next-biggest stack). The {villain_pos} ({pusher_s} stack) shoves {villain_bb} BB; \
+             UTG folded with {other_bb} BB. You hold {hand_s} ({cat}). {to_call} to call into \
+             {pot}: {required:.0}% equity needed. Call or fold?",
+            hero_bb as f32 / next_bb as f32
+        ),
+    };
+
+    let leverage = match pusher {
+        PusherStack::Big => format!(
+            "Calling risks {risk_pct:.0}% of your stack against the one player who can take the \
+             chip lead from you; under ICM the chips you lose are worth more than the chips you \
+             win, so only strong hands call."
+        ),
+        _ => format!(
+            "Calling risks only {risk_pct:.0}% of your stack while the {pusher_s} stack risks \
+             elimination — that is the chip leader's ICM leverage, and it lets you call wider \
+             than the {required:.0}% price alone suggests."
+        ),
+    };
+
+    let simple_call = if calls {
+        match pusher {
+            PusherStack::Big => format!("Correct — call. {hand_s} is strong enough to risk a big part of your stack."),
+            _ => format!("Correct — call. Losing only costs you a small part of your stack, and knocking out the {pusher_s} stack makes your lead even bigger."),
+        }
+    } else {
+        match pusher {
+            PusherStack::Big => format!("Calling is too risky. Losing to the other big stack with {hand_s} would throw away your chip lead."),
+            _ => format!("{hand_s} is too weak even against a {pusher_s} stack. Save your chips for better spots."),
+        }
+    };
+    let simple_fold = if calls {
+        format!("Folding {hand_s} is too tight. As the chip leader you can afford this call, and it puts pressure on everyone else.")
+    } else {
+        format!("Correct — fold. {hand_s} isn't worth putting this many chips at risk.")
+    };
+    let tech_call = if calls {
+        format!("Correct. A {cat} hand calls a {pusher_s}-stack shove. {leverage}")
+    } else {
+        format!("Calling with a {cat} hand against a {pusher_s}-stack shove loses chips and tournament equity. {leverage}")
+    };
+    let tech_fold = if calls {
+        format!("Folding a {cat} hand gives up the chip leader's edge against a {pusher_s} stack. {leverage}")
+    } else {
+        format!("Correct. A {cat} hand folds to a {pusher_s}-stack shove. {leverage}")
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "How much of your stack would you lose, and could the player who shoved knock you off the top?",
+        TextStyle::Technical => "Consider: the pusher's stack size, how much of your stack the call risks, and ICM.",
+    }.to_string());
+
+    let answers = vec![
+        AnswerOption {
+            id: "A".to_string(),
+            text: "Fold".to_string(),
+            is_correct: !calls,
+            rating: if !calls { AnswerRating::Correct } else { fold_rating(cat) },
+            hint: hint.clone(),
+            explanation: match text_style {
+                TextStyle::Simple    => simple_fold,
+                TextStyle::Technical => tech_fold,
+            },
+        },
+        AnswerOption {
+            id: "B".to_string(),
+            text: format!("Call {to_call}"),
+            is_correct: calls,
+            rating: if calls { AnswerRating::Correct } else { AnswerRating::Mistake },
+            hint: hint.clone(),
+            explanation: match text_style {
+                TextStyle::Simple    => simple_call,
+                TextStyle::Technical => tech_call,
+            },
+        },
+    ];
+
+    let players = vec![
+        PlayerState { seat: 1, position: Position::UTG, stack: other_stack,   is_hero: false, is_active: false, bounty: None },
+        PlayerState { seat: 2, position: villain_pos,   stack: villain_stack, is_hero: false, is_active: true,  bounty: None },
+        PlayerState { seat: 3, position: Position::BB,  stack: hero_stack,    is_hero: true,  is_active: true,  bounty: None },
+    ];
+
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::ChipLeaderPlay,
+        branch_key,
+        table_setup: TableSetup {
+            game_type:     GameType::Tournament,
+            hero_position: Position::BB,
+            hero_hand,
+            board:         vec![],
+            players,
+            pot_size:      pot,
+            current_bet:   villain_stack,
+            antes:         None,
+        },
+        question,
+        answers,
+        key_concepts: key_concepts(TrainingTopic::ChipLeaderPlay, &[]),
+        villain_tendency: None,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// T24 — Blinds vs Blinds (BV-)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Hero's first action when folded to in the SB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BvbAction {
+    Fold,
+    Limp,
+    Raise,
+}
+
+/// Simplified SB strategy against the BB: raise hands that play well as a
+/// favourite, complete the marginal hands that profit from the 1 BB discount
+/// but not from bloating an OOP pot, fold trash.
+fn bvb_action(cat: HandCategory) -> BvbAction {
+    match cat {
+        HandCategory::Premium | HandCategory::Strong | HandCategory::Playable => BvbAction::Raise,
+        HandCategory::Marginal => BvbAction::Limp,
+        HandCategory::Trash    => BvbAction::Fold,
+    }
+}
+
+/// T24 — Blinds vs Blinds (BV-).
+///
+/// Folded to hero in the SB with only the BB left.  Hero folds, completes
+/// or raises to 3 BB.  At Intermediate and Advanced the options also carry
+/// the flop plan once the BB calls — raise then c-bet small, limp then
+/// check — plus a "limp, then lead" trap; the board stays empty.
+///
+/// RNG order: stack.
+pub fn generate_blinds_vs_blinds<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
+    let cat = classify_hand(hero_hand);
+
+    let bb = 2u32;
+    let stack_bb = match difficulty {
+        DifficultyLevel::Beginner     => 100u32,
+        DifficultyLevel::Intermediate => rng.gen_range(60..=150),
+        DifficultyLevel::Advanced     => rng.gen_range(40..=200),
+    };
+    let stack = stack_bb * bb;
+    let pot = bb + bb / 2;
+    let raise_to = 3 * bb;
+    // The BB's price to call the raise.
+    let bb_call = raise_to - bb;
+    let bb_needs = bb_call as f32 / (2 * raise_to) as f32 * 100.0;
+    let compound = difficulty != DifficultyLevel::Beginner;
+
+    let action = bvb_action(cat);
+    let correct = match action {
+        BvbAction::Fold  => "A",
+        BvbAction::Limp  => "B",
+        BvbAction::Raise => "C",
+    };
+    let branch_key = format!("BvB:{action:?}:{}", cat.name());
+
+    let hand_s = normalized_hand_notation(hero_hand);
+
+    let plan = if compound {
+        match text_style {
+            TextStyle::Simple    => " Choose your move now and your plan for the flop if the Big Blind calls or checks.",
+            TextStyle::Technical => " Pick the preflop action together with the flop plan if the BB calls or checks behind.",
+        }
+    } else {
+        ""
+    };
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "Before the flop. Everyone folds to you in the Small Blind with {hand_s}. Only the \
+             Big Blind is left. Pot: {pot} chips. Stack: {stack} chips.{plan} What do you do?"
+        ),
+        TextStyle::Technical => format!(
+            "Blind vs blind. Folded to you in the SB with {hand_s} ({cat}), {stack_bb} BB \
+             effective. Pot {pot}, {} to complete. You act first on every later street.{plan} \
+             Fold, limp, or raise to {raise_to}?",
+            bb / 2
+        ),
+    };
+
+    let position_note = format!(
+        "The SB is last to act preflop but first on every later street, while the BB closes \
+         the action at a discount: facing a raise to {raise_to} it calls {bb_call} into {} and \
+         needs only {bb_needs:.0}% equity, and after a limp it can check or raise with any hand.",
+        2 * raise_to - bb_call
+    );
+
+    let simple = |id: &str| -> String {
+        match (id, correct) {
+            ("A", "A") => format!("Correct — fold. {hand_s} is too weak to play out of position, even for half a big blind more."),
+            ("B", "B") => format!("Correct — just call. {hand_s} is worth seeing a cheap flop, but not worth building a big pot when you act first after the flop."),
+            ("C", "C") => format!("Correct — raise. {hand_s} is ahead of most hands the Big Blind holds, so make them pay to see a flop."),
+            ("D", _) => "Leading the flop after just calling is a mistake: your hand is capped and the Big Blind will raise you off it.".to_string(),
+            ("A", _) => format!("Folding {hand_s} is too tight — against just the Big Blind you can play far more hands."),
+            (_, "A") => format!("{hand_s} is too weak to play, even against just one opponent."),
+            ("B", _) => format!("Just calling is too passive with {hand_s}. Raise and take the lead."),
+            _ => format!("Raising {hand_s} builds a big pot you will play out of position. Just call instead."),
+        }
+    };
+    let technical = |id: &str| -> String {
+        let verdict = match (id, correct) {
+            _ if id == correct => match correct {
+                "A" => format!("Correct. A {cat} hand can't realise enough equity OOP to complete profitably."),
+                "B" if compound => format!("Correct. A {cat} hand completes for the discount and checks the flop: the limping range is weak and capped, so it plays for showdown OOP."),
+                "B" => format!("Correct. A {cat} hand completes for the discount but is too weak to build a pot OOP."),
+                _ if compound => format!("Correct. A {cat} hand raises for value and c-bets small on the flop, where the SB's raising range holds the range advantage."),
+                _ => format!("Correct. A {cat} hand is ahead of the BB's range: raise for value."),
+            },
+            ("D", _) => "Donk-leading a limped range into the BB, whose range is uncapped, gets raised off equity.".to_string(),
+            ("A", _) => format!("Folding a {cat} hand over-folds the SB; heads-up against the BB it has a profitable continue."),
+            (_, "A") => format!("Continuing with a {cat} hand bleeds chips OOP against the BB's wider, position-aided range."),
+            ("B", _) => format!("Limping a {cat} hand gives up value and lets the BB realise its equity for free."),
+            _ => format!("Raising a {cat} hand bloats an OOP pot with a hand that does better completing."),
+        };
+        format!("{verdict} {position_note}")
+    };
+
+    let rate = |id: &str| match id {
+        "D" => AnswerRating::Mistake,
+        _   => fold_call_raise_rating(id, correct, fold_rating(cat)),
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Only the Big Blind is left, but they act last after the flop. How strong is your hand?",
+        TextStyle::Technical => "Consider: your hand category, being OOP postflop, and the BB's discount.",
+    }.to_string());
+
+    let options = if compound {
+        vec![
+            ("A", "Fold".to_string()),
+            ("B", "Limp, then check the flop".to_string()),
+            ("C", format!("Raise to {raise_to}, then c-bet 33% on the flop")),
+            ("D", "Limp, then lead the flop".to_string()),
+        ]
+    } else {
+        vec![
+            ("A", "Fold".to_string()),
+            ("B", format!("Limp ({} more)", bb / 2)),
+            ("C", format!("Raise to {raise_to}")),
+        ]
+    };
+    let answers: Vec<AnswerOption> = options
+        .into_iter()
+        .map(|(id, text)| AnswerOption {
+            id: id.to_string(),
+            text,
+            is_correct: id == correct,
+            rating: rate(id),
+            hint: hint.clone(),
+            explanation: match text_style {
+                TextStyle::Simple    => simple(id),
+                TextStyle::Technical => technical(id),
+            },
+        })
+        .collect();
+
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::BlindsVsBlinds,
+        branch_key,
+        table_setup: TableSetup {
+            game_type:     GameType::CashGame,
+            hero_position: Position::SB,
+            hero_hand,
+            board:         vec![],
+            players:       heads_up(Position::SB, Position::BB, stack, stack),
+            pot_size:      pot,
+            current_bet:   bb,
+            antes:         None,
+        },
+        question,
+        answers,
+        key_concepts: key_concepts(TrainingTopic::BlindsVsBlinds, &[]),
+        villain_tendency: None,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// T25 — Short Stack Strategy (SS-)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Approximate share of hands a 10–19 BB cash-game stack shoves when folded
+/// to in `pos` (6-max, no ICM).
+fn short_stack_shove_width(pos: Position) -> f32 {
+    match pos {
+        Position::UTG => 0.12,
+        Position::HJ  => 0.15,
+        Position::CO  => 0.20,
+        Position::BTN => 0.30,
+        _             => 0.40,
+    }
+}
+
+/// Push-or-fold rule for a short cash-game stack: strong hands always shove,
+/// playable hands shove from late position or from early position at
+/// 14 BB or less, marginal hands only from BTN/SB at 14 BB or less.
+fn short_stack_pushes(cat: HandCategory, pos: Position, stack_bb: u32) -> bool {
+    let late = matches!(pos, Position::CO | Position::BTN | Position::SB);
+    let short = stack_bb <= 14;
+    match cat {
+        HandCategory::Premium | HandCategory::Strong => true,
+        HandCategory::Playable => late || short,
+        HandCategory::Marginal => short && matches!(pos, Position::BTN | Position::SB),
+        HandCategory::Trash    => false,
+    }
+}
+
+/// T25 — Short Stack Strategy (SS-).
+///
+/// Cash game, folded to hero with 10–19 BB effective at every difficulty.
+/// Hero shoves or folds; the BB has 100 BB.
+///
+/// RNG order: stack → hero_pos.
+pub fn generate_short_stack<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
+    let cat = classify_hand(hero_hand);
+
+    let bb = 2u32;
+    let stack_bb = match difficulty {
+        DifficultyLevel::Beginner => [10u32, 15][rng.gen_range(0..2)],
+        _                         => rng.gen_range(10..=19),
+    };
+    let hero_pos = [Position::UTG, Position::HJ, Position::CO, Position::BTN, Position::SB][rng.gen_range(0..5)];
+
+    let stack = stack_bb * bb;
+    let pot = bb + bb / 2;
+    let pushes = short_stack_pushes(cat, hero_pos, stack_bb);
+    let branch_key = format!("ShortStack:{stack_bb}BB:{}:{hero_pos:?}", cat.name());
+
+    let width = short_stack_shove_width(hero_pos);
+    // Facing the shove, the BB would have to defend this often to stop any
+    // two cards from profiting.
+    let mdf = minimum_defense_frequency(stack - bb, pot);
+    let hand_s = normalized_hand_notation(hero_hand);
+
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "Cash game, before the flop. You have only {stack_bb} big blinds. Everyone folds to \
+             you in the {hero_pos} with {hand_s}. Go all-in or fold?"
+        ),
+        TextStyle::Technical => format!(
+            "Cash game, {stack_bb} BB effective. Folded to you in the {hero_pos} with {hand_s} \
+             ({cat}). A {stack_bb} BB shove into {:.1} BB gives the BB an MDF of only {:.0}%, \
+             and {hero_pos} shoves about {:.0}% of hands at this depth. Shove or fold?",
+            pot as f32 / bb as f32, mdf * 100.0, width * 100.0
+        ),
+    };
+
+    let context = format!(
+        "With no ICM, chips are money, so the only question is chip EV. Shoving wins the \
+         blinds uncontested without seeing a flop (no flop, no rake) and never puts a short \
+         stack in a tough postflop spot. The blinds can't defend the {:.0}% MDF without \
+         calling off with junk, so fold equity carries the shove; {hero_pos} shoves about \
+         {:.0}% of hands here.",
+        mdf * 100.0, width * 100.0
+    );
+    let push_explanation = match text_style {
+        TextStyle::Simple => if pushes {
+            format!("Correct — go all-in. {hand_s} is strong enough to shove {stack_bb} big blinds from the {hero_pos}, and most of the time everyone folds.")
+        } else {
+            format!("Too loose. {hand_s} is too weak to shove from the {hero_pos}; when someone calls, you are usually behind.")
+        },
+        TextStyle::Technical => if pushes {
+            format!("Correct. A {cat} hand is inside the {hero_pos} shoving range at {stack_bb} BB. {context}")
+        } else {
+            format!("A {cat} hand is outside the {hero_pos} shoving range at {stack_bb} BB; the calls you get dominate it. {context}")
+        },
+    };
+    let fold_explanation = match text_style {
+        TextStyle::Simple => if pushes {
+            format!("Folding {hand_s} is too tight. With only {stack_bb} big blinds you must shove good hands like this.")
+        } else {
+            format!("Correct — fold. {hand_s} isn't good enough to risk your whole stack from the {hero_pos}.")
+        },
+        TextStyle::Technical => if pushes {
+            format!("Folding a {cat} hand gives up a profitable shove. {context}")
+        } else {
+            format!("Correct. Folding a {cat} hand from the {hero_pos} keeps the {stack_bb} BB for a better spot. {context}")
+        },
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "With a short stack, it's all-in or fold. How many players are still to act behind you?",
+        TextStyle::Technical => "Consider: stack depth, your position's shoving range, and the blinds' fold equity.",
+    }.to_string());
+
+    let answers = vec![
+        AnswerOption {
+            id: "A".to_string(),
+            text: "Fold".to_string(),
+            is_correct: !pushes,
+            rating: if !pushes { AnswerRating::Correct } else { fold_rating(cat) },
+            hint: hint.clone(),
+            explanation: fold_explanation,
+        },
+        AnswerOption {
+            id: "B".to_string(),
+            text: format!("All-in ({stack} chips)"),
+            is_correct: pushes,
+            rating: if pushes { AnswerRating::Correct } else { AnswerRating::Mistake },
+            hint: hint.clone(),
+            explanation: push_explanation,
+        },
+    ];
+
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::ShortStackStrategy,
+        branch_key,
+        table_setup: TableSetup {
+            game_type:     GameType::CashGame,
+            hero_position: hero_pos,
+            hero_hand,
+            board:         vec![],
+            players:       heads_up(hero_pos, Position::BB, stack, 100 * bb),
+            pot_size:      pot,
+            current_bet:   bb,
+            antes:         None,
+        },
+        question,
+        answers,
+        key_concepts: key_concepts(TrainingTopic::ShortStackStrategy, &[]),
+        villain_tendency: None,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// T26 — Blind Stealing (ST-)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Typical population fold-to-steal rates `(SB, BB)` against an open from
+/// `pos`; the SB has no SB to get through when it steals itself.
+fn fold_to_steal(pos: Position) -> (f32, f32) {
+    match pos {
+        Position::CO  => (0.90, 0.60),
+        Position::BTN => (0.88, 0.55),
+        _             => (1.0, 0.50),
+    }
+}
+
+/// T26 — Blind Stealing (ST-).
+///
+/// Folded to hero in CO, BTN or SB.  Hero steals (opens) when
+/// `starting_hand_percentile` puts the hand inside the 6-max opening width
+/// for the seat — CO 27%, BTN 43%, SB 40% — and folds otherwise.
+///
+/// RNG order: hero_pos → stack.
+pub fn generate_blind_stealing<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
+    let cat = classify_hand(hero_hand);
+    let percentile = starting_hand_percentile(hero_hand);
+
+    let hero_pos = [Position::CO, Position::BTN, Position::SB][rng.gen_range(0..3)];
+    let bb = 2u32;
+    let stack_bb = stack_for_difficulty(rng, difficulty);
+    let stack = stack_bb * bb;
+
+    let width = PositionRange::standard_6max().get(hero_pos);
+    let steals = percentile < width;
+    let branch_key = format!("BlindSteal:{hero_pos:?}:{}", cat.name());
+
+    // CO/BTN open 2.5 BB; the SB opens 3 BB out of position.
+    let open = if hero_pos == Position::SB { 3 * bb } else { 5 * bb / 2 };
+    let pot = bb + bb / 2;
+    let posted = if hero_pos == Position::SB { bb / 2 } else { 0 };
+    let risk = open - posted;
+    let dead = pot - posted;
+    let break_even = risk as f32 / (risk + dead) as f32;
+    let (sb_folds, bb_folds) = fold_to_steal(hero_pos);
+    let blinds_fold = sb_folds * bb_folds;
+
+    let hand_s = normalized_hand_notation(hero_hand);
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "Before the flop. Everyone folds to you in the {hero_pos} with {hand_s}. Only the \
+             blinds are left. Stack: {stack_bb} big blinds. Raise to try to win the blinds, or fold?"
+        ),
+        TextStyle::Technical => format!(
+            "Unopened pot, folded to you in the {hero_pos} with {hand_s} ({cat}, top {:.0}% of \
+             hands). {stack_bb} BB effective. Steal to {open} chips or fold?",
+            percentile * 100.0
+        ),
+    };
+
+    let steal_note = format!(
+        "The {hero_pos} opens about {:.0}% of hands. Raising to {open} risks {risk} chips to win \
+         {dead} chips of dead money, so the steal breaks even if the blinds fold {:.0}% of the \
+         time; they fold about {:.0}% (typical fold-to-steal), and position and equity when \
+         called cover the rest.",
+        width * 100.0, break_even * 100.0, blinds_fold * 100.0
+    );
+
+    let simple_raise = if steals {
+        format!("Correct — raise. With only the blinds left, {hand_s} is good enough to attack. They will fold a lot, and you win the blinds for free.")
+    } else {
+        format!("Too loose. Even against just the blinds, {hand_s} is too weak to raise from the {hero_pos}.")
+    };
+    let simple_fold = if steals {
+        format!("Folding {hand_s} is too tight. The blinds fold often, so you should raise many more hands from the {hero_pos} than just big pairs.")
+    } else {
+        format!("Correct — fold. {hand_s} is too weak even for a steal from the {hero_pos}.")
+    };
+    let tech_raise = if steals {
+        format!("Correct. At the top {:.0}% of hands, {hand_s} is inside the {hero_pos} stealing range. {steal_note}", percentile * 100.0)
+    } else {
+        format!("{hand_s} ranks in the top {:.0}% only — outside the {hero_pos} stealing range; when the blinds defend it plays poorly. {steal_note}", percentile * 100.0)
+    };
+    let tech_fold = if steals {
+        format!("Folding {hand_s} passes up a profitable steal. {steal_note}")
+    } else {
+        format!("Correct. {hand_s} is too far outside the {hero_pos} range to open. {steal_note}")
+    };
+
+    // Hands within 5 points of the range edge are close either way.
+    let miss = if (percentile - width).abs() < 0.05 { AnswerRating::Plausible } else { AnswerRating::Mistake };
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Only the blinds are left to act. How often do you think they'll fold?",
+        TextStyle::Technical => "Consider: your seat's opening width, fold-to-steal, and the dead money.",
+    }.to_string());
+
+    let answers = vec![
+        AnswerOption {
+            id: "A".to_string(),
+            text: "Fold".to_string(),
+            is_correct: !steals,
+            rating: if !steals {
+                AnswerRating::Correct
+            } else if matches!(cat, HandCategory::Premium | HandCategory::Strong) {
+                AnswerRating::Blunder
+            } else {
+                miss
+            },
+            hint: hint.clone(),
+            explanation: match text_style {
+                TextStyle::Simple    => simple_fold,
+                TextStyle::Technical => tech_fold,
+            },
+        },
+        AnswerOption {
+            id: "B".to_string(),
+            text: format!("Raise to {open}"),
+            is_correct: steals,
+            rating: if steals { AnswerRating::Correct } else { miss },
+            hint: hint.clone(),
+            explanation: match text_style {
+                TextStyle::Simple    => simple_raise,
+                TextStyle::Technical => tech_raise,
+            },
+        },
+    ];
+
+    let mut players = vec![PlayerState {
+        seat: 1, position: Position::BB, stack, is_hero: false, is_active: true, bounty: None,
+    }];
+    if hero_pos != Position::SB {
+        players.push(PlayerState {
+            seat: 2, position: Position::SB, stack, is_hero: false, is_active: true, bounty: None,
+        });
+    }
+    players.push(PlayerState {
+        seat: players.len() as u8 + 1, position: hero_pos, stack, is_hero: true, is_active: true, bounty: None,
+    });
+
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::BlindStealing,
+        branch_key,
+        table_setup: TableSetup {
+            game_type:     GameType::CashGame,
+            hero_position: hero_pos,
+            hero_hand,
+            board:         vec![],
+            players,
+            pot_size:      pot,
+            current_bet:   bb,
+            antes:         None,
+        },
+        question,
+        answers,
+        key_concepts: key_concepts(TrainingTopic::BlindStealing, &[]),
+        villain_tendency: None,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// T27 — Heads-Up Tournament (HU-)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Deepest effective stack (BB) at which the button shoves `hand` in a
+/// heads-up push/fold game — a simplified Nash chart: any pair, any ace and
+/// two broadway cards shove at every drilled depth, any king to 12 BB, any
+/// queen to 9 BB, suited connectors and one-gappers (5-4s up) to 7 BB.
+fn hu_push_max_bb(hand: [Card; 2]) -> u32 {
+    let (hi, lo) = (hand[0].rank.0.max(hand[1].rank.0), hand[0].rank.0.min(hand[1].rank.0));
+    let suited = hand[0].suit == hand[1].suit;
+    match (hi, lo) {
+        _ if hi == lo       => 25,
+        (14, _)             => 25,
+        (_, 10..)           => 25,
+        (13, _)             => 12,
+        (12, _)             => 9,
+        _ if suited && lo >= 4 && hi - lo <= 2 => 7,
+        _                   => 0,
+    }
+}
+
+/// T27 — Heads-Up Tournament (HU-).
+///
+/// Winner-take-all heads-up SNG: hero is on the button (the small blind)
+/// with 3–20 BB effective and shoves or folds.  With a `[1.0, 0.0]` payout
+/// table ICM equity is just the chip share, so the chip-EV Nash chart of
+/// [`hu_push_max_bb`] decides.
+///
+/// RNG order: effective stack → whether hero covers → the bigger stack.
+pub fn generate_hu_tournament<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
+    let cat = classify_hand(hero_hand);
+
+    let bb = 100u32; // tournament chips, 100 = 1 BB
+    let stack_bb = match difficulty {
+        DifficultyLevel::Beginner     => rng.gen_range(6..=12u32),
+        DifficultyLevel::Intermediate => rng.gen_range(5..=15),
+        DifficultyLevel::Advanced     => rng.gen_range(3..=20),
+    };
+    let hero_covers = rng.gen_bool(0.5);
+    let big_bb = rng.gen_range(stack_bb..=stack_bb * 3);
+    let (hero_bb, villain_bb) = if hero_covers { (big_bb, stack_bb) } else { (stack_bb, big_bb) };
+    let (hero_stack, villain_stack) = (hero_bb * bb, villain_bb * bb);
+
+    let table = ICMPayoutTable { places: vec![1.0, 0.0] };
+    let equity = icm_equity(&[hero_stack, villain_stack], &table)
+        .expect("valid winner-take-all payout table")[0];
+
+    let max_bb = hu_push_max_bb(hero_hand);
+    let pushes = stack_bb <= max_bb;
+    let branch_key = format!("HUTournament:{stack_bb}:{}", cat.name());
+
+    let hand_s = normalized_hand_notation(hero_hand);
+    let pot = bb + bb / 2;
+
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "Heads-up tournament, winner takes all. You are on the Button with {hand_s} and \
+             {hero_bb} big blinds; your opponent has {villain_bb}. You act first. Go all-in or fold?"
+        ),
+        TextStyle::Technical => format!(
+            "HU SNG (winner-take-all). You are on the BTN/SB with {hand_s} ({cat}); stacks \
+             {hero_bb} BB vs {villain_bb} BB, {stack_bb} BB effective. Shove or fold?"
+        ),
+    };
+
+    let chart = if max_bb >= 25 {
+        format!("{hand_s} shoves at any depth drilled here.")
+    } else if max_bb == 0 {
+        format!("{hand_s} is outside the heads-up shoving chart even at 3 BB.")
+    } else {
+        format!("{hand_s} shoves up to about {max_bb} BB.")
+    };
+    let context = format!(
+        "Heads-up Nash ranges are very wide — any pair, any ace, any king to 12 BB and most \
+         broadway hands shove, because the BB must call off with a weak hand or fold. {chart} \
+         With winner-take-all payouts ICM equity equals your chip share ({:.0}%), so chip EV \
+         alone decides.",
+        equity * 100.0
+    );
+
+    let push_explanation = match text_style {
+        TextStyle::Simple => if pushes {
+            format!("Correct — go all-in. Heads-up, {hand_s} is a strong hand at {stack_bb} big blinds. Your opponent has to fold or gamble.")
+        } else {
+            format!("Too loose. Even heads-up, {hand_s} is too weak to go all-in with {stack_bb} big blinds.")
+        },
+        TextStyle::Technical => if pushes {
+            format!("Correct. {context}")
+        } else {
+            format!("Shoving {stack_bb} BB here is -EV; the BB's calling range dominates you. {context}")
+        },
+    };
+    let fold_explanation = match text_style {
+        TextStyle::Simple => if pushes {
+            format!("Folding {hand_s} is too tight. Heads-up, almost any pair, ace or king is good enough to go all-in.")
+        } else {
+            format!("Correct — fold. {hand_s} is one of the weakest hands; wait for a better one.")
+        },
+        TextStyle::Technical => if pushes {
+            format!("Folding gives up a +EV shove and half a big blind. {context}")
+        } else {
+            format!("Correct. {context}")
+        },
+    };
+
+    // Within 2 BB of the chart's cut-off the wrong choice is only marginal.
+    let miss = if max_bb < 25 && stack_bb.abs_diff(max_bb) <= 2 {
+        AnswerRating::Plausible
+    } else {
+        AnswerRating::Mistake
+    };
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Heads-up, hands are much stronger than at a full table. Count your big blinds.",
+        TextStyle::Technical => "Consider: effective stack in BB and how wide heads-up push ranges are.",
+    }.to_string());
+
+    let answers = vec![
+        AnswerOption {
+            id: "A".to_string(),
+            text: "Fold".to_string(),
+            is_correct: !pushes,
+            rating: if !pushes {
+                AnswerRating::Correct
+            } else if matches!(cat, HandCategory::Premium | HandCategory::Strong) {
+                AnswerRating::Blunder
+            } else {
+                miss
+            },
+            hint: hint.clone(),
+            explanation: fold_explanation,
+        },
+        AnswerOption {
+            id: "B".to_string(),
+            text: "All-in".to_string(),
+            is_correct: pushes,
+            rating: if pushes { AnswerRating::Correct } else { miss },
+            hint: hint.clone(),
+            explanation: push_explanation,
+        },
+    ];
+
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::HeadsUpTournament,
+        branch_key,
+        table_setup: TableSetup {
+            game_type:     GameType::Tournament,
+            hero_position: Position::BTN,
+            hero_hand,
+            board:         vec![],
+            players:       heads_up(Position::BTN, Position::BB, hero_stack, villain_stack),
+            pot_size:      pot,
+            current_bet:   bb,
+            antes:         None,
+        },
+        question,
+        answers,
+        key_concepts: key_concepts(TrainingTopic::HeadsUpTournament, &[]),
+        villain_tendency: None,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// T29 — Suited Connectors (SC-)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Stack-to-call multiple a suited connector needs to call an open: it
+/// flops a strong draw or better only about one time in five, and the hits
+/// must win a big pot to pay for the misses.
+const SUITED_CONNECTOR_MIN_RATIO: u32 = 20;
+
+/// T29 — Suited Connectors (SC-).
+///
+/// Hero holds 54s–98s facing a 3 BB open.  Hero calls in position or from
+/// the BB when the stack is at least 20× the call, and folds from the SB
+/// (out of position with the BB behind) or when the stacks are too shallow;
+/// 3-betting is never the answer.  At Advanced the options add the flop plan
+/// after calling.  The first card comes from the dealer and fixes the suit;
+/// the ranks come from the RNG.
+///
+/// RNG order: low rank → hero_pos → opener → stack.
+pub fn generate_suited_connectors<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let high = dealer.deal_matching(|c| (5..=9).contains(&c.rank.0))?;
+    let low = high.rank.0 - 1;
+    let hero_hand = [high, dealer.deal_matching(|c| c.suit == high.suit && c.rank.0 == low)?];
+    let ranks = format!("{}{}", Rank(low + 1).symbol(), Rank(low).symbol());
+
+    let hero_pos = [Position::CO, Position::BTN, Position::SB, Position::BB][rng.gen_range(0..4)];
+    let seats = [Position::UTG, Position::HJ, Position::CO, Position::BTN, Position::SB];
+    let before = seats.iter().position(|&p| p == hero_pos).unwrap_or(seats.len());
+    let opener = seats[rng.gen_range(0..before)];
+
+    let bb = 2u32;
+    let stack_bb = match difficulty {
+        DifficultyLevel::Beginner     => if rng.gen_bool(0.5) { 40u32 } else { 100 },
+        DifficultyLevel::Intermediate => rng.gen_range(30..=150),
+        DifficultyLevel::Advanced     => rng.gen_range(25..=200),
+    };
+    let stack = stack_bb * bb;
+    let open = 3 * bb;
+    let posted = match hero_pos {
+        Position::SB => bb / 2,
+        Position::BB => bb,
+        _            => 0,
+    };
+    let call = open - posted;
+    let pot = open + bb + bb / 2;
+    let three_bet = open * 3;
+    let ratio = stack / call;
+
+    let in_position = !matches!(hero_pos, Position::SB | Position::BB);
+    let calls = hero_pos != Position::SB && ratio >= SUITED_CONNECTOR_MIN_RATIO;
+    let compound = difficulty == DifficultyLevel::Advanced;
+    let correct = if calls { "B" } else { "A" };
+    // At Advanced, C is the call with a loose flop plan and D the 3-bet.
+    let three_bet_id = if compound { "D" } else { "C" };
+    let branch_key = format!("SC:{ranks}:{hero_pos:?}:{}", if calls { "Call" } else { "Fold" });
+
+    let hand_s = normalized_hand_notation(hero_hand);
+    let plan = if compound { " Choose your action and your flop plan if you call." } else { "" };
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "Before the flop. You have {hand_s} in the {hero_pos}. {opener} raised to 3 big \
+             blinds and everyone else folded to you. Calling costs {call} chips; you have \
+             {stack} chips. Pot: {pot} chips.{plan} What do you do?"
+        ),
+        TextStyle::Technical => format!(
+            "Suited connector. You hold {hand_s} in the {hero_pos}; {opener} opens to {open} \
+             chips. {call} to call into {pot}, {stack} effective ({stack_bb} BB, {ratio}× the \
+             call). You will be {} postflop.{plan} Fold, call, or 3-bet to {three_bet}?",
+            if in_position { "in position" } else { "out of position" }
+        ),
+    };
+
+    let why = if hero_pos == Position::SB {
+        "From the SB you play the whole hand out of position and the BB can still squeeze \
+         behind — a suited connector can't realise its equity there."
+            .to_string()
+    } else if !calls {
+        format!(
+            "A suited connector flops a strong draw or better only about one time in five. With \
+             {ratio}× the call behind, the hits can't win enough to pay for the misses; it needs \
+             about {SUITED_CONNECTOR_MIN_RATIO}×."
+        )
+    } else {
+        format!(
+            "With {ratio}× the call behind (at least {SUITED_CONNECTOR_MIN_RATIO}× needed), the \
+             straights and flushes it makes win big pots against the opener's big pairs and \
+             top-pair hands — implied odds{}.",
+            if in_position { " realised from position" } else { " plus the BB's discount" }
+        )
+    };
+
+    let simple = |id: &str| -> String {
+        match (id, correct) {
+            ("A", "A") => format!("Correct — fold. {hand_s} looks pretty, but here it can't win enough to be worth it."),
+            ("B", "B") if compound => format!("Correct — call, then keep going only if the flop gives you a pair or a good draw. {hand_s} wins big pots when it hits."),
+            ("B", "B") => format!("Correct — call. {hand_s} can win a big pot when it makes a straight or flush, and the stacks are deep enough."),
+            _ if id == three_bet_id => format!("Re-raising with {hand_s} turns a good calling hand into a bluff."),
+            ("C", "B") => "Calling is right, but chasing every flop with a weak piece loses money. Only continue with a pair or a good draw.".to_string(),
+            ("A", _) => format!("Folding {hand_s} is too tight here — the stacks are deep enough to call."),
+            _ => format!("Calling with {hand_s} here costs money. Fold."),
+        }
+    };
+    let technical = |id: &str| -> String {
+        let verdict = match (id, correct) {
+            _ if id == correct && correct == "A" => "Correct. Fold.".to_string(),
+            _ if id == correct && compound => "Correct. Call, then continue on the flop with a pair, \
+                 an 8+ out draw, or better, and give up with backdoors and overcards.".to_string(),
+            _ if id == correct => "Correct. Call.".to_string(),
+            _ if id == three_bet_id => "3-betting a suited connector for value overplays it; it does best \
+                 seeing flops cheaply.".to_string(),
+            ("C", "B") => "The call is right, but floating every flop with gutshots and backdoors \
+                 burns the implied odds the call was for.".to_string(),
+            ("A", _) => "Folding gives up a profitable implied-odds call.".to_string(),
+            _ => "Calling here is -EV.".to_string(),
+        };
+        format!("{verdict} {why}")
+    };
+
+    let rate = |id: &str| match (id, correct) {
+        _ if id == correct => AnswerRating::Correct,
+        ("C", "B") if compound => AnswerRating::Plausible,
+        _ => AnswerRating::Mistake,
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "These hands win big pots when they hit, but they rarely hit. Are the stacks deep, and will you act last?",
+        TextStyle::Technical => "Consider: stack-to-call ratio, position, and implied odds.",
+    }.to_string());
+
+    let options = if compound {
+        vec![
+            ("A", "Fold".to_string()),
+            ("B", format!("Call {call}, then continue only with a pair or a strong draw")),
+            ("C", format!("Call {call}, then call down with any piece of the flop")),
+            ("D", format!("3-bet to {three_bet}")),
+        ]
+    } else {
+        vec![
+            ("A", "Fold".to_string()),
+            ("B", format!("Call {call}")),
+            ("C", format!("3-bet to {three_bet}")),
+        ]
+    };
+    let answers: Vec<AnswerOption> = options
+        .into_iter()
+        .map(|(id, text)| AnswerOption {
+            id: id.to_string(),
+            text,
+            is_correct: id == correct,
+            rating: rate(id),
+            hint: hint.clone(),
+            explanation: match text_style {
+                TextStyle::Simple    => simple(id),
+                TextStyle::Technical => technical(id),
+            },
+        })
+        .collect();
+
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::SuitedConnectors,
+        branch_key,
+        table_setup: TableSetup {
+            game_type:     GameType::CashGame,
+            hero_position: hero_pos,
+            hero_hand,
+            board:         vec![],
+            players:       heads_up(hero_pos, opener, stack, stack),
+            pot_size:      pot,
+            current_bet:   open,
+            antes:         None,
+        },
+        question,
+        answers,
+        key_concepts: key_concepts(TrainingTopic::SuitedConnectors, &[]),
+        villain_tendency: None,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// T32 — Poker Math (PM-)
+//
+// A combinatorics or probability quiz rather than a table decision.  Each
+// question has four answers: the exact value and three common misconceptions
+// (counting hand types instead of combos, ordered deals, rule of 4 on the
+// turn, and so on).  Beginner draws only counting questions; Intermediate
+// adds probabilities; Advanced adds the multi-card board questions.
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// One poker-math question with its answers and the reasoning behind them.
+pub(crate) struct MathQuestion {
+    /// `branch_key` suffix.
+    pub(crate) key: &'static str,
+    /// Lowest difficulty that draws this question.
+    level: DifficultyLevel,
+    question: &'static str,
+    pub(crate) options: [&'static str; 4],
+    pub(crate) correct: usize,
+    simple: &'static str,
+    technical: &'static str,
+    /// Why each wrong option is tempting; empty for the correct one.
+    traps: [&'static str; 4],
+}
+
+pub(crate) const MATH_QUESTIONS: [MathQuestion; 12] = [
+    MathQuestion {
+        key: "PairCombos",
+        level: DifficultyLevel::Beginner,
+        question: "How many combinations of pocket aces exist?",
+        options: ["4", "6", "12", "16"],
+        correct: 1,
+        simple: "There are four aces, and any two of them make a pair: 6 ways.",
+        technical: "Pairs: C(4,2) = 4 × 3 / 2 = 6 combos.",
+        traps: [
+            "4 is the number of aces, not the number of ways to pick two of them.",
+            "",
+            "12 counts A♠A♥ and A♥A♠ as different hands — the order of the cards doesn't matter.",
+            "16 pairs every ace with every ace, including itself.",
+        ],
+    },
+    MathQuestion {
+        key: "SuitedCombos",
+        level: DifficultyLevel::Beginner,
+        question: "How many combinations of AK suited exist?",
+        options: ["1", "4", "6", "12"],
+        correct: 1,
+        simple: "There is one suited AK for each suit: 4 combos.",
+        technical: "Suited unpaired hands: one combo per suit, 4.",
+        traps: [
+            "AKs is one hand type, but it can be dealt in any of the four suits.",
+            "",
+            "6 is the count for a pocket pair, not a suited hand.",
+            "12 is the number of offsuit AK combos.",
+        ],
+    },
+    MathQuestion {
+        key: "OffsuitCombos",
+        level: DifficultyLevel::Beginner,
+        question: "How many combinations of AK offsuit exist?",
+        options: ["4", "8", "12", "16"],
+        correct: 2,
+        simple: "Each of the four aces can go with three kings of a different suit: 12 combos.",
+        technical: "Offsuit unpaired hands: 4 × 3 = 12 combos (16 total minus 4 suited).",
+        traps: [
+            "4 is the suited count.",
+            "8 doesn't match any way of pairing four aces with four kings.",
+            "",
+            "16 is every AK, including the 4 suited ones.",
+        ],
+    },
+    MathQuestion {
+        key: "StartingHands",
+        level: DifficultyLevel::Beginner,
+        question: "How many different two-card starting hands can be dealt from a 52-card deck?",
+        options: ["169", "1,326", "2,652", "2,704"],
+        correct: 1,
+        simple: "Any two of the 52 cards make a hand: 1,326 combinations.",
+        technical: "C(52,2) = 52 × 51 / 2 = 1,326 combos, grouped into 169 hand types.",
+        traps: [
+            "169 is the number of hand types (AA, AKs, AKo, …), not combinations.",
+            "",
+            "2,652 counts the two cards in both orders.",
+            "2,704 is 52 × 52, which lets a card pair with itself.",
+        ],
+    },
+    MathQuestion {
+        key: "DealtAces",
+        level: DifficultyLevel::Beginner,
+        question: "How often are you dealt pocket aces?",
+        options: ["1 in 52", "1 in 169", "1 in 221", "1 in 1,326"],
+        correct: 2,
+        simple: "6 of the 1,326 possible hands are aces: about 1 hand in 221.",
+        technical: "6 / 1,326 = 1 / 221 ≈ 0.45%.",
+        traps: [
+            "1 in 52 is the chance of one specific card.",
+            "1 in 169 treats every hand type as equally likely — pairs are rarer than offsuit hands.",
+            "",
+            "1 in 1,326 is one specific combo, such as A♠A♥.",
+        ],
+    },
+    MathQuestion {
+        key: "PotOddsPrice",
+        level: DifficultyLevel::Beginner,
+        question: "Villain bets the size of the pot. How much equity do you need to call?",
+        options: ["25%", "33%", "50%", "100%"],
+        correct: 1,
+        simple: "You call 1 pot to win 3 (the pot, the bet and your call): you need to win 1 time in 3.",
+        technical: "Required equity = call / (pot + bet + call) = 1 / 3 ≈ 33%.",
+        traps: [
+            "25% is the price of a half-pot bet.",
+            "",
+            "50% compares the call with the bet only, leaving out the pot that is already there.",
+            "100% confuses the bet size with the equity needed.",
+        ],
+    },
+    MathQuestion {
+        key: "FlopSet",
+        level: DifficultyLevel::Intermediate,
+        question: "You hold a pocket pair. How often do you flop exactly a set (not a full house or quads)?",
+        options: ["4%", "8%", "10.8%", "20%"],
+        correct: 2,
+        simple: "About 1 flop in 9 gives you a set.",
+        technical: "2 × (C(48,2) − 72) / C(50,3) = 2,112 / 19,600 ≈ 10.8%; set or better is ~11.8%.",
+        traps: [
+            "4% is what two outs give on a single card.",
+            "8% applies the rule of 4 to two outs, but the flop is three cards, not two.",
+            "",
+            "20% overrates it — a set comes about one flop in nine.",
+        ],
+    },
+    MathQuestion {
+        key: "FlopPair",
+        level: DifficultyLevel::Intermediate,
+        question: "You hold two unpaired cards. How often does the flop pair at least one of them?",
+        options: ["12%", "32.4%", "50%", "66%"],
+        correct: 1,
+        simple: "You miss completely about two flops in three: you pair up about a third of the time.",
+        technical: "1 − C(44,3) / C(50,3) = 1 − 13,244 / 19,600 ≈ 32.4%.",
+        traps: [
+            "12% is about the chance of flopping a set with a pocket pair.",
+            "",
+            "50% feels right, but most flops miss both hole cards.",
+            "66% is the chance of missing, not hitting.",
+        ],
+    },
+    MathQuestion {
+        key: "FlushDrawByRiver",
+        level: DifficultyLevel::Intermediate,
+        question: "You flop a flush draw. How often does it complete by the river?",
+        options: ["9%", "19.6%", "35%", "50%"],
+        correct: 2,
+        simple: "Nine outs with two cards to come: a bit more than one time in three.",
+        technical: "1 − (38/47 × 37/46) ≈ 35%; rule of 4: 9 × 4 = 36%.",
+        traps: [
+            "9% counts the outs as percentages.",
+            "19.6% is the chance on one card — the turn alone or the river alone.",
+            "",
+            "50% overrates the draw; it misses about two times in three.",
+        ],
+    },
+    MathQuestion {
+        key: "OesdOnRiver",
+        level: DifficultyLevel::Intermediate,
+        question: "You have an open-ended straight draw on the turn. How often does the river complete it?",
+        options: ["8%", "17.4%", "31.5%", "50%"],
+        correct: 1,
+        simple: "Eight outs with one card to come: about one time in six.",
+        technical: "8 / 46 ≈ 17.4%; rule of 2: 8 × 2 = 16%.",
+        traps: [
+            "8% counts the outs as percentages.",
+            "",
+            "31.5% is the flop number, with two cards to come.",
+            "50% treats two open ends as even money.",
+        ],
+    },
+    MathQuestion {
+        key: "AceOnBoard",
+        level: DifficultyLevel::Advanced,
+        question: "You hold pocket kings. How often does at least one ace appear on a five-card board?",
+        options: ["8%", "20%", "35.3%", "50%"],
+        correct: 2,
+        simple: "Four aces can land on any of five board cards: a bit more than one time in three.",
+        technical: "1 − C(46,5) / C(50,5) = 1 − 1,370,754 / 2,118,760 ≈ 35.3%.",
+        traps: [
+            "8% is roughly one card's chance (4 / 50), not five cards'.",
+            "20% stops counting at the flop.",
+            "",
+            "50% overrates it — the board runs out ace-free about two times in three.",
+        ],
+    },
+    MathQuestion {
+        key: "PairCombosTotal",
+        level: DifficultyLevel::Advanced,
+        question: "How many combinations of pocket pairs (22 through AA) exist in total?",
+        options: ["13", "52", "78", "169"],
+        correct: 2,
+        simple: "Thirteen ranks, six ways each: 78 combos.",
+        technical: "13 × C(4,2) = 13 × 6 = 78 combos — 5.9% of all 1,326 hands.",
+        traps: [
+            "13 is the number of pair types.",
+            "52 uses four combos per pair instead of six.",
+            "",
+            "169 is the number of all hand types.",
+        ],
+    },
+];
+
+/// T32 — Poker Math (PM-).
+///
+/// A quiz question drawn from [`MATH_QUESTIONS`], filtered by difficulty.
+/// The table is a plain preflop setup; the question does not depend on it.
+///
+/// RNG order: question.
+pub fn generate_poker_math<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand = [dealer.deal()?, dealer.deal()?];
+
+    let tier = |d: DifficultyLevel| match d {
+        DifficultyLevel::Beginner     => 0,
+        DifficultyLevel::Intermediate => 1,
+        DifficultyLevel::Advanced     => 2,
+    };
+    let pool: Vec<&MathQuestion> =
+        MATH_QUESTIONS.iter().filter(|q| tier(q.level) <= tier(difficulty)).collect();
+    let q = pool[rng.gen_range(0..pool.len())];
+    let branch_key = format!("PokerMath:{}", q.key);
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Count the ways it can happen, then compare with all the ways the cards can fall.",
+        TextStyle::Technical => "Consider: combinations C(n,k), and whether the count is ordered or unordered.",
+    }.to_string());
+
+    let ids = ["A", "B", "C", "D"];
+    let answers: Vec<AnswerOption> = ids
+        .iter()
+        .zip(q.options)
+        .enumerate()
+        .map(|(i, (id, text))| {
+            let is_correct = i == q.correct;
+            AnswerOption {
+                id: id.to_string(),
+                text: text.to_string(),
+                is_correct,
+                rating: if is_correct { AnswerRating::Correct } else { AnswerRating::Mistake },
+                hint: hint.clone(),
+                explanation: match (text_style, is_correct) {
+                    (TextStyle::Simple, true)     => format!("Correct. {}", q.simple),
+                    (TextStyle::Simple, false)    => format!("{} {}", q.traps[i], q.simple),
+                    (TextStyle::Technical, true)  => format!("Correct. {}", q.technical),
+                    (TextStyle::Technical, false) => format!("{} {}", q.traps[i], q.technical),
+                },
+            }
+        })
+        .collect();
+
+    let bb = 2u32;
+    let stack = 100 * bb;
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::PokerMath,
+        branch_key,
+        table_setup: TableSetup {
+            game_type:     GameType::CashGame,
+            hero_position: Position::BTN,
+            hero_hand,
+            board:         vec![],
+            players:       heads_up(Position::BTN, Position::BB, stack, stack),
+            pot_size:      bb + bb / 2,
+            current_bet:   bb,
+            antes:         None,
+        },
+        question: match text_style {
+            TextStyle::Simple    => format!("Poker math quiz. {}", q.question),
+            TextStyle::Technical => format!("Poker math — exact value. {}", q.question),
+        },
+        answers,
+        key_concepts: key_concepts(TrainingTopic::PokerMath, &[]),
+        villain_tendency: None,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// T34 — Beginner Hand Rankings (RK-)
+//
+// A showdown quiz: two 5-card hands, which one wins?  Beginner hands are
+// always different categories, Advanced hands share a category and the
+// leading rank(s), so a kicker decides; Intermediate draws either kind.
+// Both hands are built from the RNG to have exactly the chosen category.
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Categories the quiz deals, weakest first.
+const RANKING_CATEGORIES: [MadeHand; 9] = [
+    MadeHand::HighCard, MadeHand::OnePair, MadeHand::TwoPair,
+    MadeHand::ThreeOfAKind, MadeHand::Straight, MadeHand::Flush,
+    MadeHand::FullHouse, MadeHand::FourOfAKind, MadeHand::StraightFlush,
+];
+
+/// Categories where two hands can share the leading rank(s) and differ only
+/// in the kickers.
+const KICKER_CATEGORIES: [MadeHand; 4] =
+    [MadeHand::HighCard, MadeHand::OnePair, MadeHand::TwoPair, MadeHand::Flush];
+
+/// Ranks of a 5-card hand in showdown order: bigger groups first, then
+/// higher ranks (`KKK44` → K, 4; `AQ952` → A, Q, 9, 5, 2).
+fn showdown_order(cards: [Card; 5]) -> Vec<u8> {
+    let mut counts = [0u8; 15];
+    for c in &cards {
+        counts[c.rank.0 as usize] += 1;
+    }
+    let mut groups: Vec<(u8, u8)> =
+        (2..=14u8).filter(|&r| counts[r as usize] > 0).map(|r| (counts[r as usize], r)).collect();
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+    groups.into_iter().map(|(_, r)| r).collect()
+}
+
+/// Five cards making exactly `category`, none of them in `used`.  The
+/// hand's leading ranks in [`showdown_order`] are `lead` (empty = any).
+/// Straights are 6-high or better, so the high card alone ranks them.
+fn build_made_hand<R: Rng>(rng: &mut R, category: MadeHand, used: &[Card], lead: &[u8]) -> [Card; 5] {
+    let sizes: &[usize] = match category {
+        MadeHand::OnePair      => &[2, 1, 1, 1],
+        MadeHand::TwoPair      => &[2, 2, 1],
+        MadeHand::ThreeOfAKind => &[3, 1, 1],
+        MadeHand::FullHouse    => &[3, 2],
+        MadeHand::FourOfAKind  => &[4, 1],
+        _                      => &[1, 1, 1, 1, 1],
+    };
+    let one_suit = matches!(category, MadeHand::Flush | MadeHand::StraightFlush);
+    'deal: loop {
+        let ranks: Vec<u8> = match category {
+            MadeHand::Straight | MadeHand::StraightFlush => {
+                let hi = rng.gen_range(6..=14u8);
+                (hi - 4..=hi).rev().collect()
+            }
+            _ => {
+                let mut ranks = lead.to_vec();
+                while ranks.len() < sizes.len() {
+                    let r = rng.gen_range(2..=14u8);
+                    if !ranks.contains(&r) {
+                        ranks.push(r);
+                    }
+                }
+                ranks
+            }
+        };
+        let flush_suit = Suit::all()[rng.gen_range(0..4)];
+        let mut cards = Vec::with_capacity(5);
+        for (&r, &n) in ranks.iter().zip(sizes) {
+            let mut free: Vec<Suit> = Suit::all()
+                .into_iter()
+                .filter(|&s| !used.contains(&Card { rank: Rank(r), suit: s }))
+                .filter(|&s| !one_suit || s == flush_suit)
+                .collect();
+            if free.len() < n {
+                continue 'deal;
+            }
+            for _ in 0..n {
+                let suit = free.swap_remove(rng.gen_range(0..free.len()));
+                cards.push(Card { rank: Rank(r), suit });
+            }
+        }
+        let hand: [Card; 5] = cards.try_into().expect("group sizes sum to 5");
+        if evaluate_five_cards(hand).category() == category && showdown_order(hand).starts_with(lead) {
+            return hand;
+        }
+    }
+}
+
+/// Name of the `n`-th rank compared (0-based): "first", "second", ….
+fn ordinal(n: usize) -> &'static str {
+    ["first", "second", "third", "fourth", "fifth"][n.min(4)]
+}
+
+/// T34 — Beginner Hand Rankings (RK-).
+///
+/// Two 5-card hands from [`build_made_hand`]; the winner is Hand 1 or
+/// Hand 2 at random.  Hero's hole cards are dealt afterwards with
+/// `deal_matching`, passing over cards the quiz hands use; the question does
+/// not depend on them.
+///
+/// RNG order: same-category?, categories, hands, winner seat.
+pub fn generate_hand_ranking<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let same_category = match difficulty {
+        DifficultyLevel::Beginner     => false,
+        DifficultyLevel::Intermediate => rng.gen_bool(0.5),
+        DifficultyLevel::Advanced     => true,
+    };
+
+    let (winner, loser) = if same_category {
+        let category = KICKER_CATEGORIES[rng.gen_range(0..KICKER_CATEGORIES.len())];
+        let first = build_made_hand(rng, category, &[], &[]);
+        let order = showdown_order(first);
+        let lead = match category {
+            MadeHand::OnePair => &order[..1],
+            MadeHand::TwoPair => &order[..2],
+            _                 => &order[..1],
+        };
+        let second = loop {
+            let h = build_made_hand(rng, category, &first, lead);
+            if compare_hands(first, h) != std::cmp::Ordering::Equal {
+                break h;
+            }
+        };
+        if compare_hands(first, second) == std::cmp::Ordering::Greater { (first, second) } else { (second, first) }
+    } else {
+        let lo = rng.gen_range(0..RANKING_CATEGORIES.len() - 1);
+        let hi = rng.gen_range(lo + 1..RANKING_CATEGORIES.len());
+        let winner = build_made_hand(rng, RANKING_CATEGORIES[hi], &[], &[]);
+        let loser = build_made_hand(rng, RANKING_CATEGORIES[lo], &winner, &[]);
+        (winner, loser)
+    };
+    let winner_first = rng.gen_bool(0.5);
+    let (hand1, hand2) = if winner_first { (winner, loser) } else { (loser, winner) };
+
+    let free = |c: &Card| !hand1.contains(c) && !hand2.contains(c);
+    let hero_hand = [dealer.deal_matching(free)?, dealer.deal_matching(free)?];
+
+    let (w_cat, l_cat) = (evaluate_five_cards(winner).category(), evaluate_five_cards(loser).category());
+    let branch_key = format!("HandRanking:{w_cat:?}:{l_cat:?}");
+    let (w_name, l_name) = if winner_first { ("Hand 1", "Hand 2") } else { ("Hand 2", "Hand 1") };
+    let correct = if winner_first { "A" } else { "B" };
+    let hands = format!("Hand 1: {}. Hand 2: {}.", hand_description(hand1), hand_description(hand2));
+
+    let why = if w_cat != l_cat {
+        match text_style {
+            TextStyle::Simple => format!(
+                "{hands} Any {} beats any {}, whatever the cards inside each hand.",
+                w_cat.name(), l_cat.name()
+            ),
+            TextStyle::Technical => format!(
+                "{hands} Category decides first — straight flush, four of a kind, full house, \
+                 flush, straight, three of a kind, two pair, one pair, high card — so {w_name}'s \
+                 {} wins before any kicker is compared.",
+                w_cat.name()
+            ),
+        }
+    } else {
+        let (w_order, l_order) = (showdown_order(winner), showdown_order(loser));
+        let i = w_order.iter().zip(&l_order).position(|(a, b)| a != b).unwrap_or(0);
+        let (hi, lo) = (Rank(w_order[i]), Rank(l_order[i]));
+        match text_style {
+            TextStyle::Simple => format!(
+                "{hands} Both hands are {}, so compare them from the top, pairs first. The \
+                 first difference decides: {w_name}'s {hi} beats {l_name}'s {lo}.",
+                w_cat.name()
+            ),
+            TextStyle::Technical => format!(
+                "{hands} Same category ({}): compare ranks in showdown order — paired ranks \
+                 first, then kickers high to low. They match until the {} rank, where \
+                 {w_name}'s {hi} beats {l_name}'s {lo}.",
+                w_cat.name(), ordinal(i)
+            ),
+        }
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Name each hand first (pair, flush, …). Only if they match, compare the cards.",
+        TextStyle::Technical => "Consider: category first, then ranks in showdown order.",
+    }.to_string());
+
+    let options = [
+        ("A", "Hand 1 wins".to_string()),
+        ("B", "Hand 2 wins".to_string()),
+        ("C", "Split pot — the hands tie".to_string()),
+        ("D", "The suits decide it".to_string()),
+    ];
+    let answers: Vec<AnswerOption> = options
+        .into_iter()
+        .map(|(id, text)| {
+            let is_correct = id == correct;
+            let explanation = match id {
+                _ if is_correct => format!("Correct. {why}"),
+                "A" | "B"       => format!("That is the losing hand. {why}"),
+                "C"             => format!("A tie needs every rank to match; these hands differ. {why}"),
+                _               => format!("Suits never rank in poker; they only matter for making a flush. {why}"),
+            };
+            AnswerOption {
+                id: id.to_string(),
+                text,
+                is_correct,
+                rating: match id {
+                    _ if is_correct => AnswerRating::Correct,
+                    "A" | "B"       => AnswerRating::Blunder,
+                    _               => AnswerRating::Mistake,
+                },
+                explanation,
+                hint: hint.clone(),
+            }
+        })
+        .collect();
+
+    let shown = |hand: [Card; 5]| {
+        let order = showdown_order(hand);
+        let mut cards = hand;
+        cards.sort_by_key(|c| (order.iter().position(|&r| r == c.rank.0), suit_index(c.suit)));
+        cards.map(|c| c.to_string()).join(" ")
+    };
+    let (h1, h2) = (shown(hand1), shown(hand2));
+    let question = match text_style {
+        TextStyle::Simple    => format!("Hand rankings. Hand 1: {h1}. Hand 2: {h2}. Which hand wins?"),
+        TextStyle::Technical => format!("Showdown — rank the hands. Hand 1: {h1}. Hand 2: {h2}. Which hand wins?"),
+    };
+
+    let bb = 2u32;
+    let stack = 100 * bb;
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::BeginnerHandRankings,
+        branch_key,
+        table_setup: TableSetup {
+            game_type:     GameType::CashGame,
+            hero_position: Position::BTN,
+            hero_hand,
+            board:         vec![],
+            players:       heads_up(Position::BTN, Position::BB, stack, stack),
+            pot_size:      bb + bb / 2,
+            current_bet:   bb,
+            antes:         None,
+        },
+        question,
+        answers,
+        key_concepts: key_concepts(TrainingTopic::BeginnerHandRankings, &[]),
+        villain_tendency: None,
+    })
 }
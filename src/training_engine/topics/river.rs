@@ -1,6 +1,8 @@
-//! River topic generators: bluff spot, value bet, and call-or-fold.
+//! River topic generators: bluff spot, value bet, call-or-fold, triple barrel,
+//! hand reading, lay down, and when to give up.
 //!
-//! All three topics deal a full 5-card board and ask hero to act on the river.
+//! All seven topics deal a full 5-card board and ask hero to act on the river
+//! (or, for hand reading, to name villain's holding).
 //!
 //! - **T4 Bluff Spot** — Hero has no showdown value.  Decision depends on the
 //!   bluff type (missed flush draw / capped range / bricked overcards) and the
@@ -10,12 +12,137 @@
 //! - **T14 River Call or Fold** — Villain bets into hero.  Decision depends on
 //!   hero's hand strength vs the bet size: raise strong vs small bets, call
 //!   marginal vs standard bets, fold weak vs large bets.
+//! - **T21 Triple Barrel** — After barrelling flop and turn, bet value hands
+//!   and the best bluff candidates (missed draws, scare rivers), check the rest.
+//! - **T33 Hand Reading** — Villain's line over three streets (an
+//!   `ActionSequence`) narrows the range: name the most likely holding.
+//! - **T37 Lay Down** — Hero's strong non-nut hand is check-raised on the
+//!   river.  Reverse implied odds: call small raises, fold big ones.
+//! - **T38 When to Give Up** — Hero's air was called on flop and turn.  Check
+//!   the river; bluff only with the nut-flush blocker when the flush arrives.
 
 use rand::Rng;
 use crate::training_engine::{
-    helpers::{deal, hand_str, board_str, heads_up, scenario},
+    deck::CardDealer,
+    evaluator::{
+        best_hand_from_seven, blocker_value_score, blocks_nut_straight, board_suit_counts,
+        break_even_equity, classify_hero_hand_vs_board, ev_of_bet, flush_possible,
+        gto_bluff_combo_count, hand_description, hero_has_flush_draw, hero_has_nut_flush_draw,
+        hero_has_straight_draw, is_range_balanced, nut_flush_suit, nut_straight_ranks,
+        optimal_bluff_to_value_ratio, straight_possible, suit_index, HandVsBoardStrength, MadeHand,
+    },
+    helpers::{
+        answer, deal, hand_str, board_str, heads_up, fold_call_raise_rating, ladder_rating, pot_after_call,
+        scenario,
+    },
     models::*,
 };
+// The bet-size ladder of villain's line; river call-or-fold keeps its own `BetSize`.
+use crate::training_engine::models::BetSize as LineSize;
+
+/// Hero's best five-card hand on the full board, described for Technical text
+/// (e.g. "Pair of Tens with Ace kicker").
+fn made_hand_description(hand: [Card; 2], board: &[Card]) -> String {
+    let board: [Card; 5] = board.try_into().expect("river board has 5 cards");
+    hand_description(best_hand_from_seven(hand, board).0)
+}
+
+/// Technical note on who holds the nut flush when the board allows one.
+fn nut_flush_note(hand: [Card; 2], board: &[Card]) -> String {
+    let Some(suit) = nut_flush_suit(board) else {
+        return String::new();
+    };
+    let name = suit.name();
+    let ace = Card { rank: Rank(14), suit };
+    if hero_has_nut_flush_draw(hand, board) {
+        format!(" You held the nut flush draw in {name} — the {ace} in your hand blocks villain's nut flush.")
+    } else if hand.contains(&ace) {
+        format!(" Your {ace} blocks the nut flush in {name}.")
+    } else {
+        format!(" You do not hold the {ace}, so villain can still have the nut flush in {name}.")
+    }
+}
+
+/// Technical note counting the nut-flush and nut-straight combos hero's hole
+/// cards remove from villain's range; empty when hero blocks neither.
+fn blocker_note(hand: [Card; 2], board: &[Card]) -> String {
+    let mut parts = Vec::new();
+    if let Some(suit) = nut_flush_suit(board) {
+        let on_board = board_suit_counts(board)[suit_index(suit)] as usize;
+        let ace = Card { rank: Rank(14), suit };
+        if on_board >= 3 && hand.contains(&ace) {
+            // Villain's nut flush is the ace plus one more card of the suit
+            // (any card once the board shows four).
+            let combos = if on_board >= 4 {
+                52 - board.len() - 2
+            } else {
+                13 - on_board - hand.iter().filter(|c| c.suit == suit).count()
+            };
+            parts.push(format!("your {ace} removes all {combos} nut-flush combos"));
+        }
+    }
+    if blocks_nut_straight(hand, board) {
+        let needed = nut_straight_ranks(board);
+        let left = |r: Rank| 4 - hand.iter().filter(|c| c.rank == r).count();
+        match needed[..] {
+            [r] => parts.push(format!(
+                "you hold {} of the four {r}s that make the nut straight, leaving villain {}",
+                4 - left(r),
+                left(r)
+            )),
+            [a, b] => parts.push(format!(
+                "you cut villain's {b}{a} nut-straight combos from 16 to {}",
+                left(a) * left(b)
+            )),
+            _ => {}
+        }
+    }
+    if parts.is_empty() {
+        return String::new();
+    }
+    format!(
+        " Blockers: {} (blocker score {:.2}).",
+        parts.join("; "),
+        blocker_value_score(hand, board)
+    )
+}
+
+/// Technical note on the straights and flushes a river board allows villain.
+fn board_threats_note(board: &[Card]) -> String {
+    let straights = straight_possible(board).len();
+    let straight_part = match straights {
+        0 => "no straight".to_string(),
+        1 => "1 possible straight".to_string(),
+        n => format!("{n} possible straights"),
+    };
+    match flush_possible(board).first() {
+        Some(suit) => format!(" This board has {straight_part} and a possible {} flush.", suit.unicode_symbol()),
+        None => format!(" This board has {straight_part} and no possible flush."),
+    }
+}
+
+/// Value combos in the polarized river betting range T4's balance note
+/// assumes.
+const POLAR_VALUE_COMBOS: u32 = 20;
+
+/// Technical note on a balanced river range: how many bluffs the small and
+/// large sizes support per [`POLAR_VALUE_COMBOS`] value combos, and whether
+/// keeping the small size's bluff count still balances the large bet.
+fn bluff_balance_note(pot: u32, small_bet: u32, large_bet: u32) -> String {
+    let small_bluffs = gto_bluff_combo_count(small_bet, pot, POLAR_VALUE_COMBOS);
+    let large_bluffs = gto_bluff_combo_count(large_bet, pot, POLAR_VALUE_COMBOS);
+    let verdict = if is_range_balanced(POLAR_VALUE_COMBOS, small_bluffs, large_bet, pot) {
+        "still balanced"
+    } else {
+        "under-bluffed"
+    };
+    format!(
+        " Balance: a {large_bet}-chip bet can be {:.0}% bluffs — {large_bluffs} bluff combos \
+         per {POLAR_VALUE_COMBOS} value combos, against {small_bluffs} for the \
+         {small_bet}-chip bet; betting large with only {small_bluffs} bluffs is {verdict}.",
+        optimal_bluff_to_value_ratio(large_bet, pot) * 100.0
+    )
+}
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // T4 — Bluff Spot (BL-)
@@ -47,19 +174,14 @@ impl std::fmt::Display for BluffType {
     }
 }
 
-fn required_fold_frequency(bet_size: u32, pot_before_bet: u32) -> f32 {
-    let denom = pot_before_bet + bet_size;
-    if denom == 0 { return 0.0; }
-    bet_size as f32 / denom as f32
-}
-
-pub fn generate_bluff<R: Rng>(
+pub fn generate_bluff<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let (hero_hand, board) = deal(rng, 5);
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, board) = deal(dealer, 5)?;
 
     let bluff_type = match rng.gen_range(0..3) {
         0 => BluffType::MissedFlushDraw,
@@ -99,9 +221,17 @@ pub fn generate_bluff<R: Rng>(
         _                      => "C",
     };
 
-    let fold_freq_small = required_fold_frequency(small_bet, pot);
-    let fold_freq_large = required_fold_frequency(large_bet, pot);
-    let fold_freq_shove = required_fold_frequency(shove, pot);
+    let fold_freq_small = break_even_equity(small_bet, pot);
+    let fold_freq_large = break_even_equity(large_bet, pot);
+    let fold_freq_shove = break_even_equity(shove, pot);
+    // Estimated villain fold rate vs the large bet: wide calling ranges fold rarely.
+    let est_fold_large = if correct_id == "A" { 0.25 } else { 0.55 };
+    let large_bluff_ev = ev_of_bet(est_fold_large, pot, large_bet, 0.0);
+
+    let made = made_hand_description(hero_hand, &board);
+    let flush_note = nut_flush_note(hero_hand, &board);
+    let blocker_note = blocker_note(hero_hand, &board);
+    let balance_note = bluff_balance_note(pot, small_bet, large_bet);
 
     let question = match text_style {
         TextStyle::Simple => format!(
@@ -112,7 +242,7 @@ pub fn generate_bluff<R: Rng>(
         ),
         TextStyle::Technical => format!(
             "River spot. You hold {hs} ({bluff_type}) on {hero_pos}. \
-             Board: {bs}. Pot: {pot} chips ({pot_bb} BB). \
+             Board: {bs} (best five: {made}). Pot: {pot} chips ({pot_bb} BB). \
              Stack: {stack} chips (SPR = {spr:.1}). Villain checks to you. \
              Bet options: small ({small_bet} chips ~40% pot), large ({large_bet} chips ~75% pot), \
              or shove ({shove} chips). What do you do?"
@@ -135,11 +265,18 @@ pub fn generate_bluff<R: Rng>(
         )
     };
 
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Can your hand win if you check? If not, how big a bet would make them fold?",
+        TextStyle::Technical => "Consider: showdown value and the fold equity each bet size needs.",
+    }.to_string());
+
     let answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Check".to_string(),
             is_correct: correct_id == "A",
+            rating: ladder_rating("A", correct_id),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => if correct_id == "A" {
                     "Correct — check. Your opponent will call any bet you make here, so betting loses more chips than checking.".to_string()
@@ -153,6 +290,8 @@ pub fn generate_bluff<R: Rng>(
             id: "B".to_string(),
             text: "Bet small".to_string(),
             is_correct: correct_id == "B",
+            rating: ladder_rating("B", correct_id),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => if correct_id == "B" {
                     "A small bet works here — it puts just enough pressure on your opponent to fold weak hands.".to_string()
@@ -178,6 +317,8 @@ pub fn generate_bluff<R: Rng>(
             id: "C".to_string(),
             text: "Bet large".to_string(),
             is_correct: correct_id == "C",
+            rating: ladder_rating("C", correct_id),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => if correct_id == "C" {
                     "Correct — bet big! You have nothing, so your only way to win is to make your opponent fold. A big bet is the most believable and gives you the best chance they give up.".to_string()
@@ -199,8 +340,11 @@ pub fn generate_bluff<R: Rng>(
                     format!(
                         "Large bluff ({large_bet} chips) with {hs} ({bluff_type}): \
                          Requires villain to fold {:.1}% of the time to break even. \
-                         SPR = {spr:.1}. {rationale}",
+                         At an estimated {:.0}% fold rate the bluff's EV is \
+                         {large_bluff_ev:+.1} chips. SPR = {spr:.1}. {rationale}{flush_note}{blocker_note}\
+                         {balance_note}",
                         fold_freq_large * 100.0,
+                        est_fold_large * 100.0,
                     )
                 },
             },
@@ -209,6 +353,8 @@ pub fn generate_bluff<R: Rng>(
             id: "D".to_string(),
             text: "All-in".to_string(),
             is_correct: false,
+            rating: ladder_rating("D", correct_id),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => "Going all-in here is too extreme. Unless you have almost no chips left compared to the pot, a well-sized big bet does the same job at lower risk.".to_string(),
                 TextStyle::Technical => format!(
@@ -224,8 +370,8 @@ pub fn generate_bluff<R: Rng>(
     ];
 
     let players = heads_up(hero_pos, Position::BB, stack, stack);
-    scenario(scenario_id, TrainingTopic::BluffSpot, branch_key,
-        GameType::CashGame, hero_pos, hero_hand, board, players, pot, 0, question, answers)
+    Ok(scenario(scenario_id, TrainingTopic::BluffSpot, branch_key,
+        GameType::CashGame, hero_pos, hero_hand, board, players, pot, 0, question, answers))
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -265,13 +411,14 @@ fn value_strength_simple(hs: ValueStrength) -> &'static str {
     }
 }
 
-pub fn generate_value_bet<R: Rng>(
+pub fn generate_value_bet<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let (hero_hand, board) = deal(rng, 5);
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, board) = deal(dealer, 5)?;
 
     let strength = match rng.gen_range(0..3) {
         0 => ValueStrength::Nuts,
@@ -309,6 +456,8 @@ pub fn generate_value_bet<R: Rng>(
     let bs = board_str(&board);
     let strength_simple = value_strength_simple(strength);
 
+    let made = made_hand_description(hero_hand, &board);
+
     let question = match text_style {
         TextStyle::Simple => format!(
             "Last card. You have {hs} (a {strength_simple}) on the Button. \
@@ -317,7 +466,7 @@ pub fn generate_value_bet<R: Rng>(
         ),
         TextStyle::Technical => format!(
             "River spot. You hold {hs} ({strength}) on Button. \
-             Board: {bs}. Pot: {pot} chips ({pot_bb} BB). \
+             Board: {bs} (best five: {made}). Pot: {pot} chips ({pot_bb} BB). \
              Stack: {stack} chips. Villain checks to you. \
              Bet options: small ({small_bet} chips ~33%), \
              large ({large_bet} chips ~75%), overbet ({overbet} chips ~125%). \
@@ -325,11 +474,18 @@ pub fn generate_value_bet<R: Rng>(
         ),
     };
 
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "How strong is your hand really? Which worse hands could still pay you?",
+        TextStyle::Technical => "Consider: hand strength relative to villain's calling range.",
+    }.to_string());
+
     let answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Check".to_string(),
             is_correct: correct == "A",
+            rating: ladder_rating("A", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
                     ValueStrength::Medium => "Correct — check. Your hand is decent but not dominant. Betting risks giving your opponent a reason to raise and win a big pot.".to_string(),
@@ -354,6 +510,8 @@ pub fn generate_value_bet<R: Rng>(
             id: "B".to_string(),
             text: format!("Bet small ({small_bet} chips ~33%)"),
             is_correct: false,
+            rating: ladder_rating("B", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => "Betting too small here leaves money behind. Your hand is strong — bet bigger to win more.".to_string(),
                 TextStyle::Technical => format!(
@@ -367,6 +525,8 @@ pub fn generate_value_bet<R: Rng>(
             id: "C".to_string(),
             text: format!("Bet large ({large_bet} chips ~75%)"),
             is_correct: correct == "C",
+            rating: ladder_rating("C", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
                     ValueStrength::Strong => "Correct — bet big! You have a strong hand and your opponent is likely to call. Get paid as much as possible.".to_string(),
@@ -397,6 +557,8 @@ pub fn generate_value_bet<R: Rng>(
             id: "D".to_string(),
             text: format!("Overbet ({overbet} chips ~125%)"),
             is_correct: correct == "D",
+            rating: ladder_rating("D", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
                     ValueStrength::Nuts => "Correct — go big! You have the strongest possible hand here. Bet as much as you can — your opponent will likely call.".to_string(),
@@ -420,8 +582,8 @@ pub fn generate_value_bet<R: Rng>(
     ];
 
     let players = heads_up(hero_pos, Position::BB, stack, stack);
-    scenario(scenario_id, TrainingTopic::RiverValueBet, branch_key,
-        GameType::CashGame, hero_pos, hero_hand, board, players, pot, 0, question, answers)
+    Ok(scenario(scenario_id, TrainingTopic::RiverValueBet, branch_key,
+        GameType::CashGame, hero_pos, hero_hand, board, players, pot, 0, question, answers))
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -485,13 +647,14 @@ fn bet_size_simple(bs: BetSize) -> &'static str {
     }
 }
 
-pub fn generate_call_or_fold<R: Rng>(
+pub fn generate_call_or_fold<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let (hero_hand, board) = deal(rng, 5);
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, board) = deal(dealer, 5)?;
 
     let (strength, bet_size) = match rng.gen_range(0..3) {
         0 => (CallerStrength::Strong,   BetSize::Small),
@@ -514,8 +677,9 @@ pub fn generate_call_or_fold<R: Rng>(
         BetSize::Large    => pot,
     };
 
+    // Hero calls villain_bet into a pot that already contains it.
     let required_equity_pct =
-        (villain_bet as f32 / (pot as f32 + villain_bet as f32 * 2.0) * 100.0).round() as u32;
+        (break_even_equity(villain_bet, pot_after_call(pot, villain_bet)) * 100.0).round() as u32;
     let raise_size = (villain_bet as f32 * 2.5).round() as u32;
 
     let correct: &str = match (strength, bet_size) {
@@ -538,6 +702,9 @@ pub fn generate_call_or_fold<R: Rng>(
     let strength_simple = caller_strength_simple(strength);
     let bet_size_simple_label = bet_size_simple(bet_size);
 
+    let made = made_hand_description(hero_hand, &board);
+    let threats = board_threats_note(&board);
+
     let question = match text_style {
         TextStyle::Simple => format!(
             "Last card. You have {hs} ({strength_simple}) on the Button. \
@@ -546,18 +713,33 @@ pub fn generate_call_or_fold<R: Rng>(
         ),
         TextStyle::Technical => format!(
             "River call or fold. You hold {hs} ({strength}) on the Button. \
-             Board: {bs}. Pot: {pot} chips ({pot_bb} BB). Stack: {stack} chips. \
-             Villain bets {villain_bet} chips ({bet_size}) into you. \
+             Board: {bs} (best five: {made}). Pot: {pot} chips ({pot_bb} BB). Stack: {stack} chips. \
+             Villain bets {villain_bet} chips ({bet_size}) into you.{threats} \
              You need ~{required_equity_pct}% equity to break even on a call. \
              What do you do?"
         ),
     };
 
+    let rate = |id: &str| {
+        let fold = match strength {
+            CallerStrength::Strong => AnswerRating::Blunder,
+            _                      => AnswerRating::Mistake,
+        };
+        fold_call_raise_rating(id, correct, fold)
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Compare your hand to the size of their bet — a big bet usually means a strong hand.",
+        TextStyle::Technical => "Consider: the pot odds offered and villain's range for this bet size.",
+    }.to_string());
+
     let answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Fold".to_string(),
             is_correct: correct == "A",
+            rating: rate("A"),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match (strength, bet_size) {
                     (CallerStrength::Weak, BetSize::Large) =>
@@ -585,6 +767,8 @@ pub fn generate_call_or_fold<R: Rng>(
             id: "B".to_string(),
             text: format!("Call ({villain_bet} chips)"),
             is_correct: correct == "B",
+            rating: rate("B"),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match (strength, bet_size) {
                     (CallerStrength::Marginal, BetSize::Standard) =>
@@ -620,6 +804,8 @@ pub fn generate_call_or_fold<R: Rng>(
             id: "C".to_string(),
             text: format!("Raise to {raise_size} chips"),
             is_correct: correct == "C",
+            rating: rate("C"),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match (strength, bet_size) {
                     (CallerStrength::Strong, BetSize::Small) =>
@@ -648,6 +834,990 @@ pub fn generate_call_or_fold<R: Rng>(
     ];
 
     let players = heads_up(hero_pos, Position::BB, stack, stack);
-    scenario(scenario_id, TrainingTopic::RiverCallOrFold, branch_key,
-        GameType::CashGame, hero_pos, hero_hand, board, players, pot, villain_bet, question, answers)
+    Ok(scenario(scenario_id, TrainingTopic::RiverCallOrFold, branch_key,
+        GameType::CashGame, hero_pos, hero_hand, board, players, pot, villain_bet, question, answers))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// T21 — Triple Barrel (TR-)
+//
+// Hero c-bet the flop and barrelled the turn from the BTN; the BB called both
+// and checks the river.  Fire a third barrel (~75% pot) or check?
+//   - Value (top pair top kicker or better)   → barrel
+//   - Showdown value (weaker pairs)            → check
+//   - Air with a missed flop draw              → barrel on a scare river or SPR ≥ 2
+//   - Pure air                                 → barrel only on a scare river with SPR ≥ 2
+// A scare river is an overcard to the flop or the third card of a suit.
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Hero's river holding after barrelling twice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BarrelHand {
+    Value,
+    Showdown,
+    Air,
+}
+
+/// Why an air hand might keep bluffing; `None` for made hands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BarrelBluff {
+    None,
+    MissedDraw,
+    PureAir,
+}
+
+fn classify_barrel_hand(hand: [Card; 2], board: &[Card]) -> BarrelHand {
+    use HandVsBoardStrength::*;
+    match classify_hero_hand_vs_board(hand, board) {
+        TopSet | MiddleSet | BottomSet | Flush | Straight | TwoPair | OverPair | TopPairTopKicker => BarrelHand::Value,
+        TopPairWeakKicker | MiddlePair | BottomPair => BarrelHand::Showdown,
+        NoPair => BarrelHand::Air,
+    }
+}
+
+/// True when the river is an overcard to the flop or brings a third card of
+/// its suit.
+fn is_scare_river(board: &[Card]) -> bool {
+    let river = board[4];
+    let flop_high = board[..3].iter().map(|c| c.rank).max().unwrap_or(Rank(0));
+    river.rank > flop_high || board_suit_counts(board)[suit_index(river.suit)] == 3
+}
+
+pub fn generate_triple_barrel<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let (hero_hand, board) = deal(dealer, 5)?;
+
+    let bb = 2u32;
+    // The pot has grown over two called barrels.
+    let (pot_bb, stack_bb) = match difficulty {
+        DifficultyLevel::Beginner     => (30u32, 60u32),
+        DifficultyLevel::Intermediate => (rng.gen_range(20..=50), rng.gen_range(20..=120)),
+        DifficultyLevel::Advanced     => (rng.gen_range(15..=70), rng.gen_range(10..=150)),
+    };
+    let pot = pot_bb * bb;
+    let stack = stack_bb * bb;
+    let spr = stack as f32 / pot as f32;
+    let bet = ((pot as f32 * 0.75).round() as u32).min(stack);
+
+    let strength = classify_barrel_hand(hero_hand, &board);
+    let missed_draw = hero_has_flush_draw(hero_hand, &board[..3]) || hero_has_straight_draw(hero_hand, &board[..3]);
+    let bluff = match (strength, missed_draw) {
+        (BarrelHand::Air, true)  => BarrelBluff::MissedDraw,
+        (BarrelHand::Air, false) => BarrelBluff::PureAir,
+        _                        => BarrelBluff::None,
+    };
+    let scare = is_scare_river(&board);
+
+    let barrel = match bluff {
+        BarrelBluff::None       => strength == BarrelHand::Value,
+        BarrelBluff::MissedDraw => scare || spr >= 2.0,
+        BarrelBluff::PureAir    => scare && spr >= 2.0,
+    };
+    let correct = if barrel { "B" } else { "A" };
+    let branch_key = format!("TripleBarrel:{strength:?}:{bluff:?}");
+
+    let hero_pos = Position::BTN;
+    let hs = hand_str(hero_hand);
+    let bs = board_str(&board);
+    let made = made_hand_description(hero_hand, &board);
+    let river_kind = if scare { "a scare card" } else { "a blank" };
+    let fold_needed = break_even_equity(bet, pot);
+    let bluff_share = optimal_bluff_to_value_ratio(bet, pot);
+
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "You bet on the flop and the turn, and your opponent called both times. \
+             The last card is out: {bs}. You have {hs}. Pot: {pot} chips. Stack: {stack} \
+             chips. Your opponent checks. Do you bet a third time ({bet} chips) or check?"
+        ),
+        TextStyle::Technical => format!(
+            "Triple barrel. BTN vs BB, you c-bet the flop and barrelled the turn; BB called \
+             both. River: {bs} ({river_kind}). You hold {hs} ({made}). Pot {pot} ({pot_bb} BB), \
+             stack {stack} (SPR {spr:.1}). BB checks. Barrel {bet} (~75% pot) or check?"
+        ),
+    };
+
+    let reason = match (strength, bluff) {
+        (BarrelHand::Value, _) => "Your hand is ahead of most of the range that called twice, so a third \
+                                   bet gets value from worse pairs.".to_string(),
+        (BarrelHand::Showdown, _) => "Your hand wins some showdowns but is rarely called by worse after \
+                                      two barrels; checking takes the free showdown.".to_string(),
+        (_, BarrelBluff::MissedDraw) if barrel => format!(
+            "Your missed draw is a natural bluff: it cannot win at showdown and blocks none of \
+             the pairs that fold. On {river_kind} with SPR {spr:.1} the bet is credible."
+        ),
+        (_, BarrelBluff::MissedDraw) => format!(
+            "Your missed draw cannot win at showdown, but on a blank river with SPR {spr:.1} \
+             there is too little behind to make the third barrel credible."
+        ),
+        _ if barrel => format!(
+            "Pure air can bluff when the river scares the caller and SPR {spr:.1} leaves a \
+             real threat behind."
+        ),
+        _ => "Pure air without a scare card has no story — the calling range that stayed for \
+              two barrels will not fold now.".to_string(),
+    };
+    let balance = format!(
+        "A {bet}-chip bet needs folds {:.0}% of the time and, for balance, can be about \
+         {:.0}% bluffs — so only the best bluff candidates should reach the river.",
+        fold_needed * 100.0,
+        bluff_share * 100.0
+    );
+
+    let technical = |id: &str| -> String {
+        let verdict = match (id == correct, id) {
+            (true, "A")  => "Correct — check.",
+            (true, _)    => "Correct — fire the third barrel.",
+            (false, "A") => "Checking is wrong here.",
+            (false, _)   => "Barrelling is wrong here.",
+        };
+        format!("{verdict} {reason} {balance}{}", blocker_note(hero_hand, &board))
+    };
+    let simple = |id: &str| -> String {
+        match (id, correct, strength) {
+            ("A", "A", BarrelHand::Showdown) => "Correct — check. Your hand might already be best, and betting again only gets called by better hands.".to_string(),
+            ("A", "A", _) => "Correct — check. Your opponent called twice and won't fold now. Save your chips.".to_string(),
+            ("B", "B", BarrelHand::Value) => "Correct — bet! Your hand is strong, so make your opponent pay to see it.".to_string(),
+            ("B", "B", _) => "Correct — bet. You can't win by checking, and this last card makes your story believable.".to_string(),
+            ("A", _, BarrelHand::Value) => "Checking wastes a strong hand — bet to win more chips.".to_string(),
+            ("A", _, _) => "Checking gives up — you can't win at showdown, and this is a good card to keep betting.".to_string(),
+            (_, _, BarrelHand::Showdown) => "Betting here only gets called by better hands. Check and see who wins.".to_string(),
+            _ => "Betting a third time here just loses more chips — your opponent will call.".to_string(),
+        }
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Can your hand win if you check? Did the last card make your bets more believable?",
+        TextStyle::Technical => "Consider: showdown value, missed draws as bluffs, the river card and SPR.",
+    }.to_string());
+
+    let mut answers: Vec<AnswerOption> = [
+        ("A", "Check".to_string()),
+        ("B", format!("Bet {bet} (third barrel)")),
+    ]
+    .into_iter()
+    .map(|(id, text)| answer(id, text, correct, AnswerRating::Mistake, text_style, simple(id), technical(id)))
+    .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
+    }
+
+    let players = heads_up(hero_pos, Position::BB, stack, stack);
+    Ok(scenario(scenario_id, TrainingTopic::TripleBarrel, branch_key,
+        GameType::CashGame, hero_pos, hero_hand, board, players, pot, 0, question, answers))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// T33 — Hand Reading (HR-)
+//
+// Villain's whole line is shown on a finished board and hero names the most
+// likely holding.  Each line in `READING_LINES` fixes the answer:
+//   - three streets of value, or check-raise and barrel  → two pair or better
+//   - two streets, then a river check                    → top pair
+//   - check-call down, or small c-bet / check / small bet → medium pair
+//   - passive on a flush draw, river lead when it misses  → bluff
+//   - the same line when the flush comes in               → two pair or better
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// The holding a hand-reading line points to, weakest first (A–D).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VillainRead {
+    Bluff,
+    MediumPair,
+    TopPair,
+    TwoPairPlus,
+}
+
+impl VillainRead {
+    const ALL: [VillainRead; 4] =
+        [VillainRead::Bluff, VillainRead::MediumPair, VillainRead::TopPair, VillainRead::TwoPairPlus];
+
+    fn id(self) -> &'static str {
+        match self {
+            VillainRead::Bluff       => "A",
+            VillainRead::MediumPair  => "B",
+            VillainRead::TopPair     => "C",
+            VillainRead::TwoPairPlus => "D",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            VillainRead::Bluff       => "A bluff or missed draw",
+            VillainRead::MediumPair  => "A medium pair",
+            VillainRead::TopPair     => "Top pair",
+            VillainRead::TwoPairPlus => "Two pair or better",
+        }
+    }
+}
+
+/// The runout a line needs for its story to hold.  Every runout is unpaired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReadingBoard {
+    /// Rainbow flop and no suit three times: no flush draw is ever in play.
+    Dry,
+    /// Two-tone flop whose flush draw never completes.
+    FlushBricked,
+    /// Two-tone flop whose flush draw completes on the river.
+    FlushCompleted,
+}
+
+struct ReadingLine {
+    /// Lowest difficulty that draws this line.
+    level: DifficultyLevel,
+    sequence: ActionSequence,
+    board: ReadingBoard,
+    read: VillainRead,
+    simple: &'static str,
+    technical: &'static str,
+}
+
+const READING_LINES: [ReadingLine; 7] = [
+    ReadingLine {
+        level: DifficultyLevel::Beginner,
+        sequence: ActionSequence {
+            villain_raised: true,
+            flop: VillainAction::Bet(LineSize::Small),
+            turn: VillainAction::Bet(LineSize::Large),
+            river: VillainAction::Bet(LineSize::Large),
+        },
+        board: ReadingBoard::Dry,
+        read: VillainRead::TwoPairPlus,
+        simple: "Betting all three streets, bigger each time, on a quiet board usually means a very strong hand.",
+        technical: "Three streets of betting, growing in size, on a runout where no draw came in. \
+                    No missed draw is left to bluff with, and one pair rarely bets three times for \
+                    value against a caller — the range is weighted to two pair, sets and better.",
+    },
+    ReadingLine {
+        level: DifficultyLevel::Beginner,
+        sequence: ActionSequence {
+            villain_raised: true,
+            flop: VillainAction::Bet(LineSize::Small),
+            turn: VillainAction::Bet(LineSize::Large),
+            river: VillainAction::Check,
+        },
+        board: ReadingBoard::Dry,
+        read: VillainRead::TopPair,
+        simple: "Betting twice and then slowing down on the river is typical of a good one-pair hand like top pair.",
+        technical: "Two streets of value, then a river check: strong enough to bet twice, not strong \
+                    enough to want a raise or a call from better. Two pair or better bets a third \
+                    time, and a hand that barrelled the turn as a bluff rarely gives up with no draw \
+                    to miss. That is top pair.",
+    },
+    ReadingLine {
+        level: DifficultyLevel::Beginner,
+        sequence: ActionSequence {
+            villain_raised: false,
+            flop: VillainAction::CheckCall,
+            turn: VillainAction::CheckCall,
+            river: VillainAction::CheckCall,
+        },
+        board: ReadingBoard::Dry,
+        read: VillainRead::MediumPair,
+        simple: "Just calling every street without ever raising usually means a medium-strength hand — a pair that wants a showdown.",
+        technical: "Check-calling every street is a bluff-catcher: good enough to call, not good \
+                    enough to raise or lead. On a runout with no draws, air folds early, and two pair \
+                    or better raises somewhere along the way. A medium pair fits best.",
+    },
+    ReadingLine {
+        level: DifficultyLevel::Intermediate,
+        sequence: ActionSequence {
+            villain_raised: false,
+            flop: VillainAction::CheckRaise,
+            turn: VillainAction::Bet(LineSize::Large),
+            river: VillainAction::Bet(LineSize::Large),
+        },
+        board: ReadingBoard::Dry,
+        read: VillainRead::TwoPairPlus,
+        simple: "Check-raising the flop and then betting big on every street shows a very strong hand.",
+        technical: "A flop check-raise followed by two large barrels on a blank runout. Semi-bluff \
+                    check-raises slow down once the draws are gone; betting big on every street \
+                    after raising is a value line from sets and two pair.",
+    },
+    ReadingLine {
+        level: DifficultyLevel::Intermediate,
+        sequence: ActionSequence {
+            villain_raised: true,
+            flop: VillainAction::Bet(LineSize::Small),
+            turn: VillainAction::Check,
+            river: VillainAction::Bet(LineSize::Small),
+        },
+        board: ReadingBoard::Dry,
+        read: VillainRead::MediumPair,
+        simple: "A small river bet after checking the turn usually means a medium hand trying to reach showdown cheaply.",
+        technical: "A small c-bet, a turn check, then a small river bet: a hand that wants a cheap \
+                    showdown and thin value from worse. Strong hands bet the turn, and bluffs size \
+                    up to get folds. The small river bet is a medium pair betting thin or blocking.",
+    },
+    ReadingLine {
+        level: DifficultyLevel::Advanced,
+        sequence: ActionSequence {
+            villain_raised: false,
+            flop: VillainAction::CheckCall,
+            turn: VillainAction::CheckCall,
+            river: VillainAction::Bet(LineSize::Large),
+        },
+        board: ReadingBoard::FlushBricked,
+        read: VillainRead::Bluff,
+        simple: "Calling along while a flush draw is on the board, then betting big when the flush misses, usually means a missed draw trying to bluff.",
+        technical: "Two passive calls on a flush-draw flop, then a large lead when the flush misses. \
+                    Made hands would have raised earlier or kept check-calling; the passive line fits \
+                    a draw, and a missed draw cannot win at showdown — the sudden lead is the bluff.",
+    },
+    ReadingLine {
+        level: DifficultyLevel::Advanced,
+        sequence: ActionSequence {
+            villain_raised: false,
+            flop: VillainAction::CheckCall,
+            turn: VillainAction::CheckCall,
+            river: VillainAction::Bet(LineSize::Large),
+        },
+        board: ReadingBoard::FlushCompleted,
+        read: VillainRead::TwoPairPlus,
+        simple: "Calling along while a flush draw is on the board, then betting big when the flush comes in, usually means the draw got there.",
+        technical: "Two passive calls on a flush-draw flop, then a large lead as the river completes \
+                    the flush. The draw that called two streets just got there: the lead is for value \
+                    with a flush, which beats every pair.",
+    },
+];
+
+/// Whether `board` (dealt so far) can still become `kind` of runout.
+fn reading_board_fits(kind: ReadingBoard, board: &[Card]) -> bool {
+    let (last, earlier) = board.split_last().expect("board has at least one card");
+    if earlier.iter().any(|c| c.rank == last.rank) {
+        return false;
+    }
+    let counts = board_suit_counts(board);
+    if kind == ReadingBoard::Dry {
+        let limit = if board.len() <= 3 { 2 } else { 3 };
+        return counts.iter().all(|&n| n < limit);
+    }
+    let flush = board[0].suit;
+    let drawn = counts[suit_index(flush)] as usize;
+    let others_ok = Suit::all().into_iter().filter(|&s| s != flush).all(|s| counts[suit_index(s)] < 3);
+    let wanted = match (board.len(), kind) {
+        (1, _)                            => 1,
+        (5, ReadingBoard::FlushCompleted) => 3,
+        _                                 => 2,
+    };
+    others_ok && drawn == wanted
+}
+
+/// T33 — Hand Reading (HR-).
+///
+/// A line from [`READING_LINES`] (filtered by difficulty) is played out on a
+/// board dealt one card at a time, each the next card in the deck that keeps
+/// the line's runout (`ReadingBoard`).  Pot and stacks follow the line's bets.
+///
+/// RNG order: line.
+pub fn generate_hand_reading<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let tier = |d: DifficultyLevel| match d {
+        DifficultyLevel::Beginner     => 0,
+        DifficultyLevel::Intermediate => 1,
+        DifficultyLevel::Advanced     => 2,
+    };
+    let pool: Vec<&ReadingLine> =
+        READING_LINES.iter().filter(|l| tier(l.level) <= tier(difficulty)).collect();
+    let line = pool[rng.gen_range(0..pool.len())];
+    let seq = line.sequence;
+
+    let hero_hand = [dealer.deal()?, dealer.deal()?];
+    let mut board: Vec<Card> = Vec::with_capacity(5);
+    while board.len() < 5 {
+        let card = dealer.deal_matching(|&c| {
+            let mut next = board.clone();
+            next.push(c);
+            reading_board_fits(line.board, &next)
+        })?;
+        board.push(card);
+    }
+
+    // Play the line out: 6 BB preflop pot, 100 BB stacks.
+    let bb = 2u32;
+    let mut pot = 6 * bb;
+    let mut hero_stack = 100 * bb - pot / 2;
+    let mut villain_stack = hero_stack;
+    let mut current_bet = 0;
+    for (street, action) in seq.streets() {
+        let half = pot / 2;
+        let (hero_in, villain_in) = match action {
+            VillainAction::Check => (0, 0),
+            VillainAction::Bet(size) => {
+                let bet = (pot as f32 * size.fraction()).round() as u32;
+                if street == Street::River {
+                    current_bet = bet;
+                    (0, bet)
+                } else {
+                    (bet, bet)
+                }
+            }
+            VillainAction::CheckCall  => (half, half),
+            VillainAction::CheckRaise => (3 * half, 3 * half),
+        };
+        pot += hero_in + villain_in;
+        hero_stack -= hero_in;
+        villain_stack -= villain_in;
+    }
+
+    let read = line.read;
+    let correct = read.id();
+    let branch_key = format!("HandReading:{}:{read:?}", seq.code());
+
+    let (hero_pos, villain_pos) = if seq.villain_raised {
+        (Position::BB, Position::CO)
+    } else {
+        (Position::BTN, Position::BB)
+    };
+    let setup = if seq.villain_raised {
+        "Villain raised from the Cutoff and you called in the Big Blind; you checked to villain on every street."
+    } else {
+        "You raised on the Button and villain called in the Big Blind; you bet whenever villain checked."
+    };
+    let facing = if current_bet > 0 { format!(", facing a {current_bet}-chip bet") } else { String::new() };
+    let hs = hand_str(hero_hand);
+    let bs = board_str(&board);
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "Hand reading. {setup} The board ran out {bs} and you hold {hs}. Villain {seq}. \
+             Pot: {pot} chips{facing}. What is villain MOST LIKELY holding?"
+        ),
+        TextStyle::Technical => format!(
+            "Hand reading. {villain_pos} vs {hero_pos}, board {bs}, you hold {hs}. Villain's line: \
+             {seq} ({}). Pot {pot}{facing}. What is villain most likely holding?",
+            seq.code()
+        ),
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Which hands bet (or check) like this on every street?",
+        TextStyle::Technical => "Consider: which holdings take each action, and which of them are still left by the river.",
+    }.to_string());
+
+    let explain = |r: VillainRead, why: &str| -> String {
+        if r == read {
+            format!("Correct. {why}")
+        } else {
+            format!("{} doesn't fit this line. {why}", r.label())
+        }
+    };
+    let mut answers: Vec<AnswerOption> = VillainRead::ALL
+        .into_iter()
+        .map(|r| {
+            let id = r.id();
+            answer(id, r.label(), correct, ladder_rating(id, correct), text_style,
+                explain(r, line.simple), explain(r, line.technical))
+        })
+        .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
+    }
+
+    let players = heads_up(hero_pos, villain_pos, hero_stack, villain_stack);
+    Ok(scenario(scenario_id, TrainingTopic::HandReading, branch_key, GameType::CashGame,
+        hero_pos, hero_hand, board, players, pot, current_bet, question, answers))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// T37 — Lay Down (LD-)
+//
+// Hero value-bets a strong hand that is not the nuts and villain check-raises.
+// A passive river check-raise is weighted to hands that beat hero, so the
+// price decides:
+//   - min-raise                        → call (cheap, and worse value raises too)
+//   - 3× raise, second-nut flush       → call (only the ace-flush and boats beat it)
+//   - 3× raise, top set on a 4-flush   → fold (every flush beats it)
+//   - all-in                           → fold
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Hero's strong-but-not-nut river hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LayDownHand {
+    /// King-high flush on a paired board: the ace-flush and full houses beat it.
+    SecondNutFlush,
+    /// Top set on a four-flush board, with no card of the flush suit.
+    TopSet,
+}
+
+/// Villain's river check-raise, as a multiple of hero's bet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RaiseSize {
+    /// Raise to 2× hero's bet.
+    Min,
+    /// Raise to 3× hero's bet.
+    Triple,
+    /// Villain moves all in (at least 5× hero's bet).
+    AllIn,
+}
+
+/// Modelled share of villain's check-raising range that hero beats: bluffs
+/// plus worse hands raising for value.  Bigger raises hold fewer of both.
+fn lay_down_equity(hand: LayDownHand, size: RaiseSize) -> f32 {
+    match (hand, size) {
+        (LayDownHand::SecondNutFlush, RaiseSize::Min)    => 0.40,
+        (LayDownHand::SecondNutFlush, RaiseSize::Triple) => 0.30,
+        (LayDownHand::SecondNutFlush, RaiseSize::AllIn)  => 0.15,
+        (LayDownHand::TopSet,         RaiseSize::Min)    => 0.30,
+        (LayDownHand::TopSet,         RaiseSize::Triple) => 0.15,
+        (LayDownHand::TopSet,         RaiseSize::AllIn)  => 0.08,
+    }
+}
+
+/// Hero's cards and the first four board cards for `hand`, dealt from
+/// `dealer` with `deal_matching`, plus the suit that matters (hero's flush
+/// suit, or the board's).
+fn deal_lay_down<D: CardDealer>(
+    dealer: &mut D,
+    hand: LayDownHand,
+) -> Result<([Card; 2], Vec<Card>, Suit), GenerationError> {
+    match hand {
+        LayDownHand::SecondNutFlush => {
+            let king = dealer.deal_matching(|c| c.rank.0 == 13)?;
+            let suit = king.suit;
+            let low = dealer.deal_matching(|c| c.suit == suit && c.rank.0 <= 12)?;
+            let mut board: Vec<Card> = Vec::with_capacity(5);
+            while board.len() < 3 {
+                let card = dealer.deal_matching(|c| {
+                    let ranks = || board.iter().map(|b| b.rank).chain([c.rank]);
+                    c.suit == suit
+                        && c.rank.0 <= 12
+                        && c.rank != low.rank
+                        && board.iter().all(|b| b.rank != c.rank)
+                        // Three suited board cards within five ranks allow a straight flush.
+                        && (board.len() < 2 || ranks().max().unwrap().0 - ranks().min().unwrap().0 > 4)
+                })?;
+                board.push(card);
+            }
+            let paired = dealer.deal_matching(|c| c.suit != suit && board.iter().any(|b| b.rank == c.rank))?;
+            board.push(paired);
+            Ok(([king, low], board, suit))
+        }
+        LayDownHand::TopSet => {
+            let first = dealer.deal_matching(|c| c.rank.0 >= 9)?;
+            let top = first.rank;
+            let hero = [first, dealer.deal_matching(|c| c.rank == top)?];
+            let mut board = vec![dealer.deal_matching(|c| c.rank == top)?];
+            // The board's suit is the one the set does not use.
+            let suit = Suit::all()
+                .into_iter()
+                .find(|&s| hero.iter().chain(&board).all(|c| c.suit != s))
+                .expect("three cards leave one suit");
+            while board.len() < 4 {
+                let card = dealer.deal_matching(|c| {
+                    c.suit == suit && c.rank < top && board.iter().all(|b| b.rank != c.rank)
+                })?;
+                board.push(card);
+            }
+            Ok((hero, board, suit))
+        }
+    }
+}
+
+/// T37 — Lay Down (LD-).
+///
+/// Hero raised on the Button, the BB check-called flop and turn, and hero
+/// bets two-thirds pot on the river holding a [`LayDownHand`]; the BB
+/// check-raises.  Hero's cards and four board cards come from
+/// [`deal_lay_down`]; the last board card is the next one in the deck that
+/// shares no rank with the others and keeps hero's hand category.  The board
+/// is then shuffled so the draws arrive in any order.
+///
+/// RNG order: hand → raise size → pot → stack → board order.
+pub fn generate_lay_down<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let hand = [LayDownHand::SecondNutFlush, LayDownHand::TopSet][rng.gen_range(0..2)];
+    let (hero_hand, mut board, suit) = deal_lay_down(dealer, hand)?;
+    let category = match hand {
+        LayDownHand::SecondNutFlush => MadeHand::Flush,
+        LayDownHand::TopSet         => MadeHand::ThreeOfAKind,
+    };
+    let last = dealer.deal_matching(|&c| {
+        let suit_ok = match hand {
+            LayDownHand::SecondNutFlush => c.suit != suit,
+            LayDownHand::TopSet         => c.suit == suit && c.rank < hero_hand[0].rank,
+        };
+        if !suit_ok || hero_hand.iter().chain(&board).any(|h| h.rank == c.rank) {
+            return false;
+        }
+        let river: [Card; 5] = [board[0], board[1], board[2], board[3], c];
+        best_hand_from_seven(hero_hand, river).1.category() == category
+    })?;
+    board.push(last);
+
+    let sizes: &[RaiseSize] = match difficulty {
+        DifficultyLevel::Beginner => &[RaiseSize::Min, RaiseSize::AllIn],
+        _                         => &[RaiseSize::Min, RaiseSize::Triple, RaiseSize::AllIn],
+    };
+    let size = sizes[rng.gen_range(0..sizes.len())];
+    let bb = 2u32;
+    let (pot_bb, stack_bb) = match difficulty {
+        DifficultyLevel::Beginner     => (30u32, 100u32),
+        DifficultyLevel::Intermediate => (rng.gen_range(20..=40), rng.gen_range(80..=150)),
+        DifficultyLevel::Advanced     => (rng.gen_range(16..=60), rng.gen_range(60..=200)),
+    };
+    let pot_before = pot_bb * bb;
+    let bet = pot_before * 2 / 3;
+    // Deep enough that all in is a real overbet raise.
+    let stack = (stack_bb * bb).max(bet * 5);
+    let raise_to = match size {
+        RaiseSize::Min    => bet * 2,
+        RaiseSize::Triple => bet * 3,
+        RaiseSize::AllIn  => stack,
+    };
+    for i in (1..board.len()).rev() {
+        board.swap(i, rng.gen_range(0..=i));
+    }
+
+    let call = raise_to - bet;
+    let pot = pot_before + bet + raise_to;
+    let required = break_even_equity(call, pot);
+    let equity = lay_down_equity(hand, size);
+    let correct = if equity >= required { "B" } else { "A" };
+    let branch_key = format!("LayDown:{hand:?}:{size:?}");
+
+    let hs = hand_str(hero_hand);
+    let bs = board_str(&board);
+    let made = made_hand_description(hero_hand, &board);
+    let suit_name = suit.name();
+    let raise_label = match size {
+        RaiseSize::Min    => format!("raises to {raise_to}"),
+        RaiseSize::Triple => format!("raises to {raise_to}, three times your bet"),
+        RaiseSize::AllIn  => format!("moves all in for {raise_to}"),
+    };
+    let check_raise = match size {
+        RaiseSize::AllIn => format!("check-raises all in for {raise_to}"),
+        _                => format!("check-raises to {raise_to}"),
+    };
+    let hand_simple = match hand {
+        LayDownHand::SecondNutFlush => format!("a king-high flush in {suit_name}"),
+        LayDownHand::TopSet         => "three of a kind with the top card".to_string(),
+    };
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "You raised before the flop on the Button and the big blind called, then called \
+             your bets on the flop and turn. Board: {bs}. You have {hs} — {hand_simple}. You \
+             bet {bet} chips into {pot_before} on the river and your opponent {raise_label}. \
+             Pot: {pot} chips. Fold, or call {call} more?"
+        ),
+        TextStyle::Technical => format!(
+            "Lay-down spot. BTN vs BB, single-raised pot; BB check-called flop and turn. Board \
+             {bs}, you hold {hs} ({made}). You bet {bet} into {pot_before}; BB {check_raise}, \
+             {stack} effective. Fold or call {call}?"
+        ),
+    };
+
+    let beaten_by = match hand {
+        LayDownHand::SecondNutFlush => format!(
+            "the ace-high flush in {suit_name} and every full house on this paired board"
+        ),
+        LayDownHand::TopSet => format!("any one of the remaining {suit_name}, which makes a flush"),
+    };
+    let beaten_simple = match hand {
+        LayDownHand::SecondNutFlush => "the ace-high flush or a full house",
+        LayDownHand::TopSet         => "a flush",
+    };
+    let price = format!(
+        "You need {:.0}% to call {call} into {pot}; {} check-raise holds about {:.0}% hands you beat.",
+        required * 100.0,
+        match size {
+            RaiseSize::Min    => "a min",
+            RaiseSize::Triple => "a 3×",
+            RaiseSize::AllIn  => "an all-in",
+        },
+        equity * 100.0
+    );
+    let verdict = if correct == "A" {
+        format!(
+            "Fold. Your hand is strong, but a passive player's big river check-raise \
+             is weighted to {beaten_by}. {price} This is reverse implied odds: the \
+             hand wins small pots when it is ahead and loses a stack when it is beaten, and \
+             calling here stacks off as a dominated hand."
+        )
+    } else {
+        format!(
+            "Call. Villain still raises {beaten_by}, but {}. {price} Reverse implied odds only \
+             bite when the price is high: warning signs are a passive player raising big, \
+             an obvious nut hand on board, and a size that leaves no room for worse value.",
+            match size {
+                RaiseSize::Min => "a min-raise also comes from worse hands and offers a great price",
+                _ => "on this board worse flushes raise for value too, and the price is fair",
+            }
+        )
+    };
+    let simple = |id: &str| -> String {
+        match (id == correct, correct) {
+            (true, "A") => format!(
+                "Correct — fold. Your hand is strong, but a raise this big almost always means \
+                 {beaten_simple}. Calling pays off a better hand."
+            ),
+            (true, _) => "Correct — call. The raise is small enough that the price is good, and \
+                          your opponent also makes it with hands you beat."
+                .to_string(),
+            (false, "A") => format!(
+                "Calling loses chips. A big raise after calling two streets usually means \
+                 {beaten_simple} — strong hands have to be folded sometimes."
+            ),
+            _ => "Folding is too careful. The price is good enough to call with a hand this strong."
+                .to_string(),
+        }
+    };
+    let technical = |id: &str| -> String {
+        let lead = if id == correct { "Correct." } else { "Incorrect." };
+        format!("{lead} {verdict}")
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Which hands raise this big after just calling the flop and turn?",
+        TextStyle::Technical => "Consider: villain's check-raising range at this size, and the price of the call.",
+    }.to_string());
+
+    let wrong = if (equity - required).abs() > 0.10 { AnswerRating::Blunder } else { AnswerRating::Mistake };
+    let mut answers: Vec<AnswerOption> = [("A", "Fold".to_string()), ("B", format!("Call {call}"))]
+        .into_iter()
+        .map(|(id, text)| answer(id, text, correct, wrong, text_style, simple(id), technical(id)))
+        .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
+    }
+
+    let players = heads_up(Position::BTN, Position::BB, stack, stack);
+    Ok(scenario(scenario_id, TrainingTopic::LayDown, branch_key, GameType::CashGame,
+        Position::BTN, hero_hand, board, players, pot, raise_to, question, answers))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// T38 — When to Give Up (GU-)
+//
+// Hero barrelled flop and turn with air, villain called both, and villain
+// checks the river.  Two calls leave a range of made hands, so a bluff has
+// almost no fold equity:
+//   - no draw completed                      → check (range is pairs and better)
+//   - flush completed, hero lacks the ace    → check (draws got there as well)
+//   - flush completed, hero holds the ace    → bet (Advanced only: villain can
+//                                              never hold the nut flush)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// What villain's flop-and-turn calls leave on the river.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CallerRange {
+    /// Blank runout: pairs and better, nothing missed.
+    Condensed,
+    /// The flush completed on the river and hero does not block it.
+    Strengthened,
+    /// The flush completed and hero holds its ace: villain's best hands are
+    /// capped below the nut flush.
+    Capped,
+}
+
+impl CallerRange {
+    /// Modelled share of villain's river-checking range that folds to a
+    /// three-quarter-pot bet.
+    fn fold_probability(self) -> f32 {
+        match self {
+            CallerRange::Condensed    => 0.15,
+            CallerRange::Strengthened => 0.10,
+            CallerRange::Capped       => 0.55,
+        }
+    }
+}
+
+/// T38 — When to Give Up (GU-).
+///
+/// Every card comes from the dealer via `deal_matching`, and no card pairs
+/// another.  A `Condensed` board never shows a suit three times.  Otherwise
+/// the first two board cards and the river share the flush suit and the
+/// turn cards are off it; hero holds none of that suit — except for
+/// `Capped`, where hero's first card is its ace.  No card before the river
+/// gives hero a straight, and the river leaves hero with no pair, straight
+/// or flush.
+///
+/// RNG order: range → pot → stack.
+pub fn generate_give_up<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    // The blocker exception stays rare (one Advanced spot in ten), and only
+    // Advanced draws it.
+    let range = match difficulty {
+        DifficultyLevel::Advanced if rng.gen_bool(0.1) => CallerRange::Capped,
+        _ if rng.gen_bool(0.5) => CallerRange::Strengthened,
+        _ => CallerRange::Condensed,
+    };
+
+    let first = if range == CallerRange::Capped {
+        dealer.deal_matching(|c| c.rank.0 == 14)?
+    } else {
+        dealer.deal()?
+    };
+    let second = dealer.deal_matching(|c| {
+        c.rank != first.rank && (range != CallerRange::Capped || c.suit != first.suit)
+    })?;
+    let hero_hand = [first, second];
+    let is_air = |board: &[Card]| {
+        let river: [Card; 5] = board.try_into().expect("five board cards");
+        best_hand_from_seven(hero_hand, river).1.category() == MadeHand::HighCard
+    };
+    let shares_rank = |c: &Card, board: &[Card]| hero_hand.iter().chain(board).any(|h| h.rank == c.rank);
+    let no_straight = |c: &Card, board: &[Card]| {
+        let next: Vec<Card> = board.iter().copied().chain([*c]).collect();
+        classify_hero_hand_vs_board(hero_hand, &next) != HandVsBoardStrength::Straight
+    };
+
+    let mut board: Vec<Card> = Vec::with_capacity(5);
+    if range == CallerRange::Condensed {
+        while board.len() < 5 {
+            let card = dealer.deal_matching(|c| {
+                !shares_rank(c, &board)
+                    && board.iter().filter(|b| b.suit == c.suit).count() < 2
+                    && no_straight(c, &board)
+                    && (board.len() < 4 || is_air(&[&board[..], &[*c]].concat()))
+            })?;
+            board.push(card);
+        }
+    } else {
+        // The flush suit: hero's ace for Capped, otherwise one hero lacks.
+        let flush_card = |c: &Card, board: &[Card]| {
+            let suit_ok = match board.first() {
+                Some(b) => c.suit == b.suit,
+                None if range == CallerRange::Capped => c.suit == hero_hand[0].suit,
+                None => hero_hand.iter().all(|h| h.suit != c.suit),
+            };
+            suit_ok && c.rank.0 <= 13 && !shares_rank(c, board)
+        };
+        while board.len() < 2 {
+            let card = dealer.deal_matching(|c| flush_card(c, &board))?;
+            board.push(card);
+        }
+        while board.len() < 4 {
+            let card = dealer.deal_matching(|c| {
+                c.suit != board[0].suit && !shares_rank(c, &board) && no_straight(c, &board)
+            })?;
+            board.push(card);
+        }
+        let river = dealer.deal_matching(|c| flush_card(c, &board) && is_air(&[&board[..], &[*c]].concat()))?;
+        board.push(river);
+    }
+    // Names the flush suit; the Condensed text never mentions it.
+    let suit = board[0].suit;
+    let ace = Card { rank: Rank(14), suit };
+
+    let bb = 2u32;
+    let (pot_bb, stack_bb) = match difficulty {
+        DifficultyLevel::Beginner     => (24u32, 100u32),
+        DifficultyLevel::Intermediate => (rng.gen_range(16..=40), rng.gen_range(60..=150)),
+        DifficultyLevel::Advanced     => (rng.gen_range(12..=60), rng.gen_range(50..=200)),
+    };
+    let pot = pot_bb * bb;
+    let stack = stack_bb * bb;
+    let bet = (pot * 3 / 4).min(stack);
+
+    let fold = range.fold_probability();
+    let needed = break_even_equity(bet, pot);
+    let ev = ev_of_bet(fold, pot, bet, 0.0);
+    let correct = if ev > 0.0 { "B" } else { "A" };
+    let branch_key = format!("GiveUp:{range:?}");
+
+    let hs = hand_str(hero_hand);
+    let bs = board_str(&board);
+    let made = made_hand_description(hero_hand, &board);
+    let suit_name = suit.name();
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "You raised before the flop on the Button and bet the flop and the turn; the big \
+             blind called both times. Board: {bs}. You have {hs} — no pair and no draw. Your \
+             opponent checks the river. Pot: {pot} chips. Do you bluff {bet} chips or check \
+             and give up?"
+        ),
+        TextStyle::Technical => format!(
+            "Give-up spot. BTN vs BB, single-raised pot; you c-bet the flop and barrelled the \
+             turn, BB called both. Board {bs}, you hold {hs} ({made}, no showdown value). BB \
+             checks the river. Pot {pot}, {stack} effective. Check, or bluff {bet} (75% pot)?"
+        ),
+    };
+
+    let story = match range {
+        CallerRange::Condensed => "Villain called flop and turn — their range is now made hands, \
+            and any bet has negligible fold equity. No flush arrived to change that: the pairs \
+            and better that called twice will call again."
+            .to_string(),
+        CallerRange::Strengthened => format!(
+            "Villain called flop and turn — their range is now made hands, and any bet has \
+             negligible fold equity. The river also completes the flush in {suit_name}, so the \
+             draws that called are now flushes."
+        ),
+        CallerRange::Capped => format!(
+            "Villain called flop and turn, so their range is made hands — normally a reason to \
+             give up. The exception: the river completes the flush in {suit_name} and you hold the \
+             {ace}, so villain can never have the nut flush. A big bet represents it, and one \
+             pair cannot call."
+        ),
+    };
+    let math = format!(
+        "A {bet}-chip bluff into {pot} needs {:.0}% folds; villain folds about {:.0}% here, so \
+         the bluff is worth {ev:+.1} chips and checking is worth 0.{}",
+        needed * 100.0,
+        fold * 100.0,
+        if range == CallerRange::Strengthened { nut_flush_note(hero_hand, &board) } else { String::new() }
+    );
+    let verdict = if correct == "A" { "Check and give up." } else { "Bluff." };
+    let simple = |id: &str| -> String {
+        match (id == correct, correct) {
+            (true, "A") => "Correct — give up. Your opponent called two bets, so they have a real \
+                            hand and will not fold now. Betting just loses more chips."
+                .to_string(),
+            (false, "A") => "Bluffing here rarely works. After calling two bets your opponent has \
+                             a hand they will call with again."
+                .to_string(),
+            (true, _) => format!(
+                "Correct — bet. The flush came in and you hold the ace of {suit_name}, so your \
+                 opponent can never have the best flush. Their pairs will usually fold to a big bet."
+            ),
+            _ => format!(
+                "Giving up is too cautious here. You hold the ace of {suit_name}, so you can \
+                 represent the best flush and make pairs fold."
+            ),
+        }
+    };
+    let technical = |id: &str| -> String {
+        let lead = if id == correct { "Correct." } else { "Incorrect." };
+        format!("{lead} {story} {math} {verdict}")
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "After two calls, what kind of hand does your opponent have?",
+        TextStyle::Technical => "Consider: villain's range after calling two streets, and whether your cards change it.",
+    }.to_string());
+
+    // Bluffing into a range that never folds throws chips away.
+    let wrong = if correct == "A" { AnswerRating::Blunder } else { AnswerRating::Mistake };
+    let mut answers: Vec<AnswerOption> =
+        [("A", "Check — give up".to_string()), ("B", format!("Bet {bet} (bluff)"))]
+            .into_iter()
+            .map(|(id, text)| answer(id, text, correct, wrong, text_style, simple(id), technical(id)))
+            .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
+    }
+
+    let players = heads_up(Position::BTN, Position::BB, stack, stack);
+    Ok(scenario(scenario_id, TrainingTopic::WhenToGiveUp, branch_key, GameType::CashGame,
+        Position::BTN, hero_hand, board, players, pot, 0, question, answers))
 }
@@ -1,6 +1,7 @@
-//! Turn topic generators: barrel, probe bet, and delayed c-bet.
+//! Turn topic generators: barrel, probe bet, delayed c-bet, turn draw, and
+//! fold equity bluff.
 //!
-//! All three topics deal a 4-card board (flop + turn) and ask hero what to do
+//! All five topics deal a 4-card board (flop + turn) and ask hero what to do
 //! on the turn.  The key analysis in each:
 //!
 //! - **T6 Turn Barrel** — Classifies the turn card (Blank / ScareBroadway /
@@ -10,12 +11,24 @@
 //! - **T16 Delayed C-Bet** — Hero is IP (BTN) after checking back the flop.
 //!   Combines hand strength with turn-card type (Blank / Scare) to decide
 //!   whether to fire a delayed c-bet and at what size.
+//! - **T31 Turn Draw** — Hero's draw missed the turn and villain bets again.
+//!   One-street equity (rule of 2) against the price decides fold / call;
+//!   combo draws raise.
+//! - **T36 Fold Equity Bluff** — Same spot with a single draw, but the
+//!   question states villain's fold frequency: the semi-bluff raise is right
+//!   when `ev_of_raise` is positive.
 
 use rand::Rng;
 use crate::training_engine::{
-    deck::Deck,
-    evaluator::{board_texture, BoardTexture},
-    helpers::{hand_str, board_str, heads_up, scenario},
+    deck::CardDealer,
+    evaluator::{
+        board_texture, break_even_equity, classify_draw_combined, equity_of_combined_draws,
+        ev_of_raise, BoardTexture, DrawType,
+    },
+    helpers::{
+        answer, board_str, deal_draw, fold_call_raise_rating, hand_str, heads_up, key_concepts,
+        ladder_rating, scenario,
+    },
     models::*,
 };
 
@@ -55,22 +68,22 @@ fn classify_barrel_turn(flop: &[Card], turn: &Card) -> BarrelTurnCard {
             return BarrelTurnCard::DrawComplete;
         }
     }
-    if turn.rank.0 >= 10 {
+    if turn.is_broadway() {
         return BarrelTurnCard::ScareBroadway;
     }
     BarrelTurnCard::Blank
 }
 
-pub fn generate_barrel<R: Rng>(
+pub fn generate_barrel<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let mut deck = Deck::new_shuffled(rng);
-    let hero_hand: [Card; 2] = [deck.deal(), deck.deal()];
-    let flop: Vec<Card> = deck.deal_n(3);
-    let turn = deck.deal();
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
+    let flop: Vec<Card> = dealer.deal_community_cards(Street::Flop)?;
+    let turn = dealer.deal_additional_cards(&flop, Street::Turn)?[0];
 
     let texture = board_texture(&flop);
     let turn_type = classify_barrel_turn(&flop, &turn);
@@ -87,8 +100,8 @@ pub fn generate_barrel<R: Rng>(
     let hero_pos = if rng.gen_bool(0.5) { Position::BTN } else { Position::CO };
 
     let players = vec![
-        PlayerState { seat: 1, position: Position::BB, stack, is_hero: false, is_active: true },
-        PlayerState { seat: 2, position: hero_pos, stack, is_hero: true, is_active: true },
+        PlayerState { seat: 1, position: Position::BB, stack, is_hero: false, is_active: true, bounty: None },
+        PlayerState { seat: 2, position: hero_pos, stack, is_hero: true, is_active: true, bounty: None },
     ];
 
     let branch_key = match turn_type {
@@ -249,10 +262,15 @@ pub fn generate_barrel<R: Rng>(
         },
     };
 
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "What did the new card change? Think about whose hands it helps.",
+        TextStyle::Technical => "Consider: whether the turn completes draws or improves your perceived range.",
+    }.to_string());
+
     let answers = vec![
-        AnswerOption { id: "A".to_string(), text: "Check".to_string(), is_correct: correct == "A", explanation: check_exp },
-        AnswerOption { id: "B".to_string(), text: "Bet medium".to_string(), is_correct: correct == "B", explanation: bet50_exp },
-        AnswerOption { id: "C".to_string(), text: "Bet large".to_string(), is_correct: correct == "C", explanation: bet80_exp },
+        AnswerOption { id: "A".to_string(), text: "Check".to_string(), is_correct: correct == "A", rating: ladder_rating("A", correct), hint: hint.clone(), explanation: check_exp },
+        AnswerOption { id: "B".to_string(), text: "Bet medium".to_string(), is_correct: correct == "B", rating: ladder_rating("B", correct), hint: hint.clone(), explanation: bet50_exp },
+        AnswerOption { id: "C".to_string(), text: "Bet large".to_string(), is_correct: correct == "C", rating: ladder_rating("C", correct), hint: hint.clone(), explanation: bet80_exp },
     ];
 
     let mut board = flop;
@@ -266,9 +284,19 @@ pub fn generate_barrel<R: Rng>(
         players,
         pot_size: pot,
         current_bet: 0,
+        antes: None,
     };
 
-    TrainingScenario { scenario_id, topic: TrainingTopic::TurnBarrelDecision, branch_key, table_setup, question, answers }
+    Ok(TrainingScenario {
+        scenario_id,
+        topic: TrainingTopic::TurnBarrelDecision,
+        branch_key,
+        table_setup,
+        question,
+        answers,
+        key_concepts: key_concepts(TrainingTopic::TurnBarrelDecision, &[]),
+        villain_tendency: None,
+    })
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -308,15 +336,15 @@ fn probe_strength_simple(ps: ProbeStrength) -> &'static str {
     }
 }
 
-pub fn generate_probe<R: Rng>(
+pub fn generate_probe<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let mut deck = Deck::new_shuffled(rng);
-    let hero_hand: [Card; 2] = [deck.deal(), deck.deal()];
-    let board: Vec<Card> = deck.deal_n(4);
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
+    let board: Vec<Card> = dealer.deal_community_cards(Street::Turn)?;
 
     let strength = match rng.gen_range(0..3) {
         0 => ProbeStrength::Strong,
@@ -370,11 +398,18 @@ pub fn generate_probe<R: Rng>(
         ),
     };
 
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Your opponent skipped betting last street. How strong is your hand now?",
+        TextStyle::Technical => "Consider: villain's capped range after the missed c-bet and your hand strength.",
+    }.to_string());
+
     let answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Check".to_string(),
             is_correct: correct == "A",
+            rating: ladder_rating("A", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
                     ProbeStrength::Weak => "Correct — check. Your hand is weak and your opponent didn't bet on the flop — no reason to bet now.".to_string(),
@@ -400,6 +435,8 @@ pub fn generate_probe<R: Rng>(
             id: "B".to_string(),
             text: format!("Probe small ({small_probe} chips ~40%)"),
             is_correct: correct == "B",
+            rating: ladder_rating("B", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
                     ProbeStrength::Medium => "Correct — bet small. Your hand is decent but not great. A small bet tests the water and may win the pot without risking too much.".to_string(),
@@ -432,6 +469,8 @@ pub fn generate_probe<R: Rng>(
             id: "C".to_string(),
             text: format!("Probe large ({large_probe} chips ~70%)"),
             is_correct: correct == "C",
+            rating: ladder_rating("C", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
                     ProbeStrength::Strong => "Correct — bet big! You have a strong hand and the Button didn't bet after the flop (a sign of weakness). Take the pot now with a big bet.".to_string(),
@@ -461,12 +500,12 @@ pub fn generate_probe<R: Rng>(
     ];
 
     let players = vec![
-        PlayerState { seat: 1, position: Position::BTN, stack, is_hero: false, is_active: true },
-        PlayerState { seat: 2, position: hero_pos,      stack, is_hero: true,  is_active: true },
+        PlayerState { seat: 1, position: Position::BTN, stack, is_hero: false, is_active: true, bounty: None },
+        PlayerState { seat: 2, position: hero_pos,      stack, is_hero: true,  is_active: true, bounty: None },
     ];
 
-    scenario(scenario_id, TrainingTopic::TurnProbeBet, branch_key,
-        GameType::CashGame, hero_pos, hero_hand, board, players, pot, 0, question, answers)
+    Ok(scenario(scenario_id, TrainingTopic::TurnProbeBet, branch_key,
+        GameType::CashGame, hero_pos, hero_hand, board, players, pot, 0, question, answers))
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -571,15 +610,15 @@ pub(crate) fn classify_turn_card(flop: &[Card], turn: &Card) -> TurnCard {
     TurnCard::Blank
 }
 
-pub fn generate_delayed_cbet<R: Rng>(
+pub fn generate_delayed_cbet<R: Rng, D: CardDealer>(
     rng: &mut R,
+    dealer: &mut D,
     difficulty: DifficultyLevel,
     scenario_id: String,
     text_style: TextStyle,
-) -> TrainingScenario {
-    let mut deck = Deck::new_shuffled(rng);
-    let hero_hand: [Card; 2] = [deck.deal(), deck.deal()];
-    let board: Vec<Card> = deck.deal_n(4);
+) -> Result<TrainingScenario, GenerationError> {
+    let hero_hand: [Card; 2] = [dealer.deal()?, dealer.deal()?];
+    let board: Vec<Card> = dealer.deal_community_cards(Street::Turn)?;
 
     let flop = &board[..3];
     let turn = &board[3];
@@ -642,11 +681,18 @@ pub fn generate_delayed_cbet<R: Rng>(
         ),
     };
 
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "You checked the flop. Does the new card help you, and how strong is your hand?",
+        TextStyle::Technical => "Consider: your hand strength and whether the turn card favours your range.",
+    }.to_string());
+
     let answers = vec![
         AnswerOption {
             id: "A".to_string(),
             text: "Check".to_string(),
             is_correct: correct == "A",
+            rating: ladder_rating("A", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match (strength, turn_type) {
                     (TurnStrength::Weak, _) =>
@@ -690,6 +736,8 @@ pub fn generate_delayed_cbet<R: Rng>(
             id: "B".to_string(),
             text: format!("Small delayed c-bet ({small_cbet} chips ~33%)"),
             is_correct: correct == "B",
+            rating: ladder_rating("B", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match (strength, turn_type) {
                     (TurnStrength::Medium, TurnCard::Blank) =>
@@ -729,6 +777,8 @@ pub fn generate_delayed_cbet<R: Rng>(
             id: "C".to_string(),
             text: format!("Medium delayed c-bet ({medium_cbet} chips ~60%)"),
             is_correct: correct == "C",
+            rating: ladder_rating("C", correct),
+            hint: hint.clone(),
             explanation: match text_style {
                 TextStyle::Simple => match strength {
                     TurnStrength::Strong =>
@@ -764,6 +814,336 @@ pub fn generate_delayed_cbet<R: Rng>(
     ];
 
     let players = heads_up(hero_pos, Position::BB, stack, stack);
-    scenario(scenario_id, TrainingTopic::DelayedCbet, branch_key,
-        GameType::CashGame, hero_pos, hero_hand, board, players, pot, 0, question, answers)
+    Ok(scenario(scenario_id, TrainingTopic::DelayedCbet, branch_key,
+        GameType::CashGame, hero_pos, hero_hand, board, players, pot, 0, question, answers))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// T31 — Turn Draw (DR-)
+//
+// Hero called a flop bet with a draw, the turn missed, and villain bets again.
+// With one card to come the draw is worth about half its flop equity (rule
+// of 2, not 4):
+//   - ComboDraw                          → raise (semi-bluff: ~33% plus fold equity)
+//   - one-card equity ≥ price of the bet → call
+//   - otherwise                          → fold
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Fill `core` out to a turn board with the next cards in the deck that
+/// neither pair hero or the board nor change hero's `expected` draws.
+fn complete_draw_board<D: CardDealer>(
+    dealer: &mut D,
+    hero_hand: [Card; 2],
+    core: &[Card; 2],
+    expected: &[DrawType],
+) -> Result<Vec<Card>, GenerationError> {
+    let mut board = core.to_vec();
+    while board.len() < 4 {
+        let card = dealer.deal_matching(|&c| {
+            let mut next = board.clone();
+            next.push(c);
+            !hero_hand.iter().chain(&board).any(|h| h.rank == c.rank)
+                && classify_draw_combined(hero_hand, &next) == expected
+        })?;
+        board.push(card);
+    }
+    Ok(board)
+}
+
+/// T31 — Turn Draw (DR-).
+///
+/// Hero called a CO c-bet on the Button with a draw; the turn misses and the
+/// CO bets again.  Hero's cards and the two board cards that make the draw
+/// come from [`deal_draw`]; [`complete_draw_board`] deals the other two.
+///
+/// RNG order: draw type → draw ranks → bet size → pot → stack.
+pub fn generate_turn_draw<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let draw = DrawType::all()[rng.gen_range(0..4)];
+    let (hero_hand, core) = deal_draw(rng, dealer, draw)?;
+    let expected = classify_draw_combined(hero_hand, &core);
+    let board = complete_draw_board(dealer, hero_hand, &core, &expected)?;
+
+    let bet_pcts: &[u32] = match difficulty {
+        DifficultyLevel::Beginner     => &[25, 75],
+        DifficultyLevel::Intermediate => &[25, 50, 75],
+        DifficultyLevel::Advanced     => &[25, 50, 75, 100],
+    };
+    let bet_pct = bet_pcts[rng.gen_range(0..bet_pcts.len())];
+    let bb = 2u32;
+    let (pot_bb, stack_bb) = match difficulty {
+        DifficultyLevel::Beginner     => (12u32, 100u32),
+        DifficultyLevel::Intermediate => (rng.gen_range(8..=24), rng.gen_range(60..=150)),
+        DifficultyLevel::Advanced     => (rng.gen_range(6..=40), rng.gen_range(40..=200)),
+    };
+    let pot_before = pot_bb * bb;
+    let bet = pot_before * bet_pct / 100;
+    let pot = pot_before + bet;
+    let stack = stack_bb * bb;
+    let raise_to = (bet * 3).min(stack);
+
+    let required = break_even_equity(bet, pot);
+    let turn_eq = equity_of_combined_draws(&expected, 1);
+    let flop_eq = equity_of_combined_draws(&expected, 2);
+    let outs = draw.outs();
+    let correct = if draw == DrawType::ComboDraw {
+        "C"
+    } else if turn_eq >= required {
+        "B"
+    } else {
+        "A"
+    };
+    let branch_key = format!("TurnDraw:{draw:?}:{bet_pct}");
+
+    let hs = hand_str(hero_hand);
+    let bs = board_str(&board);
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "You called a raise on the Button and called a bet on the flop with a draw. The \
+             turn missed. Board: {bs}. You have {hs} — a {draw}. Your opponent bets {bet} \
+             chips into {pot_before}. Pot: {pot} chips. Stack: {stack} chips. What do you do?"
+        ),
+        TextStyle::Technical => format!(
+            "Turn draw. BTN vs CO, single-raised pot; you called the flop c-bet. Board {bs}, \
+             you hold {hs} ({draw}, {outs} outs). CO bets {bet} into {pot_before} \
+             ({bet_pct}% pot), {stack} effective. Fold, call {bet}, or raise to {raise_to}?"
+        ),
+    };
+
+    // What the same price would have meant with two cards to come.
+    let flop_note = match (flop_eq >= required, turn_eq >= required) {
+        (true, false) => "would make this bet a call on the flop — but villain's turn bet only \
+                          buys one card, so here it is a fold",
+        (true, true) => "leaves a wide margin on the flop; with one card the margin is much thinner",
+        _ => "is not enough even with two cards to come",
+    };
+    let math = format!(
+        "One card to come: {outs} outs × 2 ≈ {:.0}% (exactly {outs}/46 = {:.1}%), against \
+         {bet}/{} = {:.1}% needed. With two cards to come the rule of 4 gives ~{:.0}% \
+         (exactly {:.1}%), which {flop_note}.",
+        outs as f32 * 2.0,
+        turn_eq * 100.0,
+        pot + bet,
+        required * 100.0,
+        outs as f32 * 4.0,
+        flop_eq * 100.0
+    );
+    let verdict = match correct {
+        "C" => "A combo draw still has about a third of the pot in equity and wins outright \
+                when villain folds — raise as a semi-bluff.",
+        "B" => "The one-card equity covers the price, so call.",
+        _ => "The one-card equity does not cover the price, so fold.",
+    };
+
+    let simple = |id: &str| -> String {
+        match (id, correct) {
+            ("A", "A") => format!(
+                "Correct — fold. With only one card to come, your {draw} hits about {:.0}% of \
+                 the time. That is not enough for this bet.",
+                turn_eq * 100.0
+            ),
+            ("B", "B") => format!(
+                "Correct — call. The bet is small, and your {draw} hits about {:.0}% of the time \
+                 on the river — enough for this price.",
+                turn_eq * 100.0
+            ),
+            ("C", "C") => "Correct — raise. You have lots of ways to win: your opponent may fold \
+                           now, and you still hit often when called."
+                .to_string(),
+            ("A", _) => "Folding gives up too much — your draw is worth more than this bet costs.".to_string(),
+            (_, "A") => "Don't count the flop odds — with one card left, your draw hits about half as \
+                         often. Fold."
+                .to_string(),
+            ("B", _) => "Calling is fine, but with this many outs raising wins more — your opponent \
+                         may fold."
+                .to_string(),
+            _ => "Raising risks a lot with just a draw. Calling the small bet is enough.".to_string(),
+        }
+    };
+    let technical = |id: &str| -> String {
+        let why = match (id, correct) {
+            _ if id == correct => format!("Correct. {verdict}"),
+            ("A", _) => format!("Folding is wrong. {verdict}"),
+            (_, "A") => format!("Continuing is wrong. {verdict}"),
+            ("B", _) => format!("Calling is close, but raising is better. {verdict}"),
+            _ => format!("Raising is close, but calling is enough. {verdict}"),
+        };
+        format!("{why} {math}")
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "With only the river to come, how often does your draw hit?",
+        TextStyle::Technical => "Consider: one-street equity (rule of 2) versus the price of the bet.",
+    }.to_string());
+
+    let fold = if correct == "A" { AnswerRating::Correct } else { AnswerRating::Mistake };
+    let mut answers: Vec<AnswerOption> = [
+        ("A", "Fold".to_string()),
+        ("B", format!("Call {bet}")),
+        ("C", format!("Raise to {raise_to}")),
+    ]
+    .into_iter()
+    .map(|(id, text)| {
+        answer(id, text, correct, fold_call_raise_rating(id, correct, fold), text_style, simple(id), technical(id))
+    })
+    .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
+    }
+
+    let players = heads_up(Position::BTN, Position::CO, stack, stack);
+    Ok(scenario(scenario_id, TrainingTopic::TurnDraw, branch_key, GameType::CashGame,
+        Position::BTN, hero_hand, board, players, pot, bet, question, answers))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// T36 — Fold Equity Bluff (FE-)
+//
+// Hero holds a turn draw and villain bets.  The question states how often
+// villain folds to a raise; the semi-bluff raise is profitable exactly when
+//   fold × pot + (1 − fold) × (equity × pot_if_called − raise) > 0
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// T36 — Fold Equity Bluff (FE-).
+///
+/// Hero called a CO c-bet on the Button with an open-ender or a flush draw;
+/// the turn misses and the CO bets again.  The question gives villain's fold
+/// frequency against a raise to 3× and asks whether the raise makes money,
+/// per [`ev_of_raise`] with hero's one-card equity.  The board is dealt as
+/// in T31.
+///
+/// RNG order: draw type → draw ranks → fold frequency → bet size → pot → stack.
+pub fn generate_fold_equity_bluff<R: Rng, D: CardDealer>(
+    rng: &mut R,
+    dealer: &mut D,
+    difficulty: DifficultyLevel,
+    scenario_id: String,
+    text_style: TextStyle,
+) -> Result<TrainingScenario, GenerationError> {
+    let draw = [DrawType::OESD, DrawType::FlushDraw][rng.gen_range(0..2)];
+    let (hero_hand, core) = deal_draw(rng, dealer, draw)?;
+    let expected = classify_draw_combined(hero_hand, &core);
+    let board = complete_draw_board(dealer, hero_hand, &core, &expected)?;
+
+    // Break-even sits between ~23% and ~47% folds for these sizes, so
+    // Beginner frequencies are far from it and Advanced ones close.
+    let fold_pcts: &[u32] = match difficulty {
+        DifficultyLevel::Beginner     => &[10, 20, 60, 70],
+        DifficultyLevel::Intermediate => &[20, 25, 50, 55, 60],
+        DifficultyLevel::Advanced     => &[25, 30, 35, 40, 45, 50],
+    };
+    let fold_pct = fold_pcts[rng.gen_range(0..fold_pcts.len())];
+    let bet_pcts: &[u32] = match difficulty {
+        DifficultyLevel::Beginner     => &[50],
+        DifficultyLevel::Intermediate => &[50, 75],
+        DifficultyLevel::Advanced     => &[33, 50, 75, 100],
+    };
+    let bet_pct = bet_pcts[rng.gen_range(0..bet_pcts.len())];
+    let bb = 2u32;
+    let (pot_bb, stack_bb) = match difficulty {
+        DifficultyLevel::Beginner     => (12u32, 100u32),
+        DifficultyLevel::Intermediate => (rng.gen_range(8..=24), rng.gen_range(80..=150)),
+        DifficultyLevel::Advanced     => (rng.gen_range(6..=40), rng.gen_range(80..=200)),
+    };
+    let pot_before = pot_bb * bb;
+    let bet = pot_before * bet_pct / 100;
+    let pot = pot_before + bet;
+    let stack = stack_bb * bb;
+    let raise_to = (bet * 3).min(stack);
+    let pot_if_called = pot + 2 * raise_to - bet;
+
+    let fold = fold_pct as f32 / 100.0;
+    let equity = equity_of_combined_draws(&expected, 1);
+    let eq_pct = (equity * 100.0).round() as u32;
+    let ev = ev_of_raise(fold, pot, bet, raise_to, equity);
+    let called = equity * pot_if_called as f32 - raise_to as f32;
+    let break_even = -called / (pot as f32 - called);
+    let profitable = ev > 0.0;
+    let correct = if profitable { "A" } else { "B" };
+    let branch_key = format!(
+        "FoldEquityBluff:{fold_pct}:{eq_pct}:{}",
+        if profitable { "Profitable" } else { "Unprofitable" }
+    );
+
+    let hs = hand_str(hero_hand);
+    let bs = board_str(&board);
+    let outs = draw.outs();
+    let question = match text_style {
+        TextStyle::Simple => format!(
+            "You called a bet on the flop with a draw and the turn missed. Board: {bs}. You \
+             have {hs} ({draw}), which hits about {eq_pct}% of the time on the river. Your \
+             opponent bets {bet} chips; the pot is now {pot}. If you raise to {raise_to}, they \
+             fold {fold_pct}% of the time; if they call, the pot becomes {pot_if_called}. \
+             Raise value = fold chance × pot + call chance × (your share of the called pot − \
+             {raise_to}). Does the raise make money?"
+        ),
+        TextStyle::Technical => format!(
+            "Fold equity. BTN vs CO, board {bs}, you hold {hs} ({draw}, {outs} outs, {eq_pct}% \
+             with one card to come). CO bets {bet} into {pot_before}, {stack} effective. CO \
+             folds {fold_pct}% to a raise to {raise_to}. EV(raise) = fold × pot + (1 − fold) × \
+             (equity × pot_if_called − cost) = {fold:.2} × {pot} + {:.2} × ({equity:.3} × \
+             {pot_if_called} − {raise_to}). Is the semi-bluff raise profitable?",
+            1.0 - fold
+        ),
+    };
+
+    let math = format!(
+        "EV = {fold:.2} × {pot} + {:.2} × ({equity:.3} × {pot_if_called} − {raise_to}) = {:.1} \
+         + {:.2} × ({called:.1}) = {ev:+.1} chips. Break-even fold frequency: {:.0}%.",
+        1.0 - fold,
+        fold * pot as f32,
+        1.0 - fold,
+        break_even * 100.0
+    );
+    let verdict = if profitable {
+        format!("Villain folds {fold_pct}%, more than the {:.0}% the raise needs — raise.", break_even * 100.0)
+    } else {
+        format!("Villain folds only {fold_pct}%, less than the {:.0}% the raise needs — do not raise.", break_even * 100.0)
+    };
+    let simple = |id: &str| -> String {
+        let lead = if id == correct { "Correct." } else if id == "A" { "Not here." } else { "Too cautious." };
+        if profitable {
+            format!(
+                "{lead} Your opponent folds often enough: the {pot} chips you win when they \
+                 fold more than pay for the times they call. The raise earns about {ev:.0} chips."
+            )
+        } else {
+            format!(
+                "{lead} Your opponent does not fold often enough: when they call, your draw \
+                 usually misses and the raise loses about {:.0} chips on average.",
+                -ev
+            )
+        }
+    };
+    let technical = |id: &str| -> String {
+        let lead = if id == correct { "Correct." } else { "Incorrect." };
+        format!("{lead} {verdict} {math}")
+    };
+
+    let hint = Some(match text_style {
+        TextStyle::Simple    => "Compare what you win when they fold with what you lose when they call.",
+        TextStyle::Technical => "Consider: solve fold × pot = (1 − fold) × loss when called.",
+    }.to_string());
+
+    // Missing a clearly profitable or clearly losing raise is a blunder.
+    let wrong = if ev.abs() >= 0.1 * pot as f32 { AnswerRating::Blunder } else { AnswerRating::Mistake };
+    let mut answers: Vec<AnswerOption> = [
+        ("A", format!("Yes — raise to {raise_to}")),
+        ("B", "No — the raise loses chips".to_string()),
+    ]
+    .into_iter()
+    .map(|(id, text)| answer(id, text, correct, wrong, text_style, simple(id), technical(id)))
+    .collect();
+    for a in &mut answers {
+        a.hint = hint.clone();
+    }
+
+    let players = heads_up(Position::BTN, Position::CO, stack, stack);
+    Ok(scenario(scenario_id, TrainingTopic::FoldEquityBluff, branch_key, GameType::CashGame,
+        Position::BTN, hero_hand, board, players, pot, bet, question, answers))
 }
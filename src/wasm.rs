@@ -0,0 +1,60 @@
+//! WebAssembly entry points (the `wasm` feature).
+//!
+//! Browsers talk to the engine in JSON: a [`TrainingRequest`] goes in as a
+//! JSON string and the [`TrainingScenario`] comes back as one, using the same
+//! serde representation as the rest of the crate.  On native targets these
+//! are ordinary functions, so they can be tested without a browser.
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::training_engine::{generate_training, TrainingRequest};
+
+/// Generate a scenario from a JSON-encoded [`TrainingRequest`].
+///
+/// Returns the scenario as JSON, or `{"error": "..."}` if the request does
+/// not parse or generation fails.
+#[wasm_bindgen]
+pub fn generate_training_json(request_json: &str) -> String {
+    let result = serde_json::from_str::<TrainingRequest>(request_json)
+        .map_err(|e| e.to_string())
+        .and_then(|request| generate_training(request).map_err(|e| e.to_string()));
+    match result {
+        Ok(scenario) => serde_json::to_string(&scenario).expect("scenarios are always serializable"),
+        Err(message) => serde_json::json!({ "error": message }).to_string(),
+    }
+}
+
+/// Whether `request_json` parses as a [`TrainingRequest`], without generating.
+#[wasm_bindgen]
+pub fn validate_request_json(request_json: &str) -> bool {
+    serde_json::from_str::<TrainingRequest>(request_json).is_ok()
+}
+
+#[cfg(test)]
+mod wasm_test {
+    use super::*;
+    use crate::training_engine::{TrainingScenario, TrainingTopic};
+
+    #[test]
+    fn json_round_trip_for_every_topic() {
+        for topic in TrainingTopic::ALL {
+            let request = TrainingRequest { rng_seed: Some(7), ..TrainingRequest::new(topic) };
+            let request_json = serde_json::to_string(&request).unwrap();
+            assert!(validate_request_json(&request_json));
+
+            let scenario: TrainingScenario =
+                serde_json::from_str(&generate_training_json(&request_json)).unwrap();
+            assert_eq!(scenario, generate_training(request).unwrap(), "{topic:?}");
+        }
+    }
+
+    #[test]
+    fn bad_requests_are_rejected() {
+        assert!(!validate_request_json("{}"));
+        assert!(!validate_request_json(r#"{"topic": {"Topic": "NoSuchTopic"}}"#));
+        assert!(validate_request_json(r#"{"topic": {"Street": "Flop"}}"#));
+
+        let reply: serde_json::Value = serde_json::from_str(&generate_training_json("not json")).unwrap();
+        assert!(reply["error"].as_str().is_some_and(|e| !e.is_empty()), "{reply}");
+    }
+}